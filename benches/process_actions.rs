@@ -0,0 +1,109 @@
+//! Measures how long it takes to process the actions of an `InputMap`,
+//! comparing `InputMap::process_actions_in_context` to `InputMap::process_actions_with_state`
+//! when most actions are disabled.
+//!
+//! Run with `cargo bench --bench process_actions`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use bevy::prelude::*;
+use leafwing_input_manager::buttonlike::ButtonValue;
+use leafwing_input_manager::input_map::{InputContexts, UpdatedActions};
+use leafwing_input_manager::prelude::updating::CentralInputStore;
+use leafwing_input_manager::prelude::*;
+
+#[derive(Actionlike, Clone, Copy, Debug, Reflect, PartialEq, Eq, Hash)]
+enum Action {
+    Jump,
+    Run,
+    Crouch,
+    #[actionlike(Axis)]
+    Throttle,
+    #[actionlike(Axis)]
+    Steer,
+    #[actionlike(DualAxis)]
+    Move,
+    #[actionlike(DualAxis)]
+    Look,
+}
+
+const ITERATIONS: u32 = 100_000;
+
+fn input_map() -> InputMap<Action> {
+    InputMap::default()
+        .with(Action::Jump, KeyCode::Space)
+        .with(Action::Run, KeyCode::ShiftLeft)
+        .with(Action::Crouch, KeyCode::ControlLeft)
+        .with_axis(
+            Action::Throttle,
+            VirtualAxis::ws().with_deadzone_symmetric(0.1),
+        )
+        .with_axis(
+            Action::Steer,
+            VirtualAxis::ad().with_deadzone_symmetric(0.1),
+        )
+        .with_dual_axis(Action::Move, VirtualDPad::wasd().with_circle_deadzone(0.1))
+        .with_dual_axis(
+            Action::Look,
+            VirtualDPad::arrow_keys().with_circle_deadzone(0.1),
+        )
+}
+
+fn input_store() -> CentralInputStore {
+    let mut input_store = CentralInputStore::default();
+    for key in [
+        KeyCode::Space,
+        KeyCode::KeyW,
+        KeyCode::KeyD,
+        KeyCode::ArrowUp,
+    ] {
+        input_store.update_buttonlike(key, ButtonValue::from_pressed(true));
+    }
+    input_store
+}
+
+fn bench(
+    process: impl Fn(&InputMap<Action>, &CentralInputStore) -> UpdatedActions<Action>,
+) -> Duration {
+    let input_map = input_map();
+    let input_store = input_store();
+
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        black_box(process(black_box(&input_map), black_box(&input_store)));
+    }
+    start.elapsed() / ITERATIONS
+}
+
+fn main() {
+    let contexts = InputContexts::default();
+    let mut action_state = ActionState::<Action>::default();
+    for action in [Action::Throttle, Action::Steer, Action::Move, Action::Look] {
+        action_state.disable_action(&action);
+    }
+
+    println!(
+        "process_actions_in_context: {:?} per call",
+        bench(|input_map, input_store| {
+            input_map.process_actions_in_context(
+                None,
+                input_store,
+                ClashStrategy::PrioritizeLongest,
+                &contexts,
+            )
+        })
+    );
+    println!(
+        "process_actions_with_state: {:?} per call",
+        bench(|input_map, input_store| {
+            input_map.process_actions_with_state(
+                None,
+                input_store,
+                ClashStrategy::PrioritizeLongest,
+                &contexts,
+                &action_state,
+            )
+        })
+    );
+}
@@ -0,0 +1,80 @@
+//! Measures how long it takes to tick many `ActionState` components,
+//! comparing the `tick_action_state` system to ticking each action state one by one.
+//!
+//! Run with `cargo bench --bench tick_action_state`.
+//! Parallel iteration requires Bevy's `multi_threaded` feature.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use bevy::prelude::*;
+use bevy::time::Real;
+use leafwing_input_manager::prelude::*;
+use leafwing_input_manager::systems::tick_action_state;
+
+#[derive(Actionlike, Clone, Copy, Debug, Reflect, PartialEq, Eq, Hash)]
+enum Action {
+    Jump,
+    Run,
+    Crouch,
+}
+
+const ENTITIES: usize = 10_000;
+const ITERATIONS: u32 = 100;
+
+fn action_state(index: usize) -> ActionState<Action> {
+    let mut action_state = ActionState::default();
+    match index % 3 {
+        0 => action_state.press(&Action::Jump),
+        1 => action_state.press(&Action::Run),
+        _ => action_state.release(&Action::Crouch),
+    }
+    action_state
+}
+
+fn bench_system() -> Duration {
+    let mut app = App::new();
+    app.add_plugins(TaskPoolPlugin::default())
+        .init_resource::<Time<Real>>()
+        .add_systems(Update, tick_action_state::<Action>);
+    for index in 0..ENTITIES {
+        app.world_mut().spawn(action_state(index));
+    }
+
+    // Warm up the schedule before measuring
+    app.update();
+
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        app.world_mut().resource_mut::<Time<Real>>().update();
+        app.update();
+    }
+    start.elapsed() / ITERATIONS
+}
+
+fn bench_serial() -> Duration {
+    let mut action_states: Vec<_> = (0..ENTITIES).map(action_state).collect();
+
+    let start = Instant::now();
+    let mut previous_instant = Instant::now();
+    for _ in 0..ITERATIONS {
+        let current_instant = Instant::now();
+        for action_state in action_states.iter_mut() {
+            action_state.tick(current_instant, previous_instant);
+        }
+        previous_instant = current_instant;
+        black_box(&action_states);
+    }
+    start.elapsed() / ITERATIONS
+}
+
+fn main() {
+    println!(
+        "tick_action_state system: {:?} per tick of {ENTITIES} entities",
+        bench_system()
+    );
+    println!(
+        "serial ticks:             {:?} per tick of {ENTITIES} entities",
+        bench_serial()
+    );
+}
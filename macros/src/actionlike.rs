@@ -2,7 +2,8 @@ use crate::utils;
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
 use std::collections::BTreeMap;
-use syn::{Attribute, Data, DataEnum, DeriveInput, Error, Ident};
+use syn::punctuated::Punctuated;
+use syn::{Attribute, Data, DataEnum, DeriveInput, Error, Expr, Ident, Lit, LitStr, Meta, Token};
 
 // This approach and implementation is inspired by the `strum` crate,
 // Copyright (c) 2019 Peter Glotfelty
@@ -15,39 +16,84 @@ pub(crate) fn actionlike_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
 
     let crate_path = utils::crate_path();
 
-    let default_control = parse_default_control(ast)?;
+    let default_attr = parse_default_attr(ast)?;
+    let default_control = default_attr
+        .control
+        .unwrap_or_else(|| Ident::new("Button", Span::call_site()));
     let input_control_kind_body =
         generate_input_control_kind_body(ast, &crate_path, &default_control)?;
+    let group_fn = generate_group_fn(ast, default_attr.group.as_ref())?;
+    let variants_fn = generate_variants_fn(ast)?;
     Ok(quote! {
         impl #impl_generics #crate_path::Actionlike for #enum_name #type_generics #where_clause {
             fn input_control_kind(&self) -> #crate_path::InputControlKind {
                 #input_control_kind_body
             }
+
+            #group_fn
+
+            #variants_fn
         }
     })
 }
 
-fn parse_default_control(ast: &DeriveInput) -> syn::Result<Ident> {
+/// The contents of an `#[actionlike(...)]` attribute.
+#[derive(Default)]
+struct ActionlikeAttr {
+    /// The `InputControlKind` variant, like `Button`.
+    control: Option<Ident>,
+    /// The group name, set with `group = "name"`.
+    group: Option<LitStr>,
+}
+
+fn parse_default_attr(ast: &DeriveInput) -> syn::Result<ActionlikeAttr> {
     if let Some(attr) = ast
         .attrs
         .iter()
         .find(|attr| attr.meta.path().is_ident("actionlike"))
     {
-        parse_control_attr(attr)
+        parse_actionlike_attr(attr)
     } else {
-        Ok(Ident::new("Button", Span::call_site()))
+        Ok(ActionlikeAttr::default())
     }
 }
 
-fn parse_control_attr(attr: &Attribute) -> syn::Result<Ident> {
-    attr.meta
+fn parse_actionlike_attr(attr: &Attribute) -> syn::Result<ActionlikeAttr> {
+    let error = || {
+        let span = quote!(#attr);
+        let msg =
+            "expected items like `#[actionlike(Button)]` or `#[actionlike(group = \"name\")]`";
+        Error::new_spanned(span, msg)
+    };
+
+    let items = attr
+        .meta
         .require_list()
-        .and_then(|list| list.parse_args::<Ident>())
-        .map_err(|_| {
-            let span = quote!(#attr);
-            let msg = "expected only one item like `#[actionlike(Button)]`";
-            Error::new_spanned(span, msg)
-        })
+        .and_then(|list| list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated))
+        .map_err(|_| error())?;
+
+    let mut parsed = ActionlikeAttr::default();
+    for item in items {
+        match item {
+            Meta::Path(path) if parsed.control.is_none() => {
+                parsed.control = Some(path.require_ident().map_err(|_| error())?.clone());
+            }
+            Meta::NameValue(name_value)
+                if name_value.path.is_ident("group") && parsed.group.is_none() =>
+            {
+                match name_value.value {
+                    Expr::Lit(expr) => match expr.lit {
+                        Lit::Str(group) => parsed.group = Some(group),
+                        _ => return Err(error()),
+                    },
+                    _ => return Err(error()),
+                }
+            }
+            _ => return Err(error()),
+        }
+    }
+
+    Ok(parsed)
 }
 
 fn generate_input_control_kind_body(
@@ -65,7 +111,7 @@ fn generate_input_control_kind_body(
 
             let controls: Vec<_> = controls
                 .iter()
-                .map(|(variant, control)| quote!(Self::#variant => #crate_path::InputControlKind::#control,))
+                .map(|(variant, control)| quote!(Self::#variant { .. } => #crate_path::InputControlKind::#control,))
                 .collect();
             Ok(quote! {
                 match self {
@@ -89,11 +135,95 @@ fn parse_variant_controls(
             .iter()
             .filter(|attr| attr.meta.path().is_ident("actionlike"))
         {
-            let control = parse_control_attr(attr)?;
-            if &control != default_control {
-                map.insert(variant.ident.clone(), control);
+            if let Some(control) = parse_actionlike_attr(attr)?.control {
+                if &control != default_control {
+                    map.insert(variant.ident.clone(), control);
+                }
             }
         }
     }
     Ok(map)
 }
+
+fn generate_group_fn(
+    ast: &DeriveInput,
+    default_group: Option<&LitStr>,
+) -> Result<TokenStream, Error> {
+    let groups = match &ast.data {
+        Data::Enum(enum_data) => parse_variant_groups(enum_data)?,
+        _ => BTreeMap::new(),
+    };
+
+    // Fall back to the trait's default implementation when no groups are assigned.
+    if groups.is_empty() && default_group.is_none() {
+        return Ok(TokenStream::new());
+    }
+
+    let default_group = match default_group {
+        Some(group) => quote!(::core::option::Option::Some(#group)),
+        None => quote!(::core::option::Option::None),
+    };
+
+    let groups: Vec<_> = groups
+        .iter()
+        .map(|(variant, group)| quote!(Self::#variant { .. } => ::core::option::Option::Some(#group),))
+        .collect();
+    Ok(quote! {
+        fn group(&self) -> ::core::option::Option<&'static str> {
+            match self {
+                #(#groups)*
+                #[allow(unreachable_patterns)]
+                _ => #default_group,
+            }
+        }
+    })
+}
+
+fn parse_variant_groups(data: &DataEnum) -> syn::Result<BTreeMap<Ident, LitStr>> {
+    let mut map = BTreeMap::<Ident, LitStr>::new();
+    for variant in data.variants.iter() {
+        for attr in variant
+            .attrs
+            .iter()
+            .filter(|attr| attr.meta.path().is_ident("actionlike"))
+        {
+            if let Some(group) = parse_actionlike_attr(attr)?.group {
+                map.insert(variant.ident.clone(), group);
+            }
+        }
+    }
+    Ok(map)
+}
+
+fn generate_variants_fn(ast: &DeriveInput) -> syn::Result<TokenStream> {
+    let Data::Enum(enum_data) = &ast.data else {
+        // Fall back to the trait's default implementation for structs and unions.
+        return Ok(TokenStream::new());
+    };
+
+    // Variants with fields can't be constructed without knowing their values,
+    // so the enum can't be enumerated.
+    let errors = enum_data
+        .variants
+        .iter()
+        .filter(|variant| !variant.fields.is_empty())
+        .map(|variant| {
+            let msg = "`Actionlike` can only be derived for enums whose variants have no fields, \
+                as `Actionlike::variants` must be able to list every action";
+            Error::new_spanned(&variant.fields, msg)
+        })
+        .reduce(|mut errors, error| {
+            errors.combine(error);
+            errors
+        });
+    if let Some(errors) = errors {
+        return Err(errors);
+    }
+
+    let variants = enum_data.variants.iter().map(|variant| &variant.ident);
+    Ok(quote! {
+        fn variants() -> impl ::core::iter::Iterator<Item = Self> {
+            [#(Self::#variants),*].into_iter()
+        }
+    })
+}
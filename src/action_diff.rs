@@ -12,7 +12,7 @@ use bevy::{
         query::QueryFilter,
     },
     math::{Vec2, Vec3},
-    prelude::{EntityMapper, EventWriter, Query, Res},
+    prelude::{EntityMapper, EventWriter, Query, Reflect, Res, Resource},
     utils::{HashMap, HashSet},
 };
 use serde::{Deserialize, Serialize};
@@ -40,6 +40,12 @@ pub enum ActionDiff<A: Actionlike> {
         /// The value of the action
         action: A,
     },
+    /// The action was [consumed](ActionState::consume),
+    /// releasing it until its inputs are released and pressed again
+    Consumed {
+        /// The value of the action
+        action: A,
+    },
     /// The value of the action changed
     AxisChanged {
         /// The value of the action
@@ -86,6 +92,27 @@ impl<A: Actionlike> MapEntities for ActionDiffEvent<A> {
     }
 }
 
+/// The smallest change in the value of an axislike, dual-axislike or triple-axislike action
+/// that [`generate_action_diffs`](crate::systems::generate_action_diffs) will send an [`ActionDiff`] for.
+///
+/// Smaller changes are accumulated until they exceed this threshold,
+/// so slowly drifting values are still sent eventually, without sending a diff every frame.
+/// Changes to or from exactly zero are always sent.
+/// Dual-axis and triple-axis changes are measured by the distance between the old and new values.
+///
+/// Buttonlike presses and releases are never suppressed.
+///
+/// If this resource does not exist, the [`Default`] value of `1e-4` is used.
+/// Set this to `0.0` to send every change.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct ActionDiffEpsilon(pub f32);
+
+impl Default for ActionDiffEpsilon {
+    fn default() -> Self {
+        Self(1e-4)
+    }
+}
+
 /// Stores the state of all actions in the current frame.
 ///
 /// Inside of the hashmap, [`Entity::PLACEHOLDER`] represents the global / resource state of the action.
@@ -95,6 +122,7 @@ pub struct SummarizedActionState<A: Actionlike> {
     axis_state_map: HashMap<Entity, HashMap<A, f32>>,
     dual_axis_state_map: HashMap<Entity, HashMap<A, Vec2>>,
     triple_axis_state_map: HashMap<Entity, HashMap<A, Vec3>>,
+    consumed_state_map: HashMap<Entity, HashSet<A>>,
 }
 
 impl<A: Actionlike> SummarizedActionState<A> {
@@ -134,18 +162,23 @@ impl<A: Actionlike> SummarizedActionState<A> {
         let mut axis_state_map = HashMap::default();
         let mut dual_axis_state_map = HashMap::default();
         let mut triple_axis_state_map = HashMap::default();
+        let mut consumed_state_map = HashMap::default();
 
         if let Some(global_action_state) = global_action_state {
             let mut per_entity_button_state = HashMap::default();
             let mut per_entity_axis_state = HashMap::default();
             let mut per_entity_dual_axis_state = HashMap::default();
             let mut per_entity_triple_axis_state = HashMap::default();
+            let mut per_entity_consumed_state = HashSet::default();
 
             for (action, action_data) in global_action_state.all_action_data() {
                 match &action_data.kind_data {
                     ActionKindData::Button(button_data) => {
                         per_entity_button_state
                             .insert(action.clone(), button_data.to_button_value());
+                        if button_data.consumed {
+                            per_entity_consumed_state.insert(action.clone());
+                        }
                     }
                     ActionKindData::Axis(axis_data) => {
                         per_entity_axis_state.insert(action.clone(), axis_data.value);
@@ -164,6 +197,7 @@ impl<A: Actionlike> SummarizedActionState<A> {
             axis_state_map.insert(Entity::PLACEHOLDER, per_entity_axis_state);
             dual_axis_state_map.insert(Entity::PLACEHOLDER, per_entity_dual_axis_state);
             triple_axis_state_map.insert(Entity::PLACEHOLDER, per_entity_triple_axis_state);
+            consumed_state_map.insert(Entity::PLACEHOLDER, per_entity_consumed_state);
         }
 
         for (entity, action_state) in action_state_query.iter() {
@@ -171,12 +205,16 @@ impl<A: Actionlike> SummarizedActionState<A> {
             let mut per_entity_axis_state = HashMap::default();
             let mut per_entity_dual_axis_state = HashMap::default();
             let mut per_entity_triple_axis_state = HashMap::default();
+            let mut per_entity_consumed_state = HashSet::default();
 
             for (action, action_data) in action_state.all_action_data() {
                 match &action_data.kind_data {
                     ActionKindData::Button(button_data) => {
                         per_entity_button_state
                             .insert(action.clone(), button_data.to_button_value());
+                        if button_data.consumed {
+                            per_entity_consumed_state.insert(action.clone());
+                        }
                     }
                     ActionKindData::Axis(axis_data) => {
                         per_entity_axis_state.insert(action.clone(), axis_data.value);
@@ -195,6 +233,7 @@ impl<A: Actionlike> SummarizedActionState<A> {
             axis_state_map.insert(entity, per_entity_axis_state);
             dual_axis_state_map.insert(entity, per_entity_dual_axis_state);
             triple_axis_state_map.insert(entity, per_entity_triple_axis_state);
+            consumed_state_map.insert(entity, per_entity_consumed_state);
         }
 
         Self {
@@ -202,12 +241,63 @@ impl<A: Actionlike> SummarizedActionState<A> {
             axis_state_map,
             dual_axis_state_map,
             triple_axis_state_map,
+            consumed_state_map,
         }
     }
 
+    /// Replaces each axislike, dual-axislike and triple-axislike value that differs from its `previous` value
+    /// by no more than `epsilon` with the `previous` value,
+    /// so that no [`ActionDiff`] is generated for it.
+    ///
+    /// Changes to or from exactly zero are never coalesced,
+    /// so that receivers always know when an axis comes to rest or starts moving.
+    /// Missing previous values are treated as default.
+    /// Buttonlike values are left unchanged.
+    pub fn coalesce_axes(&mut self, previous: &Self, epsilon: f32) {
+        fn coalesce<A: Actionlike, T: Copy + Default + PartialEq>(
+            current: &mut HashMap<Entity, HashMap<A, T>>,
+            previous: &HashMap<Entity, HashMap<A, T>>,
+            within_epsilon: impl Fn(T, T) -> bool,
+        ) {
+            for (entity, current_values) in current.iter_mut() {
+                let previous_values = previous.get(entity);
+                for (action, current_value) in current_values.iter_mut() {
+                    let previous_value = previous_values
+                        .and_then(|previous_values| previous_values.get(action))
+                        .copied()
+                        .unwrap_or_default();
+
+                    let zero = T::default();
+                    let crosses_zero = (previous_value == zero) != (*current_value == zero);
+                    if !crosses_zero && within_epsilon(previous_value, *current_value) {
+                        *current_value = previous_value;
+                    }
+                }
+            }
+        }
+
+        coalesce(
+            &mut self.axis_state_map,
+            &previous.axis_state_map,
+            |a, b| (a - b).abs() <= epsilon,
+        );
+        coalesce(
+            &mut self.dual_axis_state_map,
+            &previous.dual_axis_state_map,
+            |a, b| a.distance(b) <= epsilon,
+        );
+        coalesce(
+            &mut self.triple_axis_state_map,
+            &previous.triple_axis_state_map,
+            |a, b| a.distance(b) <= epsilon,
+        );
+    }
+
     /// Generates an [`ActionDiff`] for button data,
     /// if the button has changed state.
     ///
+    /// A [`ActionDiff::Released`] is only generated when the button was previously pressed,
+    /// so a button that stays released never generates a second release.
     ///
     /// Previous values will be treated as default if they were not present.
     pub fn button_diff(
@@ -218,16 +308,35 @@ impl<A: Actionlike> SummarizedActionState<A> {
         let previous_button = previous_button.unwrap_or_default();
         let current_button = current_button?;
 
-        (previous_button != current_button).then(|| {
-            if current_button.pressed {
-                ActionDiff::Pressed {
-                    action,
-                    value: current_button.value,
-                }
-            } else {
-                ActionDiff::Released { action }
-            }
-        })
+        if current_button.pressed {
+            (previous_button != current_button).then_some(ActionDiff::Pressed {
+                action,
+                value: current_button.value,
+            })
+        } else {
+            previous_button
+                .pressed
+                .then_some(ActionDiff::Released { action })
+        }
+    }
+
+    /// Generates an [`ActionDiff::Consumed`] if the button was [consumed](ActionState::consume) since the previous frame,
+    /// falling back to [`SummarizedActionState::button_diff`] otherwise.
+    ///
+    /// As consuming an action releases it, no separate [`ActionDiff::Released`] is generated for it,
+    /// and physically releasing its inputs later does not generate another.
+    pub fn consumable_button_diff(
+        action: A,
+        previous_button: Option<ButtonValue>,
+        current_button: Option<ButtonValue>,
+        previously_consumed: bool,
+        currently_consumed: bool,
+    ) -> Option<ActionDiff<A>> {
+        if currently_consumed && !previously_consumed {
+            Some(ActionDiff::Consumed { action })
+        } else {
+            Self::button_diff(action, previous_button, current_button)
+        }
     }
 
     /// Generates an [`ActionDiff`] for axis data,
@@ -286,14 +395,26 @@ impl<A: Actionlike> SummarizedActionState<A> {
 
         if let Some(current_button_state) = self.button_state_map.get(entity) {
             let previous_button_state = previous.button_state_map.get(entity);
+            let current_consumed_state = self.consumed_state_map.get(entity);
+            let previous_consumed_state = previous.consumed_state_map.get(entity);
             for (action, current_button) in current_button_state {
                 let previous_button = previous_button_state
                     .and_then(|previous_button_state| previous_button_state.get(action))
                     .copied();
-
-                if let Some(diff) =
-                    Self::button_diff(action.clone(), previous_button, Some(*current_button))
-                {
+                let previously_consumed =
+                    previous_consumed_state.is_some_and(|previous_consumed_state| {
+                        previous_consumed_state.contains(action)
+                    });
+                let currently_consumed = current_consumed_state
+                    .is_some_and(|current_consumed_state| current_consumed_state.contains(action));
+
+                if let Some(diff) = Self::consumable_button_diff(
+                    action.clone(),
+                    previous_button,
+                    Some(*current_button),
+                    previously_consumed,
+                    currently_consumed,
+                ) {
                     action_diffs.push(diff);
                 }
             }
@@ -376,6 +497,7 @@ impl<A: Actionlike> Default for SummarizedActionState<A> {
             axis_state_map: Default::default(),
             dual_axis_state_map: Default::default(),
             triple_axis_state_map: Default::default(),
+            consumed_state_map: Default::default(),
         }
     }
 }
@@ -433,11 +555,15 @@ mod tests {
         global_triple_axis_state.insert(TestAction::TripleAxis, Vec3::new(0.5, 0.7, 0.9));
         triple_axis_state_map.insert(entity, global_triple_axis_state);
 
+        let mut consumed_state_map = HashMap::default();
+        consumed_state_map.insert(entity, HashSet::default());
+
         SummarizedActionState {
             button_state_map,
             axis_state_map,
             dual_axis_state_map,
             triple_axis_state_map,
+            consumed_state_map,
         }
     }
 
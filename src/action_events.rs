@@ -0,0 +1,39 @@
+//! Events sent when buttonlike actions are pressed or released.
+//!
+//! These allow game logic to be driven by [`EventReader`](bevy::prelude::EventReader)s or observers,
+//! rather than polling [`ActionState::just_pressed`] every frame.
+//! They are only sent once enabled using [`InputManagerPlugin::send_action_events`](crate::plugin::InputManagerPlugin::send_action_events).
+
+use bevy::ecs::{entity::Entity, event::Event};
+
+#[cfg(doc)]
+use crate::action_state::ActionState;
+use crate::Actionlike;
+
+/// Sent when a buttonlike action is just pressed.
+///
+/// This is both written to the [`Events<ActionPressed<A>>`](bevy::prelude::Events) resource and triggered for observers.
+/// When the [`ActionState`] is a component, the event targets its entity.
+#[derive(Debug, Clone, PartialEq, Eq, Event)]
+pub struct ActionPressed<A: Actionlike> {
+    /// The entity whose [`ActionState`] component changed,
+    /// or `None` if the [`ActionState`] is a resource.
+    pub entity: Option<Entity>,
+
+    /// The action that was just pressed.
+    pub action: A,
+}
+
+/// Sent when a buttonlike action is just released.
+///
+/// This is both written to the [`Events<ActionReleased<A>>`](bevy::prelude::Events) resource and triggered for observers.
+/// When the [`ActionState`] is a component, the event targets its entity.
+#[derive(Debug, Clone, PartialEq, Eq, Event)]
+pub struct ActionReleased<A: Actionlike> {
+    /// The entity whose [`ActionState`] component changed,
+    /// or `None` if the [`ActionState`] is a resource.
+    pub entity: Option<Entity>,
+
+    /// The action that was just released.
+    pub action: A,
+}
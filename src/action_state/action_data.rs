@@ -7,10 +7,14 @@ use bevy::{
 };
 use serde::{Deserialize, Serialize};
 
-use crate::buttonlike::ButtonValue;
+use crate::buttonlike::{BufferConfig, ButtonValue};
+use crate::input_processing::ExponentialSmoothing;
 #[cfg(feature = "timing")]
-use crate::timing::Timing;
-use crate::{buttonlike::ButtonState, InputControlKind};
+use crate::timing::{RepeatConfig, Timing};
+use crate::user_input::Buttonlike;
+use crate::{buttonlike::ButtonState, Actionlike, InputControlKind};
+#[cfg(feature = "timing")]
+use bevy::utils::Duration;
 
 /// Data about the state of an action.
 ///
@@ -42,17 +46,61 @@ impl ActionData {
         }
     }
 
+    /// Is this the default data for the [`InputControlKind`] of the `action`,
+    /// as created by [`ActionData::from_kind`]?
+    ///
+    /// The [`ButtonData::triggering_input`] is ignored, as it is only tracked at runtime and isn't serialized.
+    pub fn is_default_for<A: Actionlike>(&self, action: &A) -> bool {
+        let mut default = Self::from_kind(action.input_control_kind());
+        if let (ActionKindData::Button(data), ActionKindData::Button(default_data)) =
+            (&self.kind_data, &mut default.kind_data)
+        {
+            default_data
+                .triggering_input
+                .clone_from(&data.triggering_input);
+        }
+        *self == default
+    }
+
     /// Ticks the action data, updating the state of the action.
     pub fn tick(&mut self, _current_instant: Instant, _previous_instant: Instant) {
         match self.kind_data {
             ActionKindData::Button(ref mut data) => {
+                data.buffered_frames = if data.state.just_pressed() {
+                    data.buffer.frames
+                } else {
+                    data.buffered_frames.saturating_sub(1)
+                };
+
                 data.state.tick();
+                data.suppressed = false;
 
                 #[cfg(feature = "timing")]
-                data.timing.tick(_current_instant, _previous_instant);
+                {
+                    let held_before = data.timing.current_duration;
+                    data.timing.tick(_current_instant, _previous_instant);
+                    data.cooldown_remaining = data.cooldown_remaining.saturating_sub(
+                        _current_instant.saturating_duration_since(_previous_instant),
+                    );
+
+                    // While pressed, the timing started when the action was pressed
+                    if data.state.pressed() {
+                        data.timing.instant_pressed = data.timing.instant_started;
+                    }
+
+                    // Held actions are pressed again each time that they repeat
+                    if let Some(repeat) = data.repeat {
+                        let held = data.timing.current_duration;
+                        if data.state.pressed() && repeat.repeats_between(held_before, held) {
+                            data.state = ButtonState::JustPressed;
+                        }
+                    }
+                }
             }
             ActionKindData::Axis(ref mut _data) => {}
-            ActionKindData::DualAxis(ref mut _data) => {}
+            ActionKindData::DualAxis(ref mut data) => {
+                data.previous_pair = data.pair;
+            }
             ActionKindData::TripleAxis(ref mut _data) => {}
         }
     }
@@ -79,8 +127,17 @@ impl ActionKindData {
             Self::Button(data) => {
                 data.fixed_update_state = data.state;
                 data.fixed_update_value = data.value;
+                data.fixed_update_suppressed = data.suppressed;
+                data.fixed_update_buffered_frames = data.buffered_frames;
                 data.state = data.update_state;
                 data.value = data.update_value;
+                data.suppressed = data.update_suppressed;
+                data.buffered_frames = data.update_buffered_frames;
+                #[cfg(feature = "timing")]
+                {
+                    data.fixed_update_cooldown_remaining = data.cooldown_remaining;
+                    data.cooldown_remaining = data.update_cooldown_remaining;
+                }
             }
             Self::Axis(data) => {
                 data.fixed_update_value = data.value;
@@ -88,7 +145,9 @@ impl ActionKindData {
             }
             Self::DualAxis(data) => {
                 data.fixed_update_pair = data.pair;
+                data.fixed_update_previous_pair = data.previous_pair;
                 data.pair = data.update_pair;
+                data.previous_pair = data.update_previous_pair;
             }
             Self::TripleAxis(data) => {
                 data.fixed_update_triple = data.triple;
@@ -97,6 +156,51 @@ impl ActionKindData {
         }
     }
 
+    pub(super) fn clear_schedule_states(&mut self) {
+        // reset the `state` along with the saved `update_state` and `fixed_update_state`,
+        // keeping the configuration of the action
+        match self {
+            Self::Button(data) => {
+                data.state = ButtonState::Released;
+                data.update_state = ButtonState::Released;
+                data.fixed_update_state = ButtonState::Released;
+                data.value = 0.0;
+                data.update_value = 0.0;
+                data.fixed_update_value = 0.0;
+                data.suppressed = false;
+                data.update_suppressed = false;
+                data.fixed_update_suppressed = false;
+                data.buffered_frames = 0;
+                data.update_buffered_frames = 0;
+                data.fixed_update_buffered_frames = 0;
+                #[cfg(feature = "timing")]
+                {
+                    data.cooldown_remaining = Duration::ZERO;
+                    data.update_cooldown_remaining = Duration::ZERO;
+                    data.fixed_update_cooldown_remaining = Duration::ZERO;
+                }
+            }
+            Self::Axis(data) => {
+                data.value = 0.0;
+                data.update_value = 0.0;
+                data.fixed_update_value = 0.0;
+            }
+            Self::DualAxis(data) => {
+                data.pair = Vec2::ZERO;
+                data.update_pair = Vec2::ZERO;
+                data.fixed_update_pair = Vec2::ZERO;
+                data.previous_pair = Vec2::ZERO;
+                data.update_previous_pair = Vec2::ZERO;
+                data.fixed_update_previous_pair = Vec2::ZERO;
+            }
+            Self::TripleAxis(data) => {
+                data.triple = Vec3::ZERO;
+                data.update_triple = Vec3::ZERO;
+                data.fixed_update_triple = Vec3::ZERO;
+            }
+        }
+    }
+
     pub(super) fn swap_to_fixed_update_state(&mut self) {
         // save the changes applied to `state` into `update_state`
         // switch to loading the `fixed_update_state` into `state`
@@ -104,8 +208,17 @@ impl ActionKindData {
             Self::Button(data) => {
                 data.update_state = data.state;
                 data.update_value = data.value;
+                data.update_suppressed = data.suppressed;
+                data.update_buffered_frames = data.buffered_frames;
                 data.state = data.fixed_update_state;
                 data.value = data.fixed_update_value;
+                data.suppressed = data.fixed_update_suppressed;
+                data.buffered_frames = data.fixed_update_buffered_frames;
+                #[cfg(feature = "timing")]
+                {
+                    data.update_cooldown_remaining = data.cooldown_remaining;
+                    data.cooldown_remaining = data.fixed_update_cooldown_remaining;
+                }
             }
             Self::Axis(data) => {
                 data.update_value = data.value;
@@ -113,7 +226,9 @@ impl ActionKindData {
             }
             Self::DualAxis(data) => {
                 data.update_pair = data.pair;
+                data.update_previous_pair = data.previous_pair;
                 data.pair = data.fixed_update_pair;
+                data.previous_pair = data.fixed_update_previous_pair;
             }
             Self::TripleAxis(data) => {
                 data.update_triple = data.triple;
@@ -138,9 +253,66 @@ pub struct ButtonData {
     pub update_value: f32,
     /// The `value` of the action in the `FixedMain` schedule
     pub fixed_update_value: f32,
+    /// Has the action been consumed?
+    ///
+    /// Consumed actions remain released until their inputs are released and pressed again.
+    #[serde(default)]
+    pub consumed: bool,
+    /// Is the action suppressed until the next tick?
+    ///
+    /// Suppressed actions report as released, but their underlying state is still tracked.
+    #[serde(default)]
+    pub suppressed: bool,
+    /// Whether the action is suppressed in the `Main` schedule
+    #[serde(default)]
+    pub update_suppressed: bool,
+    /// Whether the action is suppressed in the `FixedMain` schedule
+    #[serde(default)]
+    pub fixed_update_suppressed: bool,
+    /// How long should presses of the action be buffered for?
+    #[serde(default)]
+    pub buffer: BufferConfig,
+    /// The number of remaining ticks for which the action will be reported as just pressed, due to its `buffer`.
+    #[serde(default)]
+    pub buffered_frames: u32,
+    /// The `buffered_frames` of the action in the `Main` schedule
+    #[serde(default)]
+    pub update_buffered_frames: u32,
+    /// The `buffered_frames` of the action in the `FixedMain` schedule
+    #[serde(default)]
+    pub fixed_update_buffered_frames: u32,
     /// When was the button pressed / released, and how long has it been held for?
     #[cfg(feature = "timing")]
     pub timing: Timing,
+    /// How should the action repeat while held?
+    ///
+    /// If this is `None`, the action does not repeat.
+    #[cfg(feature = "timing")]
+    #[serde(default)]
+    pub repeat: Option<RepeatConfig>,
+    /// How long must the action wait after being fired before it can be just pressed again?
+    #[cfg(feature = "timing")]
+    #[serde(default)]
+    pub cooldown: Duration,
+    /// How much longer until the action can be just pressed again?
+    #[cfg(feature = "timing")]
+    #[serde(default)]
+    pub cooldown_remaining: Duration,
+    /// The `cooldown_remaining` of the action in the `Main` schedule
+    #[cfg(feature = "timing")]
+    #[serde(default)]
+    pub update_cooldown_remaining: Duration,
+    /// The `cooldown_remaining` of the action in the `FixedMain` schedule
+    #[cfg(feature = "timing")]
+    #[serde(default)]
+    pub fixed_update_cooldown_remaining: Duration,
+    /// The input that most recently caused the action to be pressed.
+    ///
+    /// This is only recorded when enabled using
+    /// [`InputManagerPlugin::record_triggering_inputs`](crate::plugin::InputManagerPlugin::record_triggering_inputs),
+    /// and is not serialized.
+    #[serde(skip)]
+    pub triggering_input: Option<Box<dyn Buttonlike>>,
 }
 
 impl ButtonData {
@@ -152,8 +324,27 @@ impl ButtonData {
         value: 1.0,
         update_value: 1.0,
         fixed_update_value: 1.0,
+        consumed: false,
+        suppressed: false,
+        update_suppressed: false,
+        fixed_update_suppressed: false,
+        buffer: BufferConfig::NONE,
+        buffered_frames: 0,
+        update_buffered_frames: 0,
+        fixed_update_buffered_frames: 0,
         #[cfg(feature = "timing")]
         timing: Timing::NEW,
+        #[cfg(feature = "timing")]
+        repeat: None,
+        #[cfg(feature = "timing")]
+        cooldown: Duration::ZERO,
+        #[cfg(feature = "timing")]
+        cooldown_remaining: Duration::ZERO,
+        #[cfg(feature = "timing")]
+        update_cooldown_remaining: Duration::ZERO,
+        #[cfg(feature = "timing")]
+        fixed_update_cooldown_remaining: Duration::ZERO,
+        triggering_input: None,
     };
 
     /// The default data for a button that was just released.
@@ -164,8 +355,27 @@ impl ButtonData {
         value: 0.0,
         update_value: 0.0,
         fixed_update_value: 0.0,
+        consumed: false,
+        suppressed: false,
+        update_suppressed: false,
+        fixed_update_suppressed: false,
+        buffer: BufferConfig::NONE,
+        buffered_frames: 0,
+        update_buffered_frames: 0,
+        fixed_update_buffered_frames: 0,
         #[cfg(feature = "timing")]
         timing: Timing::NEW,
+        #[cfg(feature = "timing")]
+        repeat: None,
+        #[cfg(feature = "timing")]
+        cooldown: Duration::ZERO,
+        #[cfg(feature = "timing")]
+        cooldown_remaining: Duration::ZERO,
+        #[cfg(feature = "timing")]
+        update_cooldown_remaining: Duration::ZERO,
+        #[cfg(feature = "timing")]
+        fixed_update_cooldown_remaining: Duration::ZERO,
+        triggering_input: None,
     };
 
     /// The default data for a button that is released,
@@ -180,8 +390,27 @@ impl ButtonData {
         value: 0.0,
         update_value: 0.0,
         fixed_update_value: 0.0,
+        consumed: false,
+        suppressed: false,
+        update_suppressed: false,
+        fixed_update_suppressed: false,
+        buffer: BufferConfig::NONE,
+        buffered_frames: 0,
+        update_buffered_frames: 0,
+        fixed_update_buffered_frames: 0,
         #[cfg(feature = "timing")]
         timing: Timing::NEW,
+        #[cfg(feature = "timing")]
+        repeat: None,
+        #[cfg(feature = "timing")]
+        cooldown: Duration::ZERO,
+        #[cfg(feature = "timing")]
+        cooldown_remaining: Duration::ZERO,
+        #[cfg(feature = "timing")]
+        update_cooldown_remaining: Duration::ZERO,
+        #[cfg(feature = "timing")]
+        fixed_update_cooldown_remaining: Duration::ZERO,
+        triggering_input: None,
     };
 
     /// Is the action currently pressed?
@@ -191,11 +420,19 @@ impl ButtonData {
         self.state.pressed()
     }
 
-    /// Was the action pressed since the last time it was ticked?
+    /// Was the action pressed since the last time it was ticked,
+    /// or is its press still buffered?
+    ///
+    /// This is always `false` while the action is on cooldown.
     #[inline]
     #[must_use]
     pub fn just_pressed(&self) -> bool {
-        self.state.just_pressed()
+        #[cfg(feature = "timing")]
+        if self.cooldown_remaining > Duration::ZERO {
+            return false;
+        }
+
+        self.state.just_pressed() || self.buffered_frames > 0
     }
 
     /// Is the action currently released?
@@ -229,6 +466,9 @@ pub struct AxisData {
     pub update_value: f32,
     /// The `value` of the action in the `FixedMain` schedule
     pub fixed_update_value: f32,
+    /// The smoothing applied to new values of the action, if any
+    #[serde(default)]
+    pub smoothing: Option<ExponentialSmoothing>,
 }
 
 /// The raw data for an [`ActionState`](super::ActionState) corresponding to a pair of virtual axes.
@@ -240,6 +480,18 @@ pub struct DualAxisData {
     pub update_pair: Vec2,
     /// The `pair` of the action in the `FixedMain` schedule
     pub fixed_update_pair: Vec2,
+    /// The XY coordinates of the axis when the action was last ticked
+    #[serde(default)]
+    pub previous_pair: Vec2,
+    /// The `previous_pair` of the action in the `Main` schedule
+    #[serde(default)]
+    pub update_previous_pair: Vec2,
+    /// The `previous_pair` of the action in the `FixedMain` schedule
+    #[serde(default)]
+    pub fixed_update_previous_pair: Vec2,
+    /// The smoothing applied to new values of the action, if any
+    #[serde(default)]
+    pub smoothing: Option<ExponentialSmoothing>,
 }
 
 /// The raw data for an [`ActionState`](super::ActionState) corresponding to a triple of virtual axes.
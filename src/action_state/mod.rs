@@ -1,18 +1,25 @@
 //! This module contains [`ActionState`] and its supporting methods and impls.
 
+use crate::action_diff::{ActionDiff, SummarizedActionState};
+use crate::buttonlike::BufferConfig;
+use crate::input_map::UpdatedActions;
 use crate::input_map::UpdatedValue;
-use crate::{action_diff::ActionDiff, input_map::UpdatedActions};
+use crate::input_processing::ExponentialSmoothing;
+#[cfg(feature = "timing")]
+use crate::timing::RepeatConfig;
+use crate::user_input::Buttonlike;
 use crate::{Actionlike, InputControlKind};
 
 use bevy::prelude::Resource;
 use bevy::reflect::Reflect;
 #[cfg(feature = "timing")]
 use bevy::utils::Duration;
-use bevy::utils::{HashMap, Instant};
+use bevy::utils::{HashMap, HashSet, Instant};
 use bevy::{ecs::component::Component, prelude::ReflectComponent};
 use bevy::{
+    ecs::query::QueryFilter,
     math::{Vec2, Vec3},
-    prelude::ReflectResource,
+    prelude::{Query, ReflectResource},
 };
 use serde::{Deserialize, Serialize};
 
@@ -25,12 +32,13 @@ pub use action_data::*;
 ///
 /// # Disabling actions
 ///
-/// Actions can be disabled in four different ways, with increasing granularity:
+/// Actions can be disabled in five different ways, with increasing granularity:
 ///
 /// 1. By disabling updates to all actions using a run condition on [`InputManagerSystem::Update`](crate::plugin::InputManagerSystem::Update).
 /// 2. By disabling updates to all actions of type `A` using a run condition on [`TickActionStateSystem::<A>`](crate::plugin::TickActionStateSystem).
 /// 3. By setting a specific action state to disabled using [`ActionState::disable`].
-/// 4. By disabling a specific action using [`ActionState::disable_action`].
+/// 4. By disabling a group of actions using [`ActionState::disable_group`].
+/// 5. By disabling a specific action using [`ActionState::disable_action`].
 ///
 /// More general mechanisms of disabling actions will cause specific mechanisms to be ignored.
 /// For example, if an entire action state is disabled, then enabling or disabling individual actions will have no effect.
@@ -82,21 +90,69 @@ pub use action_data::*;
 /// assert!(action_state.released(&Action::Jump));
 /// assert!(!action_state.just_released(&Action::Jump));
 /// ```
-#[derive(Resource, Component, Clone, Debug, PartialEq, Serialize, Deserialize, Reflect)]
+///
+/// ## Serialization
+///
+/// Actions whose [`ActionData`] is the default for their [`InputControlKind`],
+/// such as buttons that have never been pressed, are omitted when serializing an [`ActionState`].
+/// Missing actions are read as having their default data, so they are recreated lazily after deserializing,
+/// and two [`ActionState`]s compare as equal whether or not such actions are stored.
+#[derive(Resource, Component, Clone, Debug, Serialize, Deserialize, Reflect)]
 #[reflect(Resource, Component)]
 pub struct ActionState<A: Actionlike> {
     /// Whether or not all of the actions are disabled.
     disabled: bool,
+    /// The [groups](Actionlike::group) of actions that are disabled.
+    #[serde(default)]
+    disabled_groups: HashSet<String>,
     /// The shared action data for each action
+    #[serde(
+        serialize_with = "serialize_non_default_action_data",
+        bound(serialize = "A: Serialize")
+    )]
     action_data: HashMap<A, ActionData>,
 }
 
+/// Serializes the [`ActionData`] of each action, skipping the actions whose data is the default for their kind.
+fn serialize_non_default_action_data<A: Actionlike + Serialize, S: serde::Serializer>(
+    action_data: &HashMap<A, ActionData>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_map(
+        action_data
+            .iter()
+            .filter(|(action, action_data)| !action_data.is_default_for(*action)),
+    )
+}
+
+// Actions without stored data have the default data for their kind,
+// so the stored data of these actions is compared to the default instead
+impl<A: Actionlike> PartialEq for ActionState<A> {
+    fn eq(&self, other: &Self) -> bool {
+        let missing_or_default = |action_data: &HashMap<A, ActionData>,
+                                  other: &HashMap<A, ActionData>| {
+            action_data
+                .iter()
+                .all(|(action, action_data)| match other.get(action) {
+                    Some(other_data) => action_data == other_data,
+                    None => action_data.is_default_for(action),
+                })
+        };
+
+        self.disabled == other.disabled
+            && self.disabled_groups == other.disabled_groups
+            && missing_or_default(&self.action_data, &other.action_data)
+            && missing_or_default(&other.action_data, &self.action_data)
+    }
+}
+
 // The derive does not work unless A: Default,
 // so we have to implement it manually
 impl<A: Actionlike> Default for ActionState<A> {
     fn default() -> Self {
         Self {
             disabled: false,
+            disabled_groups: HashSet::default(),
             action_data: HashMap::default(),
         }
     }
@@ -128,6 +184,28 @@ impl<A: Actionlike> ActionState<A> {
         }
     }
 
+    /// Resets the state of every action in both the `Main` and `FixedMain` schedules,
+    /// releasing all buttonlike actions and zeroing all axislike actions,
+    /// such as when teleporting between levels, to prevent stale input from leaking into the new scene.
+    ///
+    /// Unlike [`ActionState::reset_all`], which only affects the schedule currently running,
+    /// this also clears the state saved for the other schedule,
+    /// along with any suppression, buffered presses and remaining cooldowns.
+    /// Configuration such as input buffers, repeats, cooldowns and smoothing is kept,
+    /// as is whether actions are disabled or consumed.
+    ///
+    /// # Fixed update steps
+    ///
+    /// The state used by the `FixedMain` schedule is only updated from inputs once per frame,
+    /// before its first step.
+    /// If this is called during a fixed update step, the remaining steps of that frame will see every action as released,
+    /// and inputs that are still held press their actions again, as just pressed, on the next frame in both schedules.
+    pub fn clear_schedule_states(&mut self) {
+        for action_datum in self.action_data.values_mut() {
+            action_datum.kind_data.clear_schedule_states();
+        }
+    }
+
     /// Updates the [`ActionState`] based on the provided [`UpdatedActions`].
     ///
     /// The `action_data` is typically constructed from [`InputMap::process_actions`](crate::input_map::InputMap::process_actions),
@@ -140,16 +218,28 @@ impl<A: Actionlike> ActionState<A> {
             match updated_value {
                 UpdatedValue::Button(pressed) => {
                     if *pressed {
-                        self.press(action);
+                        // Consumed actions stay released until their inputs are released
+                        if !self.consumed(action) {
+                            self.press(action);
+                        }
                     } else {
                         self.release(action);
+                        self.button_data_mut_or_default(action).consumed = false;
                     }
                 }
                 UpdatedValue::Axis(value) => {
-                    self.set_value(action, *value);
+                    let axis_data = self.axis_data_mut_or_default(action);
+                    axis_data.value = match axis_data.smoothing {
+                        Some(smoothing) => smoothing.smooth_value(axis_data.value, *value),
+                        None => *value,
+                    };
                 }
                 UpdatedValue::DualAxis(pair) => {
-                    self.set_axis_pair(action, *pair);
+                    let dual_axis_data = self.dual_axis_data_mut_or_default(action);
+                    dual_axis_data.pair = match dual_axis_data.smoothing {
+                        Some(smoothing) => smoothing.smooth_pair(dual_axis_data.pair, *pair),
+                        None => *pair,
+                    };
                 }
                 UpdatedValue::TripleAxis(triple) => {
                     self.set_axis_triple(action, *triple);
@@ -210,6 +300,25 @@ impl<A: Actionlike> ActionState<A> {
             .for_each(|action_datum| action_datum.tick(_current_instant, _previous_instant));
     }
 
+    /// Ticks every [`ActionState`] component matched by the `query`, using Bevy's parallel query iteration.
+    ///
+    /// This is what [`tick_action_state`](crate::systems::tick_action_state) uses to tick the [`ActionState`] components,
+    /// and is useful to tick them from your own systems, such as when managing the schedule yourself.
+    /// Parallel iteration requires Bevy's `multi_threaded` feature, and otherwise falls back to ticking them one by one.
+    ///
+    /// As ticking each [`ActionState`] only depends on its own data and the shared timestamps,
+    /// the results are identical to calling [`ActionState::tick`] on each of them in any order,
+    /// so this has no impact on determinism.
+    pub fn tick_all_entities<F: QueryFilter>(
+        query: &mut Query<&mut ActionState<A>, F>,
+        current_instant: Instant,
+        previous_instant: Instant,
+    ) {
+        query.par_iter_mut().for_each(|mut action_state| {
+            action_state.tick(current_instant, previous_instant);
+        });
+    }
+
     /// A reference to the [`ActionData`] corresponding to the `action`.
     #[inline]
     #[must_use]
@@ -551,7 +660,7 @@ impl<A: Actionlike> ActionState<A> {
     pub fn button_value(&self, action: &A) -> f32 {
         debug_assert_eq!(action.input_control_kind(), InputControlKind::Button);
 
-        if self.action_disabled(action) {
+        if self.action_disabled(action) || self.suppressed(action) {
             return 0.0;
         }
 
@@ -638,6 +747,41 @@ impl<A: Actionlike> ActionState<A> {
         self.value(action).clamp(-1., 1.)
     }
 
+    /// Get the value of the `primary` action, falling back to the value of the `fallback` action if it is zero.
+    ///
+    /// This is useful when both an analog and a digital source feed the same control,
+    /// such as a throttle bound to both a trigger and a key:
+    /// the analog `primary` action is preferred whenever it is active.
+    ///
+    /// Both actions may be either buttonlike or axislike,
+    /// and their values are read using [`button_value`](Self::button_value) or [`value`](Self::value) respectively.
+    ///
+    /// # Warning
+    ///
+    /// This value will be 0. by default,
+    /// even if neither action is a buttonlike or axislike action.
+    #[must_use]
+    #[track_caller]
+    pub fn value_preferring(&self, primary: A, fallback: A) -> f32 {
+        let primary_value = self.single_value(&primary);
+        if primary_value != 0.0 {
+            return primary_value;
+        }
+
+        self.single_value(&fallback)
+    }
+
+    /// Get the value of the buttonlike or axislike `action`.
+    #[must_use]
+    #[track_caller]
+    fn single_value(&self, action: &A) -> f32 {
+        match action.input_control_kind() {
+            InputControlKind::Button => self.button_value(action),
+            InputControlKind::Axis => self.value(action),
+            _ => 0.0,
+        }
+    }
+
     /// Get the [`Vec2`] from the binding that triggered the corresponding `action`.
     ///
     /// Only events that represent dual-axis control provide a [`Vec2`],
@@ -687,6 +831,65 @@ impl<A: Actionlike> ActionState<A> {
         pair.clamp(Vec2::NEG_ONE, Vec2::ONE)
     }
 
+    /// Get the [`Vec2`] associated with the corresponding `action` as a movement vector,
+    /// with its length clamped to `1.0` and rotated counterclockwise by `yaw` radians.
+    ///
+    /// This is typically used to move a character relative to the direction a camera is facing.
+    /// Unlike [`clamped_axis_pair`](Self::clamped_axis_pair), the length of the pair is clamped,
+    /// so diagonal movement isn't faster than movement along either axis.
+    ///
+    /// # Warning
+    ///
+    /// This value will be [`Vec2::ZERO`] by default,
+    /// even if the action is not a dual-axislike action.
+    #[must_use]
+    #[track_caller]
+    pub fn movement_vector(&self, action: &A, yaw: f32) -> Vec2 {
+        let pair = self.axis_pair(action).clamp_length_max(1.0);
+        Vec2::from_angle(yaw).rotate(pair)
+    }
+
+    /// Was the dual-axislike `action` pushed past the `threshold` since the last time [tick](ActionState::tick) was called?
+    ///
+    /// Returns the normalized direction of the push on the frame the length of its [`axis_pair`](Self::axis_pair)
+    /// rises above the `threshold`, and [`None`] otherwise.
+    /// The action must return within the `threshold` before it can be pushed again,
+    /// making this useful for discrete "nudges" of an analog stick, such as when navigating menus.
+    ///
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use bevy::utils::Instant;
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+    /// enum Action {
+    ///     #[actionlike(DualAxis)]
+    ///     Navigate,
+    /// }
+    ///
+    /// let mut action_state = ActionState::<Action>::default();
+    /// action_state.set_axis_pair(&Action::Navigate, Vec2::new(0.0, 0.8));
+    /// assert_eq!(action_state.dual_axis_pushed(&Action::Navigate, 0.5), Some(Vec2::Y));
+    ///
+    /// // Holding the stick in place does not push it again
+    /// action_state.tick(Instant::now(), Instant::now());
+    /// assert_eq!(action_state.dual_axis_pushed(&Action::Navigate, 0.5), None);
+    /// ```
+    #[must_use]
+    #[track_caller]
+    pub fn dual_axis_pushed(&self, action: &A, threshold: f32) -> Option<Vec2> {
+        debug_assert_eq!(action.input_control_kind(), InputControlKind::DualAxis);
+
+        if self.action_disabled(action) {
+            return None;
+        }
+
+        let dual_axis_data = self.dual_axis_data(action)?;
+        let pushed = dual_axis_data.pair.length() > threshold
+            && dual_axis_data.previous_pair.length() <= threshold;
+        pushed.then(|| dual_axis_data.pair.normalize_or_zero())
+    }
+
     /// Get the [`Vec3`] from the binding that triggered the corresponding `action`.
     ///
     /// Only events that represent triple-axis control provide a [`Vec3`],
@@ -736,6 +939,32 @@ impl<A: Actionlike> ActionState<A> {
         triple.clamp(Vec3::NEG_ONE, Vec3::ONE)
     }
 
+    /// Get the [`Vec3`] associated with the corresponding `action` in spherical coordinates,
+    /// returned as `(radius, theta, phi)`.
+    ///
+    /// - `radius` is the length of the vector.
+    /// - `theta` is the polar angle in radians, measured from the positive Z axis, in `[0, π]`.
+    /// - `phi` is the azimuthal angle in radians, measured from the positive X axis towards the positive Y axis, in `(-π, π]`.
+    ///
+    /// # Warning
+    ///
+    /// This value will be `(0.0, 0.0, 0.0)` by default,
+    /// even if the action is not a triple-axislike action.
+    #[must_use]
+    #[track_caller]
+    pub fn axis_triple_spherical(&self, action: &A) -> (f32, f32, f32) {
+        let triple = self.axis_triple(action);
+
+        let radius = triple.length();
+        if radius == 0.0 {
+            return (0.0, 0.0, 0.0);
+        }
+
+        let theta = (triple.z / radius).clamp(-1.0, 1.0).acos();
+        let phi = triple.y.atan2(triple.x);
+        (radius, theta, phi)
+    }
+
     /// Manually sets the [`ButtonData`] of the corresponding `action`
     ///
     /// You should almost always use more direct methods, as they are simpler and less error-prone.
@@ -795,12 +1024,26 @@ impl<A: Actionlike> ActionState<A> {
         #[cfg(feature = "timing")]
         if action_data.state.released() {
             action_data.timing.flip();
+            action_data.timing.instant_pressed = None;
+            action_data.timing.instant_consumed = None;
         }
 
         action_data.state.press();
         action_data.value = 1.0;
     }
 
+    /// Press the `action`, recording `value` as how far it has been pressed
+    ///
+    /// This behaves like [`ActionState::press`], but stores the provided `value`
+    /// instead of `1.0`, which is useful for simulating partially pressed analog buttons such as triggers.
+    /// Unlike [`ActionState::set_button_value`], the action is pressed regardless of `value`.
+    #[inline]
+    #[track_caller]
+    pub fn press_with_value(&mut self, action: &A, value: f32) {
+        self.press(action);
+        self.button_data_mut_or_default(action).value = value;
+    }
+
     /// Release the `action`
     ///
     /// No initial instant will be recorded.
@@ -820,6 +1063,141 @@ impl<A: Actionlike> ActionState<A> {
         action_data.value = 0.0;
     }
 
+    /// Consumes the `action`
+    ///
+    /// The action will be released, and will remain released
+    /// until its inputs are released and then pressed again.
+    ///
+    /// This is useful when an action should only be handled once,
+    /// such as when a menu is opened with the same key that would otherwise trigger an in-game ability.
+    ///
+    /// # Re-pressing
+    ///
+    /// Consuming an action immediately releases it, so it will report as [`just_released`](Self::just_released)
+    /// on the frame it was consumed, and an [`ActionDiff::Consumed`] will be generated for it in place of an [`ActionDiff::Released`].
+    ///
+    /// While consumed, [`ActionState::update`] ignores any input that is still holding the action down.
+    /// Once [`ActionState::update`] observes that the inputs have been released,
+    /// the action is no longer consumed, and the next genuine press will press it again.
+    /// Calling [`ActionState::press`] directly bypasses this latch.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bevy::prelude::Reflect;
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+    /// enum Action {
+    ///     Interact,
+    /// }
+    ///
+    /// let mut action_state = ActionState::<Action>::default();
+    /// action_state.press(&Action::Interact);
+    ///
+    /// action_state.consume(&Action::Interact);
+    /// assert!(action_state.consumed(&Action::Interact));
+    /// assert!(action_state.released(&Action::Interact));
+    /// assert!(action_state.just_released(&Action::Interact));
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn consume(&mut self, action: &A) {
+        debug_assert_eq!(action.input_control_kind(), InputControlKind::Button);
+
+        #[cfg(feature = "timing")]
+        {
+            let action_data = self.button_data_mut_or_default(action);
+            if action_data.pressed() {
+                action_data.timing.consume();
+            }
+        }
+
+        self.release(action);
+        let action_data = self.button_data_mut_or_default(action);
+        action_data.consumed = true;
+        action_data.buffered_frames = 0;
+    }
+
+    /// Has this `action` been [consumed](Self::consume) since its inputs were last released?
+    ///
+    /// # Warning
+    ///
+    /// This value will be `false` by default,
+    /// even if the action is not a buttonlike action.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn consumed(&self, action: &A) -> bool {
+        debug_assert_eq!(action.input_control_kind(), InputControlKind::Button);
+
+        self.button_data(action)
+            .is_some_and(|button_data| button_data.consumed)
+    }
+
+    /// Consumes all [`Buttonlike`](crate::user_input::Buttonlike) actions
+    ///
+    /// Axislike actions are skipped, as consumption only applies to buttons.
+    #[inline]
+    pub fn consume_all(&mut self) {
+        for action in self.keys().iter() {
+            if action.input_control_kind() == InputControlKind::Button {
+                self.consume(action);
+            }
+        }
+    }
+
+    /// Configures how long presses of the `action` are buffered for, as described by the provided [`BufferConfig`]
+    ///
+    /// While a press is buffered, the action reports as [`just_pressed`](Self::just_pressed).
+    /// Use [`BufferConfig::NONE`] to stop buffering presses.
+    #[track_caller]
+    pub fn set_buffer(&mut self, action: &A, buffer: BufferConfig) {
+        debug_assert_eq!(action.input_control_kind(), InputControlKind::Button);
+
+        self.button_data_mut_or_default(action).buffer = buffer;
+    }
+
+    /// The [`BufferConfig`] of the `action`
+    #[must_use]
+    #[track_caller]
+    pub fn buffer(&self, action: &A) -> BufferConfig {
+        debug_assert_eq!(action.input_control_kind(), InputControlKind::Button);
+
+        self.button_data(action)
+            .map(|data| data.buffer)
+            .unwrap_or_default()
+    }
+
+    /// Suppresses the `action` until the next time [`ActionState::tick`] is called
+    ///
+    /// While suppressed, the action will report as released (but not just released), and its value will be zero.
+    /// Unlike [`ActionState::consume`], the underlying state of the action is left untouched:
+    /// if its inputs are still held, it will report as pressed again (but not just pressed) after the next tick.
+    #[inline]
+    #[track_caller]
+    pub fn suppress_for_one_frame(&mut self, action: &A) {
+        debug_assert_eq!(action.input_control_kind(), InputControlKind::Button);
+
+        self.button_data_mut_or_default(action).suppressed = true;
+    }
+
+    /// Has this `action` been [suppressed](Self::suppress_for_one_frame) since [`ActionState::tick`] was last called?
+    ///
+    /// # Warning
+    ///
+    /// This value will be `false` by default,
+    /// even if the action is not a buttonlike action.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn suppressed(&self, action: &A) -> bool {
+        debug_assert_eq!(action.input_control_kind(), InputControlKind::Button);
+
+        self.button_data(action)
+            .is_some_and(|button_data| button_data.suppressed)
+    }
+
     /// Resets an action to its default state.
     ///
     /// Buttons will be released, and axes will be set to 0.
@@ -850,6 +1228,59 @@ impl<A: Actionlike> ActionState<A> {
         }
     }
 
+    /// Inserts default [`ActionData`] for every action returned by [`Actionlike::variants`] that has no data yet,
+    /// leaving the data of other actions untouched.
+    ///
+    /// This is useful after deserializing a partial [`ActionState`],
+    /// as actions whose data is the default for their kind are omitted when serializing,
+    /// to guarantee that [`ActionState::all_action_data`] and [`ActionState::keys`] list every action.
+    ///
+    /// Actions that aren't listed by [`Actionlike::variants`], such as the values of a struct, are not initialized.
+    pub fn initialize_all(&mut self) {
+        for action in A::variants() {
+            self.action_data_mut_or_default(&action);
+        }
+    }
+
+    /// Replaces the state of every action with the `authoritative` state,
+    /// such as a correction sent by a server.
+    ///
+    /// All of the [`ActionData`] is copied, along with whether the entire [`ActionState`]
+    /// and each [group](Actionlike::group) of actions are disabled.
+    /// Actions that aren't part of the `authoritative` state are reset to their default state.
+    ///
+    /// If the `timing` feature is enabled, the durations of the `authoritative` state are copied.
+    /// The [`Instant`]s at which buttons were pressed or released aren't serialized,
+    /// so when they are missing from the `authoritative` state, the local ones are kept
+    /// for buttons that were already pressed or released locally.
+    /// Otherwise, they are recorded on the next call to [`ActionState::tick`].
+    pub fn overwrite_from(&mut self, authoritative: &ActionState<A>) {
+        #[cfg(feature = "timing")]
+        let local_data = std::mem::take(&mut self.action_data);
+
+        self.disabled = authoritative.disabled;
+        self.disabled_groups
+            .clone_from(&authoritative.disabled_groups);
+        self.action_data.clone_from(&authoritative.action_data);
+
+        #[cfg(feature = "timing")]
+        for (action, action_data) in self.action_data.iter_mut() {
+            let (ActionKindData::Button(button_data), Some(ActionKindData::Button(local))) = (
+                &mut action_data.kind_data,
+                local_data.get(action).map(|data| &data.kind_data),
+            ) else {
+                continue;
+            };
+
+            if button_data.state.pressed() == local.state.pressed() {
+                let timing = &mut button_data.timing;
+                timing.instant_started = timing.instant_started.or(local.timing.instant_started);
+                timing.instant_pressed = timing.instant_pressed.or(local.timing.instant_pressed);
+                timing.instant_consumed = timing.instant_consumed.or(local.timing.instant_consumed);
+            }
+        }
+    }
+
     /// Is the entire [`ActionState`] currently disabled?
     pub fn disabled(&self) -> bool {
         self.disabled
@@ -859,7 +1290,7 @@ impl<A: Actionlike> ActionState<A> {
     #[inline]
     #[must_use]
     pub fn action_disabled(&self, action: &A) -> bool {
-        if self.disabled {
+        if self.disabled || self.action_group_disabled(action) {
             return true;
         }
 
@@ -919,6 +1350,45 @@ impl<A: Actionlike> ActionState<A> {
         }
     }
 
+    /// Is the `group` of actions currently disabled?
+    #[inline]
+    #[must_use]
+    pub fn group_disabled(&self, group: &str) -> bool {
+        self.disabled_groups.contains(group)
+    }
+
+    /// Is the [group](Actionlike::group) that this `action` belongs to currently disabled?
+    #[inline]
+    fn action_group_disabled(&self, action: &A) -> bool {
+        action
+            .group()
+            .is_some_and(|group| self.disabled_groups.contains(group))
+    }
+
+    /// Disables all actions that belong to the `group`,
+    /// including those that don't have any [`ActionData`] yet.
+    ///
+    /// The values of these actions will be reset to their default state.
+    /// Enabling an individual action of a disabled group has no effect until the group is enabled.
+    #[inline]
+    pub fn disable_group(&mut self, group: &str) {
+        self.disabled_groups.insert(group.to_string());
+
+        for action in self.keys() {
+            if action.group() == Some(group) {
+                self.reset(&action);
+            }
+        }
+    }
+
+    /// Enables all actions that belong to the `group`.
+    ///
+    /// Actions that were individually disabled with [`ActionState::disable_action`] remain disabled.
+    #[inline]
+    pub fn enable_group(&mut self, group: &str) {
+        self.disabled_groups.remove(group);
+    }
+
     /// Is this `action` currently pressed?
     ///
     /// # Warning
@@ -931,7 +1401,7 @@ impl<A: Actionlike> ActionState<A> {
     pub fn pressed(&self, action: &A) -> bool {
         debug_assert_eq!(action.input_control_kind(), InputControlKind::Button);
 
-        if self.action_disabled(action) {
+        if self.action_disabled(action) || self.suppressed(action) {
             return false;
         }
 
@@ -953,7 +1423,7 @@ impl<A: Actionlike> ActionState<A> {
     pub fn just_pressed(&self, action: &A) -> bool {
         debug_assert_eq!(action.input_control_kind(), InputControlKind::Button);
 
-        if self.action_disabled(action) {
+        if self.action_disabled(action) || self.suppressed(action) {
             return false;
         }
 
@@ -977,7 +1447,7 @@ impl<A: Actionlike> ActionState<A> {
     pub fn released(&self, action: &A) -> bool {
         debug_assert_eq!(action.input_control_kind(), InputControlKind::Button);
 
-        if self.action_disabled(action) {
+        if self.action_disabled(action) || self.suppressed(action) {
             return true;
         }
 
@@ -999,7 +1469,7 @@ impl<A: Actionlike> ActionState<A> {
     pub fn just_released(&self, action: &A) -> bool {
         debug_assert_eq!(action.input_control_kind(), InputControlKind::Button);
 
-        if self.action_disabled(action) {
+        if self.action_disabled(action) || self.suppressed(action) {
             return false;
         }
 
@@ -1009,52 +1479,126 @@ impl<A: Actionlike> ActionState<A> {
         }
     }
 
+    /// Returns the input that most recently caused this `action` to be pressed.
+    ///
+    /// The input is kept after the action is released, until it is pressed again.
+    ///
+    /// # Warning
+    ///
+    /// Triggering inputs are only recorded when enabled using
+    /// [`InputManagerPlugin::record_triggering_inputs`](crate::plugin::InputManagerPlugin::record_triggering_inputs),
+    /// so this will be `None` by default.
+    #[inline]
     #[must_use]
-    /// Which actions are currently pressed?
-    pub fn get_pressed(&self) -> Vec<A> {
-        let all_actions = self.action_data.keys().cloned();
+    #[track_caller]
+    pub fn triggering_input(&self, action: &A) -> Option<&dyn Buttonlike> {
+        debug_assert_eq!(action.input_control_kind(), InputControlKind::Button);
 
-        all_actions
-            .into_iter()
+        self.button_data(action)?.triggering_input.as_deref()
+    }
+
+    /// Returns an iterator over the buttonlike actions that are currently pressed
+    ///
+    /// Unlike [`ActionState::get_pressed`], this borrows the actions rather than allocating.
+    pub fn pressed_actions(&self) -> impl Iterator<Item = &A> {
+        self.button_actions().filter(|action| self.pressed(action))
+    }
+
+    /// Returns an iterator over the buttonlike actions that were just pressed
+    ///
+    /// Unlike [`ActionState::get_just_pressed`], this borrows the actions rather than allocating.
+    pub fn just_pressed_actions(&self) -> impl Iterator<Item = &A> {
+        self.button_actions()
+            .filter(|action| self.just_pressed(action))
+    }
+
+    /// Returns an iterator over the buttonlike actions that are currently released
+    ///
+    /// Unlike [`ActionState::get_released`], this borrows the actions rather than allocating.
+    pub fn released_actions(&self) -> impl Iterator<Item = &A> {
+        self.button_actions().filter(|action| self.released(action))
+    }
+
+    /// Returns an iterator over the buttonlike actions that were just released
+    ///
+    /// Unlike [`ActionState::get_just_released`], this borrows the actions rather than allocating.
+    pub fn just_released_actions(&self) -> impl Iterator<Item = &A> {
+        self.button_actions()
+            .filter(|action| self.just_released(action))
+    }
+
+    /// Returns an iterator over all buttonlike actions with data stored in this [`ActionState`]
+    fn button_actions(&self) -> impl Iterator<Item = &A> {
+        self.action_data
+            .keys()
             .filter(|action| action.input_control_kind() == InputControlKind::Button)
-            .filter(|action| self.pressed(action))
-            .collect()
+    }
+
+    #[must_use]
+    /// Which actions are currently pressed?
+    pub fn get_pressed(&self) -> Vec<A> {
+        self.pressed_actions().cloned().collect()
     }
 
     #[must_use]
     /// Which actions were just pressed?
     pub fn get_just_pressed(&self) -> Vec<A> {
-        let all_actions = self.action_data.keys().cloned();
-
-        all_actions
-            .into_iter()
-            .filter(|action| action.input_control_kind() == InputControlKind::Button)
-            .filter(|action| self.just_pressed(action))
-            .collect()
+        self.just_pressed_actions().cloned().collect()
     }
 
     #[must_use]
     /// Which actions are currently released?
     pub fn get_released(&self) -> Vec<A> {
-        let all_actions = self.action_data.keys().cloned();
-
-        all_actions
-            .into_iter()
-            .filter(|action| action.input_control_kind() == InputControlKind::Button)
-            .filter(|action| self.released(action))
-            .collect()
+        self.released_actions().cloned().collect()
     }
 
     #[must_use]
     /// Which actions were just released?
     pub fn get_just_released(&self) -> Vec<A> {
-        let all_actions = self.action_data.keys().cloned();
-
-        all_actions
-            .into_iter()
-            .filter(|action| action.input_control_kind() == InputControlKind::Button)
-            .filter(|action| self.just_released(action))
-            .collect()
+        self.just_released_actions().cloned().collect()
+    }
+
+    /// Are any of the provided `actions` active beyond the `threshold`?
+    ///
+    /// This is useful for aggregate checks, such as whether a character is idle or moving,
+    /// and handles actions of any kind:
+    /// - Buttonlike actions are active if they are pressed and their [`button_value`](Self::button_value) exceeds the `threshold`.
+    /// - Axislike actions are active if the magnitude of their [`value`](Self::value) exceeds the `threshold`.
+    /// - Dual-axislike and triple-axislike actions are active if the length of their
+    ///   [`axis_pair`](Self::axis_pair) or [`axis_triple`](Self::axis_triple) exceeds the `threshold`.
+    ///
+    /// Disabled actions are never active.
+    ///
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+    /// enum Action {
+    ///     #[actionlike(DualAxis)]
+    ///     Move,
+    ///     Jump,
+    /// }
+    ///
+    /// let mut action_state = ActionState::<Action>::default();
+    /// action_state.set_axis_pair(&Action::Move, Vec2::new(0.05, 0.0));
+    /// assert!(!action_state.any_active(&[Action::Move, Action::Jump], 0.1));
+    ///
+    /// action_state.press(&Action::Jump);
+    /// assert!(action_state.any_active(&[Action::Move, Action::Jump], 0.1));
+    /// ```
+    #[must_use]
+    pub fn any_active(&self, actions: &[A], threshold: f32) -> bool {
+        actions
+            .iter()
+            .any(|action| match action.input_control_kind() {
+                InputControlKind::Button => {
+                    self.pressed(action) && self.button_value(action) > threshold
+                }
+                InputControlKind::Axis => self.value(action).abs() > threshold,
+                InputControlKind::DualAxis => self.axis_pair(action).length() > threshold,
+                InputControlKind::TripleAxis => self.axis_triple(action).length() > threshold,
+            })
     }
 
     /// The [`Instant`] that the action was last pressed or released
@@ -1091,6 +1635,124 @@ impl<A: Actionlike> ActionState<A> {
             .unwrap_or_default()
     }
 
+    /// Is this `action` currently pressed, and has it been held for longer than the provided `duration`?
+    ///
+    /// Like [`ActionState::current_duration`], this is only updated when [`ActionState::tick`] is called,
+    /// so an action that was just pressed has not been held for any positive `duration` yet.
+    /// Disabled actions are never held.
+    ///
+    /// ```rust
+    /// use bevy::prelude::Reflect;
+    /// use bevy::utils::{Duration, Instant};
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+    /// enum Action {
+    ///     Charge,
+    /// }
+    ///
+    /// let mut action_state = ActionState::<Action>::default();
+    /// action_state.press(&Action::Charge);
+    ///
+    /// let t0 = Instant::now();
+    /// let t1 = t0 + Duration::from_millis(500);
+    /// action_state.tick(t1, t0);
+    ///
+    /// assert!(action_state.held_longer_than(&Action::Charge, Duration::from_millis(250)));
+    /// assert!(!action_state.held_longer_than(&Action::Charge, Duration::from_secs(1)));
+    /// ```
+    #[cfg(feature = "timing")]
+    #[must_use]
+    #[track_caller]
+    pub fn held_longer_than(&self, action: &A, duration: Duration) -> bool {
+        self.pressed(action) && self.current_duration(action) > duration
+    }
+
+    /// How far this `action` has been charged up, ramping linearly from `0.0` to `1.0`
+    /// as it is held for up to the `full_charge` duration.
+    ///
+    /// This is computed as `current_duration / full_charge`, capped at `1.0`,
+    /// and is always `0.0` while the action is released.
+    /// If `full_charge` is zero, pressed actions are fully charged immediately.
+    ///
+    /// The raw [`button_value`](Self::button_value) of the action is left untouched,
+    /// and, like [`ActionState::current_duration`], this is only updated when [`ActionState::tick`] is called.
+    ///
+    /// ```rust
+    /// use bevy::prelude::Reflect;
+    /// use bevy::utils::{Duration, Instant};
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+    /// enum Action {
+    ///     Charge,
+    /// }
+    ///
+    /// let full_charge = Duration::from_secs(2);
+    /// let mut action_state = ActionState::<Action>::default();
+    /// action_state.press(&Action::Charge);
+    ///
+    /// let t0 = Instant::now();
+    /// let t1 = t0 + Duration::from_millis(500);
+    /// action_state.tick(t1, t0);
+    /// assert_eq!(action_state.charge_value(&Action::Charge, full_charge), 0.25);
+    ///
+    /// action_state.release(&Action::Charge);
+    /// assert_eq!(action_state.charge_value(&Action::Charge, full_charge), 0.0);
+    /// ```
+    #[cfg(feature = "timing")]
+    #[must_use]
+    #[track_caller]
+    pub fn charge_value(&self, action: &A, full_charge: Duration) -> f32 {
+        if !self.pressed(action) {
+            return 0.0;
+        }
+
+        if full_charge.is_zero() {
+            return 1.0;
+        }
+
+        let held = self.current_duration(action).as_secs_f32();
+        (held / full_charge.as_secs_f32()).min(1.0)
+    }
+
+    /// The [`Duration`] elapsed between the most recent press of the action and the last tick
+    ///
+    /// This is useful for combo windows, measuring how long ago the action was [`just_pressed`](Self::just_pressed),
+    /// even if it has since been released.
+    ///
+    /// Like [`ActionState::current_duration`], this is only updated when [`ActionState::tick`] is called.
+    /// This will be [`None`] if the action was never pressed,
+    /// or if it has not been ticked since it was last pressed.
+    #[cfg(feature = "timing")]
+    #[must_use]
+    #[track_caller]
+    pub fn time_since_just_pressed(&self, action: &A) -> Option<Duration> {
+        debug_assert_eq!(action.input_control_kind(), InputControlKind::Button);
+
+        self.button_data(action)?.timing.time_since_pressed()
+    }
+
+    /// The [`Duration`] elapsed between the most recent press of the action and when it was [consumed](Self::consume)
+    ///
+    /// This is useful for profiling input latency, measuring how long it takes for a press to be handled.
+    /// If the action has not been consumed since it was last pressed,
+    /// the [`Duration`] between the press and the most recent tick is returned instead.
+    ///
+    /// The press is recorded at the start of the tick after it was pressed,
+    /// while consumption is recorded as the time of the most recent tick.
+    /// Both are measured using the [`Time`](bevy::time::Time) passed to [`ActionState::tick`].
+    /// This will be [`None`] if the action was never pressed,
+    /// or if it has not been ticked since it was last pressed.
+    #[cfg(feature = "timing")]
+    #[must_use]
+    #[track_caller]
+    pub fn latency_since_press(&self, action: &A) -> Option<Duration> {
+        debug_assert_eq!(action.input_control_kind(), InputControlKind::Button);
+
+        self.button_data(action)?.timing.latency_since_pressed()
+    }
+
     /// The [`Duration`] for which the action was last held or released
     ///
     /// This is a snapshot of the [`ActionState::current_duration`] state at the time
@@ -1108,17 +1770,174 @@ impl<A: Actionlike> ActionState<A> {
             .unwrap_or_default()
     }
 
+    /// Configures the `action` to repeat while held, as described by the provided [`RepeatConfig`]
+    ///
+    /// Each time that the held action repeats, it is [`just_pressed`](ActionState::just_pressed) again for a single tick.
+    /// Pass `None` to stop the action from repeating.
+    #[cfg(feature = "timing")]
+    #[track_caller]
+    pub fn set_repeat(&mut self, action: &A, repeat: Option<RepeatConfig>) {
+        debug_assert_eq!(action.input_control_kind(), InputControlKind::Button);
+
+        self.button_data_mut_or_default(action).repeat = repeat;
+    }
+
+    /// The [`RepeatConfig`] of the `action`, if it has been configured to repeat
+    #[cfg(feature = "timing")]
+    #[must_use]
+    #[track_caller]
+    pub fn repeat(&self, action: &A) -> Option<RepeatConfig> {
+        debug_assert_eq!(action.input_control_kind(), InputControlKind::Button);
+
+        self.button_data(action)?.repeat
+    }
+
+    /// The remaining [`Duration`] until the held `action` next repeats
+    ///
+    /// This will be [`None`] if the action has no [`RepeatConfig`],
+    /// or if it is not currently pressed.
+    ///
+    /// Like [`ActionState::current_duration`], this is only updated when [`ActionState::tick`] is called.
+    #[cfg(feature = "timing")]
+    #[must_use]
+    #[track_caller]
+    pub fn time_until_repeat(&self, action: &A) -> Option<Duration> {
+        debug_assert_eq!(action.input_control_kind(), InputControlKind::Button);
+
+        let button_data = self.button_data(action)?;
+        if !button_data.pressed() {
+            return None;
+        }
+
+        let repeat = button_data.repeat?;
+        Some(repeat.time_until_repeat(button_data.timing.current_duration))
+    }
+
+    /// Smooths new values of the axislike or dual-axislike `action` using the provided [`ExponentialSmoothing`]
+    ///
+    /// Smoothing is applied as the action is updated by [`ActionState::update`],
+    /// blending each new value with the current value of the action.
+    /// Setting the value directly, such as with [`ActionState::set_value`] or [`ActionState::reset`], bypasses the smoothing.
+    ///
+    /// Pass `None` to stop smoothing the action.
+    ///
+    /// Other kinds of actions can't be smoothed, so this does nothing if the `action` is neither axislike nor dual-axislike.
+    #[track_caller]
+    pub fn set_smoothing(&mut self, action: &A, smoothing: Option<ExponentialSmoothing>) {
+        match action.input_control_kind() {
+            InputControlKind::Axis => self.axis_data_mut_or_default(action).smoothing = smoothing,
+            InputControlKind::DualAxis => {
+                self.dual_axis_data_mut_or_default(action).smoothing = smoothing
+            }
+            InputControlKind::Button | InputControlKind::TripleAxis => {}
+        }
+    }
+
+    /// The [`ExponentialSmoothing`] of the axislike or dual-axislike `action`, if it has been configured
+    #[must_use]
+    #[track_caller]
+    pub fn smoothing(&self, action: &A) -> Option<ExponentialSmoothing> {
+        match action.input_control_kind() {
+            InputControlKind::Axis => self.axis_data(action)?.smoothing,
+            InputControlKind::DualAxis => self.dual_axis_data(action)?.smoothing,
+            _ => None,
+        }
+    }
+
+    /// Sets the `cooldown` of the `action`: how long it must wait after being [fired](Self::try_fire)
+    /// before it can be [`just_pressed`](Self::just_pressed) again
+    ///
+    /// A `cooldown` of [`Duration::ZERO`] disables the cooldown.
+    /// Changing the cooldown does not affect the remaining cooldown of an action that was already fired.
+    #[cfg(feature = "timing")]
+    #[track_caller]
+    pub fn set_cooldown(&mut self, action: &A, cooldown: Duration) {
+        debug_assert_eq!(action.input_control_kind(), InputControlKind::Button);
+
+        self.button_data_mut_or_default(action).cooldown = cooldown;
+    }
+
+    /// The cooldown of the `action`, as set by [`ActionState::set_cooldown`]
+    #[cfg(feature = "timing")]
+    #[must_use]
+    #[track_caller]
+    pub fn cooldown(&self, action: &A) -> Duration {
+        debug_assert_eq!(action.input_control_kind(), InputControlKind::Button);
+
+        self.button_data(action)
+            .map(|data| data.cooldown)
+            .unwrap_or_default()
+    }
+
+    /// The remaining [`Duration`] until the `action` can be [`just_pressed`](Self::just_pressed) again
+    ///
+    /// Like [`ActionState::current_duration`], this is only updated when [`ActionState::tick`] is called.
+    #[cfg(feature = "timing")]
+    #[must_use]
+    #[track_caller]
+    pub fn cooldown_remaining(&self, action: &A) -> Duration {
+        debug_assert_eq!(action.input_control_kind(), InputControlKind::Button);
+
+        self.button_data(action)
+            .map(|data| data.cooldown_remaining)
+            .unwrap_or_default()
+    }
+
+    /// Fires the `action` if it was [`just_pressed`](Self::just_pressed), starting its cooldown
+    ///
+    /// Returns `true` if the action was fired.
+    /// While the action is on cooldown, it is never just pressed, so this will return `false`.
+    ///
+    /// ```rust
+    /// use bevy::prelude::Reflect;
+    /// use bevy::utils::{Duration, Instant};
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+    /// enum Ability {
+    ///     Fireball,
+    /// }
+    ///
+    /// let mut action_state = ActionState::<Ability>::default();
+    /// action_state.set_cooldown(&Ability::Fireball, Duration::from_secs(2));
+    ///
+    /// action_state.press(&Ability::Fireball);
+    /// assert!(action_state.try_fire(&Ability::Fireball));
+    ///
+    /// // Pressing the action again is ignored until the cooldown has elapsed
+    /// action_state.release(&Ability::Fireball);
+    /// action_state.press(&Ability::Fireball);
+    /// assert!(!action_state.just_pressed(&Ability::Fireball));
+    /// assert!(!action_state.try_fire(&Ability::Fireball));
+    /// ```
+    #[cfg(feature = "timing")]
+    #[track_caller]
+    pub fn try_fire(&mut self, action: &A) -> bool {
+        if !self.just_pressed(action) {
+            return false;
+        }
+
+        let button_data = self.button_data_mut_or_default(action);
+        button_data.cooldown_remaining = button_data.cooldown;
+        true
+    }
+
     /// Applies an [`ActionDiff`] (usually received over the network) to the [`ActionState`].
     ///
     /// This lets you reconstruct an [`ActionState`] from a stream of [`ActionDiff`]s
     pub fn apply_diff(&mut self, action_diff: &ActionDiff<A>) {
         match action_diff {
             ActionDiff::Pressed { action, value } => {
+                // The sender only presses consumed actions again once their inputs were released
+                self.button_data_mut_or_default(action).consumed = false;
                 self.set_button_value(action, *value);
             }
             ActionDiff::Released { action } => {
                 self.release(action);
             }
+            ActionDiff::Consumed { action } => {
+                self.consume(action);
+            }
             ActionDiff::AxisChanged { action, value } => {
                 self.set_value(action, *value);
             }
@@ -1134,12 +1953,224 @@ impl<A: Actionlike> ActionState<A> {
         };
     }
 
+    /// Applies a batch of [`ActionDiff`]s (usually received over the network in a single tick) to the [`ActionState`], in order.
+    ///
+    /// Later diffs take precedence, so an action that is pressed and then released within the same batch ends up released.
+    /// As with [`ActionState::apply_diff`], timing information is only updated when a buttonlike action actually changes state:
+    /// a [`ActionDiff::Pressed`] for an action that is already pressed only updates its value.
+    ///
+    /// ```rust
+    /// use bevy::prelude::Reflect;
+    /// use leafwing_input_manager::action_diff::ActionDiff;
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+    /// enum Action {
+    ///     Jump,
+    /// }
+    ///
+    /// let mut action_state = ActionState::<Action>::default();
+    /// action_state.apply_diffs(&[
+    ///     ActionDiff::Pressed { action: Action::Jump, value: 1.0 },
+    ///     ActionDiff::Released { action: Action::Jump },
+    /// ]);
+    /// assert!(action_state.released(&Action::Jump));
+    /// ```
+    pub fn apply_diffs(&mut self, action_diffs: &[ActionDiff<A>]) {
+        for action_diff in action_diffs {
+            self.apply_diff(action_diff);
+        }
+    }
+
+    /// Compares this [`ActionState`] to a `previous` one, generating the [`ActionDiff`]s needed to turn the `previous` state into this one.
+    ///
+    /// This produces the same diffs as the [`generate_action_diffs`](crate::systems::generate_action_diffs) system,
+    /// but works on any pair of action states, outside of the ECS.
+    /// Unlike that system, small changes to axislike values are not held back by an [`ActionDiffEpsilon`](crate::action_diff::ActionDiffEpsilon).
+    /// Buttonlike actions generate [`ActionDiff::Pressed`] or [`ActionDiff::Released`] when their state changes,
+    /// or [`ActionDiff::Consumed`] when they are [consumed](Self::consume),
+    /// while axislike actions generate the corresponding `*Changed` diff only when their values differ.
+    /// Actions without data in either state are compared against their default state,
+    /// so replacing an [`ActionState`] with a default one generates the diffs needed to release its actions.
+    ///
+    /// The diffs are returned in no particular order.
+    ///
+    /// ```rust
+    /// use bevy::prelude::Reflect;
+    /// use leafwing_input_manager::action_diff::ActionDiff;
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+    /// enum Action {
+    ///     Jump,
+    /// }
+    ///
+    /// let previous = ActionState::<Action>::default();
+    /// let mut current = previous.clone();
+    /// current.press(&Action::Jump);
+    ///
+    /// let diffs = current.generate_diffs(&previous);
+    /// assert_eq!(diffs, vec![ActionDiff::Pressed { action: Action::Jump, value: 1.0 }]);
+    ///
+    /// // Applying the diffs to the previous state reproduces the current one
+    /// let mut replica = previous.clone();
+    /// replica.apply_diffs(&diffs);
+    /// assert!(replica.pressed(&Action::Jump));
+    /// ```
+    #[must_use]
+    pub fn generate_diffs(&self, previous: &ActionState<A>) -> Vec<ActionDiff<A>> {
+        let actions: HashSet<&A> = self
+            .action_data
+            .keys()
+            .chain(previous.action_data.keys())
+            .collect();
+
+        actions
+            .into_iter()
+            .filter_map(|action| {
+                let current_data = self.action_data(action).map(|data| &data.kind_data);
+                let previous_data = previous.action_data(action).map(|data| &data.kind_data);
+                let action = action.clone();
+
+                // Actions without data in this state are compared as if they were in their default state
+                match action.input_control_kind() {
+                    InputControlKind::Button => {
+                        let current = match current_data {
+                            Some(ActionKindData::Button(current)) => Some(current),
+                            _ => None,
+                        };
+                        let previous = match previous_data {
+                            Some(ActionKindData::Button(previous)) => Some(previous),
+                            _ => None,
+                        };
+                        SummarizedActionState::consumable_button_diff(
+                            action,
+                            previous.map(|previous| previous.to_button_value()),
+                            Some(
+                                current
+                                    .map(|current| current.to_button_value())
+                                    .unwrap_or_default(),
+                            ),
+                            previous.is_some_and(|previous| previous.consumed),
+                            current.is_some_and(|current| current.consumed),
+                        )
+                    }
+                    InputControlKind::Axis => {
+                        let value = |data: Option<&ActionKindData>| match data {
+                            Some(ActionKindData::Axis(data)) => Some(data.value),
+                            _ => None,
+                        };
+                        SummarizedActionState::axis_diff(
+                            action,
+                            value(previous_data),
+                            Some(value(current_data).unwrap_or_default()),
+                        )
+                    }
+                    InputControlKind::DualAxis => {
+                        let pair = |data: Option<&ActionKindData>| match data {
+                            Some(ActionKindData::DualAxis(data)) => Some(data.pair),
+                            _ => None,
+                        };
+                        SummarizedActionState::dual_axis_diff(
+                            action,
+                            pair(previous_data),
+                            Some(pair(current_data).unwrap_or_default()),
+                        )
+                    }
+                    InputControlKind::TripleAxis => {
+                        let triple = |data: Option<&ActionKindData>| match data {
+                            Some(ActionKindData::TripleAxis(data)) => Some(data.triple),
+                            _ => None,
+                        };
+                        SummarizedActionState::triple_axis_diff(
+                            action,
+                            triple(previous_data),
+                            Some(triple(current_data).unwrap_or_default()),
+                        )
+                    }
+                }
+            })
+            .collect()
+    }
+
     /// Returns an owned list of the [`Actionlike`] keys in this [`ActionState`].
     #[inline]
     #[must_use]
     pub fn keys(&self) -> Vec<A> {
         self.action_data.keys().cloned().collect()
     }
+
+    /// Returns the [`value`](Self::value) of every axislike action stored in this [`ActionState`].
+    ///
+    /// Values are read from the stored [`AxisData`] without being recomputed,
+    /// and disabled actions report a value of `0.0`.
+    /// Actions that have never been updated are not included.
+    #[must_use]
+    pub fn all_values(&self) -> HashMap<A, f32> {
+        self.action_data
+            .iter()
+            .filter_map(|(action, action_data)| match &action_data.kind_data {
+                ActionKindData::Axis(data) => Some((action, data.value)),
+                _ => None,
+            })
+            .map(|(action, value)| {
+                let value = if self.action_disabled(action) {
+                    0.0
+                } else {
+                    value
+                };
+                (action.clone(), value)
+            })
+            .collect()
+    }
+
+    /// Returns the [`axis_pair`](Self::axis_pair) of every dual-axislike action stored in this [`ActionState`].
+    ///
+    /// Pairs are read from the stored [`DualAxisData`] without being recomputed,
+    /// and disabled actions report [`Vec2::ZERO`].
+    /// Actions that have never been updated are not included.
+    #[must_use]
+    pub fn all_axis_pairs(&self) -> HashMap<A, Vec2> {
+        self.action_data
+            .iter()
+            .filter_map(|(action, action_data)| match &action_data.kind_data {
+                ActionKindData::DualAxis(data) => Some((action, data.pair)),
+                _ => None,
+            })
+            .map(|(action, pair)| {
+                let pair = if self.action_disabled(action) {
+                    Vec2::ZERO
+                } else {
+                    pair
+                };
+                (action.clone(), pair)
+            })
+            .collect()
+    }
+
+    /// Returns the [`axis_triple`](Self::axis_triple) of every triple-axislike action stored in this [`ActionState`].
+    ///
+    /// Triples are read from the stored [`TripleAxisData`] without being recomputed,
+    /// and disabled actions report [`Vec3::ZERO`].
+    /// Actions that have never been updated are not included.
+    #[must_use]
+    pub fn all_axis_triples(&self) -> HashMap<A, Vec3> {
+        self.action_data
+            .iter()
+            .filter_map(|(action, action_data)| match &action_data.kind_data {
+                ActionKindData::TripleAxis(data) => Some((action, data.triple)),
+                _ => None,
+            })
+            .map(|(action, triple)| {
+                let triple = if self.action_disabled(action) {
+                    Vec3::ZERO
+                } else {
+                    triple
+                };
+                (action.clone(), triple)
+            })
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -1263,6 +2294,1318 @@ mod tests {
         assert!(!action_state.just_released(&Action::Two));
     }
 
+    #[test]
+    fn synthetic_press_with_value() {
+        use bevy::utils::Instant;
+
+        #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+        enum Action {
+            Trigger,
+        }
+
+        let mut action_state = ActionState::<Action>::default();
+        action_state.press_with_value(&Action::Trigger, 0.5);
+
+        assert!(action_state.pressed(&Action::Trigger));
+        assert!(action_state.just_pressed(&Action::Trigger));
+        assert_eq!(action_state.button_value(&Action::Trigger), 0.5);
+        assert_eq!(action_state.clamped_button_value(&Action::Trigger), 0.5);
+
+        // Pressing further updates the value without re-pressing
+        action_state.tick(Instant::now(), Instant::now());
+        action_state.press_with_value(&Action::Trigger, 0.75);
+        assert!(action_state.pressed(&Action::Trigger));
+        assert!(!action_state.just_pressed(&Action::Trigger));
+        assert_eq!(action_state.button_value(&Action::Trigger), 0.75);
+
+        // Releasing resets the value
+        action_state.release(&Action::Trigger);
+        assert!(action_state.just_released(&Action::Trigger));
+        assert_eq!(action_state.button_value(&Action::Trigger), 0.0);
+    }
+
+    #[test]
+    fn suppress_for_one_frame() {
+        use crate::input_map::{UpdatedActions, UpdatedValue};
+        use bevy::utils::{HashMap, Instant};
+
+        #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+        enum Action {
+            Jump,
+            Run,
+        }
+
+        let held = || {
+            UpdatedActions(HashMap::from([
+                (Action::Jump, UpdatedValue::Button(true)),
+                (Action::Run, UpdatedValue::Button(true)),
+            ]))
+        };
+
+        let mut action_state = ActionState::<Action>::default();
+        action_state.update(held());
+        action_state.suppress_for_one_frame(&Action::Jump);
+
+        // The suppressed action reports as released for the current frame
+        assert!(action_state.suppressed(&Action::Jump));
+        assert!(!action_state.pressed(&Action::Jump));
+        assert!(!action_state.just_pressed(&Action::Jump));
+        assert!(action_state.released(&Action::Jump));
+        assert!(!action_state.just_released(&Action::Jump));
+        assert_eq!(action_state.button_value(&Action::Jump), 0.0);
+        assert_eq!(action_state.get_pressed(), vec![Action::Run]);
+
+        // Other actions are unaffected
+        assert!(action_state.just_pressed(&Action::Run));
+
+        // Updates during the same frame do not clear the suppression
+        action_state.update(held());
+        assert!(action_state.released(&Action::Jump));
+
+        // The suppression is cleared by the next tick
+        action_state.tick(Instant::now(), Instant::now());
+        action_state.update(held());
+        assert!(!action_state.suppressed(&Action::Jump));
+        assert!(action_state.pressed(&Action::Jump));
+        assert!(!action_state.just_pressed(&Action::Jump));
+        assert_eq!(action_state.button_value(&Action::Jump), 1.0);
+    }
+
+    #[test]
+    fn suppression_is_tracked_per_schedule() {
+        use bevy::utils::Instant;
+
+        #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+        enum Action {
+            Jump,
+        }
+
+        // The action is pressed in both schedules
+        let mut action_state = ActionState::<Action>::default();
+        action_state.press(&Action::Jump);
+        action_state.swap_to_fixed_update_state();
+        action_state.press(&Action::Jump);
+        action_state.swap_to_update_state();
+
+        // Suppressing the action in the `Main` schedule doesn't affect the `FixedMain` schedule
+        action_state.suppress_for_one_frame(&Action::Jump);
+        action_state.swap_to_fixed_update_state();
+        assert!(!action_state.suppressed(&Action::Jump));
+        assert!(action_state.pressed(&Action::Jump));
+
+        // And ticking the `FixedMain` schedule doesn't clear the suppression in the `Main` schedule
+        action_state.tick(Instant::now(), Instant::now());
+        action_state.swap_to_update_state();
+        assert!(action_state.suppressed(&Action::Jump));
+        assert!(!action_state.pressed(&Action::Jump));
+    }
+
+    #[test]
+    fn clear_schedule_states() {
+        use crate::action_state::ActionKindData;
+        use crate::buttonlike::{BufferConfig, ButtonState};
+        use crate::Actionlike;
+
+        #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+        enum Action {
+            Jump,
+            #[actionlike(Axis)]
+            Zoom,
+            #[actionlike(DualAxis)]
+            Move,
+            #[actionlike(TripleAxis)]
+            Fly,
+        }
+
+        let populate = |action_state: &mut ActionState<Action>| {
+            action_state.press(&Action::Jump);
+            action_state.set_value(&Action::Zoom, 0.5);
+            action_state.set_axis_pair(&Action::Move, Vec2::ONE);
+            action_state.set_axis_triple(&Action::Fly, Vec3::ONE);
+        };
+
+        // Populate the state of both schedules
+        let mut action_state = ActionState::<Action>::default();
+        action_state.set_buffer(&Action::Jump, BufferConfig::frames(3));
+        populate(&mut action_state);
+        action_state.swap_to_fixed_update_state();
+        populate(&mut action_state);
+        action_state.swap_to_update_state();
+
+        action_state.clear_schedule_states();
+
+        // Both schedules are cleared
+        for _ in 0..2 {
+            for action in Action::variants() {
+                let action_data = action_state.action_data(&action).unwrap();
+                match &action_data.kind_data {
+                    ActionKindData::Button(data) => {
+                        assert_eq!(data.state, ButtonState::Released);
+                        assert_eq!(data.update_state, ButtonState::Released);
+                        assert_eq!(data.fixed_update_state, ButtonState::Released);
+                        assert_eq!(data.value, 0.0);
+                        assert_eq!(data.update_value, 0.0);
+                        assert_eq!(data.fixed_update_value, 0.0);
+                        assert_eq!(data.buffered_frames, 0);
+                        assert_eq!(data.update_buffered_frames, 0);
+                        assert_eq!(data.fixed_update_buffered_frames, 0);
+                        // While the configuration is kept
+                        assert_eq!(data.buffer, BufferConfig::frames(3));
+                    }
+                    _ => assert!(action_data.is_default_for(&action)),
+                }
+            }
+            assert!(action_state.released(&Action::Jump));
+            assert_eq!(action_state.axis_pair(&Action::Move), Vec2::ZERO);
+            action_state.swap_to_fixed_update_state();
+        }
+    }
+
+    #[test]
+    fn movement_vector() {
+        use std::f32::consts::FRAC_PI_2;
+
+        #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+        enum Action {
+            #[actionlike(DualAxis)]
+            Move,
+        }
+
+        let mut action_state = ActionState::<Action>::default();
+        assert_eq!(
+            action_state.movement_vector(&Action::Move, FRAC_PI_2),
+            Vec2::ZERO
+        );
+
+        // Inputs within the unit circle are only rotated
+        action_state.set_axis_pair(&Action::Move, Vec2::new(0.5, 0.0));
+        let movement = action_state.movement_vector(&Action::Move, FRAC_PI_2);
+        assert!((movement - Vec2::new(0.0, 0.5)).length() <= 0.00001);
+
+        // Longer inputs are clamped to a length of one before being rotated
+        action_state.set_axis_pair(&Action::Move, Vec2::new(3.0, 4.0));
+        let movement = action_state.movement_vector(&Action::Move, FRAC_PI_2);
+        assert!((movement - Vec2::new(-0.8, 0.6)).length() <= 0.00001);
+
+        // Without any yaw, the input is only clamped
+        let movement = action_state.movement_vector(&Action::Move, 0.0);
+        assert!((movement - Vec2::new(0.6, 0.8)).length() <= 0.00001);
+    }
+
+    #[test]
+    fn any_active() {
+        #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+        enum Action {
+            Sprint,
+            #[actionlike(Axis)]
+            Throttle,
+            #[actionlike(DualAxis)]
+            Move,
+            #[actionlike(TripleAxis)]
+            Fly,
+        }
+
+        let actions = [Action::Sprint, Action::Throttle, Action::Move, Action::Fly];
+        let mut action_state = ActionState::<Action>::default();
+        assert!(!action_state.any_active(&actions, 0.1));
+
+        // Values within the threshold are ignored
+        action_state.set_button_value(&Action::Sprint, 0.05);
+        action_state.set_value(&Action::Throttle, -0.1);
+        action_state.set_axis_pair(&Action::Move, Vec2::new(0.06, 0.05));
+        action_state.set_axis_triple(&Action::Fly, Vec3::new(0.0, 0.0, 0.1));
+        assert!(!action_state.any_active(&actions, 0.1));
+
+        // Each kind of action can be active on its own
+        action_state.set_value(&Action::Throttle, -0.5);
+        assert!(action_state.any_active(&actions, 0.1));
+        assert!(!action_state.any_active(&[Action::Sprint, Action::Move], 0.1));
+
+        action_state.set_axis_pair(&Action::Move, Vec2::new(0.6, 0.8));
+        assert!(action_state.any_active(&[Action::Sprint, Action::Move], 0.1));
+
+        action_state.set_axis_triple(&Action::Fly, Vec3::new(0.0, 0.0, -1.0));
+        assert!(action_state.any_active(&[Action::Fly], 0.1));
+
+        action_state.press(&Action::Sprint);
+        assert!(action_state.any_active(&[Action::Sprint], 0.1));
+
+        // Disabled actions are never active
+        action_state.disable_action(&Action::Sprint);
+        assert!(!action_state.any_active(&[Action::Sprint], 0.1));
+        assert!(!action_state.any_active(&[], 0.0));
+    }
+
+    #[test]
+    fn disable_group() {
+        #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+        enum Action {
+            #[actionlike(group = "combat")]
+            Attack,
+            #[actionlike(Axis, group = "combat")]
+            Aim,
+            #[actionlike(DualAxis, group = "movement")]
+            Move,
+            Pause,
+        }
+
+        let mut action_state = ActionState::<Action>::default();
+        action_state.press(&Action::Attack);
+        action_state.set_value(&Action::Aim, 0.5);
+        action_state.set_axis_pair(&Action::Move, Vec2::X);
+        action_state.press(&Action::Pause);
+
+        action_state.disable_group("combat");
+        assert!(action_state.group_disabled("combat"));
+        assert!(!action_state.group_disabled("movement"));
+
+        // Actions in the group are disabled and reset
+        assert!(action_state.action_disabled(&Action::Attack));
+        assert!(!action_state.pressed(&Action::Attack));
+        assert_eq!(action_state.value(&Action::Aim), 0.0);
+
+        // Other actions are unaffected
+        assert_eq!(action_state.axis_pair(&Action::Move), Vec2::X);
+        assert!(action_state.pressed(&Action::Pause));
+
+        // Updates to disabled groups are hidden
+        action_state.press(&Action::Attack);
+        assert!(!action_state.pressed(&Action::Attack));
+
+        // Individual actions can't escape a disabled group
+        action_state.enable_action(&Action::Attack);
+        assert!(action_state.action_disabled(&Action::Attack));
+
+        action_state.enable_group("combat");
+        assert!(!action_state.group_disabled("combat"));
+        assert!(action_state.pressed(&Action::Attack));
+        assert!(!action_state.action_disabled(&Action::Aim));
+    }
+
+    #[test]
+    fn apply_diffs() {
+        use crate::action_diff::ActionDiff;
+        use bevy::utils::Instant;
+
+        #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+        enum Action {
+            Jump,
+            #[actionlike(DualAxis)]
+            Move,
+        }
+
+        // A press and release within the same tick ends released
+        let mut action_state = ActionState::<Action>::default();
+        action_state.apply_diffs(&[
+            ActionDiff::Pressed {
+                action: Action::Jump,
+                value: 1.0,
+            },
+            ActionDiff::Released {
+                action: Action::Jump,
+            },
+        ]);
+        assert!(action_state.released(&Action::Jump));
+        assert!(action_state.just_released(&Action::Jump));
+        assert_eq!(action_state.button_value(&Action::Jump), 0.0);
+
+        // A press followed by a change to another action applies both
+        let mut action_state = ActionState::<Action>::default();
+        action_state.apply_diffs(&[
+            ActionDiff::Pressed {
+                action: Action::Jump,
+                value: 0.5,
+            },
+            ActionDiff::DualAxisChanged {
+                action: Action::Move,
+                axis_pair: Vec2::new(0.3, -0.4),
+            },
+        ]);
+        assert!(action_state.just_pressed(&Action::Jump));
+        assert_eq!(action_state.button_value(&Action::Jump), 0.5);
+        assert_eq!(action_state.axis_pair(&Action::Move), Vec2::new(0.3, -0.4));
+
+        // Pressing an action that is already pressed only updates its value
+        action_state.tick(Instant::now(), Instant::now());
+        action_state.apply_diffs(&[ActionDiff::Pressed {
+            action: Action::Jump,
+            value: 1.0,
+        }]);
+        assert!(action_state.pressed(&Action::Jump));
+        assert!(!action_state.just_pressed(&Action::Jump));
+        assert_eq!(action_state.button_value(&Action::Jump), 1.0);
+    }
+
+    #[cfg(feature = "timing")]
+    #[test]
+    fn charge_value() {
+        use bevy::utils::{Duration, Instant};
+
+        #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+        enum Action {
+            Charge,
+        }
+
+        let full_charge = Duration::from_millis(400);
+        let mut action_state = ActionState::<Action>::default();
+        assert_eq!(action_state.charge_value(&Action::Charge, full_charge), 0.0);
+
+        // Just pressed actions have no charge yet
+        action_state.press(&Action::Charge);
+        assert_eq!(action_state.charge_value(&Action::Charge, full_charge), 0.0);
+
+        // Holding the action ramps up the charge linearly
+        let t0 = Instant::now();
+        let t1 = t0 + Duration::from_millis(100);
+        action_state.tick(t1, t0);
+        assert_eq!(
+            action_state.charge_value(&Action::Charge, full_charge),
+            0.25
+        );
+
+        let t2 = t1 + Duration::from_millis(200);
+        action_state.tick(t2, t1);
+        assert_eq!(
+            action_state.charge_value(&Action::Charge, full_charge),
+            0.75
+        );
+
+        // The charge is capped once the full charge duration has passed
+        let t3 = t2 + Duration::from_millis(100);
+        action_state.tick(t3, t2);
+        assert_eq!(action_state.charge_value(&Action::Charge, full_charge), 1.0);
+
+        let t4 = t3 + Duration::from_millis(500);
+        action_state.tick(t4, t3);
+        assert_eq!(action_state.charge_value(&Action::Charge, full_charge), 1.0);
+
+        // The raw value is unaffected
+        assert_eq!(action_state.button_value(&Action::Charge), 1.0);
+
+        // A zero duration charges instantly
+        assert_eq!(
+            action_state.charge_value(&Action::Charge, Duration::ZERO),
+            1.0
+        );
+
+        // Releasing the action discards the charge
+        action_state.release(&Action::Charge);
+        assert_eq!(action_state.charge_value(&Action::Charge, full_charge), 0.0);
+        assert_eq!(
+            action_state.charge_value(&Action::Charge, Duration::ZERO),
+            0.0
+        );
+    }
+
+    #[cfg(feature = "timing")]
+    #[test]
+    fn held_longer_than() {
+        use bevy::utils::{Duration, Instant};
+
+        #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+        enum Action {
+            Charge,
+        }
+
+        let threshold = Duration::from_millis(100);
+        let mut action_state = ActionState::<Action>::default();
+        assert!(!action_state.held_longer_than(&Action::Charge, Duration::ZERO));
+
+        // Just pressed actions have not been held for any positive duration
+        action_state.press(&Action::Charge);
+        assert!(!action_state.held_longer_than(&Action::Charge, Duration::from_nanos(1)));
+
+        let t0 = Instant::now();
+        let t1 = t0 + Duration::from_millis(60);
+        action_state.tick(t1, t0);
+        assert!(!action_state.held_longer_than(&Action::Charge, threshold));
+
+        // Reaching the threshold is not enough
+        let t2 = t1 + Duration::from_millis(40);
+        action_state.tick(t2, t1);
+        assert_eq!(action_state.current_duration(&Action::Charge), threshold);
+        assert!(!action_state.held_longer_than(&Action::Charge, threshold));
+
+        // Crossing it is
+        let t3 = t2 + Duration::from_millis(10);
+        action_state.tick(t3, t2);
+        assert!(action_state.held_longer_than(&Action::Charge, threshold));
+
+        // Released actions are never held, even though their release duration grows
+        action_state.release(&Action::Charge);
+        let t4 = t3 + Duration::from_millis(200);
+        action_state.tick(t4, t3);
+        assert!(!action_state.held_longer_than(&Action::Charge, threshold));
+    }
+
+    #[test]
+    fn generate_diffs() {
+        use crate::action_diff::ActionDiff;
+
+        #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+        enum Action {
+            Jump,
+            Crouch,
+            Shoot,
+            #[actionlike(Axis)]
+            Throttle,
+            #[actionlike(DualAxis)]
+            Move,
+            #[actionlike(TripleAxis)]
+            Fly,
+        }
+
+        let mut previous = ActionState::<Action>::default();
+        previous.press(&Action::Crouch);
+        previous.press(&Action::Shoot);
+        previous.set_value(&Action::Throttle, 0.5);
+        previous.set_axis_pair(&Action::Move, Vec2::X);
+
+        // Identical states generate no diffs
+        assert!(previous.generate_diffs(&previous).is_empty());
+
+        let mut current = previous.clone();
+        current.press(&Action::Jump);
+        current.release(&Action::Crouch);
+        current.set_value(&Action::Throttle, 0.5);
+        current.set_axis_pair(&Action::Move, Vec2::Y);
+        current.set_axis_triple(&Action::Fly, Vec3::Z);
+
+        let diffs = current.generate_diffs(&previous);
+        let expected = [
+            ActionDiff::Pressed {
+                action: Action::Jump,
+                value: 1.0,
+            },
+            ActionDiff::Released {
+                action: Action::Crouch,
+            },
+            ActionDiff::DualAxisChanged {
+                action: Action::Move,
+                axis_pair: Vec2::Y,
+            },
+            ActionDiff::TripleAxisChanged {
+                action: Action::Fly,
+                axis_triple: Vec3::Z,
+            },
+        ];
+        assert_eq!(diffs.len(), expected.len());
+        for diff in expected {
+            assert!(diffs.contains(&diff), "missing {diff:?} in {diffs:?}");
+        }
+
+        // The diffs reconstruct the current state from the previous one
+        let mut replica = previous.clone();
+        replica.apply_diffs(&diffs);
+        assert!(replica.generate_diffs(&current).is_empty());
+
+        // Releasing a button that was never pressed generates no diff
+        let mut released = ActionState::<Action>::default();
+        released.release(&Action::Jump);
+        assert!(released.generate_diffs(&ActionState::default()).is_empty());
+
+        // Actions missing from the current state are compared against their default state
+        previous.press(&Action::Jump);
+        let mut replica = previous.clone();
+        replica.apply_diffs(&ActionState::default().generate_diffs(&previous));
+        assert!(!replica.pressed(&Action::Jump));
+        assert!(!replica.pressed(&Action::Crouch));
+        assert!(!replica.pressed(&Action::Shoot));
+        assert_eq!(replica.value(&Action::Throttle), 0.0);
+        assert_eq!(replica.axis_pair(&Action::Move), Vec2::ZERO);
+    }
+
+    #[test]
+    fn dual_axis_pushed() {
+        use bevy::utils::Instant;
+
+        #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+        enum Action {
+            #[actionlike(DualAxis)]
+            Navigate,
+        }
+
+        let mut action_state = ActionState::<Action>::default();
+        let mut nudge = |pair: Vec2| {
+            action_state.tick(Instant::now(), Instant::now());
+            action_state.set_axis_pair(&Action::Navigate, pair);
+            action_state.dual_axis_pushed(&Action::Navigate, 0.5)
+        };
+
+        // Resting or drifting within the threshold is not a push
+        assert_eq!(nudge(Vec2::ZERO), None);
+        assert_eq!(nudge(Vec2::new(0.3, 0.0)), None);
+
+        // Crossing the threshold pushes the stick in its direction, once
+        assert_eq!(nudge(Vec2::new(0.9, 0.0)), Some(Vec2::X));
+        assert_eq!(nudge(Vec2::new(1.0, 0.0)), None);
+        assert_eq!(nudge(Vec2::new(0.0, 1.0)), None);
+
+        // The stick must return within the threshold before it can be pushed again
+        assert_eq!(nudge(Vec2::new(0.0, -0.2)), None);
+        assert_eq!(nudge(Vec2::new(0.0, -0.7)), Some(Vec2::NEG_Y));
+
+        // Disabled actions are never pushed
+        action_state.disable_action(&Action::Navigate);
+        action_state.tick(Instant::now(), Instant::now());
+        action_state.set_axis_pair(&Action::Navigate, Vec2::ZERO);
+        action_state.tick(Instant::now(), Instant::now());
+        action_state.set_axis_pair(&Action::Navigate, Vec2::X);
+        assert_eq!(action_state.dual_axis_pushed(&Action::Navigate, 0.5), None);
+    }
+
+    #[test]
+    fn value_preferring() {
+        use crate::input_map::{UpdatedActions, UpdatedValue};
+        use bevy::utils::HashMap;
+
+        #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+        enum Action {
+            #[actionlike(Axis)]
+            Throttle,
+            Accelerate,
+        }
+
+        let mut action_state = ActionState::<Action>::default();
+
+        // Neither action is active
+        assert_eq!(
+            action_state.value_preferring(Action::Throttle, Action::Accelerate),
+            0.0
+        );
+
+        // The primary action is zero, so the pressed fallback is used
+        action_state.update(UpdatedActions(HashMap::from([
+            (Action::Throttle, UpdatedValue::Axis(0.0)),
+            (Action::Accelerate, UpdatedValue::Button(true)),
+        ])));
+        assert_eq!(
+            action_state.value_preferring(Action::Throttle, Action::Accelerate),
+            1.0
+        );
+
+        // The primary action is preferred whenever it is active
+        action_state.update(UpdatedActions(HashMap::from([
+            (Action::Throttle, UpdatedValue::Axis(0.4)),
+            (Action::Accelerate, UpdatedValue::Button(true)),
+        ])));
+        assert_eq!(
+            action_state.value_preferring(Action::Throttle, Action::Accelerate),
+            0.4
+        );
+    }
+
+    #[test]
+    fn all_values() {
+        #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+        enum Action {
+            Jump,
+            #[actionlike(Axis)]
+            Throttle,
+            #[actionlike(Axis)]
+            Brake,
+            #[actionlike(DualAxis)]
+            Move,
+            #[actionlike(DualAxis)]
+            Look,
+            #[actionlike(TripleAxis)]
+            Fly,
+        }
+
+        let mut action_state = ActionState::<Action>::default();
+        action_state.press(&Action::Jump);
+        action_state.set_value(&Action::Throttle, 0.7);
+        action_state.set_value(&Action::Brake, -0.2);
+        action_state.set_axis_pair(&Action::Move, Vec2::new(0.3, -0.4));
+        action_state.set_axis_pair(&Action::Look, Vec2::X);
+        action_state.set_axis_triple(&Action::Fly, Vec3::new(0.1, 0.2, 0.3));
+
+        // Disabled actions report zero
+        action_state.disable_action(&Action::Brake);
+        action_state.disable_action(&Action::Look);
+
+        let assert_matches_getters = |action_state: &ActionState<Action>| {
+            let values = action_state.all_values();
+            assert_eq!(values.len(), 2);
+            for (action, value) in &values {
+                assert_eq!(*value, action_state.value(action));
+            }
+
+            let pairs = action_state.all_axis_pairs();
+            assert_eq!(pairs.len(), 2);
+            for (action, pair) in &pairs {
+                assert_eq!(*pair, action_state.axis_pair(action));
+            }
+
+            let triples = action_state.all_axis_triples();
+            assert_eq!(triples.len(), 1);
+            for (action, triple) in &triples {
+                assert_eq!(*triple, action_state.axis_triple(action));
+            }
+        };
+
+        assert_matches_getters(&action_state);
+        assert_eq!(action_state.all_values()[&Action::Throttle], 0.7);
+        assert_eq!(action_state.all_values()[&Action::Brake], 0.0);
+        assert_eq!(action_state.all_axis_pairs()[&Action::Look], Vec2::ZERO);
+
+        // As does everything once the whole action state is disabled
+        action_state.disable();
+        assert_matches_getters(&action_state);
+        assert_eq!(action_state.all_values()[&Action::Throttle], 0.0);
+        assert_eq!(action_state.all_axis_triples()[&Action::Fly], Vec3::ZERO);
+    }
+
+    #[test]
+    fn smoothing() {
+        use crate::input_map::{UpdatedActions, UpdatedValue};
+        use crate::input_processing::ExponentialSmoothing;
+        use bevy::utils::HashMap;
+
+        #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+        enum Action {
+            #[actionlike(Axis)]
+            Zoom,
+            #[actionlike(DualAxis)]
+            Look,
+            Jump,
+        }
+
+        let step = || {
+            UpdatedActions(HashMap::from([
+                (Action::Zoom, UpdatedValue::Axis(1.0)),
+                (Action::Look, UpdatedValue::DualAxis(Vec2::new(2.0, -1.0))),
+            ]))
+        };
+
+        let mut action_state = ActionState::<Action>::default();
+        let smoothing = ExponentialSmoothing::new(0.5);
+        action_state.set_smoothing(&Action::Zoom, Some(smoothing));
+        action_state.set_smoothing(&Action::Look, Some(smoothing));
+        assert_eq!(action_state.smoothing(&Action::Look), Some(smoothing));
+
+        // A step input converges geometrically toward the target
+        for frame in 1..10 {
+            action_state.update(step());
+
+            let remaining = 0.5_f32.powi(frame);
+            assert_eq!(action_state.value(&Action::Zoom), 1.0 - remaining);
+            assert_eq!(
+                action_state.axis_pair(&Action::Look),
+                Vec2::new(2.0, -1.0) * (1.0 - remaining)
+            );
+        }
+
+        // Resetting the actions also resets the smoothing
+        action_state.reset_all();
+        action_state.update(step());
+        assert_eq!(action_state.value(&Action::Zoom), 0.5);
+        assert_eq!(action_state.axis_pair(&Action::Look), Vec2::new(1.0, -0.5));
+
+        // Without smoothing, new values are applied immediately
+        action_state.set_smoothing(&Action::Zoom, None);
+        action_state.update(step());
+        assert_eq!(action_state.smoothing(&Action::Zoom), None);
+        assert_eq!(action_state.value(&Action::Zoom), 1.0);
+
+        // Buttonlike actions can't be smoothed
+        action_state.set_smoothing(&Action::Jump, Some(smoothing));
+        assert_eq!(action_state.smoothing(&Action::Jump), None);
+        assert_eq!(action_state.action_data(&Action::Jump), None);
+    }
+
+    #[test]
+    fn overwrite_from() {
+        use bevy::utils::Instant;
+
+        #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+        enum Action {
+            Jump,
+            Run,
+            #[actionlike(Axis)]
+            Zoom,
+        }
+
+        // The local state is pressed, while the server has just released the action
+        let mut local = ActionState::<Action>::default();
+        local.press(&Action::Jump);
+        local.set_value(&Action::Zoom, 0.5);
+        local.tick(Instant::now(), Instant::now());
+        assert!(local.pressed(&Action::Jump));
+
+        let mut authoritative = ActionState::<Action>::default();
+        authoritative.press(&Action::Jump);
+        authoritative.tick(Instant::now(), Instant::now());
+        authoritative.release(&Action::Jump);
+        authoritative.disable_action(&Action::Run);
+
+        local.overwrite_from(&authoritative);
+        assert_eq!(local, authoritative);
+        assert!(local.just_released(&Action::Jump));
+        assert!(local.action_disabled(&Action::Run));
+
+        // Actions missing from the authoritative state are reset
+        assert_eq!(local.value(&Action::Zoom), 0.0);
+
+        // Ticking proceeds from the authoritative state
+        local.tick(Instant::now(), Instant::now());
+        assert!(local.released(&Action::Jump));
+        assert!(!local.just_released(&Action::Jump));
+
+        // Overwriting a pressed state with a state that has been released for a while
+        // doesn't report the action as just released
+        local.press(&Action::Jump);
+        local.tick(Instant::now(), Instant::now());
+        authoritative.tick(Instant::now(), Instant::now());
+        local.overwrite_from(&authoritative);
+        assert!(local.released(&Action::Jump));
+        assert!(!local.just_released(&Action::Jump));
+
+        // Pressing the action again locally is detected as usual
+        local.press(&Action::Jump);
+        assert!(local.just_pressed(&Action::Jump));
+    }
+
+    #[test]
+    fn serialization_omits_default_actions() {
+        use crate::action_state::ActionData;
+        use crate::Actionlike;
+        use bevy::utils::HashMap;
+
+        #[derive(
+            Actionlike,
+            Clone,
+            Copy,
+            PartialEq,
+            Eq,
+            Hash,
+            Debug,
+            Reflect,
+            serde::Serialize,
+            serde::Deserialize,
+        )]
+        enum Action {
+            Jump,
+            Run,
+            Hide,
+            Crouch,
+            Attack,
+            #[actionlike(Axis)]
+            Zoom,
+            #[actionlike(DualAxis)]
+            Move,
+            #[actionlike(TripleAxis)]
+            Fly,
+        }
+
+        let mut action_state = ActionState::<Action>::default();
+        for action in Action::variants() {
+            action_state.action_data_mut_or_default(&action);
+        }
+        action_state.press(&Action::Jump);
+        assert_eq!(action_state.all_action_data().len(), 8);
+
+        let compact = ron::to_string(&action_state).unwrap();
+        let full = ron::to_string(action_state.all_action_data()).unwrap();
+        assert!(compact.len() < full.len());
+
+        // Only the pressed action is stored
+        let deserialized: ActionState<Action> = ron::from_str(&compact).unwrap();
+        assert_eq!(deserialized.all_action_data().len(), 1);
+        assert!(deserialized.pressed(&Action::Jump));
+        assert!(deserialized.released(&Action::Run));
+        assert_eq!(deserialized.axis_pair(&Action::Move), Vec2::ZERO);
+
+        // Missing actions are treated as having their default data
+        assert_eq!(deserialized, action_state);
+        assert_eq!(action_state, deserialized);
+
+        // Fully serialized states can still be deserialized
+        let full_map: HashMap<Action, ActionData> = ron::from_str(&full).unwrap();
+        assert_eq!(&full_map, action_state.all_action_data());
+
+        // While non-default data is still compared
+        let mut released = deserialized.clone();
+        released.release(&Action::Jump);
+        assert_ne!(released, action_state);
+    }
+
+    #[cfg(feature = "keyboard")]
+    #[test]
+    fn compact_serialization_ignores_triggering_inputs() {
+        use bevy::input::keyboard::KeyCode;
+
+        #[derive(
+            Actionlike,
+            Clone,
+            Copy,
+            PartialEq,
+            Eq,
+            Hash,
+            Debug,
+            Reflect,
+            serde::Serialize,
+            serde::Deserialize,
+        )]
+        enum Action {
+            Jump,
+        }
+
+        // The triggering input is recorded when pressed, and kept after release
+        let mut action_state = ActionState::<Action>::default();
+        action_state.press(&Action::Jump);
+        action_state
+            .button_data_mut(&Action::Jump)
+            .unwrap()
+            .triggering_input = Some(Box::new(KeyCode::Space));
+        assert!(!action_state
+            .action_data(&Action::Jump)
+            .unwrap()
+            .is_default_for(&Action::Jump));
+
+        action_state.release(&Action::Jump);
+        let now = bevy::utils::Instant::now();
+        action_state.tick(now, now);
+        assert!(action_state.triggering_input(&Action::Jump).is_some());
+
+        // Released actions are still omitted, as the triggering input isn't serialized
+        assert!(action_state
+            .action_data(&Action::Jump)
+            .unwrap()
+            .is_default_for(&Action::Jump));
+        let compact = ron::to_string(&action_state).unwrap();
+        let deserialized: ActionState<Action> = ron::from_str(&compact).unwrap();
+        assert!(deserialized.all_action_data().is_empty());
+        assert_eq!(deserialized, action_state);
+    }
+
+    #[test]
+    fn initialize_all() {
+        #[derive(
+            Actionlike,
+            Clone,
+            Copy,
+            PartialEq,
+            Eq,
+            Hash,
+            Debug,
+            Reflect,
+            serde::Serialize,
+            serde::Deserialize,
+        )]
+        enum Action {
+            Jump,
+            Run,
+            #[actionlike(Axis)]
+            Zoom,
+            #[actionlike(DualAxis)]
+            Move,
+            #[actionlike(TripleAxis)]
+            Fly,
+        }
+
+        // Default actions are omitted when serializing, so they are missing once deserialized
+        let mut action_state = ActionState::<Action>::default();
+        action_state.press(&Action::Jump);
+        action_state.set_value(&Action::Zoom, 0.5);
+        let serialized = ron::to_string(&action_state).unwrap();
+        let mut deserialized: ActionState<Action> = ron::from_str(&serialized).unwrap();
+        assert_eq!(deserialized.keys().len(), 2);
+
+        deserialized.initialize_all();
+        let keys = deserialized.keys();
+        assert_eq!(keys.len(), 5);
+        for action in [
+            Action::Jump,
+            Action::Run,
+            Action::Zoom,
+            Action::Move,
+            Action::Fly,
+        ] {
+            assert!(keys.contains(&action));
+        }
+
+        // Each missing action is initialized with the default data for its kind
+        assert!(deserialized
+            .action_data(&Action::Run)
+            .unwrap()
+            .is_default_for(&Action::Run));
+        assert!(deserialized
+            .action_data(&Action::Fly)
+            .unwrap()
+            .is_default_for(&Action::Fly));
+
+        // While existing data is preserved
+        assert!(deserialized.pressed(&Action::Jump));
+        assert_eq!(deserialized.value(&Action::Zoom), 0.5);
+    }
+
+    #[cfg(feature = "timing")]
+    #[test]
+    fn overwrite_from_preserves_instants() {
+        use bevy::utils::Instant;
+        use std::time::Duration;
+
+        #[derive(
+            Actionlike,
+            Clone,
+            Copy,
+            PartialEq,
+            Eq,
+            Hash,
+            Debug,
+            Reflect,
+            serde::Serialize,
+            serde::Deserialize,
+        )]
+        enum Action {
+            Jump,
+            Run,
+        }
+
+        let start = Instant::now();
+        let mut local = ActionState::<Action>::default();
+        local.press(&Action::Jump);
+        local.press(&Action::Run);
+        local.tick(start, start);
+        local.tick(start + Duration::from_millis(100), start);
+
+        // Instants aren't serialized, so they are missing from states received over the network
+        let mut authoritative = ActionState::<Action>::default();
+        authoritative.press(&Action::Jump);
+        authoritative.press(&Action::Run);
+        authoritative.tick(start, start);
+        authoritative.tick(start + Duration::from_millis(200), start);
+        authoritative.release(&Action::Run);
+        let serialized = ron::to_string(&authoritative).unwrap();
+        let authoritative: ActionState<Action> = ron::from_str(&serialized).unwrap();
+
+        local.overwrite_from(&authoritative);
+
+        // Buttons pressed in both states keep their local instants, but use the authoritative durations
+        assert_eq!(local.instant_started(&Action::Jump), Some(start));
+        assert_eq!(
+            local.current_duration(&Action::Jump),
+            Duration::from_millis(200)
+        );
+
+        // Buttons whose state changed record new instants on the next tick
+        assert!(local.released(&Action::Run));
+        assert_eq!(local.instant_started(&Action::Run), None);
+        local.tick(
+            start + Duration::from_millis(300),
+            start + Duration::from_millis(200),
+        );
+        assert_eq!(
+            local.instant_started(&Action::Run),
+            Some(start + Duration::from_millis(200))
+        );
+    }
+
+    #[test]
+    fn buffered_presses() {
+        use crate::buttonlike::BufferConfig;
+        use crate::input_map::{UpdatedActions, UpdatedValue};
+        use bevy::utils::{HashMap, Instant};
+
+        #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+        enum Action {
+            Jump,
+            Attack,
+        }
+
+        let inputs = |pressed: bool| {
+            UpdatedActions(HashMap::from([
+                (Action::Jump, UpdatedValue::Button(pressed)),
+                (Action::Attack, UpdatedValue::Button(pressed)),
+            ]))
+        };
+
+        let mut action_state = ActionState::<Action>::default();
+        action_state.set_buffer(&Action::Jump, BufferConfig::frames(3));
+        assert_eq!(action_state.buffer(&Action::Jump), BufferConfig::frames(3));
+        assert_eq!(action_state.buffer(&Action::Attack), BufferConfig::NONE);
+
+        // Frame 0: both actions are pressed for a single frame
+        action_state.update(inputs(true));
+        assert!(action_state.just_pressed(&Action::Jump));
+        assert!(action_state.just_pressed(&Action::Attack));
+
+        // Frames 1 to 3: the buffered press is still just pressed, even once released
+        for frame in 1..=3 {
+            action_state.tick(Instant::now(), Instant::now());
+            action_state.update(inputs(false));
+            assert!(action_state.just_pressed(&Action::Jump), "frame {frame}");
+            assert!(!action_state.just_pressed(&Action::Attack), "frame {frame}");
+        }
+
+        // Frame 4: the buffer has expired
+        action_state.tick(Instant::now(), Instant::now());
+        action_state.update(inputs(false));
+        assert!(!action_state.just_pressed(&Action::Jump));
+
+        // Consuming the action clears the buffer
+        action_state.update(inputs(true));
+        action_state.tick(Instant::now(), Instant::now());
+        assert!(action_state.just_pressed(&Action::Jump));
+        action_state.consume(&Action::Jump);
+        assert!(!action_state.just_pressed(&Action::Jump));
+    }
+
+    #[test]
+    fn buffered_presses_are_tracked_per_schedule() {
+        use crate::buttonlike::BufferConfig;
+        use bevy::utils::Instant;
+
+        #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+        enum Action {
+            Jump,
+        }
+
+        let mut action_state = ActionState::<Action>::default();
+        action_state.set_buffer(&Action::Jump, BufferConfig::frames(2));
+
+        // The action is pressed and buffered in the `Main` schedule
+        action_state.press(&Action::Jump);
+        action_state.tick(Instant::now(), Instant::now());
+        assert!(action_state.just_pressed(&Action::Jump));
+
+        // Ticking the `FixedMain` schedule several times doesn't count down the buffer in the `Main` schedule
+        action_state.swap_to_fixed_update_state();
+        for _ in 0..3 {
+            action_state.tick(Instant::now(), Instant::now());
+        }
+        action_state.swap_to_update_state();
+        assert!(action_state.just_pressed(&Action::Jump));
+
+        action_state.tick(Instant::now(), Instant::now());
+        assert!(action_state.just_pressed(&Action::Jump));
+        action_state.tick(Instant::now(), Instant::now());
+        assert!(!action_state.just_pressed(&Action::Jump));
+    }
+
+    #[test]
+    fn action_iterators() {
+        use crate::input_map::{UpdatedActions, UpdatedValue};
+        use bevy::utils::{HashMap, HashSet, Instant};
+
+        #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+        enum Action {
+            Run,
+            Jump,
+            Hide,
+            #[actionlike(Axis)]
+            Axis,
+        }
+
+        let mut action_state = ActionState::<Action>::default();
+        action_state.update(UpdatedActions(HashMap::from([
+            (Action::Run, UpdatedValue::Button(true)),
+            (Action::Jump, UpdatedValue::Button(true)),
+            (Action::Hide, UpdatedValue::Button(false)),
+            (Action::Axis, UpdatedValue::Axis(1.0)),
+        ])));
+        action_state.tick(Instant::now(), Instant::now());
+        action_state.update(UpdatedActions(HashMap::from([
+            (Action::Run, UpdatedValue::Button(false)),
+            (Action::Hide, UpdatedValue::Button(true)),
+        ])));
+
+        let set = |actions: Vec<Action>| actions.into_iter().collect::<HashSet<_>>();
+        let iter_set = |actions: Vec<&Action>| actions.into_iter().copied().collect::<HashSet<_>>();
+
+        // The iterators match the allocating methods, and only include buttonlike actions
+        assert_eq!(
+            iter_set(action_state.pressed_actions().collect()),
+            set(action_state.get_pressed())
+        );
+        assert_eq!(
+            set(action_state.get_pressed()),
+            set(vec![Action::Jump, Action::Hide])
+        );
+
+        assert_eq!(
+            iter_set(action_state.just_pressed_actions().collect()),
+            set(action_state.get_just_pressed())
+        );
+        assert_eq!(
+            set(action_state.get_just_pressed()),
+            set(vec![Action::Hide])
+        );
+
+        assert_eq!(
+            iter_set(action_state.released_actions().collect()),
+            set(action_state.get_released())
+        );
+        assert_eq!(set(action_state.get_released()), set(vec![Action::Run]));
+
+        assert_eq!(
+            iter_set(action_state.just_released_actions().collect()),
+            set(action_state.get_just_released())
+        );
+        assert_eq!(
+            set(action_state.get_just_released()),
+            set(vec![Action::Run])
+        );
+    }
+
+    #[test]
+    fn consume_all() {
+        use crate::input_map::{UpdatedActions, UpdatedValue};
+        use bevy::utils::{HashMap, Instant};
+
+        #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+        enum Action {
+            One,
+            Two,
+            #[actionlike(Axis)]
+            Axis,
+        }
+
+        let held = |pressed: bool| {
+            UpdatedActions(HashMap::from([
+                (Action::One, UpdatedValue::Button(pressed)),
+                (Action::Two, UpdatedValue::Button(pressed)),
+                (Action::Axis, UpdatedValue::Axis(0.5)),
+            ]))
+        };
+
+        let mut action_state = ActionState::<Action>::default();
+        action_state.update(held(true));
+        assert!(action_state.pressed(&Action::One));
+        assert!(action_state.pressed(&Action::Two));
+
+        action_state.consume_all();
+        assert!(action_state.released(&Action::One));
+        assert!(action_state.released(&Action::Two));
+        assert_eq!(action_state.value(&Action::Axis), 0.5);
+
+        // Holding the inputs down does not press the consumed actions again
+        action_state.tick(Instant::now(), Instant::now());
+        action_state.update(held(true));
+        assert!(action_state.released(&Action::One));
+        assert!(action_state.released(&Action::Two));
+
+        // Until they are physically released and pressed again
+        action_state.update(held(false));
+        action_state.update(held(true));
+        assert!(action_state.just_pressed(&Action::One));
+        assert!(action_state.just_pressed(&Action::Two));
+    }
+
+    #[test]
+    fn consume_until_re_press() {
+        use crate::action_diff::{ActionDiff, SummarizedActionState};
+        use crate::input_map::{UpdatedActions, UpdatedValue};
+        use bevy::utils::{HashMap, Instant};
+
+        #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+        enum Action {
+            Jump,
+        }
+
+        let held = |pressed: bool| {
+            UpdatedActions(HashMap::from([(
+                Action::Jump,
+                UpdatedValue::Button(pressed),
+            )]))
+        };
+        let button_value = |action_state: &ActionState<Action>| {
+            action_state
+                .button_data(&Action::Jump)
+                .map(|button_data| button_data.to_button_value())
+        };
+
+        let mut action_state = ActionState::<Action>::default();
+        action_state.update(held(true));
+        assert!(action_state.just_pressed(&Action::Jump));
+        let pressed_value = button_value(&action_state);
+
+        // Consuming releases the action, generating a release diff
+        action_state.consume(&Action::Jump);
+        assert!(action_state.just_released(&Action::Jump));
+        assert_eq!(
+            SummarizedActionState::button_diff(
+                Action::Jump,
+                pressed_value,
+                button_value(&action_state)
+            ),
+            Some(ActionDiff::Released {
+                action: Action::Jump
+            })
+        );
+
+        // The input is still held down, but the action stays released
+        for _ in 0..2 {
+            action_state.tick(Instant::now(), Instant::now());
+            action_state.update(held(true));
+            assert!(action_state.released(&Action::Jump));
+            assert!(!action_state.just_pressed(&Action::Jump));
+            assert!(action_state.consumed(&Action::Jump));
+        }
+
+        // A fresh press is required
+        action_state.tick(Instant::now(), Instant::now());
+        action_state.update(held(false));
+        assert!(!action_state.consumed(&Action::Jump));
+        action_state.update(held(true));
+        assert!(action_state.just_pressed(&Action::Jump));
+    }
+
+    #[test]
+    fn axis_triple_spherical() {
+        use std::f32::consts::FRAC_PI_4;
+
+        #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+        enum Action {
+            #[actionlike(TripleAxis)]
+            Look,
+        }
+
+        let mut action_state = ActionState::<Action>::default();
+        assert_eq!(
+            action_state.axis_triple_spherical(&Action::Look),
+            (0.0, 0.0, 0.0)
+        );
+
+        action_state.set_axis_triple(&Action::Look, Vec3::new(1.0, 1.0, 2.0_f32.sqrt()));
+        let (radius, theta, phi) = action_state.axis_triple_spherical(&Action::Look);
+        assert!((radius - 2.0).abs() <= f32::EPSILON);
+        assert!((theta - FRAC_PI_4).abs() <= 1e-6);
+        assert!((phi - FRAC_PI_4).abs() <= 1e-6);
+    }
+
+    #[test]
+    fn deserialize_data_without_new_fields() {
+        use crate::action_state::{AxisData, ButtonData, DualAxisData};
+
+        let timing = if cfg!(feature = "timing") {
+            "timing: (current_duration: (secs: 0, nanos: 0), previous_duration: (secs: 0, nanos: 0)), "
+        } else {
+            ""
+        };
+        let button = format!(
+            "(state: JustPressed, update_state: Released, fixed_update_state: Released, \
+             value: 1.0, update_value: 0.0, fixed_update_value: 0.0, {timing})"
+        );
+        let button: ButtonData = ron::from_str(&button).unwrap();
+        assert!(button.just_pressed());
+        assert!(!button.consumed);
+        assert_eq!(button.buffered_frames, 0);
+
+        let axis: AxisData =
+            ron::from_str("(value: 0.5, update_value: 0.0, fixed_update_value: 0.0)").unwrap();
+        assert_eq!(axis.value, 0.5);
+        assert_eq!(axis.smoothing, None);
+
+        let dual_axis: DualAxisData = ron::from_str(
+            "(pair: (1.0, 0.0), update_pair: (0.0, 0.0), fixed_update_pair: (0.0, 0.0))",
+        )
+        .unwrap();
+        assert_eq!(dual_axis.pair, Vec2::X);
+        assert_eq!(dual_axis.previous_pair, Vec2::ZERO);
+    }
+
     #[cfg(feature = "keyboard")]
     #[test]
     #[ignore = "Clashing inputs for non-buttonlike inputs is broken."]
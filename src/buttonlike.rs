@@ -122,3 +122,34 @@ impl ButtonState {
         *self == ButtonState::JustReleased
     }
 }
+
+/// Configures input buffering for a buttonlike action,
+/// keeping it [`just_pressed`](crate::action_state::ActionState::just_pressed) for several frames after it was pressed.
+///
+/// This is useful for fighting games and platformers,
+/// where a jump pressed a few frames before landing should still register.
+///
+/// A buffered action remains just pressed for `frames` additional calls to [`ActionState::tick`](crate::action_state::ActionState::tick),
+/// even if it is released in the meantime.
+/// Consuming the action with [`ActionState::consume`](crate::action_state::ActionState::consume) clears the buffer,
+/// so that a buffered press is only handled once.
+///
+/// By default, presses are not buffered.
+///
+/// Set this on an action using [`ActionState::set_buffer`](crate::action_state::ActionState::set_buffer).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+pub struct BufferConfig {
+    /// The number of ticks after the press for which the action remains just pressed.
+    pub frames: u32,
+}
+
+impl BufferConfig {
+    /// Presses are not buffered, and are only just pressed until the next tick.
+    pub const NONE: Self = Self { frames: 0 };
+
+    /// Creates a [`BufferConfig`] that keeps the action just pressed for the given number of additional `frames`.
+    #[inline]
+    pub const fn frames(frames: u32) -> Self {
+        Self { frames }
+    }
+}
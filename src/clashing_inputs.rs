@@ -6,12 +6,12 @@
 
 use std::cmp::Ordering;
 
-use bevy::prelude::{Entity, Resource};
+use bevy::prelude::{Entity, Reflect, Resource};
 use serde::{Deserialize, Serialize};
 
-use crate::input_map::{InputMap, UpdatedActions};
+use crate::input_map::{InputContexts, InputMap, UpdatedActions};
 use crate::prelude::updating::CentralInputStore;
-use crate::user_input::Buttonlike;
+use crate::user_input::{Buttonlike, UserInputWrapper};
 use crate::{Actionlike, InputControlKind};
 
 /// How should clashing inputs by handled by an [`InputMap`]?
@@ -27,8 +27,11 @@ use crate::{Actionlike, InputControlKind};
 ///
 /// This strategy is only used when assessing the actions and input holistically,
 /// in [`InputMap::process_actions`], using [`InputMap::handle_clashes`].
+///
+/// The [`ClashStrategy`] resource applies to every [`InputMap`],
+/// unless a map overrides it using [`InputMap::with_clash_strategy`].
 #[non_exhaustive]
-#[derive(Resource, Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize, Default)]
+#[derive(Resource, Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize, Default, Reflect)]
 pub enum ClashStrategy {
     /// All matching inputs will always be pressed
     PressAll,
@@ -177,7 +180,26 @@ impl<A: Actionlike> InputMap<A> {
         clash_strategy: ClashStrategy,
         gamepad: Entity,
     ) {
-        for clash in self.get_clashes(updated_actions, input_store, gamepad) {
+        self.handle_clashes_in_context(
+            updated_actions,
+            input_store,
+            clash_strategy,
+            gamepad,
+            &InputContexts::default(),
+        );
+    }
+
+    /// Resolve clashing button-like inputs like [`InputMap::handle_clashes`],
+    /// ignoring the bindings whose context is inactive in the provided [`InputContexts`].
+    pub fn handle_clashes_in_context(
+        &self,
+        updated_actions: &mut UpdatedActions<A>,
+        input_store: &CentralInputStore,
+        clash_strategy: ClashStrategy,
+        gamepad: Entity,
+        contexts: &InputContexts,
+    ) {
+        for clash in self.get_clashes(updated_actions, input_store, gamepad, contexts) {
             // Remove the action in the pair that was overruled, if any
             if let Some(culled_action) = resolve_clash(&clash, clash_strategy, input_store, gamepad)
             {
@@ -186,13 +208,46 @@ impl<A: Actionlike> InputMap<A> {
         }
     }
 
+    /// Finds every pair of [`Buttonlike`] actions whose bindings clash under the given [`ClashStrategy`],
+    /// allowing control schemes to be checked for clashes before they are used.
+    ///
+    /// Unlike [`InputMap::handle_clashes`], this doesn't depend on which inputs are currently pressed,
+    /// and bindings restricted to a context are always considered,
+    /// while [disabled](InputMap::disable_binding) bindings are ignored.
+    /// Each pair of clashing actions is only reported once.
+    ///
+    /// [`ClashStrategy::PressAll`] never resolves clashes, so no pairs are reported for it.
+    #[must_use]
+    pub fn find_clashes(&self, clash_strategy: ClashStrategy) -> Vec<(A, A)> {
+        match clash_strategy {
+            ClashStrategy::PressAll => return Vec::new(),
+            ClashStrategy::PrioritizeLongest => {}
+        }
+
+        let contexts = self.all_binding_contexts();
+        let actions: Vec<&A> = self.buttonlike_actions().collect();
+        let mut clashes = Vec::default();
+
+        for (index, action_a) in actions.iter().enumerate() {
+            for action_b in actions.iter().skip(index + 1) {
+                if self.possible_clash(action_a, action_b, &contexts).is_some() {
+                    clashes.push(((*action_a).clone(), (*action_b).clone()));
+                }
+            }
+        }
+
+        clashes
+    }
+
     /// Updates the cache of possible input clashes
-    pub(crate) fn possible_clashes(&self) -> Vec<Clash<A>> {
+    ///
+    /// Bindings whose context is inactive in the provided [`InputContexts`] can't clash.
+    pub(crate) fn possible_clashes(&self, contexts: &InputContexts) -> Vec<Clash<A>> {
         let mut clashes = Vec::default();
 
         for action_a in self.buttonlike_actions() {
             for action_b in self.buttonlike_actions() {
-                if let Some(clash) = self.possible_clash(action_a, action_b) {
+                if let Some(clash) = self.possible_clash(action_a, action_b, contexts) {
                     clashes.push(clash);
                 }
             }
@@ -210,11 +265,12 @@ impl<A: Actionlike> InputMap<A> {
         updated_actions: &UpdatedActions<A>,
         input_store: &CentralInputStore,
         gamepad: Entity,
+        contexts: &InputContexts,
     ) -> Vec<Clash<A>> {
         let mut clashes = Vec::default();
 
         // We can limit our search to the cached set of possibly clashing actions
-        for clash in self.possible_clashes() {
+        for clash in self.possible_clashes(contexts) {
             let pressed_a = updated_actions.pressed(&clash.action_a);
             let pressed_b = updated_actions.pressed(&clash.action_b);
 
@@ -274,11 +330,29 @@ impl<A: Actionlike> InputMap<A> {
     /// If the pair of actions could clash, how?
     // FIXME: does not handle axis inputs. Should use the `decomposed` method instead of `get_buttonlike`
     #[must_use]
-    fn possible_clash(&self, action_a: &A, action_b: &A) -> Option<Clash<A>> {
+    fn possible_clash(
+        &self,
+        action_a: &A,
+        action_b: &A,
+        contexts: &InputContexts,
+    ) -> Option<Clash<A>> {
         let mut clash = Clash::new(action_a.clone(), action_b.clone());
+        let in_context = |action: &A, binding: &dyn Buttonlike| {
+            self.binding_considered(action, contexts, |input| {
+                matches!(input, UserInputWrapper::Button(tagged) if tagged.as_ref() == binding)
+            })
+        };
 
-        for input_a in self.get_buttonlike(action_a)? {
-            for input_b in self.get_buttonlike(action_b)? {
+        for input_a in self
+            .get_buttonlike(action_a)?
+            .iter()
+            .filter(|input| in_context(action_a, input.as_ref()))
+        {
+            for input_b in self
+                .get_buttonlike(action_b)?
+                .iter()
+                .filter(|input| in_context(action_b, input.as_ref()))
+            {
                 if input_a.decompose().clashes_with(&input_b.decompose()) {
                     clash.inputs_a.push(input_a.clone());
                     clash.inputs_b.push(input_b.clone());
@@ -534,7 +608,9 @@ mod tests {
         fn button_chord_clash_construction() {
             let input_map = test_input_map();
 
-            let observed_clash = input_map.possible_clash(&One, &OneAndTwo).unwrap();
+            let observed_clash = input_map
+                .possible_clash(&One, &OneAndTwo, &InputContexts::default())
+                .unwrap();
 
             let correct_clash = Clash {
                 action_a: One,
@@ -551,7 +627,7 @@ mod tests {
             let input_map = test_input_map();
 
             let observed_clash = input_map
-                .possible_clash(&OneAndTwoAndThree, &OneAndTwo)
+                .possible_clash(&OneAndTwoAndThree, &OneAndTwo, &InputContexts::default())
                 .unwrap();
             let correct_clash = Clash {
                 action_a: OneAndTwoAndThree,
@@ -567,13 +643,46 @@ mod tests {
         fn can_clash() {
             let input_map = test_input_map();
 
-            assert!(input_map.possible_clash(&One, &Two).is_none());
-            assert!(input_map.possible_clash(&One, &OneAndTwo).is_some());
-            assert!(input_map.possible_clash(&One, &OneAndTwoAndThree).is_some());
-            assert!(input_map.possible_clash(&One, &TwoAndThree).is_none());
             assert!(input_map
-                .possible_clash(&OneAndTwo, &OneAndTwoAndThree)
+                .possible_clash(&One, &Two, &InputContexts::default())
+                .is_none());
+            assert!(input_map
+                .possible_clash(&One, &OneAndTwo, &InputContexts::default())
+                .is_some());
+            assert!(input_map
+                .possible_clash(&One, &OneAndTwoAndThree, &InputContexts::default())
                 .is_some());
+            assert!(input_map
+                .possible_clash(&One, &TwoAndThree, &InputContexts::default())
+                .is_none());
+            assert!(input_map
+                .possible_clash(&OneAndTwo, &OneAndTwoAndThree, &InputContexts::default())
+                .is_some());
+        }
+
+        #[test]
+        fn find_clashes() {
+            let input_map = test_input_map();
+            let clashes = input_map.find_clashes(ClashStrategy::PrioritizeLongest);
+            let reported = |action_a: Action, action_b: Action| {
+                clashes.contains(&(action_a, action_b)) || clashes.contains(&(action_b, action_a))
+            };
+
+            // Chords clash with their subsets
+            assert!(reported(One, OneAndTwo));
+            assert!(reported(OneAndTwo, OneAndTwoAndThree));
+            assert!(reported(CtrlOne, CtrlAltOne));
+            assert!(!reported(One, Two));
+            assert!(!reported(OneAndTwo, TwoAndThree));
+
+            // Each pair is only reported once
+            let once = clashes
+                .iter()
+                .filter(|&&pair| pair == (One, OneAndTwo) || pair == (OneAndTwo, One))
+                .count();
+            assert_eq!(once, 1);
+
+            assert!(input_map.find_clashes(ClashStrategy::PressAll).is_empty());
         }
 
         #[test]
@@ -582,7 +691,9 @@ mod tests {
             app.add_plugins((InputPlugin, CentralInputStorePlugin));
 
             let input_map = test_input_map();
-            let simple_clash = input_map.possible_clash(&One, &OneAndTwo).unwrap();
+            let simple_clash = input_map
+                .possible_clash(&One, &OneAndTwo, &InputContexts::default())
+                .unwrap();
             Digit1.press(app.world_mut());
             Digit2.press(app.world_mut());
             app.update();
@@ -600,7 +711,9 @@ mod tests {
                 Some(One)
             );
 
-            let reversed_clash = input_map.possible_clash(&OneAndTwo, &One).unwrap();
+            let reversed_clash = input_map
+                .possible_clash(&OneAndTwo, &One, &InputContexts::default())
+                .unwrap();
             let input_store = app.world().resource::<CentralInputStore>();
 
             assert_eq!(
@@ -614,7 +727,7 @@ mod tests {
             );
 
             let chord_clash = input_map
-                .possible_clash(&OneAndTwo, &OneAndTwoAndThree)
+                .possible_clash(&OneAndTwo, &OneAndTwoAndThree, &InputContexts::default())
                 .unwrap();
             Digit3.press(app.world_mut());
             app.update();
@@ -697,7 +810,7 @@ mod tests {
 
             // Triple check that the inputs are clashing
             input_map
-                .possible_clash(&CtrlUp, &MoveDPad)
+                .possible_clash(&CtrlUp, &MoveDPad, &InputContexts::default())
                 .expect("Clash not detected");
 
             // Double check that the chord is longer than the DPad
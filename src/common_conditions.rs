@@ -1,40 +1,106 @@
 //! Run conditions for actions.
+//!
+//! Most of these conditions read the [`ActionState`] resource,
+//! and are never active while it does not exist.
+//! Conditions prefixed with `entity_` instead read the [`ActionState`] component of a specific entity,
+//! and are never active while that entity doesn't exist or lacks the component.
+//!
+//! Each entity-scoped condition looks up its entity every time that it is evaluated.
+//! This is cheap for a handful of entities,
+//! but when many entities gate their own systems, prefer a single system iterating over a [`Query`].
 
 use crate::{prelude::ActionState, Actionlike};
-use bevy::prelude::Res;
+use bevy::prelude::{Entity, Query, Res};
 
 /// Stateful run condition that can be toggled via an action press using [`ActionState::just_pressed`].
-pub fn action_toggle_active<A>(default: bool, action: A) -> impl FnMut(Res<ActionState<A>>) -> bool
+pub fn action_toggle_active<A>(
+    default: bool,
+    action: A,
+) -> impl FnMut(Option<Res<ActionState<A>>>) -> bool
 where
     A: Actionlike + Clone,
 {
     let mut active = default;
-    move |action_state: Res<ActionState<A>>| {
-        active ^= action_state.just_pressed(&action);
+    move |action_state: Option<Res<ActionState<A>>>| {
+        active ^= action_state.is_some_and(|action_state| action_state.just_pressed(&action));
         active
     }
 }
 
 /// Run condition that is active if [`ActionState::pressed`] is true for the given action.
-pub fn action_pressed<A>(action: A) -> impl FnMut(Res<ActionState<A>>) -> bool
+pub fn action_pressed<A>(action: A) -> impl FnMut(Option<Res<ActionState<A>>>) -> bool
 where
     A: Actionlike + Clone,
 {
-    move |action_state: Res<ActionState<A>>| action_state.pressed(&action)
+    move |action_state: Option<Res<ActionState<A>>>| {
+        action_state.is_some_and(|action_state| action_state.pressed(&action))
+    }
 }
 
 /// Run condition that is active if [`ActionState::just_pressed`] is true for the given action.
-pub fn action_just_pressed<A>(action: A) -> impl FnMut(Res<ActionState<A>>) -> bool
+pub fn action_just_pressed<A>(action: A) -> impl FnMut(Option<Res<ActionState<A>>>) -> bool
 where
     A: Actionlike + Clone,
 {
-    move |action_state: Res<ActionState<A>>| action_state.just_pressed(&action)
+    move |action_state: Option<Res<ActionState<A>>>| {
+        action_state.is_some_and(|action_state| action_state.just_pressed(&action))
+    }
 }
 
 /// Run condition that is active if [`ActionState::just_released`] is true for the given action.
-pub fn action_just_released<A>(action: A) -> impl FnMut(Res<ActionState<A>>) -> bool
+pub fn action_just_released<A>(action: A) -> impl FnMut(Option<Res<ActionState<A>>>) -> bool
 where
     A: Actionlike + Clone,
 {
-    move |action_state: Res<ActionState<A>>| action_state.just_released(&action)
+    move |action_state: Option<Res<ActionState<A>>>| {
+        action_state.is_some_and(|action_state| action_state.just_released(&action))
+    }
+}
+
+/// Run condition that is active if [`ActionState::pressed`] is true for the given action
+/// on the [`ActionState`] component of the `entity`.
+pub fn entity_action_pressed<A>(
+    entity: Entity,
+    action: A,
+) -> impl FnMut(Query<&ActionState<A>>) -> bool
+where
+    A: Actionlike + Clone,
+{
+    move |action_states: Query<&ActionState<A>>| {
+        action_states
+            .get(entity)
+            .is_ok_and(|action_state| action_state.pressed(&action))
+    }
+}
+
+/// Run condition that is active if [`ActionState::just_pressed`] is true for the given action
+/// on the [`ActionState`] component of the `entity`.
+pub fn entity_action_just_pressed<A>(
+    entity: Entity,
+    action: A,
+) -> impl FnMut(Query<&ActionState<A>>) -> bool
+where
+    A: Actionlike + Clone,
+{
+    move |action_states: Query<&ActionState<A>>| {
+        action_states
+            .get(entity)
+            .is_ok_and(|action_state| action_state.just_pressed(&action))
+    }
+}
+
+/// Run condition that is active if [`ActionState::just_released`] is true for the given action
+/// on the [`ActionState`] component of the `entity`.
+pub fn entity_action_just_released<A>(
+    entity: Entity,
+    action: A,
+) -> impl FnMut(Query<&ActionState<A>>) -> bool
+where
+    A: Actionlike + Clone,
+{
+    move |action_states: Query<&ActionState<A>>| {
+        action_states
+            .get(entity)
+            .is_ok_and(|action_state| action_state.just_released(&action))
+    }
 }
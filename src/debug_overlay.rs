@@ -0,0 +1,200 @@
+//! An on-screen overlay listing the live state of each action, for debugging.
+//!
+//! Insert an [`ActionStateOverlay`] resource to choose which [`ActionState`] to show,
+//! and the [`draw_action_state_overlay`] system, added by the [`InputManagerPlugin`](crate::plugin::InputManagerPlugin),
+//! draws an `egui` window on the primary window listing whether each buttonlike action is pressed
+//! and the current value of each axislike action.
+//!
+//! The overlay is drawn using the [`EguiContext`] of the primary window,
+//! so `bevy_egui`'s `EguiPlugin` must be added to see it.
+
+use std::marker::PhantomData;
+
+use bevy::prelude::{Entity, Query, Res, Resource, With};
+use bevy::window::PrimaryWindow;
+use bevy_egui::{egui, EguiContext};
+
+use crate::action_state::ActionState;
+use crate::{Actionlike, InputControlKind};
+
+/// Chooses the [`ActionState`] shown by the debug overlay drawn by [`draw_action_state_overlay`].
+///
+/// The overlay is only drawn while this resource exists.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ActionStateOverlay<A: Actionlike> {
+    /// The entity whose [`ActionState`] component is shown,
+    /// or [`None`] to show the [`ActionState`] resource.
+    pub entity: Option<Entity>,
+
+    _phantom: PhantomData<A>,
+}
+
+impl<A: Actionlike> Default for ActionStateOverlay<A> {
+    fn default() -> Self {
+        Self {
+            entity: None,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<A: Actionlike> ActionStateOverlay<A> {
+    /// Creates an overlay showing the [`ActionState`] resource.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an overlay showing the [`ActionState`] component of the `entity`.
+    #[inline]
+    pub fn for_entity(entity: Entity) -> Self {
+        Self {
+            entity: Some(entity),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Describes the current state of each action, as listed by the overlay.
+    ///
+    /// Actions are listed in the order of [`Actionlike::variants`],
+    /// followed by any other action with data in the `action_state`.
+    /// Buttonlike actions are shown as pressed or released along with their value,
+    /// and axislike actions as their current value.
+    #[must_use]
+    pub fn rows(action_state: &ActionState<A>) -> Vec<(A, String)> {
+        let mut actions: Vec<A> = A::variants().collect();
+        let mut other_actions: Vec<A> = action_state
+            .keys()
+            .into_iter()
+            .filter(|action| !actions.contains(action))
+            .collect();
+        other_actions.sort_by_cached_key(|action| format!("{action:?}"));
+        actions.extend(other_actions);
+
+        actions
+            .into_iter()
+            .map(|action| {
+                let mut description = match action.input_control_kind() {
+                    InputControlKind::Button => {
+                        let state = if action_state.pressed(&action) {
+                            "pressed"
+                        } else {
+                            "released"
+                        };
+                        format!("{state} ({:.2})", action_state.button_value(&action))
+                    }
+                    InputControlKind::Axis => format!("{:.2}", action_state.value(&action)),
+                    InputControlKind::DualAxis => {
+                        let pair = action_state.axis_pair(&action);
+                        format!("({:.2}, {:.2})", pair.x, pair.y)
+                    }
+                    InputControlKind::TripleAxis => {
+                        let triple = action_state.axis_triple(&action);
+                        format!("({:.2}, {:.2}, {:.2})", triple.x, triple.y, triple.z)
+                    }
+                };
+                if action_state.action_disabled(&action) {
+                    description.push_str(" [disabled]");
+                }
+                (action, description)
+            })
+            .collect()
+    }
+}
+
+/// Draws an `egui` window listing the state of each action of the [`ActionState`] chosen by the [`ActionStateOverlay`].
+///
+/// Nothing is drawn if the [`ActionStateOverlay`] resource, the chosen [`ActionState`],
+/// or the [`EguiContext`] of the primary window doesn't exist.
+pub fn draw_action_state_overlay<A: Actionlike>(
+    overlay: Option<Res<ActionStateOverlay<A>>>,
+    action_state: Option<Res<ActionState<A>>>,
+    query: Query<&ActionState<A>>,
+    mut egui_query: Query<&mut EguiContext, With<PrimaryWindow>>,
+) {
+    let Some(overlay) = overlay else {
+        return;
+    };
+
+    let action_state = match overlay.entity {
+        Some(entity) => query.get(entity).ok(),
+        None => action_state.as_deref(),
+    };
+    let Some(action_state) = action_state else {
+        return;
+    };
+
+    let Ok(mut egui_context) = egui_query.get_single_mut() else {
+        return;
+    };
+
+    let rows = ActionStateOverlay::rows(action_state);
+    egui::Window::new(A::short_type_path())
+        .id(egui::Id::new((
+            "leafwing_input_manager_overlay",
+            A::type_path(),
+        )))
+        .show(egui_context.get_mut(), |ui| {
+            egui::Grid::new("actions").striped(true).show(ui, |ui| {
+                for (action, description) in rows {
+                    ui.label(format!("{action:?}"));
+                    ui.monospace(description);
+                    ui.end_row();
+                }
+            });
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as leafwing_input_manager;
+    use bevy::math::Vec2;
+    use bevy::prelude::{App, MinimalPlugins, Reflect, Update};
+
+    #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+    enum Action {
+        Jump,
+        Run,
+        #[actionlike(Axis)]
+        Zoom,
+        #[actionlike(DualAxis)]
+        Move,
+    }
+
+    #[test]
+    fn overlay_rows() {
+        let mut action_state = ActionState::<Action>::default();
+        action_state.press(&Action::Jump);
+        action_state.set_value(&Action::Zoom, 0.5);
+        action_state.set_axis_pair(&Action::Move, Vec2::new(1.0, -0.25));
+        action_state.disable_action(&Action::Run);
+
+        assert_eq!(
+            ActionStateOverlay::rows(&action_state),
+            vec![
+                (Action::Jump, "pressed (1.00)".to_string()),
+                (Action::Run, "released (0.00) [disabled]".to_string()),
+                (Action::Zoom, "0.50".to_string()),
+                (Action::Move, "(1.00, -0.25)".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn overlay_runs_headless() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<ActionState<Action>>()
+            .insert_resource(ActionStateOverlay::<Action>::new())
+            .add_systems(Update, draw_action_state_overlay::<Action>);
+
+        // Without a window there is no egui context, so nothing is drawn
+        app.update();
+
+        // Overlays for missing entities are ignored too
+        let entity = app.world_mut().spawn_empty().id();
+        app.insert_resource(ActionStateOverlay::<Action>::for_entity(entity));
+        app.update();
+    }
+}
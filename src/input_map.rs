@@ -5,8 +5,13 @@ use std::hash::Hash;
 
 #[cfg(feature = "asset")]
 use bevy::asset::Asset;
+use bevy::ecs::entity::{EntityMapper, MapEntities};
+use bevy::ecs::reflect::ReflectMapEntities;
+use bevy::math::FloatOrd;
 use bevy::prelude::{Component, Deref, DerefMut, Entity, Gamepad, Query, Reflect, Resource, With};
-use bevy::utils::HashMap;
+use bevy::reflect::DynamicTypePath;
+use bevy::reflect::ReflectMut;
+use bevy::utils::{HashMap, HashSet};
 use bevy::{log::error, prelude::ReflectComponent};
 use bevy::{
     math::{Vec2, Vec3},
@@ -15,14 +20,16 @@ use bevy::{
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 
-use crate::clashing_inputs::ClashStrategy;
+use crate::action_state::ActionState;
+use crate::clashing_inputs::{BasicInputs, ClashStrategy};
+use crate::input_processing::{ActionProcessor, AxisProcessor, DeadZonePreset, DualAxisProcessor};
 use crate::prelude::updating::CentralInputStore;
-use crate::prelude::UserInputWrapper;
-use crate::user_input::{Axislike, Buttonlike, DualAxislike, TripleAxislike};
+use crate::prelude::{InputBinding, UserInputWrapper};
+use crate::user_input::{Axislike, Buttonlike, DualAxislike, TripleAxislike, UserInput};
 use crate::{Actionlike, InputControlKind};
 
 #[cfg(feature = "gamepad")]
-use crate::user_input::gamepad::find_gamepad;
+use crate::user_input::gamepad::{find_gamepad, PlayerIndex};
 
 #[cfg(not(feature = "gamepad"))]
 fn find_gamepad(_: Option<Query<Entity, With<Gamepad>>>) -> Entity {
@@ -103,7 +110,7 @@ fn find_gamepad(_: Option<Query<Entity, With<Gamepad>>>) -> Entity {
 /// ```
 #[derive(Resource, Component, Debug, Clone, PartialEq, Eq, Reflect, Serialize, Deserialize)]
 #[cfg_attr(feature = "asset", derive(Asset))]
-#[reflect(Resource, Component)]
+#[reflect(Resource, Component, MapEntities)]
 pub struct InputMap<A: Actionlike> {
     /// The underlying map that stores action-input mappings for [`Buttonlike`] actions.
     buttonlike_map: HashMap<A, Vec<Box<dyn Buttonlike>>>,
@@ -117,8 +124,67 @@ pub struct InputMap<A: Actionlike> {
     /// The underlying map that stores action-input mappings for [`TripleAxislike`] actions.
     triple_axislike_map: HashMap<A, Vec<Box<dyn TripleAxislike>>>,
 
+    /// The processors applied to the combined value of each [`Axislike`] action.
+    #[serde(
+        default = "HashMap::default",
+        skip_serializing_if = "HashMap::is_empty"
+    )]
+    axis_action_processors: HashMap<A, Vec<AxisProcessor>>,
+
+    /// The processors applied to the combined value of each [`DualAxislike`] action.
+    #[serde(
+        default = "HashMap::default",
+        skip_serializing_if = "HashMap::is_empty"
+    )]
+    dual_axis_action_processors: HashMap<A, Vec<DualAxisProcessor>>,
+
+    /// The sensitivity that the value of each action is scaled by after all processors.
+    #[serde(
+        default = "HashMap::default",
+        skip_serializing_if = "HashMap::is_empty"
+    )]
+    action_sensitivities: HashMap<A, ActionSensitivity>,
+
+    /// Where each binding came from, for bindings that aren't [`BindingSource::Default`].
+    #[serde(
+        default = "HashMap::default",
+        skip_serializing_if = "HashMap::is_empty"
+    )]
+    binding_sources: HashMap<A, Vec<(UserInputWrapper, BindingSource)>>,
+
+    /// The context that each binding requires to be active, for bindings that are restricted to one.
+    #[serde(
+        default = "HashMap::default",
+        skip_serializing_if = "HashMap::is_empty"
+    )]
+    binding_contexts: HashMap<A, Vec<(UserInputWrapper, String)>>,
+
+    /// The bindings that are ignored when processing actions, without being removed.
+    #[serde(
+        default = "HashMap::default",
+        skip_serializing_if = "HashMap::is_empty"
+    )]
+    disabled_bindings: HashMap<A, Vec<UserInputWrapper>>,
+
     /// The specified gamepad from which this map exclusively accepts input.
     associated_gamepad: Option<Entity>,
+
+    /// The local player whose gamepad this map exclusively accepts input from.
+    #[cfg(feature = "gamepad")]
+    #[serde(default = "Option::default", skip_serializing_if = "Option::is_none")]
+    associated_player: Option<PlayerIndex>,
+
+    /// The strategy used to resolve clashes for this map, overriding the one passed to [`InputMap::process_actions`].
+    #[serde(default = "Option::default", skip_serializing_if = "Option::is_none")]
+    clash_strategy: Option<ClashStrategy>,
+
+    /// The action that will be bound to the next newly pressed [`Buttonlike`] input.
+    #[serde(skip, default = "Option::default")]
+    pending_capture: Option<A>,
+
+    /// Temporarily routes the resolved state of each action to another action.
+    #[serde(skip, default = "HashMap::default")]
+    remap: HashMap<A, A>,
 }
 
 impl<A: Actionlike> Default for InputMap<A> {
@@ -128,11 +194,34 @@ impl<A: Actionlike> Default for InputMap<A> {
             axislike_map: HashMap::default(),
             dual_axislike_map: HashMap::default(),
             triple_axislike_map: HashMap::default(),
+            axis_action_processors: HashMap::default(),
+            dual_axis_action_processors: HashMap::default(),
+            action_sensitivities: HashMap::default(),
+            binding_sources: HashMap::default(),
+            binding_contexts: HashMap::default(),
+            disabled_bindings: HashMap::default(),
             associated_gamepad: None,
+            #[cfg(feature = "gamepad")]
+            associated_player: None,
+            clash_strategy: None,
+            pending_capture: None,
+            remap: HashMap::default(),
         }
     }
 }
 
+/// Remaps the associated gamepad entity, if any.
+///
+/// This allows an [`InputMap`] component to be saved to and loaded from a
+/// `DynamicScene` without pointing at a stale gamepad.
+impl<A: Actionlike> MapEntities for InputMap<A> {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        self.associated_gamepad = self
+            .associated_gamepad
+            .map(|gamepad| entity_mapper.map_entity(gamepad));
+    }
+}
+
 // Constructors
 impl<A: Actionlike> InputMap<A> {
     /// Creates an [`InputMap`] from an iterator over [`Buttonlike`] action-input bindings.
@@ -208,15 +297,15 @@ impl<A: Actionlike> InputMap<A> {
         self
     }
 
-    /// Adds multiple action-input bindings provided by an iterator.
-    /// Note that all elements within the iterator must be of the same type (homogeneous).
+    /// Adds multiple action-input [`Buttonlike`] bindings provided by an iterator.
+    /// See [`InputMap::insert_multiple`] for details.
     ///
     /// This method ensures idempotence, meaning that adding the same input
     /// for the same action multiple times will only result in a single binding being created.
     #[inline(always)]
     pub fn with_multiple(
         mut self,
-        bindings: impl IntoIterator<Item = (A, impl Buttonlike)>,
+        bindings: impl IntoIterator<Item = (A, impl Into<Box<dyn Buttonlike>>)>,
     ) -> Self {
         self.insert_multiple(bindings);
         self
@@ -248,6 +337,13 @@ impl<A: Actionlike> InputMap<A> {
     #[inline(always)]
     #[track_caller]
     pub fn insert(&mut self, action: A, button: impl Buttonlike) -> &mut Self {
+        self.insert_boxed(action, Box::new(button))
+    }
+
+    /// Inserts a binding between an `action` and a boxed [`Buttonlike`] `input`,
+    /// checking that the action is a buttonlike action.
+    #[track_caller]
+    fn insert_boxed(&mut self, action: A, button: Box<dyn Buttonlike>) -> &mut Self {
         debug_assert!(
             action.input_control_kind() == InputControlKind::Button,
             "Cannot map a Buttonlike input for action {:?} of kind {:?}",
@@ -265,7 +361,7 @@ impl<A: Actionlike> InputMap<A> {
             return self;
         }
 
-        insert_unique(&mut self.buttonlike_map, &action, Box::new(button));
+        insert_unique(&mut self.buttonlike_map, &action, button);
         self
     }
 
@@ -277,6 +373,13 @@ impl<A: Actionlike> InputMap<A> {
     #[inline(always)]
     #[track_caller]
     pub fn insert_axis(&mut self, action: A, axis: impl Axislike) -> &mut Self {
+        self.insert_boxed_axis(action, Box::new(axis))
+    }
+
+    /// Inserts a binding between an `action` and a boxed [`Axislike`] `input`,
+    /// checking that the action is an axislike action.
+    #[track_caller]
+    fn insert_boxed_axis(&mut self, action: A, axis: Box<dyn Axislike>) -> &mut Self {
         debug_assert!(
             action.input_control_kind() == InputControlKind::Axis,
             "Cannot map an Axislike input for action {:?} of kind {:?}",
@@ -294,7 +397,7 @@ impl<A: Actionlike> InputMap<A> {
             return self;
         }
 
-        insert_unique(&mut self.axislike_map, &action, Box::new(axis));
+        insert_unique(&mut self.axislike_map, &action, axis);
         self
     }
 
@@ -306,6 +409,13 @@ impl<A: Actionlike> InputMap<A> {
     #[inline(always)]
     #[track_caller]
     pub fn insert_dual_axis(&mut self, action: A, dual_axis: impl DualAxislike) -> &mut Self {
+        self.insert_boxed_dual_axis(action, Box::new(dual_axis))
+    }
+
+    /// Inserts a binding between an `action` and a boxed [`DualAxislike`] `input`,
+    /// checking that the action is a dual-axislike action.
+    #[track_caller]
+    fn insert_boxed_dual_axis(&mut self, action: A, dual_axis: Box<dyn DualAxislike>) -> &mut Self {
         debug_assert!(
             action.input_control_kind() == InputControlKind::DualAxis,
             "Cannot map a DualAxislike input for action {:?} of kind {:?}",
@@ -323,7 +433,7 @@ impl<A: Actionlike> InputMap<A> {
             return self;
         }
 
-        insert_unique(&mut self.dual_axislike_map, &action, Box::new(dual_axis));
+        insert_unique(&mut self.dual_axislike_map, &action, dual_axis);
         self
     }
 
@@ -335,6 +445,17 @@ impl<A: Actionlike> InputMap<A> {
     #[inline(always)]
     #[track_caller]
     pub fn insert_triple_axis(&mut self, action: A, triple_axis: impl TripleAxislike) -> &mut Self {
+        self.insert_boxed_triple_axis(action, Box::new(triple_axis))
+    }
+
+    /// Inserts a binding between an `action` and a boxed [`TripleAxislike`] `input`,
+    /// checking that the action is a triple-axislike action.
+    #[track_caller]
+    fn insert_boxed_triple_axis(
+        &mut self,
+        action: A,
+        triple_axis: Box<dyn TripleAxislike>,
+    ) -> &mut Self {
         debug_assert!(
             action.input_control_kind() == InputControlKind::TripleAxis,
             "Cannot map a TripleAxislike input for action {:?} of kind {:?}",
@@ -352,8 +473,7 @@ impl<A: Actionlike> InputMap<A> {
             return self;
         }
 
-        let boxed = Box::new(triple_axis);
-        insert_unique(&mut self.triple_axislike_map, &action, boxed);
+        insert_unique(&mut self.triple_axislike_map, &action, triple_axis);
         self
     }
 
@@ -387,30 +507,132 @@ impl<A: Actionlike> InputMap<A> {
     }
 
     /// Inserts multiple action-input [`Buttonlike`] bindings provided by an iterator.
-    /// Note that all elements within the iterator must be of the same type (homogeneous).
+    ///
+    /// Bindings are appended to the existing bindings of each action, in the order they are provided,
+    /// just like calling [`InputMap::insert`] for each binding.
+    /// To mix different kinds of inputs, box them first: `Box<dyn Buttonlike>` can be inserted directly.
     ///
     /// This method ensures idempotence, meaning that adding the same input
     /// for the same action multiple times will only result in a single binding being created.
+    ///
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+    /// enum Action {
+    ///     Jump,
+    ///     Shoot,
+    /// }
+    ///
+    /// let mut input_map = InputMap::default();
+    /// input_map.insert_multiple([
+    ///     (Action::Jump, Box::new(KeyCode::Space) as Box<dyn Buttonlike>),
+    ///     (Action::Jump, Box::new(GamepadButton::South)),
+    ///     (Action::Shoot, Box::new(MouseButton::Left)),
+    /// ]);
+    /// assert_eq!(input_map.get_buttonlike(&Action::Jump).unwrap().len(), 2);
+    /// ```
     #[inline(always)]
+    #[track_caller]
     pub fn insert_multiple(
         &mut self,
-        bindings: impl IntoIterator<Item = (A, impl Buttonlike)>,
+        bindings: impl IntoIterator<Item = (A, impl Into<Box<dyn Buttonlike>>)>,
+    ) -> &mut Self {
+        for (action, input) in bindings.into_iter() {
+            self.insert_boxed(action, input.into());
+        }
+        self
+    }
+
+    /// Inserts multiple action-input [`Axislike`] bindings provided by an iterator.
+    ///
+    /// Bindings are appended to the existing bindings of each action, in the order they are provided,
+    /// just like calling [`InputMap::insert_axis`] for each binding.
+    #[inline(always)]
+    #[track_caller]
+    pub fn insert_multiple_axis(
+        &mut self,
+        bindings: impl IntoIterator<Item = (A, impl Into<Box<dyn Axislike>>)>,
+    ) -> &mut Self {
+        for (action, input) in bindings.into_iter() {
+            self.insert_boxed_axis(action, input.into());
+        }
+        self
+    }
+
+    /// Inserts multiple action-input [`DualAxislike`] bindings provided by an iterator.
+    ///
+    /// Bindings are appended to the existing bindings of each action, in the order they are provided,
+    /// just like calling [`InputMap::insert_dual_axis`] for each binding.
+    #[inline(always)]
+    #[track_caller]
+    pub fn insert_multiple_dual_axis(
+        &mut self,
+        bindings: impl IntoIterator<Item = (A, impl Into<Box<dyn DualAxislike>>)>,
     ) -> &mut Self {
         for (action, input) in bindings.into_iter() {
-            self.insert(action, input);
+            self.insert_boxed_dual_axis(action, input.into());
         }
         self
     }
 
+    /// Inserts multiple action-input [`TripleAxislike`] bindings provided by an iterator.
+    ///
+    /// Bindings are appended to the existing bindings of each action, in the order they are provided,
+    /// just like calling [`InputMap::insert_triple_axis`] for each binding.
+    #[inline(always)]
+    #[track_caller]
+    pub fn insert_multiple_triple_axis(
+        &mut self,
+        bindings: impl IntoIterator<Item = (A, impl Into<Box<dyn TripleAxislike>>)>,
+    ) -> &mut Self {
+        for (action, input) in bindings.into_iter() {
+            self.insert_boxed_triple_axis(action, input.into());
+        }
+        self
+    }
+
+    /// Inserts a binding between an `action` and the input described by a typed [`InputBinding`].
+    ///
+    /// The binding is inserted like [`InputMap::insert`], [`InputMap::insert_axis`] or [`InputMap::insert_dual_axis`],
+    /// depending on its [`InputBinding::kind`].
+    #[track_caller]
+    pub fn insert_binding(&mut self, action: A, binding: InputBinding) -> &mut Self {
+        match UserInputWrapper::from(binding) {
+            UserInputWrapper::Button(input) => self.insert_boxed(action, input),
+            UserInputWrapper::Axis(input) => self.insert_boxed_axis(action, input),
+            UserInputWrapper::DualAxis(input) => self.insert_boxed_dual_axis(action, input),
+            UserInputWrapper::TripleAxis(input) => self.insert_boxed_triple_axis(action, input),
+        }
+    }
+
     /// Merges the provided [`InputMap`] into this `map`, combining their bindings,
     /// avoiding duplicates.
     ///
-    /// If the associated gamepads do not match, the association will be removed.
+    /// Actions that are only bound in `other` are inserted with all of their inputs.
+    /// For actions bound in both maps, the existing inputs keep their order,
+    /// and the inputs from `other` that are not already bound are appended after them,
+    /// in the order they appear in `other`.
+    ///
+    /// The [`BindingSource`] and context of each binding from `other` are kept, unless the binding was already tagged in this map,
+    /// and bindings [disabled](InputMap::disable_binding) in `other` are disabled in this map too.
+    /// [Action processors](InputMap::with_action_processor) from `other` are only used for actions
+    /// that have no processors in this map.
+    ///
+    /// If the associated gamepads or players do not match, the association will be removed.
+    ///
+    /// Use [`InputMap::merge_replace`] if `other` should instead override the existing bindings.
     pub fn merge(&mut self, other: &InputMap<A>) -> &mut Self {
         if self.associated_gamepad != other.associated_gamepad {
             self.clear_gamepad();
         }
 
+        #[cfg(feature = "gamepad")]
+        if self.associated_player != other.associated_player {
+            self.clear_player();
+        }
+
         for (other_action, other_inputs) in other.iter_buttonlike() {
             for other_input in other_inputs.iter().cloned() {
                 insert_unique(&mut self.buttonlike_map, other_action, other_input);
@@ -435,6 +657,114 @@ impl<A: Actionlike> InputMap<A> {
             }
         }
 
+        for (other_action, other_sources) in other.binding_sources.iter() {
+            for (other_input, other_source) in other_sources {
+                if self.binding_source(other_action, other_input) == BindingSource::Default {
+                    self.set_binding_source(other_action, other_input.clone(), *other_source);
+                }
+            }
+        }
+
+        for (other_action, other_contexts) in other.binding_contexts.iter() {
+            for (other_input, other_context) in other_contexts {
+                if self.binding_context(other_action, other_input).is_none() {
+                    self.set_binding_context(other_action, other_input.clone(), other_context);
+                }
+            }
+        }
+
+        for (other_action, other_disabled) in other.disabled_bindings.iter() {
+            for other_input in other_disabled {
+                self.disable_input(other_action, other_input.clone());
+            }
+        }
+
+        for (other_action, other_processors) in other.axis_action_processors.iter() {
+            self.axis_action_processors
+                .entry(other_action.clone())
+                .or_insert_with(|| other_processors.clone());
+        }
+
+        for (other_action, other_processors) in other.dual_axis_action_processors.iter() {
+            self.dual_axis_action_processors
+                .entry(other_action.clone())
+                .or_insert_with(|| other_processors.clone());
+        }
+
+        self
+    }
+
+    /// Merges the provided [`InputMap`] into this `map`,
+    /// replacing the bindings of any action that is bound in both maps.
+    ///
+    /// Actions that are only bound in `other` are inserted with all of their inputs,
+    /// while actions bound in both maps are rebound to exactly the inputs from `other`, in the same order.
+    /// Actions that are only bound in this map are left untouched.
+    /// The [`BindingSource`] and context of each binding from `other` are kept,
+    /// and bindings [disabled](InputMap::disable_binding) in `other` are disabled in this map too.
+    /// [Action processors](InputMap::with_action_processor) from `other` replace the processors
+    /// of the same action in this map.
+    ///
+    /// If the associated gamepads or players do not match, the association will be removed.
+    ///
+    /// Use [`InputMap::merge`] to keep the existing bindings of overlapping actions instead.
+    pub fn merge_replace(&mut self, other: &InputMap<A>) -> &mut Self {
+        if self.associated_gamepad != other.associated_gamepad {
+            self.clear_gamepad();
+        }
+
+        #[cfg(feature = "gamepad")]
+        if self.associated_player != other.associated_player {
+            self.clear_player();
+        }
+
+        for (other_action, other_inputs) in other.iter_buttonlike() {
+            self.buttonlike_map
+                .insert(other_action.clone(), other_inputs.clone());
+        }
+
+        for (other_action, other_inputs) in other.iter_axislike() {
+            self.axislike_map
+                .insert(other_action.clone(), other_inputs.clone());
+        }
+
+        for (other_action, other_inputs) in other.iter_dual_axislike() {
+            self.dual_axislike_map
+                .insert(other_action.clone(), other_inputs.clone());
+        }
+
+        for (other_action, other_inputs) in other.iter_triple_axislike() {
+            self.triple_axislike_map
+                .insert(other_action.clone(), other_inputs.clone());
+        }
+
+        for (other_action, other_sources) in other.binding_sources.iter() {
+            for (other_input, other_source) in other_sources {
+                self.set_binding_source(other_action, other_input.clone(), *other_source);
+            }
+        }
+        for (other_action, other_contexts) in other.binding_contexts.iter() {
+            for (other_input, other_context) in other_contexts {
+                self.set_binding_context(other_action, other_input.clone(), other_context);
+            }
+        }
+        for (other_action, other_disabled) in other.disabled_bindings.iter() {
+            for other_input in other_disabled {
+                self.disable_input(other_action, other_input.clone());
+            }
+        }
+        self.prune_binding_tags();
+
+        for (other_action, other_processors) in other.axis_action_processors.iter() {
+            self.axis_action_processors
+                .insert(other_action.clone(), other_processors.clone());
+        }
+
+        for (other_action, other_processors) in other.dual_axis_action_processors.iter() {
+            self.dual_axis_action_processors
+                .insert(other_action.clone(), other_processors.clone());
+        }
+
         self
     }
 }
@@ -461,6 +791,15 @@ impl<A: Actionlike> InputMap<A> {
     ///
     /// Because of this robust fallback behavior,
     /// this method can typically be ignored when writing single-player games.
+    ///
+    /// # Hot-swapping controllers
+    ///
+    /// The association is kept even if the gamepad [`Entity`] is disconnected or despawned.
+    /// In that case, this map receives no gamepad input at all, rather than falling back to another gamepad,
+    /// until it is reassigned with [`InputMap::set_gamepad`] or cleared with [`InputMap::clear_gamepad`].
+    /// Bevy keeps the entity of a disconnected gamepad around, so reconnecting the same controller resumes input.
+    ///
+    /// To follow controllers as they are swapped automatically, use [`InputMap::with_player`] instead.
     #[inline]
     pub fn with_gamepad(mut self, gamepad: Entity) -> Self {
         self.set_gamepad(gamepad);
@@ -490,609 +829,3959 @@ impl<A: Actionlike> InputMap<A> {
         self.associated_gamepad = None;
         self
     }
-}
 
-// Check whether actions are pressed
-impl<A: Actionlike> InputMap<A> {
-    /// Checks if the `action` are currently pressed by any of the associated [`Buttonlike`]s.
+    /// Returns a copy of this input map that exclusively accepts input from the given `gamepad`,
+    /// which is useful to give each player of a local co-op game the same control scheme.
     ///
-    /// Accounts for clashing inputs according to the [`ClashStrategy`] and remove conflicting actions.
-    #[must_use]
-    pub fn pressed(
-        &self,
-        action: &A,
-        input_store: &CentralInputStore,
-        clash_strategy: ClashStrategy,
-    ) -> bool {
-        let processed_actions = self.process_actions(None, input_store, clash_strategy);
-
-        let Some(updated_value) = processed_actions.get(action) else {
-            return false;
-        };
-
-        match updated_value {
-            UpdatedValue::Button(state) => *state,
-            _ => false,
-        }
-    }
-
-    /// Determines the correct state for each action according to provided [`CentralInputStore`].
+    /// All bindings are copied, including keyboard and mouse bindings.
+    /// As players rarely share a keyboard, these can be removed from the copy
+    /// using [`InputMap::clear_keyboard_and_mouse`].
     ///
-    /// This method uses the input bindings for each action to determine how to parse the input data,
-    /// and generates corresponding [`ButtonData`](crate::action_state::ButtonData),
-    /// [`AxisData`](crate::action_state::AxisData) and [`DualAxisData`](crate::action_state::DualAxisData).
+    /// Any [`PlayerIndex`](crate::user_input::gamepad::PlayerIndex) assigned to this map is not copied,
+    /// as it would replace the associated gamepad.
     ///
-    /// For [`Buttonlike`] actions, this accounts for clashing inputs according to the [`ClashStrategy`] and removes conflicting actions.
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use leafwing_input_manager::prelude::*;
     ///
-    /// [`Buttonlike`] inputs will be pressed if any of the associated inputs are pressed.
-    /// [`Axislike`] and [`DualAxislike`] inputs will be the sum of all associated inputs.
+    /// #[derive(Actionlike, PartialEq, Eq, Clone, Copy, Hash, Debug, Reflect)]
+    /// enum Action {
+    ///     Jump,
+    /// }
+    ///
+    /// let mut world = World::new();
+    /// let second_gamepad = world.spawn(()).id();
+    ///
+    /// let player_one = InputMap::default()
+    ///     .with(Action::Jump, KeyCode::Space)
+    ///     .with(Action::Jump, GamepadButton::South);
+    ///
+    /// let mut player_two = player_one.duplicate_for_gamepad(second_gamepad);
+    /// player_two.clear_keyboard_and_mouse();
+    ///
+    /// assert_eq!(player_two.gamepad(), Some(second_gamepad));
+    /// assert_eq!(player_two.get_buttonlike(&Action::Jump).unwrap().len(), 1);
+    /// ```
     #[must_use]
-    pub fn process_actions(
-        &self,
-        gamepads: Option<Query<Entity, With<Gamepad>>>,
-        input_store: &CentralInputStore,
-        clash_strategy: ClashStrategy,
-    ) -> UpdatedActions<A> {
-        let mut updated_actions = UpdatedActions::default();
-        let gamepad = self.associated_gamepad.unwrap_or(find_gamepad(gamepads));
-
-        // Generate the base action data for each action
-        for (action, _input_bindings) in self.iter_buttonlike() {
-            let mut final_state = false;
-            for binding in _input_bindings {
-                if binding.pressed(input_store, gamepad) {
-                    final_state = true;
-                    break;
-                }
-            }
+    pub fn duplicate_for_gamepad(&self, gamepad: Entity) -> Self {
+        let mut input_map = self.clone();
+        #[cfg(feature = "gamepad")]
+        input_map.clear_player();
+        input_map.set_gamepad(gamepad);
+        input_map
+    }
 
-            updated_actions.insert(action.clone(), UpdatedValue::Button(final_state));
+    /// Returns the gamepad [`Entity`] whose input is read by this input map.
+    ///
+    /// Maps assigned to a player without a gamepad read no gamepad input at all,
+    /// while other maps without an associated gamepad fall back to the first connected gamepad.
+    fn active_gamepad(&self, gamepads: Option<Query<Entity, With<Gamepad>>>) -> Entity {
+        #[cfg(feature = "gamepad")]
+        if self.associated_player.is_some() {
+            return self.associated_gamepad.unwrap_or(Entity::PLACEHOLDER);
         }
 
-        for (action, _input_bindings) in self.iter_axislike() {
-            let mut final_value = 0.0;
-            for binding in _input_bindings {
-                final_value += binding.value(input_store, gamepad);
-            }
+        self.associated_gamepad.unwrap_or(find_gamepad(gamepads))
+    }
 
-            updated_actions.insert(action.clone(), UpdatedValue::Axis(final_value));
-        }
+    /// Fetches the [`PlayerIndex`] of the local player controlling this input map.
+    #[cfg(feature = "gamepad")]
+    #[must_use]
+    #[inline]
+    pub const fn player(&self) -> Option<PlayerIndex> {
+        self.associated_player
+    }
 
-        for (action, _input_bindings) in self.iter_dual_axislike() {
-            let mut final_value = Vec2::ZERO;
-            for binding in _input_bindings {
-                final_value += binding.axis_pair(input_store, gamepad);
-            }
+    /// Assigns this input map to a local player,
+    /// so that it exclusively accepts input from the gamepad assigned to that [`PlayerIndex`].
+    ///
+    /// Players are assigned gamepads in the order they were connected, as tracked by the
+    /// [`GamepadRegistry`](crate::user_input::gamepad::GamepadRegistry) resource.
+    /// Each frame, the associated gamepad is replaced by the player's gamepad,
+    /// overriding any gamepad set using [`InputMap::set_gamepad`].
+    /// While the player has no gamepad, no gamepad input will be accepted.
+    #[cfg(feature = "gamepad")]
+    #[inline]
+    pub fn with_player(mut self, player: PlayerIndex) -> Self {
+        self.set_player(player);
+        self
+    }
 
-            updated_actions.insert(action.clone(), UpdatedValue::DualAxis(final_value));
-        }
+    /// Assigns this input map to a local player,
+    /// so that it exclusively accepts input from the gamepad assigned to that [`PlayerIndex`].
+    ///
+    /// Players are assigned gamepads in the order they were connected, as tracked by the
+    /// [`GamepadRegistry`](crate::user_input::gamepad::GamepadRegistry) resource.
+    /// Each frame, the associated gamepad is replaced by the player's gamepad,
+    /// overriding any gamepad set using [`InputMap::set_gamepad`].
+    /// While the player has no gamepad, no gamepad input will be accepted.
+    #[cfg(feature = "gamepad")]
+    #[inline]
+    pub fn set_player(&mut self, player: PlayerIndex) -> &mut Self {
+        self.associated_player = Some(player);
+        self
+    }
 
-        for (action, _input_bindings) in self.iter_triple_axislike() {
-            let mut final_value = Vec3::ZERO;
-            for binding in _input_bindings {
-                final_value += binding.axis_triple(input_store, gamepad);
-            }
+    /// Clears any [`PlayerIndex`] associated with this input map.
+    ///
+    /// The gamepad most recently assigned to the player remains associated with this map
+    /// until [`InputMap::clear_gamepad`] is called.
+    #[cfg(feature = "gamepad")]
+    #[inline]
+    pub fn clear_player(&mut self) -> &mut Self {
+        self.associated_player = None;
+        self
+    }
 
-            updated_actions.insert(action.clone(), UpdatedValue::TripleAxis(final_value));
-        }
+    /// Fetches the [`ClashStrategy`] used by this input map, if it overrides the global one.
+    #[must_use]
+    #[inline]
+    pub const fn clash_strategy(&self) -> Option<ClashStrategy> {
+        self.clash_strategy
+    }
 
-        // Handle clashing inputs, possibly removing some pressed actions from the list
-        self.handle_clashes(&mut updated_actions, input_store, clash_strategy, gamepad);
+    /// Sets the [`ClashStrategy`] used to resolve clashes between the actions of this input map.
+    ///
+    /// This takes precedence over the strategy passed to [`InputMap::process_actions`] and its variants,
+    /// which is usually the global [`ClashStrategy`] resource.
+    /// That strategy is only used by maps that do not set their own.
+    ///
+    /// This is useful when several input maps are processed in the same frame,
+    /// such as a menu map where every matching action should be pressed alongside a gameplay map that prioritizes chords.
+    #[inline]
+    pub fn with_clash_strategy(mut self, clash_strategy: ClashStrategy) -> Self {
+        self.set_clash_strategy(clash_strategy);
+        self
+    }
+
+    /// Sets the [`ClashStrategy`] used to resolve clashes between the actions of this input map.
+    ///
+    /// See [`InputMap::with_clash_strategy`] for how this interacts with the global strategy.
+    #[inline]
+    pub fn set_clash_strategy(&mut self, clash_strategy: ClashStrategy) -> &mut Self {
+        self.clash_strategy = Some(clash_strategy);
+        self
+    }
 
-        updated_actions
+    /// Clears the [`ClashStrategy`] of this input map,
+    /// so that it uses the strategy passed to [`InputMap::process_actions`] again.
+    #[inline]
+    pub fn clear_clash_strategy(&mut self) -> &mut Self {
+        self.clash_strategy = None;
+        self
     }
 }
 
-/// The output returned by [`InputMap::process_actions`],
-/// used by [`ActionState::update`](crate::action_state::ActionState) to update the state of each action.
-#[derive(Debug, Clone, PartialEq, Deref, DerefMut)]
-pub struct UpdatedActions<A: Actionlike>(pub HashMap<A, UpdatedValue>);
-
-impl<A: Actionlike> UpdatedActions<A> {
-    /// Returns `true` if the action is both buttonlike and pressed.
-    pub fn pressed(&self, action: &A) -> bool {
-        match self.0.get(action) {
-            Some(UpdatedValue::Button(state)) => *state,
-            _ => false,
-        }
+// Action-level processing
+impl<A: Actionlike> InputMap<A> {
+    /// Appends a `processor` to the processing pipeline of the `action`,
+    /// which is applied to the combined value of all inputs bound to the `action`.
+    ///
+    /// This allows processors to be shared by every binding of the `action`,
+    /// such as a single dead zone for every dual-axis input bound to a movement action.
+    ///
+    /// Each input's own processing pipeline is applied first,
+    /// then the values of all inputs are summed,
+    /// and finally the `action`'s processing pipeline is applied in the order processors were added.
+    ///
+    /// Only [`Axislike`] and [`DualAxislike`] actions can be processed,
+    /// using [`ActionProcessor::Axis`] and [`ActionProcessor::DualAxis`] respectively.
+    ///
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, PartialEq, Eq, Clone, Copy, Hash, Debug, Reflect)]
+    /// enum Action {
+    ///     #[actionlike(DualAxis)]
+    ///     Move,
+    /// }
+    ///
+    /// // Both sticks share the same dead zone
+    /// let input_map = InputMap::default()
+    ///     .with_dual_axis(Action::Move, GamepadStick::LEFT)
+    ///     .with_dual_axis(Action::Move, GamepadStick::RIGHT)
+    ///     .with_action_processor(Action::Move, DualAxisProcessor::from(CircleDeadZone::new(0.1)));
+    ///
+    /// assert_eq!(input_map.dual_axis_action_processors(&Action::Move).len(), 1);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn with_action_processor(
+        mut self,
+        action: A,
+        processor: impl Into<ActionProcessor>,
+    ) -> Self {
+        self.insert_action_processor(action, processor);
+        self
     }
-}
-
-/// An enum representing the updated value of an action.
-///
-/// Used in [`UpdatedActions`] to store the updated state of each action.
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum UpdatedValue {
-    /// A buttonlike action that was pressed or released.
-    Button(bool),
-    /// An axislike action that was updated.
-    Axis(f32),
-    /// A dual-axislike action that was updated.
-    DualAxis(Vec2),
-    /// A triple-axislike action that was updated.
-    TripleAxis(Vec3),
-}
 
-impl<A: Actionlike> Default for UpdatedActions<A> {
-    fn default() -> Self {
-        Self(HashMap::default())
-    }
-}
+    /// Appends a `processor` to the processing pipeline of the `action`,
+    /// which is applied to the combined value of all inputs bound to the `action`.
+    ///
+    /// See [`InputMap::with_action_processor`] for more details.
+    #[inline]
+    #[track_caller]
+    pub fn insert_action_processor(
+        &mut self,
+        action: A,
+        processor: impl Into<ActionProcessor>,
+    ) -> &mut Self {
+        match (processor.into(), action.input_control_kind()) {
+            (ActionProcessor::Axis(processor), InputControlKind::Axis) => {
+                self.axis_action_processors
+                    .entry(action)
+                    .or_default()
+                    .push(processor);
+            }
+            (ActionProcessor::DualAxis(processor), InputControlKind::DualAxis) => {
+                self.dual_axis_action_processors
+                    .entry(action)
+                    .or_default()
+                    .push(processor);
+            }
+            (processor, kind) => {
+                debug_assert!(
+                    false,
+                    "Cannot attach the processor {:?} to action {:?} of kind {:?}",
+                    processor, action, kind
+                );
 
-// Utilities
-impl<A: Actionlike> InputMap<A> {
-    /// Returns an iterator over all registered [`Buttonlike`] actions with their input bindings.
-    pub fn iter_buttonlike(&self) -> impl Iterator<Item = (&A, &Vec<Box<dyn Buttonlike>>)> {
-        self.buttonlike_map.iter()
-    }
+                error!(
+                    "Cannot attach the processor {:?} to action {:?} of kind {:?}",
+                    processor, action, kind
+                );
+            }
+        }
 
-    /// Returns an iterator over all registered [`Axislike`] actions with their input bindings.
-    pub fn iter_axislike(&self) -> impl Iterator<Item = (&A, &Vec<Box<dyn Axislike>>)> {
-        self.axislike_map.iter()
+        self
     }
 
-    /// Returns an iterator over all registered [`DualAxislike`] actions with their input bindings.
-    pub fn iter_dual_axislike(&self) -> impl Iterator<Item = (&A, &Vec<Box<dyn DualAxislike>>)> {
-        self.dual_axislike_map.iter()
+    /// Returns the processors applied to the combined value of the [`Axislike`] `action`.
+    pub fn axis_action_processors(&self, action: &A) -> &[AxisProcessor] {
+        self.axis_action_processors
+            .get(action)
+            .map_or(&[], |processors| processors.as_slice())
     }
 
-    /// Returns an iterator over all registered [`TripleAxislike`] actions with their input bindings.
-    pub fn iter_triple_axislike(
-        &self,
-    ) -> impl Iterator<Item = (&A, &Vec<Box<dyn TripleAxislike>>)> {
-        self.triple_axislike_map.iter()
+    /// Returns the processors applied to the combined value of the [`DualAxislike`] `action`.
+    pub fn dual_axis_action_processors(&self, action: &A) -> &[DualAxisProcessor] {
+        self.dual_axis_action_processors
+            .get(action)
+            .map_or(&[], |processors| processors.as_slice())
     }
 
-    /// Returns an iterator over all registered [`Buttonlike`] action-input bindings.
-    pub fn buttonlike_bindings(&self) -> impl Iterator<Item = (&A, &dyn Buttonlike)> {
-        self.buttonlike_map
-            .iter()
-            .flat_map(|(action, inputs)| inputs.iter().map(move |input| (action, input.as_ref())))
+    /// Removes all processors attached to the `action`.
+    pub fn clear_action_processors(&mut self, action: &A) -> &mut Self {
+        self.axis_action_processors.remove(action);
+        self.dual_axis_action_processors.remove(action);
+        self
     }
 
-    /// Returns an iterator over all registered [`Axislike`] action-input bindings.
-    pub fn axislike_bindings(&self) -> impl Iterator<Item = (&A, &dyn Axislike)> {
-        self.axislike_map
-            .iter()
-            .flat_map(|(action, inputs)| inputs.iter().map(move |input| (action, input.as_ref())))
+    /// Sets the sensitivity of the `action`, which scales its value after all processors are applied.
+    ///
+    /// Unlike an [`AxisProcessor::Sensitivity`] or [`DualAxisSensitivity`](crate::input_processing::DualAxisSensitivity) processor,
+    /// this doesn't change the processing pipeline, so it can be changed every frame,
+    /// such as from a "look sensitivity" slider in a settings menu,
+    /// and takes effect the next time the actions are processed.
+    /// A sensitivity of `1.0` leaves the value unchanged.
+    ///
+    /// This applies to [`Axislike`], [`DualAxislike`] and [`TripleAxislike`] actions,
+    /// and has no effect on [`Buttonlike`] actions.
+    ///
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, PartialEq, Eq, Clone, Copy, Hash, Debug, Reflect)]
+    /// enum Action {
+    ///     #[actionlike(DualAxis)]
+    ///     Look,
+    /// }
+    ///
+    /// let mut input_map = InputMap::default().with_dual_axis(Action::Look, MouseMove::default());
+    /// assert_eq!(input_map.action_sensitivity(&Action::Look), 1.0);
+    ///
+    /// // Called whenever the slider changes
+    /// input_map.set_action_sensitivity(&Action::Look, 0.5);
+    /// assert_eq!(input_map.action_sensitivity(&Action::Look), 0.5);
+    /// ```
+    #[inline]
+    pub fn set_action_sensitivity(&mut self, action: &A, sensitivity: f32) -> &mut Self {
+        if sensitivity == 1.0 {
+            self.action_sensitivities.remove(action);
+        } else {
+            self.action_sensitivities
+                .insert(action.clone(), ActionSensitivity(sensitivity));
+        }
+        self
     }
 
-    /// Returns an iterator over all registered [`DualAxislike`] action-input bindings.
-    pub fn dual_axislike_bindings(&self) -> impl Iterator<Item = (&A, &dyn DualAxislike)> {
-        self.dual_axislike_map
-            .iter()
-            .flat_map(|(action, inputs)| inputs.iter().map(move |input| (action, input.as_ref())))
+    /// Returns the sensitivity that the value of the `action` is scaled by,
+    /// as set by [`InputMap::set_action_sensitivity`].
+    ///
+    /// Defaults to `1.0`.
+    #[must_use]
+    #[inline]
+    pub fn action_sensitivity(&self, action: &A) -> f32 {
+        self.action_sensitivities
+            .get(action)
+            .map_or(1.0, |sensitivity| sensitivity.0)
     }
 
-    /// Returns an iterator over all registered [`TripleAxislike`] action-input bindings.
-    pub fn triple_axislike_bindings(&self) -> impl Iterator<Item = (&A, &dyn TripleAxislike)> {
-        self.triple_axislike_map
-            .iter()
-            .flat_map(|(action, inputs)| inputs.iter().map(move |input| (action, input.as_ref())))
+    /// Appends a [`CircleDeadZone`](crate::input_processing::CircleDeadZone) of the given [`DeadZonePreset`]
+    /// to the processing pipeline of each [`DualAxislike`] input bound to the `action`.
+    ///
+    /// See [`InputMap::apply_stick_deadzone`] for more details.
+    ///
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, PartialEq, Eq, Clone, Copy, Hash, Debug, Reflect)]
+    /// enum Action {
+    ///     #[actionlike(DualAxis)]
+    ///     Move,
+    /// }
+    ///
+    /// let input_map = InputMap::default()
+    ///     .with_dual_axis(Action::Move, GamepadStick::LEFT)
+    ///     .with_deadzone_preset(Action::Move, DeadZonePreset::Standard);
+    ///
+    /// let expected = GamepadStick::LEFT.with_circle_deadzone(0.1);
+    /// assert_eq!(
+    ///     input_map.get_dual_axislike(&Action::Move).unwrap()[0],
+    ///     Box::new(expected) as Box<dyn DualAxislike>
+    /// );
+    /// ```
+    #[inline]
+    pub fn with_deadzone_preset(mut self, action: A, preset: DeadZonePreset) -> Self {
+        self.apply_stick_deadzone(&action, preset);
+        self
     }
 
-    /// Returns an iterator over all registered [`Buttonlike`] actions.
-    pub fn buttonlike_actions(&self) -> impl Iterator<Item = &A> {
-        self.buttonlike_map.keys()
-    }
+    /// Appends the `deadzone` to the processing pipeline of each [`DualAxislike`] input bound to the `action`,
+    /// such as [`GamepadStick`](crate::user_input::GamepadStick)s or [`VirtualDPad`](crate::user_input::VirtualDPad)s.
+    ///
+    /// Unlike [`InputMap::insert_action_processor`], which processes the combined value of all inputs,
+    /// this processes each input separately, before their values are combined.
+    ///
+    /// Inputs are found through their `processors` field using reflection,
+    /// so inputs without a processing pipeline, such as chords, are left untouched.
+    /// Bindings of other kinds are never affected.
+    pub fn apply_stick_deadzone(
+        &mut self,
+        action: &A,
+        deadzone: impl Into<DualAxisProcessor>,
+    ) -> &mut Self {
+        let deadzone = deadzone.into();
+        let Some(bindings) = self.dual_axislike_map.get_mut(action) else {
+            return self;
+        };
 
-    /// Returns an iterator over all registered [`Axislike`] actions.
-    pub fn axislike_actions(&self) -> impl Iterator<Item = &A> {
-        self.axislike_map.keys()
-    }
+        for binding in bindings.iter_mut() {
+            let ReflectMut::Struct(input) = binding.as_mut().reflect_mut() else {
+                continue;
+            };
 
-    /// Returns an iterator over all registered [`DualAxislike`] actions.
-    pub fn dual_axislike_actions(&self) -> impl Iterator<Item = &A> {
-        self.dual_axislike_map.keys()
-    }
+            if let Some(processors) = input
+                .field_mut("processors")
+                .and_then(|processors| processors.try_downcast_mut::<Vec<DualAxisProcessor>>())
+            {
+                processors.push(deadzone.clone());
+            }
+        }
 
-    /// Returns an iterator over all registered [`TripleAxislike`] actions.
-    pub fn triple_axislike_actions(&self) -> impl Iterator<Item = &A> {
-        self.triple_axislike_map.keys()
+        self
     }
+}
 
-    /// Returns a reference to the [`UserInput`](crate::user_input::UserInput) inputs associated with the given `action`.
+// Remapping
+impl<A: Actionlike> InputMap<A> {
+    /// Temporarily routes the resolved state of each action to another action,
+    /// without modifying any bindings.
     ///
-    /// # Warning
+    /// Each key of the `remap` is an action whose state will be reported for the corresponding value instead,
+    /// while actions that aren't remapped keep their own state.
+    /// If several actions are routed to the same action, their states are combined:
+    /// buttonlike actions are pressed if any of them are pressed, and axislike values are summed.
+    /// Actions that are routed elsewhere, and that no other action is routed to,
+    /// are reported as released, or with a value of zero.
     ///
-    /// Unlike the other `get` methods, this method is forced to clone the inputs
-    /// due to the lack of [trait upcasting coercion](https://github.com/rust-lang/rust/issues/65991).
+    /// This is useful for status effects that confuse the player, such as swapping left and right.
+    /// Call [`InputMap::clear_remap`] to restore the original behavior.
+    /// Remapping replaces any existing remap, and is not serialized.
     ///
-    /// As a result, no equivalent `get_mut` method is provided.
-    #[must_use]
-    pub fn get(&self, action: &A) -> Option<Vec<UserInputWrapper>> {
-        match action.input_control_kind() {
-            InputControlKind::Button => {
-                let buttonlike = self.buttonlike_map.get(action)?;
-                Some(
-                    buttonlike
-                        .iter()
-                        .map(|input| UserInputWrapper::Button(input.clone()))
-                        .collect(),
-                )
-            }
-            InputControlKind::Axis => {
-                let axislike = self.axislike_map.get(action)?;
-                Some(
-                    axislike
-                        .iter()
-                        .map(|input| UserInputWrapper::Axis(input.clone()))
-                        .collect(),
-                )
-            }
-            InputControlKind::DualAxis => {
-                let dual_axislike = self.dual_axislike_map.get(action)?;
-                Some(
-                    dual_axislike
-                        .iter()
-                        .map(|input| UserInputWrapper::DualAxis(input.clone()))
-                        .collect(),
-                )
-            }
-            InputControlKind::TripleAxis => {
-                let triple_axislike = self.triple_axislike_map.get(action)?;
-                Some(
-                    triple_axislike
-                        .iter()
-                        .map(|input| UserInputWrapper::TripleAxis(input.clone()))
-                        .collect(),
-                )
-            }
-        }
+    /// Actions can only be routed to actions of the same [`InputControlKind`]:
+    /// other pairs are ignored, logging an error.
+    ///
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use bevy::utils::HashMap;
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+    /// enum Action {
+    ///     Left,
+    ///     Right,
+    /// }
+    ///
+    /// let mut input_map = InputMap::new([(Action::Left, KeyCode::KeyA), (Action::Right, KeyCode::KeyD)]);
+    ///
+    /// // The player is confused: swap left and right
+    /// input_map.apply_remap(HashMap::from([
+    ///     (Action::Left, Action::Right),
+    ///     (Action::Right, Action::Left),
+    /// ]));
+    /// assert_eq!(input_map.active_remap().get(&Action::Left), Some(&Action::Right));
+    ///
+    /// // The effect wears off
+    /// input_map.clear_remap();
+    /// assert!(input_map.active_remap().is_empty());
+    /// ```
+    #[track_caller]
+    pub fn apply_remap(&mut self, remap: HashMap<A, A>) -> &mut Self {
+        self.remap = remap
+            .into_iter()
+            .filter(|(from, to)| {
+                let compatible = from.input_control_kind() == to.input_control_kind();
+                if !compatible {
+                    error!(
+                        "Cannot remap action {:?} of kind {:?} to action {:?} of kind {:?}",
+                        from,
+                        from.input_control_kind(),
+                        to,
+                        to.input_control_kind()
+                    );
+                }
+                compatible
+            })
+            .collect();
+        self
     }
 
-    /// Returns a reference to the [`Buttonlike`] inputs associated with the given `action`.
-    #[must_use]
-    pub fn get_buttonlike(&self, action: &A) -> Option<&Vec<Box<dyn Buttonlike>>> {
-        self.buttonlike_map.get(action)
+    /// Removes any remap applied using [`InputMap::apply_remap`],
+    /// so that each action reports its own state again.
+    pub fn clear_remap(&mut self) -> &mut Self {
+        self.remap.clear();
+        self
     }
 
-    /// Returns a mutable reference to the [`Buttonlike`] inputs mapped to `action`
+    /// Returns the remap applied using [`InputMap::apply_remap`],
+    /// from each remapped action to the action that reports its state.
     #[must_use]
-    pub fn get_buttonlike_mut(&mut self, action: &A) -> Option<&mut Vec<Box<dyn Buttonlike>>> {
-        self.buttonlike_map.get_mut(action)
+    pub fn active_remap(&self) -> &HashMap<A, A> {
+        &self.remap
     }
 
-    /// Returns a reference to the [`Axislike`] inputs associated with the given `action`.
-    #[must_use]
-    pub fn get_axislike(&self, action: &A) -> Option<&Vec<Box<dyn Axislike>>> {
-        self.axislike_map.get(action)
-    }
+    /// Routes the state of each remapped action in the `updated_actions` to its target action.
+    fn apply_remap_to(&self, updated_actions: UpdatedActions<A>) -> UpdatedActions<A> {
+        let mut remapped = UpdatedActions::default();
+        let mut vacated = Vec::new();
 
-    /// Returns a mutable reference to the [`Axislike`] inputs mapped to `action`
-    #[must_use]
-    pub fn get_axislike_mut(&mut self, action: &A) -> Option<&mut Vec<Box<dyn Axislike>>> {
-        self.axislike_map.get_mut(action)
-    }
+        for (action, value) in updated_actions.0 {
+            let target = match self.remap.get(&action) {
+                Some(target) => {
+                    vacated.push((action, value.neutral()));
+                    target.clone()
+                }
+                None => action,
+            };
+            let combined = match (remapped.0.remove(&target), value) {
+                (None, value) => value,
+                (Some(UpdatedValue::Button(a)), UpdatedValue::Button(b)) => {
+                    UpdatedValue::Button(a || b)
+                }
+                (Some(UpdatedValue::Axis(a)), UpdatedValue::Axis(b)) => UpdatedValue::Axis(a + b),
+                (Some(UpdatedValue::DualAxis(a)), UpdatedValue::DualAxis(b)) => {
+                    UpdatedValue::DualAxis(a + b)
+                }
+                (Some(UpdatedValue::TripleAxis(a)), UpdatedValue::TripleAxis(b)) => {
+                    UpdatedValue::TripleAxis(a + b)
+                }
+                // Only actions of the same kind can be remapped to each other
+                (Some(_), value) => value,
+            };
+            remapped.0.insert(target, combined);
+        }
 
-    /// Returns a reference to the [`DualAxislike`] inputs associated with the given `action`.
-    #[must_use]
-    pub fn get_dual_axislike(&self, action: &A) -> Option<&Vec<Box<dyn DualAxislike>>> {
-        self.dual_axislike_map.get(action)
-    }
+        // Actions whose state was routed elsewhere are reset, rather than keeping their last state
+        for (action, neutral) in vacated {
+            remapped.0.entry(action).or_insert(neutral);
+        }
 
-    /// Returns a mutable reference to the [`DualAxislike`] inputs mapped to `action`
-    #[must_use]
-    pub fn get_dual_axislike_mut(&mut self, action: &A) -> Option<&mut Vec<Box<dyn DualAxislike>>> {
-        self.dual_axislike_map.get_mut(action)
+        remapped
     }
+}
 
-    /// Returns a reference to the [`TripleAxislike`] inputs associated with the given `action`.
-    #[must_use]
-    pub fn get_triple_axislike(&self, action: &A) -> Option<&Vec<Box<dyn TripleAxislike>>> {
-        self.triple_axislike_map.get(action)
+// Capturing
+impl<A: Actionlike> InputMap<A> {
+    /// Enters "learning mode", binding the next newly pressed [`Buttonlike`] input to the `action`.
+    ///
+    /// The binding is inserted by the [`capture_input_bindings`](crate::systems::capture_input_bindings) system,
+    /// which clears the pending capture once it completes.
+    /// Inputs that were already held down when the capture began are ignored,
+    /// and ties between inputs pressed on the same frame are broken in a consistent order.
+    ///
+    /// Calling this again before the capture completes replaces the pending action.
+    #[inline]
+    #[track_caller]
+    pub fn begin_capture(&mut self, action: A) -> &mut Self {
+        debug_assert!(
+            action.input_control_kind() == InputControlKind::Button,
+            "Cannot capture a Buttonlike input for action {:?} of kind {:?}",
+            action,
+            action.input_control_kind()
+        );
+
+        if action.input_control_kind() != InputControlKind::Button {
+            error!(
+                "Cannot capture a Buttonlike input for action {:?} of kind {:?}",
+                action,
+                action.input_control_kind()
+            );
+
+            return self;
+        }
+
+        self.pending_capture = Some(action);
+        self
     }
 
-    /// Returns a mutable reference to the [`TripleAxislike`] inputs mapped to `action`
+    /// Cancels any pending capture started with [`InputMap::begin_capture`].
+    #[inline]
+    pub fn cancel_capture(&mut self) -> &mut Self {
+        self.pending_capture = None;
+        self
+    }
+
+    /// Returns the action waiting to be bound to the next pressed input, if any.
     #[must_use]
-    pub fn get_triple_axislike_mut(
+    #[inline]
+    pub fn pending_capture(&self) -> Option<&A> {
+        self.pending_capture.as_ref()
+    }
+
+    /// Binds the `input` to the pending capture action, returning the action if a capture was pending.
+    ///
+    /// The new binding is tagged with [`BindingSource::User`].
+    pub(crate) fn complete_capture(&mut self, input: Box<dyn Buttonlike>) -> Option<A> {
+        let action = self.pending_capture.take()?;
+        insert_unique(&mut self.buttonlike_map, &action, input.clone());
+        self.set_binding_source(
+            &action,
+            UserInputWrapper::Button(input),
+            BindingSource::User,
+        );
+        Some(action)
+    }
+}
+
+/// The sensitivity of an action, set using [`InputMap::set_action_sensitivity`].
+///
+/// Compared and hashed using [`FloatOrd`], so that [`InputMap`]s can still be compared.
+#[derive(Debug, Clone, Copy, PartialEq, Reflect, Serialize, Deserialize)]
+#[serde(transparent)]
+struct ActionSensitivity(f32);
+
+impl Eq for ActionSensitivity {}
+
+impl Hash for ActionSensitivity {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        FloatOrd(self.0).hash(state);
+    }
+}
+
+/// Where a binding in an [`InputMap`] came from.
+///
+/// This is useful for settings menus that distinguish between the default bindings of a game
+/// and the ones customized by the player, such as to offer resetting them.
+/// Bindings are tagged using [`InputMap::set_binding_source`], and are [`BindingSource::Default`] unless tagged otherwise.
+/// Tags are serialized along with the [`InputMap`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+pub enum BindingSource {
+    /// A default binding, provided by the game.
+    #[default]
+    Default,
+
+    /// A binding customized by the user, such as through a rebinding menu.
+    ///
+    /// Bindings added using [`InputMap::begin_capture`] are tagged with this source.
+    User,
+
+    /// A binding received from a remote source, such as a server or a synced profile.
+    Remote,
+}
+
+// Binding sources
+impl<A: Actionlike> InputMap<A> {
+    /// Tags the binding of the `input` to the `action` with the provided [`BindingSource`].
+    ///
+    /// Has no effect if the `input` is not bound to the `action`.
+    /// Tags are removed along with their bindings when using the removal methods of the [`InputMap`],
+    /// but not when modifying bindings through methods like [`InputMap::get_buttonlike_mut`].
+    ///
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use leafwing_input_manager::input_map::BindingSource;
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+    /// enum Action {
+    ///     Jump,
+    /// }
+    ///
+    /// let mut input_map = InputMap::new([(Action::Jump, KeyCode::Space)]);
+    /// input_map.insert(Action::Jump, KeyCode::KeyJ);
+    /// input_map.set_binding_source(
+    ///     &Action::Jump,
+    ///     UserInputWrapper::Button(Box::new(KeyCode::KeyJ)),
+    ///     BindingSource::User,
+    /// );
+    ///
+    /// assert_eq!(
+    ///     input_map.cheat_sheet_with_sources(),
+    ///     vec![(
+    ///         "Jump".to_string(),
+    ///         vec![
+    ///             ("Space".to_string(), BindingSource::Default),
+    ///             ("J".to_string(), BindingSource::User),
+    ///         ]
+    ///     )]
+    /// );
+    /// ```
+    pub fn set_binding_source(
         &mut self,
         action: &A,
-    ) -> Option<&mut Vec<Box<dyn TripleAxislike>>> {
-        self.triple_axislike_map.get_mut(action)
+        input: UserInputWrapper,
+        source: BindingSource,
+    ) -> &mut Self {
+        if !self.is_input_bound(action, &input) {
+            return self;
+        }
+
+        let sources = self.binding_sources.entry(action.clone()).or_default();
+        sources.retain(|(tagged_input, _)| tagged_input != &input);
+        if source != BindingSource::Default {
+            sources.push((input, source));
+        }
+        if sources.is_empty() {
+            self.binding_sources.remove(action);
+        }
+
+        self
     }
 
-    /// Count the total number of registered input bindings.
+    /// Returns the [`BindingSource`] of the binding of the `input` to the `action`.
+    ///
+    /// Untagged bindings, as well as inputs that aren't bound to the `action`, are [`BindingSource::Default`].
     #[must_use]
-    pub fn len(&self) -> usize {
-        self.buttonlike_map.values().map(Vec::len).sum::<usize>()
-            + self.axislike_map.values().map(Vec::len).sum::<usize>()
-            + self.dual_axislike_map.values().map(Vec::len).sum::<usize>()
-            + self
+    pub fn binding_source(&self, action: &A, input: &UserInputWrapper) -> BindingSource {
+        self.binding_sources
+            .get(action)
+            .and_then(|sources| {
+                sources
+                    .iter()
+                    .find(|(tagged_input, _)| tagged_input == input)
+            })
+            .map_or(BindingSource::Default, |(_, source)| *source)
+    }
+
+    /// Is the `input` currently bound to the `action`?
+    fn is_input_bound(&self, action: &A, input: &UserInputWrapper) -> bool {
+        match input {
+            UserInputWrapper::Button(input) => self
+                .buttonlike_map
+                .get(action)
+                .is_some_and(|inputs| inputs.contains(input)),
+            UserInputWrapper::Axis(input) => self
+                .axislike_map
+                .get(action)
+                .is_some_and(|inputs| inputs.contains(input)),
+            UserInputWrapper::DualAxis(input) => self
+                .dual_axislike_map
+                .get(action)
+                .is_some_and(|inputs| inputs.contains(input)),
+            UserInputWrapper::TripleAxis(input) => self
                 .triple_axislike_map
-                .values()
-                .map(Vec::len)
-                .sum::<usize>()
+                .get(action)
+                .is_some_and(|inputs| inputs.contains(input)),
+        }
     }
 
-    /// Returns `true` if the map contains no action-input bindings.
+    /// Removes the source and context tags of bindings that no longer exist.
+    fn prune_binding_tags(&mut self) {
+        let mut binding_sources = std::mem::take(&mut self.binding_sources);
+        binding_sources.retain(|action, sources| {
+            sources.retain(|(input, _)| self.is_input_bound(action, input));
+            !sources.is_empty()
+        });
+        self.binding_sources = binding_sources;
+
+        let mut binding_contexts = std::mem::take(&mut self.binding_contexts);
+        binding_contexts.retain(|action, contexts| {
+            contexts.retain(|(input, _)| self.is_input_bound(action, input));
+            !contexts.is_empty()
+        });
+        self.binding_contexts = binding_contexts;
+
+        let mut disabled_bindings = std::mem::take(&mut self.disabled_bindings);
+        disabled_bindings.retain(|action, disabled| {
+            disabled.retain(|input| self.is_input_bound(action, input));
+            !disabled.is_empty()
+        });
+        self.disabled_bindings = disabled_bindings;
+    }
+}
+
+/// Tracks which game contexts are currently active,
+/// enabling the bindings of each [`InputMap`] that are restricted to them.
+///
+/// Contexts are arbitrary names like `"vehicle"` or `"menu"`, and are inactive unless set otherwise.
+/// Bindings are restricted to a context using [`InputMap::set_binding_context`],
+/// and are ignored by [`InputMap::process_actions_in_context`] while their context is inactive.
+///
+/// This resource is added by the [`InputManagerPlugin`](crate::plugin::InputManagerPlugin).
+#[derive(Resource, Debug, Default, Clone, PartialEq, Eq, Reflect, Serialize, Deserialize)]
+#[reflect(Resource)]
+pub struct InputContexts {
+    /// Whether each context is currently active.
+    contexts: HashMap<String, bool>,
+}
+
+impl InputContexts {
+    /// Sets whether the `context` is currently active.
     #[inline]
-    #[must_use]
-    pub fn is_empty(&self) -> bool {
-        self.len() == 0
+    pub fn set(&mut self, context: impl Into<String>, active: bool) -> &mut Self {
+        self.contexts.insert(context.into(), active);
+        self
     }
 
-    /// Clears the map, removing all action-input bindings.
-    pub fn clear(&mut self) {
-        self.buttonlike_map.clear();
-        self.axislike_map.clear();
-        self.dual_axislike_map.clear();
-        self.triple_axislike_map.clear();
+    /// Is the `context` currently active?
+    ///
+    /// Contexts that were never set are inactive.
+    #[inline]
+    #[must_use]
+    pub fn is_active(&self, context: &str) -> bool {
+        self.contexts.get(context).copied().unwrap_or(false)
     }
 }
 
-// Removing
+// Binding contexts
 impl<A: Actionlike> InputMap<A> {
-    /// Clears all input bindings associated with the `action`.
-    pub fn clear_action(&mut self, action: &A) {
-        match action.input_control_kind() {
-            InputControlKind::Button => {
-                self.buttonlike_map.remove(action);
-            }
-            InputControlKind::Axis => {
-                self.axislike_map.remove(action);
-            }
-            InputControlKind::DualAxis => {
-                self.dual_axislike_map.remove(action);
-            }
-            InputControlKind::TripleAxis => {
-                self.triple_axislike_map.remove(action);
+    /// Restricts the binding of the `input` to the `action` to the provided `context`,
+    /// replacing any context it was previously restricted to.
+    ///
+    /// The binding is only considered while the `context` is active in the [`InputContexts`] resource,
+    /// which allows enabling bindings based on the state of the game without swapping whole maps.
+    /// Has no effect if the `input` is not bound to the `action`.
+    ///
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use leafwing_input_manager::input_map::InputContexts;
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+    /// enum Action {
+    ///     Interact,
+    /// }
+    ///
+    /// let mut input_map = InputMap::new([(Action::Interact, KeyCode::KeyE)]);
+    /// input_map.insert(Action::Interact, KeyCode::KeyF);
+    /// input_map.set_binding_context(
+    ///     &Action::Interact,
+    ///     UserInputWrapper::Button(Box::new(KeyCode::KeyF)),
+    ///     "vehicle",
+    /// );
+    ///
+    /// let mut contexts = InputContexts::default();
+    /// let input = UserInputWrapper::Button(Box::new(KeyCode::KeyF));
+    /// assert!(!input_map.binding_active(&Action::Interact, &input, &contexts));
+    ///
+    /// contexts.set("vehicle", true);
+    /// assert!(input_map.binding_active(&Action::Interact, &input, &contexts));
+    /// ```
+    pub fn set_binding_context(
+        &mut self,
+        action: &A,
+        input: UserInputWrapper,
+        context: impl Into<String>,
+    ) -> &mut Self {
+        if !self.is_input_bound(action, &input) {
+            return self;
+        }
+
+        let contexts = self.binding_contexts.entry(action.clone()).or_default();
+        contexts.retain(|(tagged_input, _)| tagged_input != &input);
+        contexts.push((input, context.into()));
+
+        self
+    }
+
+    /// Removes the context restriction from the binding of the `input` to the `action`,
+    /// so that it is always considered.
+    pub fn clear_binding_context(&mut self, action: &A, input: &UserInputWrapper) -> &mut Self {
+        if let Some(contexts) = self.binding_contexts.get_mut(action) {
+            contexts.retain(|(tagged_input, _)| tagged_input != input);
+            if contexts.is_empty() {
+                self.binding_contexts.remove(action);
             }
         }
+
+        self
     }
 
-    /// Removes the input for the `action` at the provided index.
-    ///
-    /// Returns `Some(())` if the input was found and removed, or `None` if no matching input was found.
-    ///
-    /// # Note
+    /// Returns the context that the binding of the `input` to the `action` is restricted to, if any.
+    #[must_use]
+    pub fn binding_context(&self, action: &A, input: &UserInputWrapper) -> Option<&str> {
+        self.binding_contexts
+            .get(action)
+            .and_then(|contexts| {
+                contexts
+                    .iter()
+                    .find(|(tagged_input, _)| tagged_input == input)
+            })
+            .map(|(_, context)| context.as_str())
+    }
+
+    /// Is the binding of the `input` to the `action` considered under the provided [`InputContexts`]?
     ///
-    /// The original input cannot be returned, as the trait object may differ based on the [`InputControlKind`].
-    pub fn remove_at(&mut self, action: &A, index: usize) -> Option<()> {
-        match action.input_control_kind() {
-            InputControlKind::Button => {
-                let input_bindings = self.buttonlike_map.get_mut(action)?;
-                if input_bindings.len() > index {
-                    input_bindings.remove(index);
-                    Some(())
-                } else {
-                    None
-                }
-            }
-            InputControlKind::Axis => {
-                let input_bindings = self.axislike_map.get_mut(action)?;
-                if input_bindings.len() > index {
-                    input_bindings.remove(index);
-                    Some(())
-                } else {
-                    None
-                }
-            }
-            InputControlKind::DualAxis => {
-                let input_bindings = self.dual_axislike_map.get_mut(action)?;
-                if input_bindings.len() > index {
-                    input_bindings.remove(index);
-                    Some(())
-                } else {
-                    None
-                }
-            }
-            InputControlKind::TripleAxis => {
-                let input_bindings = self.triple_axislike_map.get_mut(action)?;
-                if input_bindings.len() > index {
-                    input_bindings.remove(index);
-                    Some(())
-                } else {
-                    None
-                }
-            }
+    /// This is `true` for bindings that aren't restricted to a context.
+    #[must_use]
+    pub fn binding_active(
+        &self,
+        action: &A,
+        input: &UserInputWrapper,
+        contexts: &InputContexts,
+    ) -> bool {
+        self.binding_context(action, input)
+            .is_none_or(|context| contexts.is_active(context))
+    }
+
+    /// Returns [`InputContexts`] in which every context that bindings of this map are restricted to is active.
+    pub(crate) fn all_binding_contexts(&self) -> InputContexts {
+        let mut contexts = InputContexts::default();
+        for (_, context) in self.binding_contexts.values().flatten() {
+            contexts.set(context.clone(), true);
         }
+
+        contexts
     }
 
-    /// Removes the input for the `action` if it exists
+    /// Is the binding found using the `is_binding` predicate considered when processing the `action`?
     ///
-    /// Returns [`Some`] with index if the input was found, or [`None`] if no matching input was found.
-    pub fn remove(&mut self, action: &A, input: impl Buttonlike) -> Option<usize> {
-        let bindings = self.buttonlike_map.get_mut(action)?;
-        let boxed_input: Box<dyn Buttonlike> = Box::new(input);
-        let index = bindings.iter().position(|input| input == &boxed_input)?;
-        bindings.remove(index);
-        Some(index)
+    /// This is the case if the binding is [active](InputMap::binding_active) under the provided [`InputContexts`]
+    /// and isn't [disabled](InputMap::disable_binding).
+    /// The binding is found using a predicate instead of comparing against a wrapped input,
+    /// so that bindings can be checked by reference.
+    #[inline]
+    pub(crate) fn binding_considered(
+        &self,
+        action: &A,
+        contexts: &InputContexts,
+        is_binding: impl Fn(&UserInputWrapper) -> bool,
+    ) -> bool {
+        let in_context = self
+            .binding_contexts
+            .get(action)
+            .and_then(|tagged| tagged.iter().find(|(input, _)| is_binding(input)))
+            .is_none_or(|(_, context)| contexts.is_active(context));
+
+        in_context
+            && !self
+                .disabled_bindings
+                .get(action)
+                .is_some_and(|disabled| disabled.iter().any(&is_binding))
     }
 }
 
-impl<A: Actionlike, U: Buttonlike> From<HashMap<A, Vec<U>>> for InputMap<A> {
-    /// Converts a [`HashMap`] mapping actions to multiple [`Buttonlike`]s into an [`InputMap`].
+// Disabled bindings
+impl<A: Actionlike> InputMap<A> {
+    /// Disables the binding at the `index` of the inputs bound to the `action`, without removing it.
     ///
-    /// # Examples
+    /// Disabled bindings are ignored when processing actions and resolving clashes,
+    /// but keep their place among the bindings of the `action`,
+    /// so they can later be re-enabled using [`InputMap::enable_binding`] without changing their order.
+    ///
+    /// The `index` refers to the bindings of the [`InputControlKind`] of the `action`, in the order they were inserted,
+    /// as returned by [`InputMap::bindings_for`] for [`Buttonlike`] actions
+    /// (or [`InputMap::axislike_bindings_for`] and its siblings for the other kinds) and by [`InputMap::get`].
+    /// The binding itself is disabled rather than its index,
+    /// so it stays disabled even if earlier bindings are removed and its index changes.
+    /// Disabling does not change the indices of any bindings.
+    ///
+    /// Returns `Some(())` if a binding was found at the `index`, or `None` if there is no such binding.
     ///
     /// ```rust
     /// use bevy::prelude::*;
-    /// use bevy::utils::HashMap;
     /// use leafwing_input_manager::prelude::*;
     ///
-    /// #[derive(Actionlike, Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
     /// enum Action {
-    ///     Run,
-    ///     Jump,
+    ///     Dash,
     /// }
     ///
-    /// // Create an InputMap from a HashMap mapping actions to their key bindings.
-    /// let mut map: HashMap<Action, Vec<KeyCode>> = HashMap::default();
+    /// let mut input_map = InputMap::new([(Action::Dash, KeyCode::ShiftLeft)]);
+    /// input_map.insert(Action::Dash, KeyCode::KeyE);
     ///
-    /// // Bind the "run" action to either the left or right shift keys to trigger the action.
-    /// map.insert(
-    ///     Action::Run,
-    ///     vec![KeyCode::ShiftLeft, KeyCode::ShiftRight],
-    /// );
+    /// // The tutorial only teaches dashing with E
+    /// input_map.disable_binding(&Action::Dash, 0);
+    /// assert!(input_map.binding_disabled(&Action::Dash, 0));
+    /// assert_eq!(input_map.bindings_for(&Action::Dash).count(), 2);
     ///
-    /// let input_map = InputMap::from(map);
+    /// input_map.enable_binding(&Action::Dash, 0);
+    /// assert!(!input_map.binding_disabled(&Action::Dash, 0));
     /// ```
-    fn from(raw_map: HashMap<A, Vec<U>>) -> Self {
-        let mut input_map = Self::default();
-        for (action, inputs) in raw_map.into_iter() {
-            input_map.insert_one_to_many(action, inputs);
+    pub fn disable_binding(&mut self, action: &A, index: usize) -> Option<()> {
+        let input = self.get(action)?.into_iter().nth(index)?;
+        self.disable_input(action, input);
+        Some(())
+    }
+
+    /// Re-enables the binding at the `index` of the inputs bound to the `action`,
+    /// after it was disabled using [`InputMap::disable_binding`].
+    ///
+    /// See [`InputMap::disable_binding`] for how bindings are indexed.
+    ///
+    /// Returns `Some(())` if a binding was found at the `index`, or `None` if there is no such binding.
+    /// Enabling a binding that isn't disabled has no effect.
+    pub fn enable_binding(&mut self, action: &A, index: usize) -> Option<()> {
+        let input = self.get(action)?.into_iter().nth(index)?;
+        if let Some(disabled) = self.disabled_bindings.get_mut(action) {
+            disabled.retain(|disabled_input| disabled_input != &input);
+            if disabled.is_empty() {
+                self.disabled_bindings.remove(action);
+            }
+        }
+
+        Some(())
+    }
+
+    /// Is the binding at the `index` of the inputs bound to the `action` [disabled](InputMap::disable_binding)?
+    ///
+    /// Returns `false` if there is no binding at the `index`.
+    #[must_use]
+    pub fn binding_disabled(&self, action: &A, index: usize) -> bool {
+        let Some(input) = self
+            .get(action)
+            .and_then(|inputs| inputs.into_iter().nth(index))
+        else {
+            return false;
+        };
+
+        self.disabled_bindings
+            .get(action)
+            .is_some_and(|disabled| disabled.contains(&input))
+    }
+
+    /// Disables the binding of the `input` to the `action`, if it is bound.
+    fn disable_input(&mut self, action: &A, input: UserInputWrapper) {
+        if !self.is_input_bound(action, &input) {
+            return;
         }
+
+        let disabled = self.disabled_bindings.entry(action.clone()).or_default();
+        if !disabled.contains(&input) {
+            disabled.push(input);
+        }
+    }
+}
+
+// Check whether actions are pressed
+impl<A: Actionlike> InputMap<A> {
+    /// Checks if the `action` are currently pressed by any of the associated [`Buttonlike`]s.
+    ///
+    /// Accounts for clashing inputs according to the [`ClashStrategy`] and remove conflicting actions.
+    #[must_use]
+    pub fn pressed(
+        &self,
+        action: &A,
+        input_store: &CentralInputStore,
+        clash_strategy: ClashStrategy,
+    ) -> bool {
+        let processed_actions = self.process_actions(None, input_store, clash_strategy);
+
+        let Some(updated_value) = processed_actions.get(action) else {
+            return false;
+        };
+
+        match updated_value {
+            UpdatedValue::Button(state) => *state,
+            _ => false,
+        }
+    }
+
+    /// Returns the first [`Buttonlike`] input bound to the `action` that is currently pressed, if any.
+    ///
+    /// Inputs bound to actions remapped to the `action` using [`InputMap::apply_remap`] are included,
+    /// while bindings whose context is inactive in the provided [`InputContexts`] are ignored.
+    #[must_use]
+    pub fn pressed_input(
+        &self,
+        action: &A,
+        gamepads: Option<Query<Entity, With<Gamepad>>>,
+        input_store: &CentralInputStore,
+        contexts: &InputContexts,
+    ) -> Option<&dyn Buttonlike> {
+        let gamepad = self.active_gamepad(gamepads);
+
+        self.iter_buttonlike()
+            .filter(|(source, _)| self.remap.get(*source).unwrap_or(source) == action)
+            .flat_map(|(source, bindings)| bindings.iter().map(move |binding| (source, binding)))
+            .find(|(source, binding)| {
+                self.binding_considered(
+                    source,
+                    contexts,
+                    |input| matches!(input, UserInputWrapper::Button(tagged) if tagged == *binding),
+                ) && binding.pressed(input_store, gamepad)
+            })
+            .map(|(_, binding)| binding.as_ref())
+    }
+
+    /// Determines the correct state for each action according to provided [`CentralInputStore`].
+    ///
+    /// This method uses the input bindings for each action to determine how to parse the input data,
+    /// and generates corresponding [`ButtonData`](crate::action_state::ButtonData),
+    /// [`AxisData`](crate::action_state::AxisData) and [`DualAxisData`](crate::action_state::DualAxisData).
+    ///
+    /// For [`Buttonlike`] actions, this accounts for clashing inputs according to the [`ClashStrategy`] and removes conflicting actions.
+    /// The `clash_strategy` is only used if this map does not set its own using [`InputMap::with_clash_strategy`].
+    ///
+    /// [`Buttonlike`] inputs will be pressed if any of the associated inputs are pressed.
+    /// [`Axislike`] and [`DualAxislike`] inputs will be the sum of all associated inputs,
+    /// after which any processors attached to the action using [`InputMap::with_action_processor`] are applied.
+    /// Their value is then scaled by the sensitivity of the action, set using [`InputMap::set_action_sensitivity`].
+    ///
+    /// Clashes are resolved using the raw values of the inputs,
+    /// so they are unaffected by action-level processors.
+    /// Finally, any remap applied using [`InputMap::apply_remap`] routes the state of each action to another action.
+    ///
+    /// Bindings restricted to a context using [`InputMap::set_binding_context`] are ignored,
+    /// as no context is active. Use [`InputMap::process_actions_in_context`] to consider them.
+    #[must_use]
+    pub fn process_actions(
+        &self,
+        gamepads: Option<Query<Entity, With<Gamepad>>>,
+        input_store: &CentralInputStore,
+        clash_strategy: ClashStrategy,
+    ) -> UpdatedActions<A> {
+        self.process_actions_in_context(
+            gamepads,
+            input_store,
+            clash_strategy,
+            &InputContexts::default(),
+        )
+    }
+
+    /// Determines the correct state for each action like [`InputMap::process_actions`],
+    /// ignoring the bindings whose context is inactive in the provided [`InputContexts`].
+    #[must_use]
+    pub fn process_actions_in_context(
+        &self,
+        gamepads: Option<Query<Entity, With<Gamepad>>>,
+        input_store: &CentralInputStore,
+        clash_strategy: ClashStrategy,
+        contexts: &InputContexts,
+    ) -> UpdatedActions<A> {
+        self.process_actions_skipping(gamepads, input_store, clash_strategy, contexts, |_| false)
+    }
+
+    /// Determines the correct state for each action like [`InputMap::process_actions_in_context`],
+    /// without evaluating the inputs of actions that are disabled in the provided [`ActionState`].
+    ///
+    /// Disabled actions always read as released or zero from their [`ActionState`],
+    /// so they are given these values directly, and the resulting state is the same as
+    /// [`InputMap::process_actions_in_context`] while the actions stay disabled.
+    ///
+    /// Actions remapped using [`InputMap::apply_remap`] are only skipped if the action they are routed to is disabled.
+    /// As a pressed [`Buttonlike`] action can overrule clashing actions,
+    /// buttonlike actions are only skipped when the [`ClashStrategy`] never resolves clashes,
+    /// or when the entire [`ActionState`] is disabled.
+    #[must_use]
+    pub fn process_actions_with_state(
+        &self,
+        gamepads: Option<Query<Entity, With<Gamepad>>>,
+        input_store: &CentralInputStore,
+        clash_strategy: ClashStrategy,
+        contexts: &InputContexts,
+        action_state: &ActionState<A>,
+    ) -> UpdatedActions<A> {
+        let skip_buttons = match self.clash_strategy.unwrap_or(clash_strategy) {
+            ClashStrategy::PressAll => true,
+            ClashStrategy::PrioritizeLongest => action_state.disabled(),
+        };
+
+        self.process_actions_skipping(gamepads, input_store, clash_strategy, contexts, |action| {
+            if action.input_control_kind() == InputControlKind::Button && !skip_buttons {
+                return false;
+            }
+            let target = self.remap.get(action).unwrap_or(action);
+            action_state.action_disabled(target)
+        })
+    }
+
+    /// Determines the correct state for each action, giving the actions for which `skip` returns `true`
+    /// their released or zero value without evaluating their inputs.
+    fn process_actions_skipping(
+        &self,
+        gamepads: Option<Query<Entity, With<Gamepad>>>,
+        input_store: &CentralInputStore,
+        clash_strategy: ClashStrategy,
+        contexts: &InputContexts,
+        skip: impl Fn(&A) -> bool,
+    ) -> UpdatedActions<A> {
+        let clash_strategy = self.clash_strategy.unwrap_or(clash_strategy);
+        let mut updated_actions = UpdatedActions::default();
+        let gamepad = self.active_gamepad(gamepads);
+
+        // Generate the base action data for each action
+        for (action, _input_bindings) in self.iter_buttonlike() {
+            if skip(action) {
+                updated_actions.insert(action.clone(), UpdatedValue::Button(false));
+                continue;
+            }
+
+            let mut final_state = false;
+            for binding in _input_bindings {
+                if !self.binding_considered(
+                    action,
+                    contexts,
+                    |input| matches!(input, UserInputWrapper::Button(tagged) if tagged == binding),
+                ) {
+                    continue;
+                }
+
+                if binding.pressed(input_store, gamepad) {
+                    final_state = true;
+                    break;
+                }
+            }
+
+            updated_actions.insert(action.clone(), UpdatedValue::Button(final_state));
+        }
+
+        for (action, _input_bindings) in self.iter_axislike() {
+            if skip(action) {
+                updated_actions.insert(action.clone(), UpdatedValue::Axis(0.0));
+                continue;
+            }
+
+            let mut final_value = 0.0;
+            for binding in _input_bindings {
+                if self.binding_considered(
+                    action,
+                    contexts,
+                    |input| matches!(input, UserInputWrapper::Axis(tagged) if tagged == binding),
+                ) {
+                    final_value += binding.value(input_store, gamepad);
+                }
+            }
+
+            for processor in self.axis_action_processors(action) {
+                final_value = processor.process(final_value);
+            }
+            final_value *= self.action_sensitivity(action);
+
+            updated_actions.insert(action.clone(), UpdatedValue::Axis(final_value));
+        }
+
+        for (action, _input_bindings) in self.iter_dual_axislike() {
+            if skip(action) {
+                updated_actions.insert(action.clone(), UpdatedValue::DualAxis(Vec2::ZERO));
+                continue;
+            }
+
+            let mut final_value = Vec2::ZERO;
+            for binding in _input_bindings {
+                if self.binding_considered(action, contexts, |input| {
+                    matches!(input, UserInputWrapper::DualAxis(tagged) if tagged == binding)
+                }) {
+                    final_value += binding.axis_pair(input_store, gamepad);
+                }
+            }
+
+            for processor in self.dual_axis_action_processors(action) {
+                final_value = processor.process(final_value);
+            }
+            final_value *= self.action_sensitivity(action);
+
+            updated_actions.insert(action.clone(), UpdatedValue::DualAxis(final_value));
+        }
+
+        for (action, _input_bindings) in self.iter_triple_axislike() {
+            if skip(action) {
+                updated_actions.insert(action.clone(), UpdatedValue::TripleAxis(Vec3::ZERO));
+                continue;
+            }
+
+            let mut final_value = Vec3::ZERO;
+            for binding in _input_bindings {
+                if self.binding_considered(action, contexts, |input| {
+                    matches!(input, UserInputWrapper::TripleAxis(tagged) if tagged == binding)
+                }) {
+                    final_value += binding.axis_triple(input_store, gamepad);
+                }
+            }
+            final_value *= self.action_sensitivity(action);
+
+            updated_actions.insert(action.clone(), UpdatedValue::TripleAxis(final_value));
+        }
+
+        // Handle clashing inputs, possibly removing some pressed actions from the list
+        self.handle_clashes_in_context(
+            &mut updated_actions,
+            input_store,
+            clash_strategy,
+            gamepad,
+            contexts,
+        );
+
+        // Actions overruled by a clash are released, rather than keeping their state from the previous frame
+        for action in self.buttonlike_actions() {
+            updated_actions
+                .entry(action.clone())
+                .or_insert(UpdatedValue::Button(false));
+        }
+
+        if self.remap.is_empty() {
+            updated_actions
+        } else {
+            self.apply_remap_to(updated_actions)
+        }
+    }
+}
+
+/// The output returned by [`InputMap::process_actions`],
+/// used by [`ActionState::update`](crate::action_state::ActionState) to update the state of each action.
+#[derive(Debug, Clone, PartialEq, Deref, DerefMut)]
+pub struct UpdatedActions<A: Actionlike>(pub HashMap<A, UpdatedValue>);
+
+impl<A: Actionlike> UpdatedActions<A> {
+    /// Returns `true` if the action is both buttonlike and pressed.
+    pub fn pressed(&self, action: &A) -> bool {
+        match self.0.get(action) {
+            Some(UpdatedValue::Button(state)) => *state,
+            _ => false,
+        }
+    }
+}
+
+/// An enum representing the updated value of an action.
+///
+/// Used in [`UpdatedActions`] to store the updated state of each action.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UpdatedValue {
+    /// A buttonlike action that was pressed or released.
+    Button(bool),
+    /// An axislike action that was updated.
+    Axis(f32),
+    /// A dual-axislike action that was updated.
+    DualAxis(Vec2),
+    /// A triple-axislike action that was updated.
+    TripleAxis(Vec3),
+}
+
+impl UpdatedValue {
+    /// Returns the value of the same kind that represents no input:
+    /// released for buttonlike actions, and zero for axislike actions.
+    fn neutral(&self) -> Self {
+        match self {
+            Self::Button(_) => Self::Button(false),
+            Self::Axis(_) => Self::Axis(0.0),
+            Self::DualAxis(_) => Self::DualAxis(Vec2::ZERO),
+            Self::TripleAxis(_) => Self::TripleAxis(Vec3::ZERO),
+        }
+    }
+}
+
+impl<A: Actionlike> Default for UpdatedActions<A> {
+    fn default() -> Self {
+        Self(HashMap::default())
+    }
+}
+
+// Utilities
+impl<A: Actionlike> InputMap<A> {
+    /// Returns an iterator over all registered [`Buttonlike`] actions with their input bindings.
+    pub fn iter_buttonlike(&self) -> impl Iterator<Item = (&A, &Vec<Box<dyn Buttonlike>>)> {
+        self.buttonlike_map.iter()
+    }
+
+    /// Returns an iterator over all registered [`Axislike`] actions with their input bindings.
+    pub fn iter_axislike(&self) -> impl Iterator<Item = (&A, &Vec<Box<dyn Axislike>>)> {
+        self.axislike_map.iter()
+    }
+
+    /// Returns an iterator over all registered [`DualAxislike`] actions with their input bindings.
+    pub fn iter_dual_axislike(&self) -> impl Iterator<Item = (&A, &Vec<Box<dyn DualAxislike>>)> {
+        self.dual_axislike_map.iter()
+    }
+
+    /// Returns an iterator over all registered [`TripleAxislike`] actions with their input bindings.
+    pub fn iter_triple_axislike(
+        &self,
+    ) -> impl Iterator<Item = (&A, &Vec<Box<dyn TripleAxislike>>)> {
+        self.triple_axislike_map.iter()
+    }
+
+    /// Returns an iterator over all actions with at least one input binding, regardless of their [`InputControlKind`].
+    ///
+    /// Each action is returned exactly once.
+    /// The order is unspecified, but stays the same across calls as long as the map is not modified.
+    ///
+    /// This is the counterpart to [`ActionState::keys`](crate::action_state::ActionState::keys).
+    pub fn actions(&self) -> impl Iterator<Item = &A> {
+        fn bound<A, T>(map: &HashMap<A, Vec<T>>) -> impl Iterator<Item = &A> {
+            map.iter()
+                .filter(|(_, inputs)| !inputs.is_empty())
+                .map(|(action, _)| action)
+        }
+
+        let mut seen = HashSet::new();
+        bound(&self.buttonlike_map)
+            .chain(bound(&self.axislike_map))
+            .chain(bound(&self.dual_axislike_map))
+            .chain(bound(&self.triple_axislike_map))
+            .filter(move |action| seen.insert(*action))
+    }
+
+    /// Returns an iterator over all registered [`Buttonlike`] action-input bindings.
+    pub fn buttonlike_bindings(&self) -> impl Iterator<Item = (&A, &dyn Buttonlike)> {
+        self.buttonlike_map
+            .iter()
+            .flat_map(|(action, inputs)| inputs.iter().map(move |input| (action, input.as_ref())))
+    }
+
+    /// Returns an iterator over all registered [`Axislike`] action-input bindings.
+    pub fn axislike_bindings(&self) -> impl Iterator<Item = (&A, &dyn Axislike)> {
+        self.axislike_map
+            .iter()
+            .flat_map(|(action, inputs)| inputs.iter().map(move |input| (action, input.as_ref())))
+    }
+
+    /// Returns an iterator over all registered [`DualAxislike`] action-input bindings.
+    pub fn dual_axislike_bindings(&self) -> impl Iterator<Item = (&A, &dyn DualAxislike)> {
+        self.dual_axislike_map
+            .iter()
+            .flat_map(|(action, inputs)| inputs.iter().map(move |input| (action, input.as_ref())))
+    }
+
+    /// Returns an iterator over all registered [`TripleAxislike`] action-input bindings.
+    pub fn triple_axislike_bindings(&self) -> impl Iterator<Item = (&A, &dyn TripleAxislike)> {
+        self.triple_axislike_map
+            .iter()
+            .flat_map(|(action, inputs)| inputs.iter().map(move |input| (action, input.as_ref())))
+    }
+
+    /// Returns an iterator over the [`Buttonlike`] inputs bound to the given `action`, in the order they were inserted.
+    ///
+    /// The iterator is empty if the `action` has no [`Buttonlike`] bindings.
+    pub fn bindings_for(&self, action: &A) -> impl Iterator<Item = &dyn Buttonlike> {
+        self.buttonlike_map
+            .get(action)
+            .into_iter()
+            .flatten()
+            .map(|input| input.as_ref())
+    }
+
+    /// Returns an iterator over the [`Axislike`] inputs bound to the given `action`, in the order they were inserted.
+    ///
+    /// The iterator is empty if the `action` has no [`Axislike`] bindings.
+    pub fn axislike_bindings_for(&self, action: &A) -> impl Iterator<Item = &dyn Axislike> {
+        self.axislike_map
+            .get(action)
+            .into_iter()
+            .flatten()
+            .map(|input| input.as_ref())
+    }
+
+    /// Returns an iterator over the [`DualAxislike`] inputs bound to the given `action`, in the order they were inserted.
+    ///
+    /// The iterator is empty if the `action` has no [`DualAxislike`] bindings.
+    pub fn dual_axislike_bindings_for(
+        &self,
+        action: &A,
+    ) -> impl Iterator<Item = &dyn DualAxislike> {
+        self.dual_axislike_map
+            .get(action)
+            .into_iter()
+            .flatten()
+            .map(|input| input.as_ref())
+    }
+
+    /// Returns an iterator over the [`TripleAxislike`] inputs bound to the given `action`, in the order they were inserted.
+    ///
+    /// The iterator is empty if the `action` has no [`TripleAxislike`] bindings.
+    pub fn triple_axislike_bindings_for(
+        &self,
+        action: &A,
+    ) -> impl Iterator<Item = &dyn TripleAxislike> {
+        self.triple_axislike_map
+            .get(action)
+            .into_iter()
+            .flatten()
+            .map(|input| input.as_ref())
+    }
+
+    /// Returns an iterator over all registered [`Buttonlike`] actions.
+    pub fn buttonlike_actions(&self) -> impl Iterator<Item = &A> {
+        self.buttonlike_map.keys()
+    }
+
+    /// Returns an iterator over all registered [`Axislike`] actions.
+    pub fn axislike_actions(&self) -> impl Iterator<Item = &A> {
+        self.axislike_map.keys()
+    }
+
+    /// Returns an iterator over all registered [`DualAxislike`] actions.
+    pub fn dual_axislike_actions(&self) -> impl Iterator<Item = &A> {
+        self.dual_axislike_map.keys()
+    }
+
+    /// Returns an iterator over all registered [`TripleAxislike`] actions.
+    pub fn triple_axislike_actions(&self) -> impl Iterator<Item = &A> {
+        self.triple_axislike_map.keys()
+    }
+
+    /// Returns the given `actions` that have no bindings in this map.
+    ///
+    /// Pass every action of your [`Actionlike`] type, such as its [variants](Actionlike::variants),
+    /// to catch forgotten bindings, for example in a test run by your CI.
+    #[must_use]
+    pub fn missing_actions(&self, actions: impl IntoIterator<Item = A>) -> Vec<A> {
+        actions
+            .into_iter()
+            .filter(|action| !self.is_bound(action))
+            .collect()
+    }
+
+    /// Asserts that each of the given `actions` has at least one binding in this map.
+    ///
+    /// Like [`debug_assert!`], this check is only performed in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug builds if any of the `actions` has no bindings,
+    /// listing all of the [missing actions](InputMap::missing_actions).
+    #[track_caller]
+    pub fn assert_complete(&self, actions: impl IntoIterator<Item = A>) {
+        if cfg!(debug_assertions) {
+            let missing_actions = self.missing_actions(actions);
+            assert!(
+                missing_actions.is_empty(),
+                "The following actions have no bindings: {missing_actions:?}"
+            );
+        }
+    }
+
+    /// Checks if the `action` has at least one binding.
+    #[must_use]
+    fn is_bound(&self, action: &A) -> bool {
+        match action.input_control_kind() {
+            InputControlKind::Button => self
+                .buttonlike_map
+                .get(action)
+                .is_some_and(|inputs| !inputs.is_empty()),
+            InputControlKind::Axis => self
+                .axislike_map
+                .get(action)
+                .is_some_and(|inputs| !inputs.is_empty()),
+            InputControlKind::DualAxis => self
+                .dual_axislike_map
+                .get(action)
+                .is_some_and(|inputs| !inputs.is_empty()),
+            InputControlKind::TripleAxis => self
+                .triple_axislike_map
+                .get(action)
+                .is_some_and(|inputs| !inputs.is_empty()),
+        }
+    }
+
+    /// Generates a textual cheat-sheet of this map, suitable for a "controls" screen or manual.
+    ///
+    /// Each entry pairs the name of an action, produced by its [`Debug`] implementation,
+    /// with the [`UserInput::display_name`] of each of its bindings, such as `"Left Shift"`.
+    /// Entries are sorted by action name, while bindings are listed in insertion order.
+    #[must_use]
+    pub fn cheat_sheet(&self) -> Vec<(String, Vec<String>)> {
+        fn describe<A: Debug, T: UserInput + ?Sized>(
+            map: &HashMap<A, Vec<Box<T>>>,
+        ) -> impl Iterator<Item = (String, Vec<String>)> + '_ {
+            map.iter().map(|(action, inputs)| {
+                let bindings = inputs.iter().map(|input| input.display_name()).collect();
+                (format!("{action:?}"), bindings)
+            })
+        }
+
+        describe(&self.buttonlike_map)
+            .chain(describe(&self.axislike_map))
+            .chain(describe(&self.dual_axislike_map))
+            .chain(describe(&self.triple_axislike_map))
+            .sorted_by(|(a, _), (b, _)| a.cmp(b))
+            .collect()
+    }
+
+    /// Generates a textual cheat-sheet of this map like [`InputMap::cheat_sheet`],
+    /// pairing each binding with its [`BindingSource`].
+    ///
+    /// This is useful for highlighting the bindings customized by the user on a "controls" screen.
+    #[must_use]
+    pub fn cheat_sheet_with_sources(&self) -> Vec<(String, Vec<(String, BindingSource)>)> {
+        fn describe<'a, A: Actionlike, T: UserInput + ?Sized>(
+            input_map: &'a InputMap<A>,
+            map: &'a HashMap<A, Vec<Box<T>>>,
+            wrap: impl Fn(&Box<T>) -> UserInputWrapper + 'a,
+        ) -> impl Iterator<Item = (String, Vec<(String, BindingSource)>)> + 'a {
+            map.iter().map(move |(action, inputs)| {
+                let bindings = inputs
+                    .iter()
+                    .map(|input| {
+                        let source = input_map.binding_source(action, &wrap(input));
+                        (input.display_name(), source)
+                    })
+                    .collect();
+                (format!("{action:?}"), bindings)
+            })
+        }
+
+        describe(self, &self.buttonlike_map, |input| {
+            UserInputWrapper::Button(input.clone())
+        })
+        .chain(describe(self, &self.axislike_map, |input| {
+            UserInputWrapper::Axis(input.clone())
+        }))
+        .chain(describe(self, &self.dual_axislike_map, |input| {
+            UserInputWrapper::DualAxis(input.clone())
+        }))
+        .chain(describe(self, &self.triple_axislike_map, |input| {
+            UserInputWrapper::TripleAxis(input.clone())
+        }))
+        .sorted_by(|(a, _), (b, _)| a.cmp(b))
+        .collect()
+    }
+
+    /// Returns a reference to the [`UserInput`](crate::user_input::UserInput) inputs associated with the given `action`.
+    ///
+    /// # Warning
+    ///
+    /// Unlike the other `get` methods, this method is forced to clone the inputs
+    /// due to the lack of [trait upcasting coercion](https://github.com/rust-lang/rust/issues/65991).
+    ///
+    /// As a result, no equivalent `get_mut` method is provided.
+    #[must_use]
+    pub fn get(&self, action: &A) -> Option<Vec<UserInputWrapper>> {
+        match action.input_control_kind() {
+            InputControlKind::Button => {
+                let buttonlike = self.buttonlike_map.get(action)?;
+                Some(
+                    buttonlike
+                        .iter()
+                        .map(|input| UserInputWrapper::Button(input.clone()))
+                        .collect(),
+                )
+            }
+            InputControlKind::Axis => {
+                let axislike = self.axislike_map.get(action)?;
+                Some(
+                    axislike
+                        .iter()
+                        .map(|input| UserInputWrapper::Axis(input.clone()))
+                        .collect(),
+                )
+            }
+            InputControlKind::DualAxis => {
+                let dual_axislike = self.dual_axislike_map.get(action)?;
+                Some(
+                    dual_axislike
+                        .iter()
+                        .map(|input| UserInputWrapper::DualAxis(input.clone()))
+                        .collect(),
+                )
+            }
+            InputControlKind::TripleAxis => {
+                let triple_axislike = self.triple_axislike_map.get(action)?;
+                Some(
+                    triple_axislike
+                        .iter()
+                        .map(|input| UserInputWrapper::TripleAxis(input.clone()))
+                        .collect(),
+                )
+            }
+        }
+    }
+
+    /// Returns a reference to the [`Buttonlike`] inputs associated with the given `action`.
+    #[must_use]
+    pub fn get_buttonlike(&self, action: &A) -> Option<&Vec<Box<dyn Buttonlike>>> {
+        self.buttonlike_map.get(action)
+    }
+
+    /// Returns a mutable reference to the [`Buttonlike`] inputs mapped to `action`
+    #[must_use]
+    pub fn get_buttonlike_mut(&mut self, action: &A) -> Option<&mut Vec<Box<dyn Buttonlike>>> {
+        self.buttonlike_map.get_mut(action)
+    }
+
+    /// Returns a reference to the [`Axislike`] inputs associated with the given `action`.
+    #[must_use]
+    pub fn get_axislike(&self, action: &A) -> Option<&Vec<Box<dyn Axislike>>> {
+        self.axislike_map.get(action)
+    }
+
+    /// Returns a mutable reference to the [`Axislike`] inputs mapped to `action`
+    #[must_use]
+    pub fn get_axislike_mut(&mut self, action: &A) -> Option<&mut Vec<Box<dyn Axislike>>> {
+        self.axislike_map.get_mut(action)
+    }
+
+    /// Returns a reference to the [`DualAxislike`] inputs associated with the given `action`.
+    #[must_use]
+    pub fn get_dual_axislike(&self, action: &A) -> Option<&Vec<Box<dyn DualAxislike>>> {
+        self.dual_axislike_map.get(action)
+    }
+
+    /// Returns a mutable reference to the [`DualAxislike`] inputs mapped to `action`
+    #[must_use]
+    pub fn get_dual_axislike_mut(&mut self, action: &A) -> Option<&mut Vec<Box<dyn DualAxislike>>> {
+        self.dual_axislike_map.get_mut(action)
+    }
+
+    /// Returns a reference to the [`TripleAxislike`] inputs associated with the given `action`.
+    #[must_use]
+    pub fn get_triple_axislike(&self, action: &A) -> Option<&Vec<Box<dyn TripleAxislike>>> {
+        self.triple_axislike_map.get(action)
+    }
+
+    /// Returns a mutable reference to the [`TripleAxislike`] inputs mapped to `action`
+    #[must_use]
+    pub fn get_triple_axislike_mut(
+        &mut self,
+        action: &A,
+    ) -> Option<&mut Vec<Box<dyn TripleAxislike>>> {
+        self.triple_axislike_map.get_mut(action)
+    }
+
+    /// Count the total number of registered input bindings.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.buttonlike_map.values().map(Vec::len).sum::<usize>()
+            + self.axislike_map.values().map(Vec::len).sum::<usize>()
+            + self.dual_axislike_map.values().map(Vec::len).sum::<usize>()
+            + self
+                .triple_axislike_map
+                .values()
+                .map(Vec::len)
+                .sum::<usize>()
+    }
+
+    /// Returns `true` if the map contains no action-input bindings.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Clears the map, removing all action-input bindings.
+    pub fn clear(&mut self) {
+        self.buttonlike_map.clear();
+        self.axislike_map.clear();
+        self.dual_axislike_map.clear();
+        self.triple_axislike_map.clear();
+        self.binding_sources.clear();
+        self.binding_contexts.clear();
+        self.disabled_bindings.clear();
+    }
+}
+
+// Removing
+impl<A: Actionlike> InputMap<A> {
+    /// Clears all input bindings associated with the `action`.
+    ///
+    /// Returns `true` if the `action` had any bindings.
+    pub fn clear_action(&mut self, action: &A) -> bool {
+        let had_bindings = match action.input_control_kind() {
+            InputControlKind::Button => self
+                .buttonlike_map
+                .remove(action)
+                .is_some_and(|bindings| !bindings.is_empty()),
+            InputControlKind::Axis => self
+                .axislike_map
+                .remove(action)
+                .is_some_and(|bindings| !bindings.is_empty()),
+            InputControlKind::DualAxis => self
+                .dual_axislike_map
+                .remove(action)
+                .is_some_and(|bindings| !bindings.is_empty()),
+            InputControlKind::TripleAxis => self
+                .triple_axislike_map
+                .remove(action)
+                .is_some_and(|bindings| !bindings.is_empty()),
+        };
+        self.binding_sources.remove(action);
+        self.binding_contexts.remove(action);
+        self.disabled_bindings.remove(action);
+        had_bindings
+    }
+
+    /// Removes the input for the `action` at the provided index.
+    ///
+    /// Returns `Some(())` if the input was found and removed, or `None` if no matching input was found.
+    ///
+    /// # Note
+    ///
+    /// The original input cannot be returned, as the trait object may differ based on the [`InputControlKind`].
+    pub fn remove_at(&mut self, action: &A, index: usize) -> Option<()> {
+        let removed = self.remove_binding_at(action, index);
+        self.prune_binding_tags();
+        removed
+    }
+
+    /// Removes the input for the `action` at the provided index, without updating the binding sources.
+    fn remove_binding_at(&mut self, action: &A, index: usize) -> Option<()> {
+        match action.input_control_kind() {
+            InputControlKind::Button => {
+                let input_bindings = self.buttonlike_map.get_mut(action)?;
+                if input_bindings.len() > index {
+                    input_bindings.remove(index);
+                    Some(())
+                } else {
+                    None
+                }
+            }
+            InputControlKind::Axis => {
+                let input_bindings = self.axislike_map.get_mut(action)?;
+                if input_bindings.len() > index {
+                    input_bindings.remove(index);
+                    Some(())
+                } else {
+                    None
+                }
+            }
+            InputControlKind::DualAxis => {
+                let input_bindings = self.dual_axislike_map.get_mut(action)?;
+                if input_bindings.len() > index {
+                    input_bindings.remove(index);
+                    Some(())
+                } else {
+                    None
+                }
+            }
+            InputControlKind::TripleAxis => {
+                let input_bindings = self.triple_axislike_map.get_mut(action)?;
+                if input_bindings.len() > index {
+                    input_bindings.remove(index);
+                    Some(())
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    /// Removes the input for the `action` if it exists
+    ///
+    /// Returns [`Some`] with index if the input was found, or [`None`] if no matching input was found.
+    pub fn remove(&mut self, action: &A, input: impl Buttonlike) -> Option<usize> {
+        self.remove_buttonlike(action, &input)
+    }
+
+    /// Removes the binding of the buttonlike `input` to the `action`, leaving its other bindings in place.
+    ///
+    /// Unlike [`InputMap::remove`], this accepts a trait object,
+    /// such as an input returned by [`InputMap::bindings_for`], which is useful for "unbind" buttons in rebinding menus.
+    ///
+    /// Returns `true` if the `input` was bound to the `action`.
+    pub fn remove_input(&mut self, action: &A, input: &dyn Buttonlike) -> bool {
+        self.remove_buttonlike(action, input).is_some()
+    }
+
+    /// Removes the buttonlike `input` from the bindings of the `action`, returning its index if it was found.
+    fn remove_buttonlike(&mut self, action: &A, input: &dyn Buttonlike) -> Option<usize> {
+        let bindings = self.buttonlike_map.get_mut(action)?;
+        let index = bindings
+            .iter()
+            .position(|binding| binding.as_ref() == input)?;
+        bindings.remove(index);
+        self.prune_binding_tags();
+        Some(index)
+    }
+
+    /// Removes the buttonlike `input` from every action it is bound to,
+    /// returning the actions that lost a binding.
+    ///
+    /// This is useful when rebinding, to prevent the same input from being bound to several actions.
+    /// Actions left without any bindings are kept in the map, without any inputs.
+    pub fn clear_input<I: Buttonlike>(&mut self, input: &I) -> Vec<A> {
+        let input: Box<dyn Buttonlike> = Box::new(dyn_clone::clone(input));
+        let actions = clear_input_from(&mut self.buttonlike_map, &input);
+        self.prune_binding_tags();
+        actions
+    }
+
+    /// Removes the axislike `input` from every action it is bound to,
+    /// returning the actions that lost a binding.
+    ///
+    /// Actions left without any bindings are kept in the map, without any inputs.
+    pub fn clear_axis_input<I: Axislike>(&mut self, input: &I) -> Vec<A> {
+        let input: Box<dyn Axislike> = Box::new(dyn_clone::clone(input));
+        let actions = clear_input_from(&mut self.axislike_map, &input);
+        self.prune_binding_tags();
+        actions
+    }
+
+    /// Removes the dual-axislike `input` from every action it is bound to,
+    /// returning the actions that lost a binding.
+    ///
+    /// Actions left without any bindings are kept in the map, without any inputs.
+    pub fn clear_dual_axis_input<I: DualAxislike>(&mut self, input: &I) -> Vec<A> {
+        let input: Box<dyn DualAxislike> = Box::new(dyn_clone::clone(input));
+        let actions = clear_input_from(&mut self.dual_axislike_map, &input);
+        self.prune_binding_tags();
+        actions
+    }
+
+    /// Removes the triple-axislike `input` from every action it is bound to,
+    /// returning the actions that lost a binding.
+    ///
+    /// Actions left without any bindings are kept in the map, without any inputs.
+    pub fn clear_triple_axis_input<I: TripleAxislike>(&mut self, input: &I) -> Vec<A> {
+        let input: Box<dyn TripleAxislike> = Box::new(dyn_clone::clone(input));
+        let actions = clear_input_from(&mut self.triple_axislike_map, &input);
+        self.prune_binding_tags();
+        actions
+    }
+
+    /// Removes every binding that can only be triggered using a keyboard or a mouse,
+    /// such as [`KeyCode`](bevy::prelude::KeyCode)s, [`VirtualDPad`](crate::user_input::VirtualDPad)s made of keys, or [`MouseMove`](crate::user_input::MouseMove).
+    ///
+    /// Bindings that can be triggered by a gamepad are kept, even if they also involve a keyboard or a mouse.
+    /// Actions left without any bindings are kept in the map, without any inputs.
+    pub fn clear_keyboard_and_mouse(&mut self) -> &mut Self {
+        fn retain_others<A, T: UserInput + ?Sized>(map: &mut HashMap<A, Vec<Box<T>>>) {
+            for bindings in map.values_mut() {
+                bindings.retain(|binding| !is_keyboard_or_mouse_input(binding.decompose()));
+            }
+        }
+
+        retain_others(&mut self.buttonlike_map);
+        retain_others(&mut self.axislike_map);
+        retain_others(&mut self.dual_axislike_map);
+        retain_others(&mut self.triple_axislike_map);
+        self.prune_binding_tags();
+        self
+    }
+}
+
+/// Checks if an input made of the `basic_inputs` can only be triggered using a keyboard or a mouse.
+///
+/// Inputs that don't decompose into any [`Buttonlike`]s are assumed to be read from other devices.
+fn is_keyboard_or_mouse_input(basic_inputs: BasicInputs) -> bool {
+    fn is_keyboard_or_mouse_button(button: &dyn Buttonlike) -> bool {
+        let button = button.as_reflect();
+        #[cfg(feature = "keyboard")]
+        if button.is::<bevy::prelude::KeyCode>() || button.is::<crate::user_input::ModifierKey>() {
+            return true;
+        }
+        #[cfg(feature = "mouse")]
+        if button.is::<bevy::prelude::MouseButton>()
+            || button.is::<crate::user_input::MouseMoveDirection>()
+            || button.is::<crate::user_input::MouseScrollDirection>()
+        {
+            return true;
+        }
+        false
+    }
+
+    let buttons = basic_inputs.inputs();
+    !buttons.is_empty()
+        && buttons
+            .iter()
+            .all(|button| is_keyboard_or_mouse_button(button.as_ref()))
+}
+
+/// Removes the `input` from the bindings of every action in the `map`,
+/// returning the actions that lost a binding.
+fn clear_input_from<A: Actionlike, T: PartialEq>(
+    map: &mut HashMap<A, Vec<T>>,
+    input: &T,
+) -> Vec<A> {
+    map.iter_mut()
+        .filter_map(|(action, bindings)| {
+            let len = bindings.len();
+            bindings.retain(|binding| binding != input);
+            (bindings.len() != len).then(|| action.clone())
+        })
+        .collect()
+}
+
+// Validation
+impl<A: Actionlike> InputMap<A> {
+    /// Checks that every input is bound to an action of the same [`InputControlKind`].
+    ///
+    /// Bindings created using the insertion methods are always consistent,
+    /// but user-edited files (such as RON keybinding files) may bind inputs to actions of a different kind,
+    /// for example by moving a buttonlike action into the `dual_axislike_map`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InputMapError::MismatchedControlKinds`] listing every inconsistent binding.
+    pub fn validate(&self) -> Result<(), InputMapError<A>> {
+        fn mismatches<'a, A: Actionlike, T: ?Sized + 'a>(
+            map: &'a HashMap<A, Vec<Box<T>>>,
+            input_kind: InputControlKind,
+            wrap: impl Fn(&Box<T>) -> UserInputWrapper + Copy + 'a,
+        ) -> impl Iterator<Item = ControlKindMismatch<A>> + 'a {
+            map.iter()
+                .filter(move |(action, _)| action.input_control_kind() != input_kind)
+                .flat_map(move |(action, inputs)| {
+                    inputs.iter().map(move |input| ControlKindMismatch {
+                        action: action.clone(),
+                        action_kind: action.input_control_kind(),
+                        input: wrap(input),
+                        input_kind,
+                    })
+                })
+        }
+
+        let mismatches: Vec<_> =
+            mismatches(&self.buttonlike_map, InputControlKind::Button, |input| {
+                UserInputWrapper::Button(input.clone())
+            })
+            .chain(mismatches(
+                &self.axislike_map,
+                InputControlKind::Axis,
+                |input| UserInputWrapper::Axis(input.clone()),
+            ))
+            .chain(mismatches(
+                &self.dual_axislike_map,
+                InputControlKind::DualAxis,
+                |input| UserInputWrapper::DualAxis(input.clone()),
+            ))
+            .chain(mismatches(
+                &self.triple_axislike_map,
+                InputControlKind::TripleAxis,
+                |input| UserInputWrapper::TripleAxis(input.clone()),
+            ))
+            .collect();
+
+        if mismatches.is_empty() {
+            Ok(())
+        } else {
+            Err(InputMapError::MismatchedControlKinds(mismatches))
+        }
+    }
+
+    /// Deserializes an [`InputMap`] using the provided `deserializer`,
+    /// then [validates](InputMap::validate) it.
+    ///
+    /// Use this when loading user-edited keybinding files,
+    /// so that inconsistent bindings are reported when loading rather than causing problems later.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InputMapError::Deserialization`] if the map could not be deserialized,
+    /// or [`InputMapError::MismatchedControlKinds`] if it is not valid.
+    pub fn load_validated<'de, D>(deserializer: D) -> Result<Self, InputMapError<A>>
+    where
+        A: Deserialize<'de>,
+        D: serde::Deserializer<'de>,
+    {
+        let input_map = Self::deserialize(deserializer)
+            .map_err(|error| InputMapError::Deserialization(error.to_string()))?;
+        input_map.validate()?;
+        Ok(input_map)
+    }
+}
+
+/// An error encountered while loading or [validating](InputMap::validate) an [`InputMap`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputMapError<A: Actionlike> {
+    /// The [`InputMap`] could not be deserialized.
+    Deserialization(String),
+
+    /// Some inputs are bound to actions of a different [`InputControlKind`].
+    ///
+    /// The mismatched bindings are listed in no particular order.
+    MismatchedControlKinds(Vec<ControlKindMismatch<A>>),
+
+    /// The serialized map was saved in a version newer than any known by [`InputMap::deserialize_with_migration`].
+    UnsupportedVersion(u32),
+}
+
+impl<A: Actionlike> std::fmt::Display for InputMapError<A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Deserialization(error) => {
+                write!(f, "failed to deserialize the InputMap: {error}")
+            }
+            Self::MismatchedControlKinds(mismatches) => {
+                write!(
+                    f,
+                    "the InputMap contains inputs bound to actions of a different kind:"
+                )?;
+                for mismatch in mismatches {
+                    write!(
+                        f,
+                        " {:?} input {:?} is bound to {:?} action {:?};",
+                        mismatch.input_kind, mismatch.input, mismatch.action_kind, mismatch.action
+                    )?;
+                }
+                Ok(())
+            }
+            Self::UnsupportedVersion(version) => {
+                write!(f, "the InputMap was saved in unsupported version {version}")
+            }
+        }
+    }
+}
+
+impl<A: Actionlike> std::error::Error for InputMapError<A> {}
+
+/// An input bound to an action of a different [`InputControlKind`], as reported by [`InputMap::validate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ControlKindMismatch<A: Actionlike> {
+    /// The action that the input is bound to.
+    pub action: A,
+
+    /// The kind of the `action`.
+    pub action_kind: InputControlKind,
+
+    /// The offending input.
+    pub input: UserInputWrapper,
+
+    /// The kind of the `input`.
+    pub input_kind: InputControlKind,
+}
+
+// Migration
+/// The serialized form of an [`InputMap`], tagged with the version of the format that it was saved in.
+///
+/// `V` is the representation of the map before it is deserialized, such as a RON string.
+/// When the shape of the map changes, for example because an action was renamed,
+/// increment the version used to save new maps and add an [`InputMapMigration`]
+/// passed to [`InputMap::deserialize_with_migration`] to upgrade older maps.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SerializedInputMap<V> {
+    /// The version of the format that the `input_map` was saved in.
+    pub version: u32,
+
+    /// The serialized map.
+    pub input_map: V,
+}
+
+impl<V> SerializedInputMap<V> {
+    /// Tags the serialized `input_map` with the `version` of the format that it was saved in.
+    #[inline]
+    pub const fn new(version: u32, input_map: V) -> Self {
+        Self { version, input_map }
+    }
+}
+
+/// Upgrades the serialized form of an [`InputMap`] from one version of its format to the next.
+pub type InputMapMigration<V> = fn(&mut V);
+
+impl<A: Actionlike> InputMap<A> {
+    /// Upgrades the serialized `value` to the latest version of its format using the provided `migrations`,
+    /// then deserializes it using the `deserialize` function.
+    ///
+    /// The migration at index `n` upgrades maps saved in version `n` to version `n + 1`,
+    /// so the latest version is the number of `migrations`.
+    /// By default, with no migrations, maps are saved in version `0` and deserialized as is.
+    ///
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use leafwing_input_manager::input_map::{InputMapMigration, SerializedInputMap};
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+    /// enum Action {
+    ///     Jump,
+    /// }
+    ///
+    /// // Version 1 renamed the `Leap` action to `Jump`
+    /// let migrations: [InputMapMigration<String>; 1] = [|map| *map = map.replace("Leap", "Jump")];
+    ///
+    /// let saved = SerializedInputMap::new(0, String::from("Leap"));
+    /// let input_map = InputMap::<Action>::deserialize_with_migration(saved, &migrations, |map| {
+    ///     assert_eq!(map, "Jump");
+    ///     Ok::<_, std::fmt::Error>(InputMap::default())
+    /// });
+    /// assert!(input_map.is_ok());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InputMapError::UnsupportedVersion`] if the `value` was saved in a version newer than the latest one,
+    /// or [`InputMapError::Deserialization`] if the upgraded map could not be deserialized.
+    pub fn deserialize_with_migration<V, E: std::fmt::Display>(
+        value: SerializedInputMap<V>,
+        migrations: &[InputMapMigration<V>],
+        deserialize: impl FnOnce(&V) -> Result<Self, E>,
+    ) -> Result<Self, InputMapError<A>> {
+        let SerializedInputMap {
+            version,
+            input_map: mut value,
+        } = value;
+
+        let pending = migrations
+            .get(version as usize..)
+            .ok_or(InputMapError::UnsupportedVersion(version))?;
+        for migrate in pending {
+            migrate(&mut value);
+        }
+
+        deserialize(&value).map_err(|error| InputMapError::Deserialization(error.to_string()))
+    }
+}
+
+// Diffing
+/// The differences between the bindings of two [`InputMap`]s, as returned by [`InputMap::diff`].
+///
+/// Only actions whose bindings differ are included.
+/// This can be serialized, for example to be shown by editor tooling.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InputMapDiff<A: Actionlike> {
+    /// The differences between the bindings of each action.
+    pub actions: HashMap<A, BindingDiff>,
+}
+
+impl<A: Actionlike> InputMapDiff<A> {
+    /// Returns `true` if the bindings of both maps are the same.
+    #[must_use]
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.actions.is_empty()
+    }
+}
+
+impl<A: Actionlike> Default for InputMapDiff<A> {
+    fn default() -> Self {
+        Self {
+            actions: HashMap::default(),
+        }
+    }
+}
+
+/// The differences between the bindings of a single action in two [`InputMap`]s.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BindingDiff {
+    /// The inputs that are only bound in the new map.
+    pub added: Vec<UserInputWrapper>,
+
+    /// The inputs that are only bound in the old map.
+    pub removed: Vec<UserInputWrapper>,
+
+    /// The inputs that were replaced by another input of the same type, as `(old, new)` pairs,
+    /// such as a key rebound to another key.
+    pub changed: Vec<(UserInputWrapper, UserInputWrapper)>,
+}
+
+impl BindingDiff {
+    /// Returns `true` if the bindings of the action are the same.
+    #[must_use]
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// The path of the concrete type of the `input`, read using reflection.
+fn input_type_path(input: &UserInputWrapper) -> &str {
+    match input {
+        UserInputWrapper::Button(input) => input.reflect_type_path(),
+        UserInputWrapper::Axis(input) => input.reflect_type_path(),
+        UserInputWrapper::DualAxis(input) => input.reflect_type_path(),
+        UserInputWrapper::TripleAxis(input) => input.reflect_type_path(),
+    }
+}
+
+impl<A: Actionlike> InputMap<A> {
+    /// Compares the bindings of this map to the `other` map, listing the bindings of each action
+    /// that were added, removed or changed in the `other` map.
+    ///
+    /// The order of the bindings of an action is ignored.
+    /// A removed input and an added input of the same type, such as two [`KeyCode`](bevy::prelude::KeyCode)s,
+    /// are reported as a single changed binding, comparing their types using reflection.
+    ///
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+    /// enum Action {
+    ///     Jump,
+    /// }
+    ///
+    /// let default_map = InputMap::new([(Action::Jump, KeyCode::Space)]);
+    /// let rebound_map = InputMap::new([(Action::Jump, KeyCode::KeyW)]);
+    ///
+    /// let diff = default_map.diff(&rebound_map);
+    /// assert_eq!(diff.actions[&Action::Jump].changed.len(), 1);
+    /// assert!(default_map.diff(&default_map).is_empty());
+    /// ```
+    #[must_use]
+    pub fn diff(&self, other: &InputMap<A>) -> InputMapDiff<A> {
+        let mut diff = InputMapDiff::default();
+        let actions: HashSet<&A> = self.actions().chain(other.actions()).collect();
+
+        for action in actions {
+            let old = self.get(action).unwrap_or_default();
+            let new = other.get(action).unwrap_or_default();
+
+            let mut added: Vec<UserInputWrapper> = new
+                .iter()
+                .filter(|input| !old.contains(input))
+                .cloned()
+                .collect();
+            let mut removed: Vec<UserInputWrapper> = old
+                .iter()
+                .filter(|input| !new.contains(input))
+                .cloned()
+                .collect();
+
+            // Pair up inputs of the same type that replaced each other
+            let mut changed = Vec::new();
+            removed.retain(|old_input| {
+                let type_path = input_type_path(old_input);
+                match added
+                    .iter()
+                    .position(|new_input| input_type_path(new_input) == type_path)
+                {
+                    Some(index) => {
+                        changed.push((old_input.clone(), added.remove(index)));
+                        false
+                    }
+                    None => true,
+                }
+            });
+
+            let binding_diff = BindingDiff {
+                added,
+                removed,
+                changed,
+            };
+            if !binding_diff.is_empty() {
+                diff.actions.insert(action.clone(), binding_diff);
+            }
+        }
+
+        diff
+    }
+}
+
+impl<A: Actionlike, U: Buttonlike> From<HashMap<A, Vec<U>>> for InputMap<A> {
+    /// Converts a [`HashMap`] mapping actions to multiple [`Buttonlike`]s into an [`InputMap`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use bevy::utils::HashMap;
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+    /// enum Action {
+    ///     Run,
+    ///     Jump,
+    /// }
+    ///
+    /// // Create an InputMap from a HashMap mapping actions to their key bindings.
+    /// let mut map: HashMap<Action, Vec<KeyCode>> = HashMap::default();
+    ///
+    /// // Bind the "run" action to either the left or right shift keys to trigger the action.
+    /// map.insert(
+    ///     Action::Run,
+    ///     vec![KeyCode::ShiftLeft, KeyCode::ShiftRight],
+    /// );
+    ///
+    /// let input_map = InputMap::from(map);
+    /// ```
+    fn from(raw_map: HashMap<A, Vec<U>>) -> Self {
+        let mut input_map = Self::default();
+        for (action, inputs) in raw_map.into_iter() {
+            input_map.insert_one_to_many(action, inputs);
+        }
+        input_map
+    }
+}
+
+impl<A: Actionlike> InputMap<A> {
+    /// Creates an [`InputMap`] from a typed config mapping actions to their [`InputBinding`]s.
+    ///
+    /// As [`InputBinding`] is a plain enum over the built-in inputs,
+    /// such configs can be (de)serialized as part of a game's settings without registering input types.
+    /// The bindings of each action are inserted in order, using [`InputMap::insert_binding`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use bevy::utils::HashMap;
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+    /// enum Action {
+    ///     Jump,
+    ///     #[actionlike(Axis)]
+    ///     Throttle,
+    /// }
+    ///
+    /// let mut config: HashMap<Action, Vec<InputBinding>> = HashMap::default();
+    /// config.insert(Action::Jump, vec![InputBinding::Key(KeyCode::Space)]);
+    /// config.insert(
+    ///     Action::Throttle,
+    ///     vec![InputBinding::KeyAxis {
+    ///         negative: KeyCode::KeyS,
+    ///         positive: KeyCode::KeyW,
+    ///     }],
+    /// );
+    ///
+    /// let input_map = InputMap::from_config(config);
+    /// assert_eq!(
+    ///     input_map,
+    ///     InputMap::default()
+    ///         .with(Action::Jump, KeyCode::Space)
+    ///         .with_axis(Action::Throttle, VirtualAxis::new(KeyCode::KeyS, KeyCode::KeyW))
+    /// );
+    /// ```
+    #[track_caller]
+    pub fn from_config(config: HashMap<A, Vec<InputBinding>>) -> Self {
+        let mut input_map = Self::default();
+        for (action, bindings) in config {
+            for binding in bindings {
+                input_map.insert_binding(action.clone(), binding);
+            }
+        }
+        input_map
+    }
+}
+
+impl<A: Actionlike, U: Buttonlike> FromIterator<(A, U)> for InputMap<A> {
+    fn from_iter<T: IntoIterator<Item = (A, U)>>(iter: T) -> Self {
+        let mut input_map = Self::default();
+        for (action, input) in iter.into_iter() {
+            input_map.insert(action, input);
+        }
+        input_map
+    }
+}
+
+#[cfg(feature = "keyboard")]
+mod tests {
+    use bevy::prelude::Reflect;
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+    use crate as leafwing_input_manager;
+    use crate::prelude::*;
+
+    #[derive(Actionlike, Serialize, Deserialize, Clone, PartialEq, Eq, Hash, Debug, Reflect)]
+    enum Action {
+        Run,
+        Jump,
+        Hide,
+        #[actionlike(Axis)]
+        Axis,
+        #[actionlike(DualAxis)]
+        DualAxis,
+        #[actionlike(TripleAxis)]
+        TripleAxis,
+    }
+
+    #[test]
+    fn creation() {
+        use bevy::input::keyboard::KeyCode;
+
+        let input_map = InputMap::default()
+            .with(Action::Run, KeyCode::KeyW)
+            .with(Action::Run, KeyCode::ShiftLeft)
+            // Duplicate associations should be ignored
+            .with(Action::Run, KeyCode::ShiftLeft)
+            .with_one_to_many(Action::Run, [KeyCode::KeyR, KeyCode::ShiftRight])
+            .with_multiple([
+                (Action::Jump, KeyCode::Space),
+                (Action::Hide, KeyCode::ControlLeft),
+                (Action::Hide, KeyCode::ControlRight),
+            ]);
+
+        let expected_bindings: HashMap<Box<dyn Buttonlike>, Action> = HashMap::from([
+            (Box::new(KeyCode::KeyW) as Box<dyn Buttonlike>, Action::Run),
+            (
+                Box::new(KeyCode::ShiftLeft) as Box<dyn Buttonlike>,
+                Action::Run,
+            ),
+            (Box::new(KeyCode::KeyR) as Box<dyn Buttonlike>, Action::Run),
+            (
+                Box::new(KeyCode::ShiftRight) as Box<dyn Buttonlike>,
+                Action::Run,
+            ),
+            (
+                Box::new(KeyCode::Space) as Box<dyn Buttonlike>,
+                Action::Jump,
+            ),
+            (
+                Box::new(KeyCode::ControlLeft) as Box<dyn Buttonlike>,
+                Action::Hide,
+            ),
+            (
+                Box::new(KeyCode::ControlRight) as Box<dyn Buttonlike>,
+                Action::Hide,
+            ),
+        ]);
+
+        for (action, input) in input_map.buttonlike_bindings() {
+            let expected_action = expected_bindings.get(input).unwrap();
+            assert_eq!(expected_action, action);
+        }
+    }
+
+    #[test]
+    fn insertion_idempotency() {
+        use bevy::input::keyboard::KeyCode;
+
+        let mut input_map = InputMap::default();
+        input_map.insert(Action::Run, KeyCode::Space);
+
+        let expected: Vec<Box<dyn Buttonlike>> = vec![Box::new(KeyCode::Space)];
+        assert_eq!(input_map.get_buttonlike(&Action::Run), Some(&expected));
+
+        // Duplicate insertions should not change anything
+        input_map.insert(Action::Run, KeyCode::Space);
+        assert_eq!(input_map.get_buttonlike(&Action::Run), Some(&expected));
+    }
+
+    #[test]
+    fn multiple_insertion() {
+        use bevy::input::keyboard::KeyCode;
+
+        let mut input_map = InputMap::default();
+        input_map.insert(Action::Run, KeyCode::Space);
+        input_map.insert(Action::Run, KeyCode::Enter);
+
+        let expected: Vec<Box<dyn Buttonlike>> =
+            vec![Box::new(KeyCode::Space), Box::new(KeyCode::Enter)];
+        assert_eq!(input_map.get_buttonlike(&Action::Run), Some(&expected));
+    }
+
+    #[test]
+    fn input_clearing() {
+        use bevy::input::keyboard::KeyCode;
+
+        let mut input_map = InputMap::default();
+        input_map.insert(Action::Run, KeyCode::Space);
+
+        // Clearing action
+        assert!(input_map.clear_action(&Action::Run));
+        assert_eq!(input_map, InputMap::default());
+        assert!(!input_map.clear_action(&Action::Run));
+
+        // Remove input at existing index
+        input_map.insert(Action::Run, KeyCode::Space);
+        input_map.insert(Action::Run, KeyCode::ShiftLeft);
+        assert!(input_map.remove_at(&Action::Run, 1).is_some());
+        assert!(
+            input_map.remove_at(&Action::Run, 1).is_none(),
+            "Should return None on second removal at the same index"
+        );
+        assert!(input_map.remove_at(&Action::Run, 0).is_some());
+        assert!(
+            input_map.remove_at(&Action::Run, 0).is_none(),
+            "Should return None on second removal at the same index"
+        );
+    }
+
+    #[test]
+    fn input_removal() {
+        use bevy::input::keyboard::KeyCode;
+
+        let mut input_map = InputMap::default()
+            .with(Action::Run, KeyCode::ShiftLeft)
+            .with(Action::Run, KeyCode::ShiftRight);
+
+        // Only the removed binding is affected
+        assert!(input_map.remove_input(&Action::Run, &KeyCode::ShiftLeft));
+        assert_eq!(
+            input_map.get_buttonlike(&Action::Run).unwrap(),
+            &[Box::new(KeyCode::ShiftRight) as Box<dyn Buttonlike>]
+        );
+
+        // Inputs that aren't bound to the action can't be removed
+        assert!(!input_map.remove_input(&Action::Run, &KeyCode::ShiftLeft));
+        assert!(!input_map.remove_input(&Action::Jump, &KeyCode::ShiftRight));
+
+        // Inputs can be removed using the bindings of the map itself
+        let binding = dyn_clone::clone_box(input_map.bindings_for(&Action::Run).next().unwrap());
+        assert!(input_map.remove_input(&Action::Run, binding.as_ref()));
+        assert_eq!(input_map.get_buttonlike(&Action::Run), Some(&Vec::new()));
+    }
+
+    #[test]
+    fn input_clearing_from_every_action() {
+        use bevy::input::keyboard::KeyCode;
+
+        let mut input_map = InputMap::default()
+            .with(Action::Run, KeyCode::Space)
+            .with(Action::Run, KeyCode::ShiftLeft)
+            .with(Action::Jump, KeyCode::Space)
+            .with(Action::Hide, KeyCode::ControlLeft)
+            .with_axis(Action::Axis, VirtualAxis::ad());
+
+        // The key is removed from both actions it was bound to
+        let mut cleared = input_map.clear_input(&KeyCode::Space);
+        cleared.sort_by_key(|action| format!("{action:?}"));
+        assert_eq!(cleared, [Action::Jump, Action::Run]);
+        assert_eq!(
+            input_map.get_buttonlike(&Action::Run).unwrap(),
+            &[Box::new(KeyCode::ShiftLeft) as Box<dyn Buttonlike>]
+        );
+
+        // Actions without any remaining bindings are kept
+        assert_eq!(input_map.get_buttonlike(&Action::Jump), Some(&Vec::new()));
+        assert_eq!(
+            input_map.get_buttonlike(&Action::Hide).unwrap(),
+            &[Box::new(KeyCode::ControlLeft) as Box<dyn Buttonlike>]
+        );
+
+        // Inputs that aren't bound don't affect any actions
+        assert!(input_map.clear_input(&KeyCode::Space).is_empty());
+        assert!(input_map.clear_axis_input(&VirtualAxis::ws()).is_empty());
+
+        assert_eq!(
+            input_map.clear_axis_input(&VirtualAxis::ad()),
+            [Action::Axis]
+        );
+        assert_eq!(input_map.get_axislike(&Action::Axis), Some(&Vec::new()));
+    }
+
+    #[test]
+    fn missing_actions() {
+        use bevy::input::keyboard::KeyCode;
+
+        assert_eq!(
+            Action::variants().collect::<Vec<_>>(),
+            [
+                Action::Run,
+                Action::Jump,
+                Action::Hide,
+                Action::Axis,
+                Action::DualAxis,
+                Action::TripleAxis,
+            ]
+        );
+
+        // Hide has been forgotten
+        let mut input_map = InputMap::default()
+            .with(Action::Run, KeyCode::ShiftLeft)
+            .with(Action::Jump, KeyCode::Space)
+            .with_axis(Action::Axis, VirtualAxis::ad())
+            .with_dual_axis(Action::DualAxis, VirtualDPad::wasd())
+            .with_triple_axis(
+                Action::TripleAxis,
+                VirtualDPad3D::new(
+                    KeyCode::KeyW,
+                    KeyCode::KeyS,
+                    KeyCode::KeyA,
+                    KeyCode::KeyD,
+                    KeyCode::KeyF,
+                    KeyCode::KeyB,
+                ),
+            );
+        assert_eq!(
+            input_map.missing_actions(Action::variants()),
+            vec![Action::Hide]
+        );
+
+        // Actions whose bindings were all removed are missing too
+        input_map.remove_at(&Action::Jump, 0);
+        assert_eq!(
+            input_map.missing_actions(Action::variants()),
+            vec![Action::Jump, Action::Hide]
+        );
+
+        input_map.insert(Action::Jump, KeyCode::Space);
+        input_map.insert(Action::Hide, KeyCode::ControlLeft);
+        assert!(input_map.missing_actions(Action::variants()).is_empty());
+        input_map.assert_complete(Action::variants());
+
+        // Only the given actions are checked
+        let input_map = InputMap::default().with(Action::Run, KeyCode::ShiftLeft);
+        assert!(input_map.missing_actions([Action::Run]).is_empty());
+        assert_eq!(
+            input_map.missing_actions([Action::Run, Action::Axis]),
+            vec![Action::Axis]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(debug_assertions)]
+    fn assert_complete_panics_on_missing_actions() {
+        use bevy::input::keyboard::KeyCode;
+
+        let input_map = InputMap::default().with(Action::Run, KeyCode::ShiftLeft);
+        input_map.assert_complete(Action::variants());
+    }
+
+    #[test]
+    fn validation() {
+        use bevy::input::keyboard::KeyCode;
+
+        let mut input_map = InputMap::default()
+            .with(Action::Run, KeyCode::ShiftLeft)
+            .with_dual_axis(Action::DualAxis, VirtualDPad::wasd());
+        assert_eq!(input_map.validate(), Ok(()));
+
+        // Bind inputs to actions of the wrong kind, as a user-edited file might
+        input_map
+            .dual_axislike_map
+            .insert(Action::Jump, vec![Box::new(VirtualDPad::arrow_keys())]);
+        input_map
+            .buttonlike_map
+            .insert(Action::Axis, vec![Box::new(KeyCode::KeyQ)]);
+
+        let Err(InputMapError::MismatchedControlKinds(mismatches)) = input_map.validate() else {
+            panic!("Expected mismatched control kinds");
+        };
+        assert_eq!(mismatches.len(), 2);
+        assert!(mismatches.contains(&ControlKindMismatch {
+            action: Action::Jump,
+            action_kind: InputControlKind::Button,
+            input: UserInputWrapper::DualAxis(Box::new(VirtualDPad::arrow_keys())),
+            input_kind: InputControlKind::DualAxis,
+        }));
+        assert!(mismatches.contains(&ControlKindMismatch {
+            action: Action::Axis,
+            action_kind: InputControlKind::Axis,
+            input: UserInputWrapper::Button(Box::new(KeyCode::KeyQ)),
+            input_kind: InputControlKind::Button,
+        }));
+    }
+
+    #[cfg(feature = "asset")]
+    #[test]
+    fn load_validated() {
+        use bevy::asset::ron;
+        use bevy::prelude::{App, KeyCode};
+
+        let mut app = App::new();
+
+        // Add the plugin to register input deserializers
+        app.add_plugins(InputManagerPlugin::<Action>::default());
+
+        let input_map = InputMap::new([(Action::Run, KeyCode::ShiftLeft)])
+            .with_dual_axis(Action::DualAxis, VirtualDPad::wasd());
+        let serialized = ron::to_string(&input_map).unwrap();
+        let mut deserializer = ron::Deserializer::from_str(&serialized).unwrap();
+        assert_eq!(
+            InputMap::<Action>::load_validated(&mut deserializer),
+            Ok(input_map)
+        );
+
+        // A user-edited file accidentally binding a dual-axis input to a buttonlike action
+        let edited = serialized.replace("DualAxis:[", "Hide:[");
+        assert_ne!(edited, serialized);
+        let mut deserializer = ron::Deserializer::from_str(&edited).unwrap();
+        let Err(InputMapError::MismatchedControlKinds(mismatches)) =
+            InputMap::<Action>::load_validated(&mut deserializer)
+        else {
+            panic!("Expected mismatched control kinds");
+        };
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].action, Action::Hide);
+        assert_eq!(mismatches[0].action_kind, InputControlKind::Button);
+        assert_eq!(mismatches[0].input_kind, InputControlKind::DualAxis);
+
+        // Malformed files are reported as deserialization errors
+        let mut deserializer = ron::Deserializer::from_str("(buttonlike_map: 5)").unwrap();
+        assert!(matches!(
+            InputMap::<Action>::load_validated(&mut deserializer),
+            Err(InputMapError::Deserialization(_))
+        ));
+    }
+
+    #[cfg(feature = "asset")]
+    #[test]
+    fn deserialize_with_migration() {
+        use bevy::asset::ron;
+        use bevy::prelude::{App, KeyCode};
+
+        let mut app = App::new();
+
+        // Add the plugin to register input deserializers
+        app.add_plugins(InputManagerPlugin::<Action>::default());
+
+        let input_map = InputMap::new([(Action::Jump, KeyCode::Space)]);
+        let serialized = ron::to_string(&input_map).unwrap();
+
+        // Version 0 called the `Jump` action `Leap`, renamed in version 1
+        let legacy = serialized.replace("Jump:", "Leap:");
+        assert_ne!(legacy, serialized);
+        let migrations: [InputMapMigration<String>; 1] =
+            [|map| *map = map.replace("Leap:", "Jump:")];
+        let deserialize = |map: &String| ron::from_str::<InputMap<Action>>(map);
+
+        assert_eq!(
+            InputMap::deserialize_with_migration(
+                SerializedInputMap::new(0, legacy.clone()),
+                &migrations,
+                deserialize,
+            ),
+            Ok(input_map.clone())
+        );
+
+        // Maps saved in the latest version aren't migrated
+        assert_eq!(
+            InputMap::deserialize_with_migration(
+                SerializedInputMap::new(1, serialized.clone()),
+                &migrations,
+                deserialize,
+            ),
+            Ok(input_map)
+        );
+
+        // Without the migration, the legacy map can't be deserialized
+        assert!(matches!(
+            InputMap::deserialize_with_migration(
+                SerializedInputMap::new(0, legacy),
+                &[],
+                deserialize,
+            ),
+            Err(InputMapError::Deserialization(_))
+        ));
+
+        // Maps from the future are rejected
+        assert_eq!(
+            InputMap::deserialize_with_migration(
+                SerializedInputMap::new(2, serialized),
+                &migrations,
+                deserialize,
+            ),
+            Err(InputMapError::UnsupportedVersion(2))
+        );
+    }
+
+    #[cfg(feature = "asset")]
+    #[test]
+    fn binding_sources() {
+        use bevy::asset::ron;
+        use bevy::prelude::{App, KeyCode};
+
+        let mut app = App::new();
+
+        // Add the plugin to register input deserializers
+        app.add_plugins(InputManagerPlugin::<Action>::default());
+
+        let space = UserInputWrapper::Button(Box::new(KeyCode::Space));
+        let key_j = UserInputWrapper::Button(Box::new(KeyCode::KeyJ));
+        let wasd = UserInputWrapper::DualAxis(Box::new(VirtualDPad::wasd()));
+
+        let mut input_map = InputMap::new([
+            (Action::Jump, KeyCode::Space),
+            (Action::Jump, KeyCode::KeyJ),
+        ])
+        .with_dual_axis(Action::DualAxis, VirtualDPad::wasd());
+        input_map
+            .set_binding_source(&Action::Jump, key_j.clone(), BindingSource::User)
+            .set_binding_source(&Action::DualAxis, wasd.clone(), BindingSource::Remote)
+            // Inputs that aren't bound to the action can't be tagged
+            .set_binding_source(&Action::Run, space.clone(), BindingSource::User);
+
+        assert_eq!(
+            input_map.binding_source(&Action::Jump, &space),
+            BindingSource::Default
+        );
+        assert_eq!(
+            input_map.binding_source(&Action::Jump, &key_j),
+            BindingSource::User
+        );
+        assert_eq!(
+            input_map.binding_source(&Action::DualAxis, &wasd),
+            BindingSource::Remote
+        );
+        assert_eq!(
+            input_map.binding_source(&Action::Run, &space),
+            BindingSource::Default
+        );
+        assert_eq!(
+            input_map.cheat_sheet_with_sources(),
+            vec![
+                (
+                    "DualAxis".to_string(),
+                    vec![("W / A / S / D".to_string(), BindingSource::Remote)]
+                ),
+                (
+                    "Jump".to_string(),
+                    vec![
+                        ("Space".to_string(), BindingSource::Default),
+                        ("J".to_string(), BindingSource::User),
+                    ]
+                ),
+            ]
+        );
+
+        // Tags survive a round-trip through serialization
+        let serialized = ron::to_string(&input_map).unwrap();
+        let deserialized: InputMap<Action> = ron::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, input_map);
+        assert_eq!(
+            deserialized.binding_source(&Action::Jump, &key_j),
+            BindingSource::User
+        );
+        assert_eq!(
+            deserialized.binding_source(&Action::DualAxis, &wasd),
+            BindingSource::Remote
+        );
+
+        // Tags are removed along with their bindings
+        input_map.remove(&Action::Jump, KeyCode::KeyJ);
+        input_map.insert(Action::Jump, KeyCode::KeyJ);
+        assert_eq!(
+            input_map.binding_source(&Action::Jump, &key_j),
+            BindingSource::Default
+        );
+
+        // Resetting a tag to the default stops storing it
+        input_map.set_binding_source(&Action::DualAxis, wasd, BindingSource::Default);
+        assert_eq!(
+            input_map,
+            InputMap::new([
+                (Action::Jump, KeyCode::Space),
+                (Action::Jump, KeyCode::KeyJ)
+            ])
+            .with_dual_axis(Action::DualAxis, VirtualDPad::wasd())
+        );
+    }
+
+    #[test]
+    fn cheat_sheet() {
+        use bevy::input::keyboard::KeyCode;
+
+        let input_map = InputMap::default()
+            .with(Action::Run, KeyCode::ShiftLeft)
+            .with(Action::Run, KeyCode::KeyR)
+            .with(Action::Jump, KeyCode::Space)
+            .with_axis(Action::Axis, VirtualAxis::ad());
+
+        assert_eq!(
+            input_map.cheat_sheet(),
+            vec![
+                ("Axis".to_string(), vec!["A / D".to_string()]),
+                ("Jump".to_string(), vec!["Space".to_string()]),
+                (
+                    "Run".to_string(),
+                    vec!["Left Shift".to_string(), "R".to_string()]
+                ),
+            ]
+        );
+
+        assert!(InputMap::<Action>::default().cheat_sheet().is_empty());
+    }
+
+    #[test]
+    fn bindings_for_action() {
+        use bevy::input::keyboard::KeyCode;
+
+        let mut input_map = InputMap::default();
+        assert_eq!(input_map.bindings_for(&Action::Run).count(), 0);
+
+        input_map.insert(Action::Run, KeyCode::Space);
+        input_map.insert(Action::Run, KeyCode::Enter);
+        input_map.insert(Action::Run, KeyCode::ShiftLeft);
+        input_map.insert(Action::Jump, KeyCode::KeyJ);
+        input_map.insert_axis(Action::Axis, VirtualAxis::ad());
+        input_map.insert_dual_axis(Action::DualAxis, VirtualDPad::wasd());
+        input_map.insert_dual_axis(Action::DualAxis, VirtualDPad::arrow_keys());
+        input_map.insert_triple_axis(
+            Action::TripleAxis,
+            VirtualDPad3D::new(
+                KeyCode::KeyW,
+                KeyCode::KeyS,
+                KeyCode::KeyA,
+                KeyCode::KeyD,
+                KeyCode::KeyF,
+                KeyCode::KeyB,
+            ),
+        );
+
+        // Bindings are returned in insertion order
+        let expected: Vec<Box<dyn Buttonlike>> = vec![
+            Box::new(KeyCode::Space),
+            Box::new(KeyCode::Enter),
+            Box::new(KeyCode::ShiftLeft),
+        ];
+        assert!(input_map
+            .bindings_for(&Action::Run)
+            .eq(expected.iter().map(|input| input.as_ref())));
+
+        let expected: Vec<Box<dyn DualAxislike>> = vec![
+            Box::new(VirtualDPad::wasd()),
+            Box::new(VirtualDPad::arrow_keys()),
+        ];
+        assert!(input_map
+            .dual_axislike_bindings_for(&Action::DualAxis)
+            .eq(expected.iter().map(|input| input.as_ref())));
+        assert_eq!(input_map.axislike_bindings_for(&Action::Axis).count(), 1);
+        assert_eq!(
+            input_map
+                .triple_axislike_bindings_for(&Action::TripleAxis)
+                .count(),
+            1
+        );
+
+        // Clearing an action leaves the order of the other bindings untouched
+        input_map.clear_action(&Action::Run);
+        assert_eq!(input_map.bindings_for(&Action::Run).count(), 0);
+        input_map.insert(Action::Run, KeyCode::Enter);
+        input_map.insert(Action::Run, KeyCode::Space);
+
+        let expected: Vec<Box<dyn Buttonlike>> =
+            vec![Box::new(KeyCode::Enter), Box::new(KeyCode::Space)];
+        assert!(input_map
+            .bindings_for(&Action::Run)
+            .eq(expected.iter().map(|input| input.as_ref())));
+
+        let expected: Vec<Box<dyn Buttonlike>> = vec![Box::new(KeyCode::KeyJ)];
+        assert!(input_map
+            .bindings_for(&Action::Jump)
+            .eq(expected.iter().map(|input| input.as_ref())));
+
+        // Actions of other kinds have no buttonlike bindings
+        assert_eq!(input_map.bindings_for(&Action::Axis).count(), 0);
+    }
+
+    #[test]
+    fn merging() {
+        use bevy::input::keyboard::KeyCode;
+
+        let mut input_map = InputMap::default();
+        let mut default_keyboard_map = InputMap::default();
+        default_keyboard_map.insert(Action::Run, KeyCode::ShiftLeft);
+        default_keyboard_map.insert(
+            Action::Hide,
+            ButtonlikeChord::new([KeyCode::ControlLeft, KeyCode::KeyH]),
+        );
+
+        let mut default_gamepad_map = InputMap::default();
+        default_gamepad_map.insert(Action::Run, KeyCode::Numpad0);
+        default_gamepad_map.insert(Action::Hide, KeyCode::Numpad7);
+
+        // Merging works
+        input_map.merge(&default_keyboard_map);
+        assert_eq!(input_map, default_keyboard_map);
+
+        // Merging is idempotent
+        input_map.merge(&default_keyboard_map);
+        assert_eq!(input_map, default_keyboard_map);
+    }
+
+    #[test]
+    fn merging_empty_maps() {
+        use bevy::input::keyboard::KeyCode;
+
+        let input_map = InputMap::new([(Action::Run, KeyCode::Space)]);
+
+        // Merging an empty map changes nothing
+        let mut merged = input_map.clone();
+        merged.merge(&InputMap::default());
+        assert_eq!(merged, input_map);
+
+        let mut replaced = input_map.clone();
+        replaced.merge_replace(&InputMap::default());
+        assert_eq!(replaced, input_map);
+
+        // Merging into an empty map copies every binding
+        let mut merged = InputMap::default();
+        merged.merge(&input_map);
+        assert_eq!(merged, input_map);
+
+        let mut replaced = InputMap::default();
+        replaced.merge_replace(&input_map);
+        assert_eq!(replaced, input_map);
+    }
+
+    #[test]
+    fn merging_disjoint_actions() {
+        use bevy::input::keyboard::KeyCode;
+
+        let base = InputMap::new([(Action::Run, KeyCode::ShiftLeft)])
+            .with_axis(Action::Axis, VirtualAxis::ad());
+        let overlay = InputMap::new([(Action::Jump, KeyCode::Space)])
+            .with_dual_axis(Action::DualAxis, VirtualDPad::wasd());
+
+        let expected = InputMap::new([
+            (Action::Run, KeyCode::ShiftLeft),
+            (Action::Jump, KeyCode::Space),
+        ])
+        .with_axis(Action::Axis, VirtualAxis::ad())
+        .with_dual_axis(Action::DualAxis, VirtualDPad::wasd());
+
+        let mut merged = base.clone();
+        merged.merge(&overlay);
+        assert_eq!(merged, expected);
+
+        let mut replaced = base.clone();
+        replaced.merge_replace(&overlay);
+        assert_eq!(replaced, expected);
+    }
+
+    #[test]
+    fn merging_overlapping_actions() {
+        use bevy::input::keyboard::KeyCode;
+
+        let base = InputMap::new([
+            (Action::Run, KeyCode::ShiftLeft),
+            (Action::Run, KeyCode::KeyR),
+            (Action::Hide, KeyCode::KeyH),
+        ]);
+        let overlay = InputMap::new([
+            (Action::Run, KeyCode::Numpad0),
+            (Action::Run, KeyCode::KeyR),
+        ]);
+
+        // Overlapping bindings are appended after the existing ones, without duplicates
+        let mut merged = base.clone();
+        merged.merge(&overlay);
+        let expected: Vec<Box<dyn Buttonlike>> = vec![
+            Box::new(KeyCode::ShiftLeft),
+            Box::new(KeyCode::KeyR),
+            Box::new(KeyCode::Numpad0),
+        ];
+        assert_eq!(merged.get_buttonlike(&Action::Run), Some(&expected));
+        assert_eq!(
+            merged.get_buttonlike(&Action::Hide),
+            base.get_buttonlike(&Action::Hide)
+        );
+
+        // Overlapping bindings are overwritten by the other map
+        let mut replaced = base.clone();
+        replaced.merge_replace(&overlay);
+        let expected: Vec<Box<dyn Buttonlike>> =
+            vec![Box::new(KeyCode::Numpad0), Box::new(KeyCode::KeyR)];
+        assert_eq!(replaced.get_buttonlike(&Action::Run), Some(&expected));
+        assert_eq!(
+            replaced.get_buttonlike(&Action::Hide),
+            base.get_buttonlike(&Action::Hide)
+        );
+    }
+
+    #[cfg(feature = "gamepad")]
+    #[test]
+    fn batched_insertion() {
+        use bevy::input::{gamepad::GamepadButton, keyboard::KeyCode};
+
+        let mut sequential = InputMap::new([(Action::Run, KeyCode::ShiftLeft)]);
+        sequential
+            .insert(Action::Jump, KeyCode::Space)
+            .insert(Action::Run, KeyCode::KeyR)
+            .insert(Action::Jump, GamepadButton::South)
+            .insert_axis(Action::Axis, GamepadControlAxis::LEFT_X)
+            .insert_axis(Action::Axis, VirtualAxis::ad())
+            .insert_dual_axis(Action::DualAxis, VirtualDPad::wasd())
+            .insert_triple_axis(
+                Action::TripleAxis,
+                VirtualDPad3D::new(
+                    KeyCode::KeyW,
+                    KeyCode::KeyS,
+                    KeyCode::KeyA,
+                    KeyCode::KeyD,
+                    KeyCode::KeyQ,
+                    KeyCode::KeyE,
+                ),
+            );
+
+        // Bindings are appended to existing actions, in order, even when mixing input types
+        let mut batched = InputMap::new([(Action::Run, KeyCode::ShiftLeft)]);
+        batched
+            .insert_multiple([
+                (
+                    Action::Jump,
+                    Box::new(KeyCode::Space) as Box<dyn Buttonlike>,
+                ),
+                (Action::Run, Box::new(KeyCode::KeyR)),
+                (Action::Jump, Box::new(GamepadButton::South)),
+                // Duplicates are ignored
+                (Action::Run, Box::new(KeyCode::KeyR)),
+            ])
+            .insert_multiple_axis([
+                (
+                    Action::Axis,
+                    Box::new(GamepadControlAxis::LEFT_X) as Box<dyn Axislike>,
+                ),
+                (Action::Axis, Box::new(VirtualAxis::ad())),
+            ])
+            .insert_multiple_dual_axis([(Action::DualAxis, VirtualDPad::wasd())])
+            .insert_multiple_triple_axis([(
+                Action::TripleAxis,
+                VirtualDPad3D::new(
+                    KeyCode::KeyW,
+                    KeyCode::KeyS,
+                    KeyCode::KeyA,
+                    KeyCode::KeyD,
+                    KeyCode::KeyQ,
+                    KeyCode::KeyE,
+                ),
+            )]);
+
+        assert_eq!(batched, sequential);
+
+        // Homogeneous inputs don't need to be boxed
+        let input_map = InputMap::default().with_multiple([
+            (Action::Run, KeyCode::ShiftLeft),
+            (Action::Run, KeyCode::KeyR),
+        ]);
+        assert_eq!(
+            input_map,
+            InputMap::new([
+                (Action::Run, KeyCode::ShiftLeft),
+                (Action::Run, KeyCode::KeyR)
+            ])
+        );
+    }
+
+    #[test]
+    fn process_actions_with_state() {
+        use crate::buttonlike::ButtonValue;
+        use bevy::input::keyboard::KeyCode;
+
+        let input_map = InputMap::default()
+            .with(Action::Run, KeyCode::KeyR)
+            .with(Action::Jump, KeyCode::Space)
+            .with_axis(Action::Axis, VirtualAxis::ad())
+            .with_dual_axis(Action::DualAxis, VirtualDPad::wasd());
+
+        let mut input_store = CentralInputStore::default();
+        for key in [KeyCode::KeyR, KeyCode::Space, KeyCode::KeyD, KeyCode::KeyW] {
+            input_store.update_buttonlike(key, ButtonValue::from_pressed(true));
+        }
+
+        let mut action_state = ActionState::<Action>::default();
+        action_state.disable_action(&Action::Run);
+        action_state.disable_action(&Action::Axis);
+
+        let contexts = InputContexts::default();
+        for clash_strategy in [ClashStrategy::PressAll, ClashStrategy::PrioritizeLongest] {
+            let expected =
+                input_map.process_actions_in_context(None, &input_store, clash_strategy, &contexts);
+            let updated_actions = input_map.process_actions_with_state(
+                None,
+                &input_store,
+                clash_strategy,
+                &contexts,
+                &action_state,
+            );
+
+            // Disabled axislike actions are zeroed without evaluating their inputs
+            assert_eq!(
+                updated_actions.get(&Action::Axis),
+                Some(&UpdatedValue::Axis(0.0))
+            );
+
+            // Buttonlike actions are only skipped if they can't overrule clashing actions
+            let run_pressed = clash_strategy == ClashStrategy::PrioritizeLongest;
+            assert_eq!(updated_actions.pressed(&Action::Run), run_pressed);
+
+            // Enabled actions are unaffected
+            for action in [Action::Jump, Action::DualAxis] {
+                assert_eq!(updated_actions.get(&action), expected.get(&action));
+            }
+
+            // Disabled actions still report zero
+            let mut action_state = action_state.clone();
+            action_state.update(updated_actions);
+            assert!(!action_state.pressed(&Action::Run));
+            assert_eq!(action_state.value(&Action::Axis), 0.0);
+            assert!(action_state.pressed(&Action::Jump));
+            assert_eq!(action_state.axis_pair(&Action::DualAxis), Vec2::ONE);
+        }
+
+        // Nothing is evaluated while the entire action state is disabled
+        action_state.disable();
+        let updated_actions = input_map.process_actions_with_state(
+            None,
+            &input_store,
+            ClashStrategy::PrioritizeLongest,
+            &contexts,
+            &action_state,
+        );
+        assert!(!updated_actions.pressed(&Action::Run));
+        assert!(!updated_actions.pressed(&Action::Jump));
+        assert_eq!(
+            updated_actions.get(&Action::DualAxis),
+            Some(&UpdatedValue::DualAxis(Vec2::ZERO))
+        );
+    }
+
+    #[test]
+    fn diff() {
+        use bevy::input::keyboard::KeyCode;
+        use bevy::prelude::App;
+
+        let mut app = App::new();
+
+        // Add the plugin to register input deserializers
+        app.add_plugins(InputManagerPlugin::<Action>::default());
+
+        let old_map = InputMap::default()
+            .with(Action::Run, KeyCode::KeyR)
+            .with(Action::Run, KeyCode::ShiftLeft)
+            .with(Action::Jump, KeyCode::Space)
+            .with(Action::Hide, KeyCode::KeyH)
+            .with(Action::Hide, KeyCode::KeyC)
+            .with_axis(Action::Axis, VirtualAxis::ad());
+        let new_map = InputMap::default()
+            // Reordered bindings don't count as changed
+            .with(Action::Run, KeyCode::ShiftLeft)
+            .with(Action::Run, KeyCode::KeyR)
+            .with(Action::Jump, KeyCode::Space)
+            .with(Action::Jump, ModifierKey::Control)
+            .with(Action::Hide, KeyCode::KeyH)
+            .with_axis(Action::Axis, VirtualAxis::ws())
+            .with_dual_axis(Action::DualAxis, VirtualDPad::wasd());
+
+        assert!(old_map.diff(&old_map).is_empty());
+
+        let diff = old_map.diff(&new_map);
+        assert!(!diff.actions.contains_key(&Action::Run));
+        assert_eq!(diff.actions.len(), 4);
+
+        // An added binding
+        let jump = &diff.actions[&Action::Jump];
+        assert_eq!(
+            jump.added,
+            vec![UserInputWrapper::Button(Box::new(ModifierKey::Control))]
+        );
+        assert!(jump.removed.is_empty() && jump.changed.is_empty());
+
+        // A removed binding
+        let hide = &diff.actions[&Action::Hide];
+        assert_eq!(
+            hide.removed,
+            vec![UserInputWrapper::Button(Box::new(KeyCode::KeyC))]
+        );
+        assert!(hide.added.is_empty() && hide.changed.is_empty());
+
+        // A binding replaced by another input of the same type
+        let axis = &diff.actions[&Action::Axis];
+        assert_eq!(
+            axis.changed,
+            vec![(
+                UserInputWrapper::Axis(Box::new(VirtualAxis::ad())),
+                UserInputWrapper::Axis(Box::new(VirtualAxis::ws())),
+            )]
+        );
+        assert!(axis.added.is_empty() && axis.removed.is_empty());
+
+        // A newly bound action
+        let dual_axis = &diff.actions[&Action::DualAxis];
+        assert_eq!(dual_axis.added.len(), 1);
+
+        // Diffing in the other direction swaps additions and removals
+        let reversed = new_map.diff(&old_map);
+        assert_eq!(reversed.actions[&Action::Jump].removed, jump.added);
+        assert_eq!(reversed.actions[&Action::Hide].added, hide.removed);
+
+        // The diff can be serialized
+        let serialized = ron::to_string(&diff).unwrap();
+        assert_eq!(
+            ron::from_str::<InputMapDiff<Action>>(&serialized).unwrap(),
+            diff
+        );
+    }
+
+    #[test]
+    fn remapping() {
+        use crate::buttonlike::ButtonValue;
+        use bevy::input::keyboard::KeyCode;
+
+        let input_map = InputMap::default()
+            .with(Action::Run, KeyCode::KeyR)
+            .with(Action::Jump, KeyCode::Space)
+            .with(Action::Hide, KeyCode::KeyH);
+        let mut remapped_input_map = input_map.clone();
+
+        let mut input_store = CentralInputStore::default();
+        input_store.update_buttonlike(KeyCode::KeyR, ButtonValue::from_pressed(true));
+        let process = |input_map: &InputMap<Action>| {
+            let updated_actions =
+                input_map.process_actions(None, &input_store, ClashStrategy::PressAll);
+            (
+                updated_actions.pressed(&Action::Run),
+                updated_actions.pressed(&Action::Jump),
+            )
+        };
+        assert_eq!(process(&input_map), (true, false));
+
+        // Swapping the actions routes the state of each to the other
+        remapped_input_map.apply_remap(HashMap::from([
+            (Action::Run, Action::Jump),
+            (Action::Jump, Action::Run),
+        ]));
+        assert_eq!(process(&remapped_input_map), (false, true));
+
+        // The original bindings are left untouched
+        assert_eq!(
+            remapped_input_map.get_buttonlike(&Action::Run),
+            input_map.get_buttonlike(&Action::Run)
+        );
+
+        // Routing several actions to the same action combines their states
+        remapped_input_map.apply_remap(HashMap::from([(Action::Run, Action::Hide)]));
+        let updated_actions =
+            remapped_input_map.process_actions(None, &input_store, ClashStrategy::PressAll);
+        assert!(updated_actions.pressed(&Action::Hide));
+
+        // Actions routed elsewhere are released, rather than left out
+        assert_eq!(
+            updated_actions.0.get(&Action::Run),
+            Some(&UpdatedValue::Button(false))
+        );
+
+        // So that their state doesn't linger when a remap is applied while they're held
+        let mut action_state = ActionState::<Action>::default();
+        remapped_input_map.clear_remap();
+        action_state.update(remapped_input_map.process_actions(
+            None,
+            &input_store,
+            ClashStrategy::PressAll,
+        ));
+        assert!(action_state.pressed(&Action::Run));
+        remapped_input_map.apply_remap(HashMap::from([(Action::Run, Action::Hide)]));
+        action_state.update(remapped_input_map.process_actions(
+            None,
+            &input_store,
+            ClashStrategy::PressAll,
+        ));
+        assert!(action_state.released(&Action::Run));
+        assert!(action_state.pressed(&Action::Hide));
+
+        // Clearing the remap restores the original behavior
+        remapped_input_map.clear_remap();
+        assert!(remapped_input_map.active_remap().is_empty());
+        assert_eq!(process(&remapped_input_map), (true, false));
+        assert_eq!(remapped_input_map, input_map);
+    }
+
+    #[test]
+    fn actions() {
+        use bevy::input::keyboard::KeyCode;
+
+        let input_map = InputMap::default()
+            .with(Action::Run, KeyCode::KeyR)
+            .with(Action::Run, KeyCode::ShiftLeft)
+            .with(Action::Jump, KeyCode::Space)
+            .with_axis(Action::Axis, VirtualAxis::ad())
+            .with_dual_axis(Action::DualAxis, VirtualDPad::arrow_keys())
+            .with_triple_axis(
+                Action::TripleAxis,
+                VirtualDPad3D::new(
+                    KeyCode::KeyW,
+                    KeyCode::KeyS,
+                    KeyCode::KeyA,
+                    KeyCode::KeyD,
+                    KeyCode::KeyQ,
+                    KeyCode::KeyE,
+                ),
+            );
+
+        let actions: Vec<_> = input_map.actions().collect();
+        assert_eq!(actions.len(), 5);
+        for action in [
+            Action::Run,
+            Action::Jump,
+            Action::Axis,
+            Action::DualAxis,
+            Action::TripleAxis,
+        ] {
+            assert_eq!(actions.iter().filter(|bound| ***bound == action).count(), 1);
+        }
+        assert!(!actions.contains(&&Action::Hide));
+
+        // The order is stable across calls
+        assert_eq!(actions, input_map.actions().collect::<Vec<_>>());
+
+        assert_eq!(InputMap::<Action>::default().actions().count(), 0);
+
+        // Actions stored in several kind maps are still returned once
+        let mut input_map = InputMap::default().with(Action::Run, KeyCode::KeyR);
         input_map
+            .axislike_map
+            .insert(Action::Run, vec![Box::new(VirtualAxis::ad())]);
+        assert_eq!(input_map.actions().collect::<Vec<_>>(), vec![&Action::Run]);
     }
-}
 
-impl<A: Actionlike, U: Buttonlike> FromIterator<(A, U)> for InputMap<A> {
-    fn from_iter<T: IntoIterator<Item = (A, U)>>(iter: T) -> Self {
-        let mut input_map = Self::default();
-        for (action, input) in iter.into_iter() {
-            input_map.insert(action, input);
-        }
-        input_map
+    #[test]
+    fn binding_contexts() {
+        use crate::buttonlike::ButtonValue;
+        use bevy::input::keyboard::KeyCode;
+
+        let mut input_map = InputMap::default()
+            .with(Action::Jump, KeyCode::Space)
+            .with(Action::Jump, KeyCode::KeyJ);
+        let key_j = UserInputWrapper::Button(Box::new(KeyCode::KeyJ));
+        input_map.set_binding_context(&Action::Jump, key_j.clone(), "platforming");
+        assert_eq!(
+            input_map.binding_context(&Action::Jump, &key_j),
+            Some("platforming")
+        );
+
+        let mut input_store = CentralInputStore::default();
+        input_store.update_buttonlike(KeyCode::KeyJ, ButtonValue::from_pressed(true));
+        let mut contexts = InputContexts::default();
+        let jump_pressed = |input_map: &InputMap<Action>,
+                            input_store: &CentralInputStore,
+                            contexts: &InputContexts| {
+            input_map
+                .process_actions_in_context(None, input_store, ClashStrategy::PressAll, contexts)
+                .pressed(&Action::Jump)
+        };
+
+        // The binding is ignored while its context is inactive
+        assert!(!jump_pressed(&input_map, &input_store, &contexts));
+        assert!(!input_map
+            .process_actions(None, &input_store, ClashStrategy::PressAll)
+            .pressed(&Action::Jump));
+
+        // Activating the context enables the binding
+        contexts.set("platforming", true);
+        assert!(jump_pressed(&input_map, &input_store, &contexts));
+
+        // Other contexts have no effect
+        contexts.set("platforming", false).set("swimming", true);
+        assert!(!jump_pressed(&input_map, &input_store, &contexts));
+
+        // Unrestricted bindings are always active
+        input_store.update_buttonlike(KeyCode::Space, ButtonValue::from_pressed(true));
+        assert!(jump_pressed(&input_map, &input_store, &contexts));
+        input_store.update_buttonlike(KeyCode::Space, ButtonValue::from_pressed(false));
+
+        // Clearing the context lifts the restriction
+        input_map.clear_binding_context(&Action::Jump, &key_j);
+        assert_eq!(input_map.binding_context(&Action::Jump, &key_j), None);
+        assert!(jump_pressed(&input_map, &input_store, &contexts));
+
+        // Contexts are removed along with their bindings
+        input_map.set_binding_context(&Action::Jump, key_j.clone(), "platforming");
+        input_map.clear_input(&KeyCode::KeyJ);
+        input_map.insert(Action::Jump, KeyCode::KeyJ);
+        assert_eq!(input_map.binding_context(&Action::Jump, &key_j), None);
     }
-}
 
-#[cfg(feature = "keyboard")]
-mod tests {
-    use bevy::prelude::Reflect;
-    use serde::{Deserialize, Serialize};
+    #[test]
+    fn disabled_bindings() {
+        use crate::buttonlike::ButtonValue;
+        use bevy::input::keyboard::KeyCode;
 
-    use super::*;
-    use crate as leafwing_input_manager;
-    use crate::prelude::*;
+        let mut input_map = InputMap::default()
+            .with(Action::Jump, KeyCode::Space)
+            .with(Action::Jump, KeyCode::KeyJ);
 
-    #[derive(Actionlike, Serialize, Deserialize, Clone, PartialEq, Eq, Hash, Debug, Reflect)]
-    enum Action {
-        Run,
-        Jump,
-        Hide,
-        #[actionlike(Axis)]
-        Axis,
-        #[actionlike(DualAxis)]
-        DualAxis,
-        #[actionlike(TripleAxis)]
-        TripleAxis,
+        let mut input_store = CentralInputStore::default();
+        let jump_pressed = |input_map: &InputMap<Action>, input_store: &CentralInputStore| {
+            input_map
+                .process_actions(None, input_store, ClashStrategy::PressAll)
+                .pressed(&Action::Jump)
+        };
+
+        // Only the enabled binding drives the action
+        assert_eq!(input_map.disable_binding(&Action::Jump, 0), Some(()));
+        assert!(input_map.binding_disabled(&Action::Jump, 0));
+        assert!(!input_map.binding_disabled(&Action::Jump, 1));
+
+        input_store.update_buttonlike(KeyCode::Space, ButtonValue::from_pressed(true));
+        assert!(!jump_pressed(&input_map, &input_store));
+        input_store.update_buttonlike(KeyCode::Space, ButtonValue::from_pressed(false));
+        input_store.update_buttonlike(KeyCode::KeyJ, ButtonValue::from_pressed(true));
+        assert!(jump_pressed(&input_map, &input_store));
+
+        // The disabled binding is retained in its place
+        assert_eq!(
+            input_map.get(&Action::Jump),
+            Some(vec![
+                UserInputWrapper::Button(Box::new(KeyCode::Space)),
+                UserInputWrapper::Button(Box::new(KeyCode::KeyJ)),
+            ])
+        );
+
+        // Re-enabling the binding lets it drive the action again
+        input_store.update_buttonlike(KeyCode::KeyJ, ButtonValue::from_pressed(false));
+        input_store.update_buttonlike(KeyCode::Space, ButtonValue::from_pressed(true));
+        assert_eq!(input_map.enable_binding(&Action::Jump, 0), Some(()));
+        assert!(!input_map.binding_disabled(&Action::Jump, 0));
+        assert!(jump_pressed(&input_map, &input_store));
+        assert_eq!(
+            input_map,
+            InputMap::default()
+                .with(Action::Jump, KeyCode::Space)
+                .with(Action::Jump, KeyCode::KeyJ)
+        );
+
+        // Missing bindings can't be disabled
+        assert_eq!(input_map.disable_binding(&Action::Jump, 2), None);
+        assert_eq!(input_map.enable_binding(&Action::Run, 0), None);
+        assert!(!input_map.binding_disabled(&Action::Jump, 2));
+
+        // The binding stays disabled when its index changes, until it is removed
+        input_map.disable_binding(&Action::Jump, 1);
+        input_map.remove_at(&Action::Jump, 0);
+        assert!(input_map.binding_disabled(&Action::Jump, 0));
+        input_map.clear_input(&KeyCode::KeyJ);
+        input_map.insert(Action::Jump, KeyCode::KeyJ);
+        assert!(!input_map.binding_disabled(&Action::Jump, 0));
     }
 
     #[test]
-    fn creation() {
+    fn inactive_bindings_do_not_clash() {
+        use crate::buttonlike::ButtonValue;
         use bevy::input::keyboard::KeyCode;
 
-        let input_map = InputMap::default()
-            .with(Action::Run, KeyCode::KeyW)
-            .with(Action::Run, KeyCode::ShiftLeft)
-            // Duplicate associations should be ignored
-            .with(Action::Run, KeyCode::ShiftLeft)
-            .with_one_to_many(Action::Run, [KeyCode::KeyR, KeyCode::ShiftRight])
-            .with_multiple([
-                (Action::Jump, KeyCode::Space),
-                (Action::Hide, KeyCode::ControlLeft),
-                (Action::Hide, KeyCode::ControlRight),
-            ]);
+        let mut input_map = InputMap::default().with(Action::Jump, KeyCode::Space).with(
+            Action::Run,
+            ButtonlikeChord::new([KeyCode::ControlLeft, KeyCode::Space]),
+        );
+        let chord = UserInputWrapper::Button(Box::new(ButtonlikeChord::new([
+            KeyCode::ControlLeft,
+            KeyCode::Space,
+        ])));
+        input_map.set_binding_context(&Action::Run, chord, "sprinting");
 
-        let expected_bindings: HashMap<Box<dyn Buttonlike>, Action> = HashMap::from([
-            (Box::new(KeyCode::KeyW) as Box<dyn Buttonlike>, Action::Run),
-            (
-                Box::new(KeyCode::ShiftLeft) as Box<dyn Buttonlike>,
-                Action::Run,
-            ),
-            (Box::new(KeyCode::KeyR) as Box<dyn Buttonlike>, Action::Run),
-            (
-                Box::new(KeyCode::ShiftRight) as Box<dyn Buttonlike>,
-                Action::Run,
-            ),
-            (
-                Box::new(KeyCode::Space) as Box<dyn Buttonlike>,
-                Action::Jump,
-            ),
-            (
-                Box::new(KeyCode::ControlLeft) as Box<dyn Buttonlike>,
-                Action::Hide,
-            ),
-            (
-                Box::new(KeyCode::ControlRight) as Box<dyn Buttonlike>,
-                Action::Hide,
+        let mut input_store = CentralInputStore::default();
+        input_store.update_buttonlike(KeyCode::ControlLeft, ButtonValue::from_pressed(true));
+        input_store.update_buttonlike(KeyCode::Space, ButtonValue::from_pressed(true));
+        let mut contexts = InputContexts::default();
+
+        // The inactive chord doesn't override the shorter binding
+        let updated_actions = input_map.process_actions_in_context(
+            None,
+            &input_store,
+            ClashStrategy::PrioritizeLongest,
+            &contexts,
+        );
+        assert!(updated_actions.pressed(&Action::Jump));
+        assert!(!updated_actions.pressed(&Action::Run));
+
+        // Once active, the longer chord wins the clash
+        contexts.set("sprinting", true);
+        let updated_actions = input_map.process_actions_in_context(
+            None,
+            &input_store,
+            ClashStrategy::PrioritizeLongest,
+            &contexts,
+        );
+        assert!(!updated_actions.pressed(&Action::Jump));
+        assert!(updated_actions.pressed(&Action::Run));
+    }
+
+    #[test]
+    fn input_capture() {
+        use bevy::input::InputPlugin;
+        use bevy::prelude::{App, KeyCode, MinimalPlugins};
+
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            InputPlugin,
+            InputManagerPlugin::<Action>::default(),
+        ));
+        let entity = app.world_mut().spawn(InputMap::<Action>::default()).id();
+
+        // Inputs held down before the capture begins are ignored
+        KeyCode::Enter.press(app.world_mut());
+        app.update();
+        let mut input_map = app.world_mut().get_mut::<InputMap<Action>>(entity).unwrap();
+        input_map.begin_capture(Action::Jump);
+        app.update();
+
+        let input_map = app.world().get::<InputMap<Action>>(entity).unwrap();
+        assert_eq!(input_map.pending_capture(), Some(&Action::Jump));
+        assert_eq!(input_map.get_buttonlike(&Action::Jump), None);
+
+        // The next newly pressed input completes the capture
+        KeyCode::Space.press(app.world_mut());
+        app.update();
+
+        let input_map = app.world().get::<InputMap<Action>>(entity).unwrap();
+        let expected: Vec<Box<dyn Buttonlike>> = vec![Box::new(KeyCode::Space)];
+        assert_eq!(input_map.pending_capture(), None);
+        assert_eq!(input_map.get_buttonlike(&Action::Jump), Some(&expected));
+        assert_eq!(
+            input_map.binding_source(
+                &Action::Jump,
+                &UserInputWrapper::Button(Box::new(KeyCode::Space))
             ),
-        ]);
+            BindingSource::User
+        );
+    }
 
-        for (action, input) in input_map.buttonlike_bindings() {
-            let expected_action = expected_bindings.get(input).unwrap();
-            assert_eq!(expected_action, action);
-        }
+    #[test]
+    fn input_capture_is_deterministic() {
+        use bevy::input::InputPlugin;
+        use bevy::prelude::{App, KeyCode, MinimalPlugins};
+
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            InputPlugin,
+            InputManagerPlugin::<Action>::default(),
+        ));
+        let entity = app.world_mut().spawn(InputMap::<Action>::default()).id();
+        let mut input_map = app.world_mut().get_mut::<InputMap<Action>>(entity).unwrap();
+        input_map.begin_capture(Action::Jump);
+        app.update();
+
+        // When several inputs are pressed on the same frame, the same one is always chosen
+        KeyCode::KeyB.press(app.world_mut());
+        KeyCode::KeyA.press(app.world_mut());
+        KeyCode::KeyC.press(app.world_mut());
+        app.update();
+
+        let input_map = app.world().get::<InputMap<Action>>(entity).unwrap();
+        let expected: Vec<Box<dyn Buttonlike>> = vec![Box::new(KeyCode::KeyA)];
+        assert_eq!(input_map.get_buttonlike(&Action::Jump), Some(&expected));
     }
 
+    #[cfg(feature = "keyboard")]
     #[test]
-    fn insertion_idempotency() {
+    fn per_map_clash_strategy() {
+        use crate::buttonlike::ButtonValue;
         use bevy::input::keyboard::KeyCode;
+        use bevy::prelude::App;
 
-        let mut input_map = InputMap::default();
-        input_map.insert(Action::Run, KeyCode::Space);
+        #[derive(
+            Actionlike, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect,
+        )]
+        enum Action {
+            Down,
+            Save,
+        }
 
-        let expected: Vec<Box<dyn Buttonlike>> = vec![Box::new(KeyCode::Space)];
-        assert_eq!(input_map.get_buttonlike(&Action::Run), Some(&expected));
+        let gameplay_map = InputMap::default().with(Action::Down, KeyCode::KeyS).with(
+            Action::Save,
+            ButtonlikeChord::new([KeyCode::ControlLeft, KeyCode::KeyS]),
+        );
+        let mut menu_map = gameplay_map
+            .clone()
+            .with_clash_strategy(ClashStrategy::PressAll);
+        assert_eq!(gameplay_map.clash_strategy(), None);
+        assert_eq!(menu_map.clash_strategy(), Some(ClashStrategy::PressAll));
 
-        // Duplicate insertions should not change anything
-        input_map.insert(Action::Run, KeyCode::Space);
-        assert_eq!(input_map.get_buttonlike(&Action::Run), Some(&expected));
+        let mut input_store = CentralInputStore::default();
+        input_store.update_buttonlike(KeyCode::ControlLeft, ButtonValue::from_pressed(true));
+        input_store.update_buttonlike(KeyCode::KeyS, ButtonValue::from_pressed(true));
+
+        // Both maps are processed with the same global strategy in the same frame
+        let global_strategy = ClashStrategy::PrioritizeLongest;
+        let gameplay_actions = gameplay_map.process_actions(None, &input_store, global_strategy);
+        let menu_actions = menu_map.process_actions(None, &input_store, global_strategy);
+
+        // The map without its own strategy uses the global one
+        assert!(gameplay_actions.pressed(&Action::Save));
+        assert!(!gameplay_actions.pressed(&Action::Down));
+
+        // While the map's own strategy takes precedence
+        assert!(menu_actions.pressed(&Action::Save));
+        assert!(menu_actions.pressed(&Action::Down));
+
+        let mut action_state = ActionState::<Action>::default();
+        action_state.update(menu_map.process_actions_with_state(
+            None,
+            &input_store,
+            global_strategy,
+            &InputContexts::default(),
+            &ActionState::default(),
+        ));
+        assert!(action_state.pressed(&Action::Down));
+
+        // Clearing the override falls back to the global strategy again
+        menu_map.clear_clash_strategy();
+        let menu_actions = menu_map.process_actions(None, &input_store, global_strategy);
+        assert!(!menu_actions.pressed(&Action::Down));
+
+        // The override is kept when the map is serialized
+        // Add the plugin to register input deserializers
+        App::new().add_plugins(InputManagerPlugin::<Action>::default());
+        let serialized = ron::to_string(&menu_map.with_clash_strategy(ClashStrategy::PressAll))
+            .expect("input map should serialize");
+        let deserialized: InputMap<Action> = ron::from_str(&serialized).unwrap();
+        assert_eq!(deserialized.clash_strategy(), Some(ClashStrategy::PressAll));
     }
 
+    #[cfg(feature = "keyboard")]
     #[test]
-    fn multiple_insertion() {
+    fn action_processors() {
+        use crate::buttonlike::ButtonValue;
         use bevy::input::keyboard::KeyCode;
 
-        let mut input_map = InputMap::default();
-        input_map.insert(Action::Run, KeyCode::Space);
-        input_map.insert(Action::Run, KeyCode::Enter);
+        #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+        enum Action {
+            Down,
+            Save,
+            #[actionlike(Axis)]
+            Zoom,
+            #[actionlike(DualAxis)]
+            Move,
+        }
 
-        let expected: Vec<Box<dyn Buttonlike>> =
-            vec![Box::new(KeyCode::Space), Box::new(KeyCode::Enter)];
-        assert_eq!(input_map.get_buttonlike(&Action::Run), Some(&expected));
+        let input_map = InputMap::default()
+            .with(Action::Down, KeyCode::KeyS)
+            .with(
+                Action::Save,
+                ButtonlikeChord::new([KeyCode::ControlLeft, KeyCode::KeyS]),
+            )
+            .with_axis(Action::Zoom, VirtualAxis::ws())
+            .with_axis(Action::Zoom, VirtualAxis::vertical_arrow_keys())
+            .with_action_processor(Action::Zoom, AxisProcessor::Inverted)
+            .with_dual_axis(Action::Move, VirtualDPad::wasd())
+            .with_dual_axis(Action::Move, VirtualDPad::arrow_keys())
+            .with_action_processor(
+                Action::Move,
+                DualAxisProcessor::from(CircleBounds::new(1.0)),
+            );
+
+        assert_eq!(
+            input_map.axis_action_processors(&Action::Zoom),
+            &[AxisProcessor::Inverted]
+        );
+        assert!(input_map.axis_action_processors(&Action::Move).is_empty());
+
+        let mut input_store = CentralInputStore::default();
+        let pressed = ButtonValue::from_pressed(true);
+        for key in [
+            KeyCode::ControlLeft,
+            KeyCode::KeyS,
+            KeyCode::ArrowDown,
+            KeyCode::KeyD,
+            KeyCode::ArrowRight,
+        ] {
+            input_store.update_buttonlike(key, pressed);
+        }
+
+        let updated_actions =
+            input_map.process_actions(None, &input_store, ClashStrategy::PrioritizeLongest);
+
+        // Action-level processors are applied after the values of all inputs are summed
+        assert_eq!(
+            updated_actions.get(&Action::Zoom),
+            Some(&UpdatedValue::Axis(2.0))
+        );
+        assert_eq!(
+            updated_actions.get(&Action::Move),
+            Some(&UpdatedValue::DualAxis(Vec2::new(1.0, -1.0).normalize()))
+        );
+
+        // Clashes are still resolved using the raw input values
+        assert!(updated_actions.pressed(&Action::Save));
+        assert!(!updated_actions.pressed(&Action::Down));
+
+        // Without processors, the summed values are reported as is
+        let mut input_map = input_map;
+        input_map.clear_action_processors(&Action::Zoom);
+        input_map.clear_action_processors(&Action::Move);
+        let updated_actions =
+            input_map.process_actions(None, &input_store, ClashStrategy::PrioritizeLongest);
+        assert_eq!(
+            updated_actions.get(&Action::Zoom),
+            Some(&UpdatedValue::Axis(-2.0))
+        );
+        assert_eq!(
+            updated_actions.get(&Action::Move),
+            Some(&UpdatedValue::DualAxis(Vec2::new(2.0, -2.0)))
+        );
     }
 
     #[test]
-    fn input_clearing() {
+    fn merging_action_processors() {
+        let base = InputMap::default()
+            .with_action_processor(Action::Axis, AxisProcessor::Inverted)
+            .with_action_processor(
+                Action::DualAxis,
+                DualAxisProcessor::from(CircleBounds::new(1.0)),
+            );
+        let overlay = InputMap::default()
+            .with_action_processor(Action::Axis, AxisProcessor::Sensitivity(2.0))
+            .with_action_processor(
+                Action::DualAxis,
+                DualAxisProcessor::from(CircleDeadZone::new(0.1)),
+            );
+
+        // Existing processors are kept
+        let mut merged = base.clone();
+        merged.merge(&overlay);
+        assert_eq!(
+            merged.axis_action_processors(&Action::Axis),
+            &[AxisProcessor::Inverted]
+        );
+        assert_eq!(
+            merged.dual_axis_action_processors(&Action::DualAxis),
+            base.dual_axis_action_processors(&Action::DualAxis)
+        );
+
+        // Processors are taken from the other map for actions without any
+        let mut merged = InputMap::default().with_axis(Action::Axis, VirtualAxis::ad());
+        merged.merge(&overlay);
+        assert_eq!(
+            merged.axis_action_processors(&Action::Axis),
+            &[AxisProcessor::Sensitivity(2.0)]
+        );
+        assert_eq!(
+            merged.dual_axis_action_processors(&Action::DualAxis),
+            overlay.dual_axis_action_processors(&Action::DualAxis)
+        );
+
+        // Existing processors are replaced by the other map
+        let mut replaced = base.clone();
+        replaced.merge_replace(&overlay);
+        assert_eq!(
+            replaced.axis_action_processors(&Action::Axis),
+            &[AxisProcessor::Sensitivity(2.0)]
+        );
+        assert_eq!(
+            replaced.dual_axis_action_processors(&Action::DualAxis),
+            overlay.dual_axis_action_processors(&Action::DualAxis)
+        );
+
+        // Processors missing from the other map are left untouched
+        let mut replaced = base.clone();
+        replaced.merge_replace(&InputMap::default());
+        assert_eq!(replaced, base);
+    }
+
+    #[cfg(feature = "keyboard")]
+    #[test]
+    fn action_sensitivity() {
         use bevy::input::keyboard::KeyCode;
 
-        let mut input_map = InputMap::default();
-        input_map.insert(Action::Run, KeyCode::Space);
+        #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect, Serialize)]
+        enum Action {
+            Jump,
+            #[actionlike(Axis)]
+            Zoom,
+            #[actionlike(DualAxis)]
+            Look,
+        }
 
-        // Clearing action
-        input_map.clear_action(&Action::Run);
-        assert_eq!(input_map, InputMap::default());
+        let mut input_map = InputMap::default()
+            .with(Action::Jump, KeyCode::Space)
+            .with_axis(Action::Zoom, VirtualAxis::ws())
+            .with_dual_axis(Action::Look, VirtualDPad::wasd())
+            .with_action_processor(
+                Action::Look,
+                DualAxisProcessor::from(CircleBounds::new(1.0)),
+            );
 
-        // Remove input at existing index
-        input_map.insert(Action::Run, KeyCode::Space);
-        input_map.insert(Action::Run, KeyCode::ShiftLeft);
-        assert!(input_map.remove_at(&Action::Run, 1).is_some());
-        assert!(
-            input_map.remove_at(&Action::Run, 1).is_none(),
-            "Should return None on second removal at the same index"
+        let mut input_store = CentralInputStore::default();
+        input_store
+            .set_button(KeyCode::Space, 1.0)
+            .set_button(KeyCode::KeyW, 1.0)
+            .set_button(KeyCode::KeyD, 1.0);
+
+        let look = Vec2::new(1.0, 1.0).normalize();
+        let updated_actions =
+            input_map.process_actions(None, &input_store, ClashStrategy::PressAll);
+        assert_eq!(
+            updated_actions.get(&Action::Look),
+            Some(&UpdatedValue::DualAxis(look))
         );
-        assert!(input_map.remove_at(&Action::Run, 0).is_some());
-        assert!(
-            input_map.remove_at(&Action::Run, 0).is_none(),
-            "Should return None on second removal at the same index"
+
+        // The sensitivity is applied immediately, after the action processors
+        input_map.set_action_sensitivity(&Action::Look, 2.0);
+        input_map.set_action_sensitivity(&Action::Zoom, -0.5);
+        input_map.set_action_sensitivity(&Action::Jump, 3.0);
+        let updated_actions =
+            input_map.process_actions(None, &input_store, ClashStrategy::PressAll);
+        assert_eq!(
+            updated_actions.get(&Action::Look),
+            Some(&UpdatedValue::DualAxis(look * 2.0))
+        );
+        assert_eq!(
+            updated_actions.get(&Action::Zoom),
+            Some(&UpdatedValue::Axis(-0.5))
+        );
+        assert!(updated_actions.pressed(&Action::Jump));
+
+        // Changing it again doesn't require rebinding any inputs
+        input_map.set_action_sensitivity(&Action::Look, 0.5);
+        assert_eq!(input_map.action_sensitivity(&Action::Look), 0.5);
+        let updated_actions =
+            input_map.process_actions(None, &input_store, ClashStrategy::PressAll);
+        assert_eq!(
+            updated_actions.get(&Action::Look),
+            Some(&UpdatedValue::DualAxis(look * 0.5))
         );
+
+        // Resetting the sensitivity to 1.0 leaves the map as if it was never set
+        input_map.set_action_sensitivity(&Action::Look, 1.0);
+        input_map.set_action_sensitivity(&Action::Zoom, 1.0);
+        input_map.set_action_sensitivity(&Action::Jump, 1.0);
+        assert_eq!(
+            input_map,
+            InputMap::default()
+                .with(Action::Jump, KeyCode::Space)
+                .with_axis(Action::Zoom, VirtualAxis::ws())
+                .with_dual_axis(Action::Look, VirtualDPad::wasd())
+                .with_action_processor(
+                    Action::Look,
+                    DualAxisProcessor::from(CircleBounds::new(1.0)),
+                )
+        );
+
+        // Sensitivities are stored as plain scalars
+        input_map.set_action_sensitivity(&Action::Zoom, 0.25);
+        let serialized = ron::to_string(&input_map).unwrap();
+        assert!(serialized.contains("action_sensitivities:{Zoom:0.25}"));
     }
 
+    #[cfg(all(feature = "gamepad", feature = "keyboard"))]
     #[test]
-    fn merging() {
+    fn apply_stick_deadzone() {
         use bevy::input::keyboard::KeyCode;
 
-        let mut input_map = InputMap::default();
-        let mut default_keyboard_map = InputMap::default();
-        default_keyboard_map.insert(Action::Run, KeyCode::ShiftLeft);
-        default_keyboard_map.insert(
-            Action::Hide,
-            ButtonlikeChord::new([KeyCode::ControlLeft, KeyCode::KeyH]),
+        let mut input_map = InputMap::default()
+            .with(Action::Run, KeyCode::ShiftLeft)
+            .with_dual_axis(Action::DualAxis, GamepadStick::LEFT)
+            .with_dual_axis(Action::DualAxis, VirtualDPad::wasd())
+            .with_dual_axis(
+                Action::DualAxis,
+                DualAxislikeChord::new(KeyCode::AltLeft, GamepadStick::RIGHT),
+            );
+        let buttons = input_map.get_buttonlike(&Action::Run).cloned();
+
+        input_map.apply_stick_deadzone(&Action::DualAxis, CircleDeadZone::new(0.15));
+
+        // The dead zone is appended to each dual-axis input with a processing pipeline
+        let deadzone = DualAxisProcessor::from(CircleDeadZone::new(0.15));
+        let bindings = input_map.get_dual_axislike(&Action::DualAxis).unwrap();
+        assert_eq!(bindings.len(), 3);
+        assert_eq!(
+            bindings[0],
+            Box::new(GamepadStick::LEFT.with_processor(deadzone.clone())) as Box<dyn DualAxislike>
+        );
+        assert_eq!(
+            bindings[1],
+            Box::new(VirtualDPad::wasd().with_processor(deadzone)) as Box<dyn DualAxislike>
         );
 
-        let mut default_gamepad_map = InputMap::default();
-        default_gamepad_map.insert(Action::Run, KeyCode::Numpad0);
-        default_gamepad_map.insert(Action::Hide, KeyCode::Numpad7);
+        // Inputs without a processing pipeline are left untouched
+        assert_eq!(
+            bindings[2],
+            Box::new(DualAxislikeChord::new(
+                KeyCode::AltLeft,
+                GamepadStick::RIGHT
+            )) as Box<dyn DualAxislike>
+        );
 
-        // Merging works
-        input_map.merge(&default_keyboard_map);
-        assert_eq!(input_map, default_keyboard_map);
+        // As are bindings of other kinds
+        assert_eq!(input_map.get_buttonlike(&Action::Run).cloned(), buttons);
+        input_map.apply_stick_deadzone(&Action::Run, CircleDeadZone::new(0.15));
+        assert_eq!(input_map.get_buttonlike(&Action::Run).cloned(), buttons);
 
-        // Merging is idempotent
-        input_map.merge(&default_keyboard_map);
-        assert_eq!(input_map, default_keyboard_map);
+        // Presets append a dead zone of the corresponding size
+        let input_map = InputMap::default()
+            .with_dual_axis(Action::DualAxis, GamepadStick::LEFT)
+            .with_deadzone_preset(Action::DualAxis, DeadZonePreset::Loose);
+        assert_eq!(
+            input_map.get_dual_axislike(&Action::DualAxis).unwrap()[0],
+            Box::new(GamepadStick::LEFT.with_circle_deadzone(0.2)) as Box<dyn DualAxislike>
+        );
     }
 
     #[cfg(feature = "gamepad")]
@@ -1108,6 +4797,148 @@ mod tests {
         assert_eq!(input_map.gamepad(), None);
     }
 
+    #[cfg(all(feature = "gamepad", feature = "mouse"))]
+    #[test]
+    fn from_config() {
+        use bevy::prelude::{GamepadButton, KeyCode, MouseButton};
+
+        let mut config = HashMap::default();
+        config.insert(
+            Action::Run,
+            vec![
+                InputBinding::Key(KeyCode::ShiftLeft),
+                InputBinding::GamepadButton(GamepadButton::LeftTrigger),
+                // Duplicate bindings should be ignored
+                InputBinding::Key(KeyCode::ShiftLeft),
+            ],
+        );
+        config.insert(
+            Action::Jump,
+            vec![
+                InputBinding::MouseButton(MouseButton::Left),
+                InputBinding::Modifier(ModifierKey::Control),
+            ],
+        );
+        config.insert(
+            Action::Axis,
+            vec![
+                InputBinding::KeyAxis {
+                    negative: KeyCode::KeyA,
+                    positive: KeyCode::KeyD,
+                },
+                InputBinding::GamepadControlAxis(GamepadControlAxis::LEFT_X),
+                InputBinding::MouseScrollAxis(MouseScrollAxis::Y),
+            ],
+        );
+        config.insert(
+            Action::DualAxis,
+            vec![
+                InputBinding::KeyDPad {
+                    up: KeyCode::KeyW,
+                    down: KeyCode::KeyS,
+                    left: KeyCode::KeyA,
+                    right: KeyCode::KeyD,
+                },
+                InputBinding::GamepadStick(GamepadStick::LEFT),
+                InputBinding::MouseMove(MouseMove::default()),
+            ],
+        );
+
+        let expected = InputMap::default()
+            .with(Action::Run, KeyCode::ShiftLeft)
+            .with(Action::Run, GamepadButton::LeftTrigger)
+            .with(Action::Jump, MouseButton::Left)
+            .with(Action::Jump, ModifierKey::Control)
+            .with_axis(Action::Axis, VirtualAxis::ad())
+            .with_axis(Action::Axis, GamepadControlAxis::LEFT_X)
+            .with_axis(Action::Axis, MouseScrollAxis::Y)
+            .with_dual_axis(Action::DualAxis, VirtualDPad::wasd())
+            .with_dual_axis(Action::DualAxis, GamepadStick::LEFT)
+            .with_dual_axis(Action::DualAxis, MouseMove::default());
+
+        assert_eq!(InputMap::from_config(config.clone()), expected);
+
+        // The config round-trips through serialization without registering any input types
+        let serialized = ron::to_string(&config).unwrap();
+        let deserialized: HashMap<Action, Vec<InputBinding>> = ron::from_str(&serialized).unwrap();
+        assert_eq!(InputMap::from_config(deserialized), expected);
+    }
+
+    #[cfg(all(feature = "gamepad", feature = "mouse"))]
+    #[test]
+    fn duplicate_for_gamepad() {
+        use bevy::prelude::{GamepadButton, KeyCode, MouseButton};
+
+        let second_gamepad = Entity::from_raw(123);
+        let mut input_map = InputMap::default()
+            .with(Action::Run, KeyCode::ShiftLeft)
+            .with(Action::Run, GamepadButton::LeftTrigger)
+            .with(Action::Jump, MouseButton::Left)
+            .with(
+                Action::Jump,
+                ButtonlikeChord::new([KeyCode::KeyJ, KeyCode::KeyK]),
+            )
+            .with(
+                Action::Hide,
+                PreferredDeviceButton::new(GamepadButton::East, KeyCode::KeyH),
+            )
+            .with_axis(Action::Axis, VirtualAxis::ad())
+            .with_axis(Action::Axis, GamepadControlAxis::LEFT_X)
+            .with_dual_axis(Action::DualAxis, MouseMove::default())
+            .with_dual_axis(Action::DualAxis, GamepadStick::LEFT)
+            .with_player(PlayerIndex(0));
+        input_map.set_binding_source(
+            &Action::Run,
+            UserInputWrapper::Button(Box::new(KeyCode::ShiftLeft)),
+            BindingSource::User,
+        );
+
+        // Every binding is copied, and the copy reads the given gamepad
+        let mut duplicate = input_map.duplicate_for_gamepad(second_gamepad);
+        assert_eq!(duplicate.gamepad(), Some(second_gamepad));
+        assert_eq!(duplicate.player(), None);
+        assert_eq!(input_map.gamepad(), None);
+        for action in Action::variants() {
+            assert_eq!(duplicate.get(&action), input_map.get(&action));
+        }
+
+        // Keyboard and mouse bindings can be stripped from the copy
+        duplicate.clear_keyboard_and_mouse();
+        assert_eq!(duplicate.gamepad(), Some(second_gamepad));
+        assert_eq!(
+            duplicate.get(&Action::Run),
+            Some(vec![UserInputWrapper::Button(Box::new(
+                GamepadButton::LeftTrigger
+            ))])
+        );
+        assert_eq!(duplicate.get(&Action::Jump), Some(Vec::new()));
+        assert_eq!(
+            duplicate.get(&Action::Hide),
+            Some(vec![UserInputWrapper::Button(Box::new(
+                PreferredDeviceButton::new(GamepadButton::East, KeyCode::KeyH)
+            ))])
+        );
+        assert_eq!(
+            duplicate.get(&Action::Axis),
+            Some(vec![UserInputWrapper::Axis(Box::new(
+                GamepadControlAxis::LEFT_X
+            ))])
+        );
+        assert_eq!(
+            duplicate.get(&Action::DualAxis),
+            Some(vec![UserInputWrapper::DualAxis(Box::new(
+                GamepadStick::LEFT
+            ))])
+        );
+        assert_eq!(
+            duplicate.binding_source(
+                &Action::Run,
+                &UserInputWrapper::Button(Box::new(KeyCode::ShiftLeft))
+            ),
+            BindingSource::Default
+        );
+    }
+
     #[cfg(feature = "keyboard")]
     #[test]
     fn input_map_serde() {
@@ -0,0 +1,32 @@
+//! Processors applied at the action level, rather than to individual inputs
+
+use bevy::prelude::Reflect;
+use serde::{Deserialize, Serialize};
+
+use super::{AxisProcessor, DualAxisProcessor};
+
+/// A processor applied to the combined value of all the inputs bound to an action,
+/// attached using [`InputMap::with_action_processor`](crate::input_map::InputMap::with_action_processor).
+///
+/// The kind of processor must match the kind of the action it is attached to.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+#[must_use]
+pub enum ActionProcessor {
+    /// Processes the value of an [`Axislike`](crate::user_input::Axislike) action.
+    Axis(AxisProcessor),
+
+    /// Processes the value of a [`DualAxislike`](crate::user_input::DualAxislike) action.
+    DualAxis(DualAxisProcessor),
+}
+
+impl From<AxisProcessor> for ActionProcessor {
+    fn from(value: AxisProcessor) -> Self {
+        Self::Axis(value)
+    }
+}
+
+impl From<DualAxisProcessor> for ActionProcessor {
+    fn from(value: DualAxisProcessor) -> Self {
+        Self::DualAxis(value)
+    }
+}
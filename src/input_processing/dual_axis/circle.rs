@@ -10,6 +10,7 @@ use bevy::{
 use serde::{Deserialize, Serialize};
 
 use super::DualAxisProcessor;
+use crate::input_processing::ProcessorError;
 
 /// Specifies a circular region defining acceptable ranges for valid dual-axis inputs,
 /// with a radius defining the maximum threshold magnitude,
@@ -61,6 +62,21 @@ impl CircleBounds {
         Self { radius: max }
     }
 
+    /// Creates a [`CircleBounds`] that restricts input values to a maximum magnitude,
+    /// like [`CircleBounds::new`] but without panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProcessorError::NegativeRadius`] if `max` is negative or NaN.
+    #[inline]
+    pub fn try_new(max: f32) -> Result<Self, ProcessorError> {
+        if max >= 0.0 {
+            Ok(Self::new(max))
+        } else {
+            Err(ProcessorError::NegativeRadius(max))
+        }
+    }
+
     /// Returns the radius of the bounds.
     #[must_use]
     #[inline]
@@ -165,6 +181,21 @@ impl CircleExclusion {
         }
     }
 
+    /// Creates a [`CircleExclusion`] that ignores input values below a minimum magnitude,
+    /// like [`CircleExclusion::new`] but without panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProcessorError::NegativeRadius`] if `threshold` is negative or NaN.
+    #[inline]
+    pub fn try_new(threshold: f32) -> Result<Self, ProcessorError> {
+        if threshold >= 0.0 {
+            Ok(Self::new(threshold))
+        } else {
+            Err(ProcessorError::NegativeRadius(threshold))
+        }
+    }
+
     /// Returns the radius of the circle.
     #[must_use]
     #[inline]
@@ -268,7 +299,8 @@ impl Hash for CircleExclusion {
 ///     }
 /// }
 /// ```
-#[doc(alias = "RadialDeadZone")]
+///
+/// To apply a response curve within the live zone, use a [`RadialDeadZone`] instead.
 #[derive(Debug, Clone, Copy, PartialEq, Reflect, Serialize, Deserialize)]
 #[must_use]
 pub struct CircleDeadZone {
@@ -299,6 +331,7 @@ impl CircleDeadZone {
     #[doc(alias = "from_radius")]
     #[inline]
     pub fn new(threshold: f32) -> Self {
+        assert!(threshold >= 0.0);
         let bounds = CircleBounds::default();
         Self {
             radius: threshold,
@@ -306,6 +339,21 @@ impl CircleDeadZone {
         }
     }
 
+    /// Creates a [`CircleDeadZone`] that excludes input values below a minimum magnitude,
+    /// like [`CircleDeadZone::new`] but without panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProcessorError::NegativeRadius`] if `threshold` is negative or NaN.
+    #[inline]
+    pub fn try_new(threshold: f32) -> Result<Self, ProcessorError> {
+        if threshold >= 0.0 {
+            Ok(Self::new(threshold))
+        } else {
+            Err(ProcessorError::NegativeRadius(threshold))
+        }
+    }
+
     /// Returns the radius of the circle.
     #[must_use]
     #[inline]
@@ -398,6 +446,306 @@ impl Hash for CircleDeadZone {
     }
 }
 
+/// Standard sizes of [`CircleDeadZone`] for gamepad sticks,
+/// avoiding the need to pick a radius by hand.
+///
+/// Apply a preset to every stick bound to an action using
+/// [`InputMap::with_deadzone_preset`](crate::input_map::InputMap::with_deadzone_preset).
+///
+/// ```rust
+/// use leafwing_input_manager::prelude::*;
+///
+/// assert_eq!(DeadZonePreset::default(), DeadZonePreset::Standard);
+/// assert_eq!(DeadZonePreset::Standard.radius(), 0.1);
+/// assert_eq!(CircleDeadZone::from(DeadZonePreset::Loose), CircleDeadZone::new(0.2));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+pub enum DeadZonePreset {
+    /// A dead zone with a radius of `0.05`, for precise, well-calibrated sticks.
+    Tight,
+
+    /// A dead zone with a radius of `0.1`, suitable for most gamepads.
+    #[default]
+    Standard,
+
+    /// A dead zone with a radius of `0.2`, for worn-out sticks that drift.
+    Loose,
+}
+
+impl DeadZonePreset {
+    /// Returns the radius of the [`CircleDeadZone`] used by this preset.
+    #[must_use]
+    #[inline]
+    pub const fn radius(&self) -> f32 {
+        match self {
+            Self::Tight => 0.05,
+            Self::Standard => 0.1,
+            Self::Loose => 0.2,
+        }
+    }
+}
+
+impl From<DeadZonePreset> for CircleDeadZone {
+    fn from(value: DeadZonePreset) -> Self {
+        CircleDeadZone::new(value.radius())
+    }
+}
+
+impl From<DeadZonePreset> for DualAxisProcessor {
+    fn from(value: DeadZonePreset) -> Self {
+        Self::CircleDeadZone(value.into())
+    }
+}
+
+/// A [`CircleDeadZone`] that applies a power curve to the magnitude of values within the "live zone".
+///
+/// After excluding values within the dead zone and normalizing the rest into the live zone,
+/// the normalized magnitude is raised to the power of the `exponent` before re-scaling the input value.
+/// Exponents greater than `1.0` give finer control over small deflections,
+/// while exponents less than `1.0` make the response more sensitive.
+///
+/// With an `exponent` of `1.0`, this behaves exactly like a [`CircleDeadZone`].
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::prelude::*;
+///
+/// // Exclude magnitudes less than or equal to 0.2, squaring the live zone magnitude
+/// let deadzone = RadialDeadZone::new(0.2, 2.0);
+///
+/// // Values within the dead zone are treated as zeros.
+/// assert_eq!(deadzone.normalize(Vec2::new(0.1, 0.0)), Vec2::ZERO);
+///
+/// // Small deflections are attenuated more than with a linear dead zone...
+/// let small = Vec2::new(0.4, 0.0);
+/// assert!((deadzone.normalize(small) - Vec2::new(0.0625, 0.0)).length() <= 0.00001);
+/// assert!((CircleDeadZone::new(0.2).normalize(small) - Vec2::new(0.25, 0.0)).length() <= 0.00001);
+///
+/// // ...while the direction is preserved and full deflection still reaches a magnitude of 1.0.
+/// let full = Vec2::new(0.0, -1.0);
+/// assert!((deadzone.normalize(full) - full).length() <= 0.00001);
+///
+/// // Values outside the bounds are restricted to the region.
+/// assert!((deadzone.normalize(Vec2::new(3.0, 4.0)) - Vec2::new(0.6, 0.8)).length() <= 0.00001);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Reflect, Serialize, Deserialize)]
+#[serde(try_from = "UncheckedRadialDeadZone")]
+#[must_use]
+pub struct RadialDeadZone {
+    /// The linear dead zone that is applied before the curve.
+    pub(crate) deadzone: CircleDeadZone,
+
+    /// The exponent applied to the normalized live zone magnitude.
+    pub(crate) exponent: f32,
+}
+
+/// The serialized form of a [`RadialDeadZone`], validated before being converted into one.
+#[derive(Deserialize)]
+#[serde(rename = "RadialDeadZone")]
+struct UncheckedRadialDeadZone {
+    deadzone: CircleDeadZone,
+    exponent: f32,
+}
+
+impl TryFrom<UncheckedRadialDeadZone> for RadialDeadZone {
+    type Error = &'static str;
+
+    fn try_from(
+        UncheckedRadialDeadZone { deadzone, exponent }: UncheckedRadialDeadZone,
+    ) -> Result<Self, Self::Error> {
+        if exponent.is_nan() || exponent <= 0.0 {
+            return Err("a RadialDeadZone requires an exponent greater than 0.0");
+        }
+        Ok(Self { deadzone, exponent })
+    }
+}
+
+impl RadialDeadZone {
+    /// Creates a [`RadialDeadZone`] that excludes input values below a minimum magnitude,
+    /// raising the normalized live zone magnitude to the power of the `exponent`.
+    ///
+    /// # Requirements
+    ///
+    /// - `threshold` >= `0.0`.
+    /// - `exponent` > `0.0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the requirements aren't met.
+    #[inline]
+    pub fn new(threshold: f32, exponent: f32) -> Self {
+        assert!(exponent > 0.0);
+        Self {
+            deadzone: CircleDeadZone::new(threshold),
+            exponent,
+        }
+    }
+
+    /// Returns the radius of the circle.
+    #[must_use]
+    #[inline]
+    pub fn radius(&self) -> f32 {
+        self.deadzone.radius()
+    }
+
+    /// Returns the exponent applied to the normalized live zone magnitude.
+    #[must_use]
+    #[inline]
+    pub fn exponent(&self) -> f32 {
+        self.exponent
+    }
+
+    /// Returns the linear [`CircleDeadZone`] that is applied before the curve.
+    #[inline]
+    pub fn linear(&self) -> CircleDeadZone {
+        self.deadzone
+    }
+
+    /// Normalizes input values into the live zone, and then applies the curve to their magnitude.
+    #[must_use]
+    pub fn normalize(&self, input_value: Vec2) -> Vec2 {
+        let normalized = self.deadzone.normalize(input_value);
+        let magnitude = normalized.length();
+        if magnitude == 0.0 {
+            return Vec2::ZERO;
+        }
+
+        normalized * (magnitude.powf(self.exponent) / magnitude)
+    }
+}
+
+impl Default for RadialDeadZone {
+    /// Creates a [`RadialDeadZone`] that excludes input values below a minimum magnitude of `0.1`,
+    /// squaring the normalized live zone magnitude.
+    #[inline]
+    fn default() -> Self {
+        RadialDeadZone::new(0.1, 2.0)
+    }
+}
+
+impl From<RadialDeadZone> for DualAxisProcessor {
+    fn from(value: RadialDeadZone) -> Self {
+        Self::RadialDeadZone(value)
+    }
+}
+
+impl Eq for RadialDeadZone {}
+
+impl Hash for RadialDeadZone {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.deadzone.hash(state);
+        FloatOrd(self.exponent).hash(state);
+    }
+}
+
+/// Boosts the magnitude of non-zero dual-axis inputs to at least `min_output`,
+/// counteracting the built-in dead zone of some hardware or games
+/// so that motion starts as soon as the stick leaves its rest position.
+///
+/// Input values with a magnitude within `(0.0, 1.0]` are linearly remapped into the range `(min_output, 1.0]`,
+/// preserving their direction.
+/// Zero input values remain zero, and input values with a magnitude beyond `1.0` are left unchanged.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::prelude::*;
+///
+/// let anti_deadzone = AntiDeadzone::new(0.2);
+///
+/// // Tiny inputs are boosted to the minimum output
+/// let boosted = anti_deadzone.boost(Vec2::new(0.0001, 0.0));
+/// assert!((boosted.length() - 0.2).abs() <= 0.001);
+///
+/// // Zero inputs remain zero, and full inputs are unchanged
+/// assert_eq!(anti_deadzone.boost(Vec2::ZERO), Vec2::ZERO);
+/// assert_eq!(anti_deadzone.boost(Vec2::Y), Vec2::Y);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Reflect, Serialize, Deserialize)]
+#[serde(try_from = "UncheckedAntiDeadzone")]
+#[must_use]
+pub struct AntiDeadzone {
+    /// The minimum magnitude of non-zero output values.
+    pub(crate) min_output: f32,
+}
+
+/// The serialized form of an [`AntiDeadzone`], validated before being converted into one.
+#[derive(Deserialize)]
+#[serde(rename = "AntiDeadzone")]
+struct UncheckedAntiDeadzone {
+    min_output: f32,
+}
+
+impl TryFrom<UncheckedAntiDeadzone> for AntiDeadzone {
+    type Error = &'static str;
+
+    fn try_from(
+        UncheckedAntiDeadzone { min_output }: UncheckedAntiDeadzone,
+    ) -> Result<Self, Self::Error> {
+        if !(0.0..1.0).contains(&min_output) {
+            return Err("an AntiDeadzone requires a min_output within [0.0, 1.0)");
+        }
+        Ok(Self { min_output })
+    }
+}
+
+impl AntiDeadzone {
+    /// Creates an [`AntiDeadzone`] that boosts the magnitude of non-zero input values to at least `min_output`.
+    ///
+    /// # Requirements
+    ///
+    /// - `0.0` <= `min_output` < `1.0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the requirements aren't met.
+    #[inline]
+    pub fn new(min_output: f32) -> Self {
+        assert!((0.0..1.0).contains(&min_output));
+        Self { min_output }
+    }
+
+    /// Returns the minimum magnitude of non-zero output values.
+    #[must_use]
+    #[inline]
+    pub fn min_output(&self) -> f32 {
+        self.min_output
+    }
+
+    /// Remaps the magnitude of non-zero input values within `(0.0, 1.0]` into `(min_output, 1.0]`.
+    #[must_use]
+    pub fn boost(&self, input_value: Vec2) -> Vec2 {
+        let magnitude = input_value.length();
+        if magnitude == 0.0 || magnitude >= 1.0 {
+            return input_value;
+        }
+
+        let boosted = self.min_output + (1.0 - self.min_output) * magnitude;
+        input_value * (boosted / magnitude)
+    }
+}
+
+impl Default for AntiDeadzone {
+    /// Creates an [`AntiDeadzone`] that boosts the magnitude of non-zero input values to at least `0.1`.
+    #[inline]
+    fn default() -> Self {
+        AntiDeadzone::new(0.1)
+    }
+}
+
+impl From<AntiDeadzone> for DualAxisProcessor {
+    fn from(value: AntiDeadzone) -> Self {
+        Self::AntiDeadzone(value)
+    }
+}
+
+impl Eq for AntiDeadzone {}
+
+impl Hash for AntiDeadzone {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        FloatOrd(self.min_output).hash(state);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -536,4 +884,196 @@ mod tests {
         let deadzone = CircleDeadZone::new(0.5);
         test_deadzone(deadzone, 0.5);
     }
+
+    #[test]
+    fn test_circle_try_new() {
+        assert_eq!(CircleBounds::try_new(2.0), Ok(CircleBounds::new(2.0)));
+        assert_eq!(CircleExclusion::try_new(0.0), Ok(CircleExclusion::new(0.0)));
+        assert_eq!(CircleDeadZone::try_new(0.1), Ok(CircleDeadZone::new(0.1)));
+
+        // Negative radii are reported instead of panicking
+        assert_eq!(
+            CircleBounds::try_new(-1.0),
+            Err(ProcessorError::NegativeRadius(-1.0))
+        );
+        assert_eq!(
+            CircleExclusion::try_new(-0.1),
+            Err(ProcessorError::NegativeRadius(-0.1))
+        );
+        assert_eq!(
+            CircleDeadZone::try_new(-0.1),
+            Err(ProcessorError::NegativeRadius(-0.1))
+        );
+        assert!(matches!(
+            CircleDeadZone::try_new(f32::NAN),
+            Err(ProcessorError::NegativeRadius(radius)) if radius.is_nan()
+        ));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_circle_deadzone_negative_radius() {
+        let _ = CircleDeadZone::new(-0.1);
+    }
+
+    #[test]
+    fn test_radial_deadzone() {
+        fn test_deadzone(deadzone: RadialDeadZone, radius: f32, exponent: f32) {
+            assert_eq!(deadzone.radius(), radius);
+            assert_eq!(deadzone.exponent(), exponent);
+            assert_eq!(deadzone.linear(), CircleDeadZone::new(radius));
+
+            let processor = DualAxisProcessor::RadialDeadZone(deadzone);
+            assert_eq!(DualAxisProcessor::from(deadzone), processor);
+
+            for x in -300..300 {
+                let x = x as f32 * 0.01;
+                for y in -300..300 {
+                    let y = y as f32 * 0.01;
+                    let value = Vec2::new(x, y);
+
+                    assert_eq!(processor.process(value), deadzone.normalize(value));
+
+                    // Values within the dead zone are treated as zeros.
+                    if value.length() <= radius {
+                        assert_eq!(deadzone.normalize(value), Vec2::ZERO);
+                    }
+                    // Values within the live zone are scaled along the curve.
+                    else if value.length() <= 1.0 {
+                        let expected_scale =
+                            f32::inverse_lerp(radius, 1.0, value.length()).powf(exponent);
+                        let expected = value.normalize() * expected_scale;
+                        let delta = (deadzone.normalize(value) - expected).abs();
+                        assert!(delta.x <= 0.00001);
+                        assert!(delta.y <= 0.00001);
+                    }
+                    // Values outside the bounds are restricted to the region.
+                    else {
+                        let expected = value.clamp_length_max(1.0);
+                        let delta = (deadzone.normalize(value) - expected).abs();
+                        assert!(delta.x <= 0.00001);
+                        assert!(delta.y <= 0.00001);
+                    }
+                }
+            }
+        }
+
+        let deadzone = RadialDeadZone::default();
+        test_deadzone(deadzone, 0.1, 2.0);
+
+        let deadzone = RadialDeadZone::new(0.2, 0.5);
+        test_deadzone(deadzone, 0.2, 0.5);
+
+        // An exponent of 1.0 matches the linear dead zone
+        let deadzone = RadialDeadZone::new(0.3, 1.0);
+        test_deadzone(deadzone, 0.3, 1.0);
+        let linear = CircleDeadZone::new(0.3);
+        for x in -30..30 {
+            for y in -30..30 {
+                let value = Vec2::new(x as f32, y as f32) * 0.1;
+                let delta = (deadzone.normalize(value) - linear.normalize(value)).abs();
+                assert!(delta.x <= 0.00001);
+                assert!(delta.y <= 0.00001);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_radial_deadzone_non_positive_exponent() {
+        let _ = RadialDeadZone::new(0.1, 0.0);
+    }
+
+    #[test]
+    fn test_radial_deadzone_deserialization_is_validated() {
+        let deadzone = RadialDeadZone::new(0.2, 0.5);
+        let serialized = ron::to_string(&deadzone).unwrap();
+        assert_eq!(
+            ron::from_str::<RadialDeadZone>(&serialized).unwrap(),
+            deadzone
+        );
+
+        for exponent in [0.0, -1.0, f32::NAN] {
+            let invalid = RadialDeadZone {
+                deadzone: CircleDeadZone::new(0.2),
+                exponent,
+            };
+            let serialized = ron::to_string(&invalid).unwrap();
+            assert!(ron::from_str::<RadialDeadZone>(&serialized).is_err());
+        }
+    }
+
+    #[test]
+    fn test_anti_deadzone() {
+        fn test_anti_deadzone(anti_deadzone: AntiDeadzone, min_output: f32) {
+            assert_eq!(anti_deadzone.min_output(), min_output);
+
+            let processor = DualAxisProcessor::AntiDeadzone(anti_deadzone);
+            assert_eq!(DualAxisProcessor::from(anti_deadzone), processor);
+
+            for x in -300..300 {
+                let x = x as f32 * 0.01;
+                for y in -300..300 {
+                    let y = y as f32 * 0.01;
+                    let value = Vec2::new(x, y);
+
+                    assert_eq!(processor.process(value), anti_deadzone.boost(value));
+
+                    // Zeros remain zeros
+                    if value == Vec2::ZERO {
+                        assert_eq!(anti_deadzone.boost(value), Vec2::ZERO);
+                    }
+                    // Values within the unit circle are remapped above the minimum output
+                    else if value.length() < 1.0 {
+                        let expected =
+                            value.normalize() * (min_output + (1.0 - min_output) * value.length());
+                        let delta = (anti_deadzone.boost(value) - expected).abs();
+                        assert!(delta.x <= 0.00001);
+                        assert!(delta.y <= 0.00001);
+                        assert!(anti_deadzone.boost(value).length() >= min_output);
+                    }
+                    // Values outside the unit circle are unchanged
+                    else {
+                        assert_eq!(anti_deadzone.boost(value), value);
+                    }
+                }
+            }
+
+            // Tiny inputs are boosted to the minimum output
+            let boosted = anti_deadzone.boost(Vec2::new(0.0, -0.000001));
+            assert!((boosted.length() - min_output).abs() <= 0.00001);
+            assert!(boosted.x == 0.0 && boosted.y < 0.0);
+        }
+
+        let anti_deadzone = AntiDeadzone::default();
+        test_anti_deadzone(anti_deadzone, 0.1);
+
+        let anti_deadzone = AntiDeadzone::new(0.25);
+        test_anti_deadzone(anti_deadzone, 0.25);
+
+        let anti_deadzone = AntiDeadzone::new(0.0);
+        test_anti_deadzone(anti_deadzone, 0.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_anti_deadzone_out_of_range_min_output() {
+        let _ = AntiDeadzone::new(1.0);
+    }
+
+    #[test]
+    fn test_anti_deadzone_deserialization_is_validated() {
+        let anti_deadzone = AntiDeadzone::new(0.25);
+        let serialized = ron::to_string(&anti_deadzone).unwrap();
+        assert_eq!(
+            ron::from_str::<AntiDeadzone>(&serialized).unwrap(),
+            anti_deadzone
+        );
+
+        for min_output in [1.0, 2.0, -0.1, f32::NAN] {
+            let invalid = AntiDeadzone { min_output };
+            let serialized = ron::to_string(&invalid).unwrap();
+            assert!(ron::from_str::<AntiDeadzone>(&serialized).is_err());
+        }
+    }
 }
@@ -1,5 +1,6 @@
 //! Processors for dual-axis input values
 
+use std::f32::consts::TAU;
 use std::hash::{Hash, Hasher};
 
 use bevy::{
@@ -55,6 +56,9 @@ pub enum DualAxisProcessor {
     /// A wrapper around [`DualAxisSensitivity`] to represent sensitivity.
     Sensitivity(DualAxisSensitivity),
 
+    /// A wrapper around [`SnapToDirections`] to represent snapping to evenly-spaced directions.
+    SnapToDirections(SnapToDirections),
+
     /// A wrapper around [`DualAxisBounds`] to represent value bounds.
     ValueBounds(DualAxisBounds),
 
@@ -73,6 +77,12 @@ pub enum DualAxisProcessor {
     /// A wrapper around [`CircleDeadZone`] to represent scaled deadzone.
     CircleDeadZone(CircleDeadZone),
 
+    /// A wrapper around [`RadialDeadZone`] to represent scaled deadzone with a response curve.
+    RadialDeadZone(RadialDeadZone),
+
+    /// A wrapper around [`AntiDeadzone`] to represent an inner boost of small values.
+    AntiDeadzone(AntiDeadzone),
+
     /// A user-defined processor that implements [`CustomDualAxisProcessor`].
     Custom(Box<dyn CustomDualAxisProcessor>),
 }
@@ -89,12 +99,15 @@ impl DualAxisProcessor {
             ),
             Self::Inverted(inversion) => inversion.invert(input_value),
             Self::Sensitivity(sensitivity) => sensitivity.scale(input_value),
+            Self::SnapToDirections(snap) => snap.snap(input_value),
             Self::ValueBounds(bounds) => bounds.clamp(input_value),
             Self::Exclusion(exclusion) => exclusion.exclude(input_value),
             Self::DeadZone(deadzone) => deadzone.normalize(input_value),
             Self::CircleBounds(bounds) => bounds.clamp(input_value),
             Self::CircleExclusion(exclusion) => exclusion.exclude(input_value),
             Self::CircleDeadZone(deadzone) => deadzone.normalize(input_value),
+            Self::RadialDeadZone(deadzone) => deadzone.normalize(input_value),
+            Self::AntiDeadzone(anti_deadzone) => anti_deadzone.boost(input_value),
             Self::Custom(processor) => processor.process(input_value),
         }
     }
@@ -163,6 +176,22 @@ pub trait WithDualAxisProcessingPipelineExt: Sized {
         self.with_processor(DualAxisSensitivity::only_y(sensitivity))
     }
 
+    /// Appends a [`SnapToDirections`] processor as the next processing step,
+    /// rounding the direction of input values to the nearest of the given number of evenly-spaced `directions`,
+    /// while preserving their magnitude.
+    ///
+    /// # Requirements
+    ///
+    /// - `directions` >= `1`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the requirements aren't met.
+    #[inline]
+    fn with_snap_to_directions(self, directions: u8) -> Self {
+        self.with_processor(SnapToDirections::new(directions))
+    }
+
     /// Appends a [`DualAxisBounds`] processor as the next processing step,
     /// restricting values within the same range `[min, max]` on both axes.
     #[inline]
@@ -484,6 +513,40 @@ pub trait WithDualAxisProcessingPipelineExt: Sized {
         self.with_processor(CircleDeadZone::new(min))
     }
 
+    /// Appends a [`RadialDeadZone`] processor as the next processing step,
+    /// ignoring values below a `min` magnitude, treating them as zeros,
+    /// then normalizing non-excluded input values into the "live zone"
+    /// and raising their normalized magnitude to the power of the `exponent`.
+    ///
+    /// # Requirements
+    ///
+    /// - `min` >= `0.0`.
+    /// - `exponent` > `0.0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the requirements aren't met.
+    #[inline]
+    fn with_radial_deadzone(self, min: f32, exponent: f32) -> Self {
+        self.with_processor(RadialDeadZone::new(min, exponent))
+    }
+
+    /// Appends an [`AntiDeadzone`] processor as the next processing step,
+    /// boosting the magnitude of non-zero input values to at least `min_output`,
+    /// so that small movements produce a response immediately.
+    ///
+    /// # Requirements
+    ///
+    /// - `0.0` <= `min_output` < `1.0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the requirements aren't met.
+    #[inline]
+    fn with_anti_deadzone(self, min_output: f32) -> Self {
+        self.with_processor(AntiDeadzone::new(min_output))
+    }
+
     /// Appends a [`DualAxisExclusion`] processor as the next processing step,
     /// ignoring values within the range `[negative_max, positive_min]` on both axes,
     /// treating them as zeros.
@@ -840,6 +903,103 @@ impl Hash for DualAxisSensitivity {
     }
 }
 
+/// Snaps the direction of dual-axis input values to the nearest of `N` evenly-spaced directions,
+/// while preserving their magnitude.
+///
+/// The first direction always points along the positive X-axis,
+/// and the rest are spaced evenly counterclockwise around the circle.
+/// Zero-length input values are left as zeros.
+///
+/// ```rust
+/// use std::f32::consts::FRAC_PI_4;
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::prelude::*;
+///
+/// // Snap to the cardinal and diagonal directions
+/// let snap = SnapToDirections::new(8);
+/// assert_eq!(snap.directions(), 8);
+///
+/// // 40° rounds to 45°, keeping the magnitude of 0.5
+/// let value = Vec2::from_angle(40f32.to_radians()) * 0.5;
+/// let snapped = snap.snap(value);
+/// assert!((snapped - Vec2::from_angle(FRAC_PI_4) * 0.5).length() <= 0.00001);
+///
+/// // Zero-length values stay zero
+/// assert_eq!(snap.snap(Vec2::ZERO), Vec2::ZERO);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+#[serde(try_from = "UncheckedSnapToDirections")]
+#[must_use]
+pub struct SnapToDirections(u8);
+
+/// The serialized form of a [`SnapToDirections`], validated before being converted into one.
+#[derive(Deserialize)]
+#[serde(rename = "SnapToDirections")]
+struct UncheckedSnapToDirections(u8);
+
+impl TryFrom<UncheckedSnapToDirections> for SnapToDirections {
+    type Error = &'static str;
+
+    fn try_from(
+        UncheckedSnapToDirections(directions): UncheckedSnapToDirections,
+    ) -> Result<Self, Self::Error> {
+        if directions == 0 {
+            return Err("a SnapToDirections requires at least one direction");
+        }
+        Ok(Self(directions))
+    }
+}
+
+impl SnapToDirections {
+    /// Snaps to the four cardinal directions: right, up, left and down.
+    pub const FOUR_WAY: Self = Self(4);
+
+    /// Snaps to the four cardinal directions and the four diagonals between them.
+    pub const EIGHT_WAY: Self = Self(8);
+
+    /// Creates a [`SnapToDirections`] that snaps to the given number of evenly-spaced `directions`.
+    ///
+    /// # Requirements
+    ///
+    /// - `directions` >= `1`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the requirements aren't met.
+    #[inline]
+    pub fn new(directions: u8) -> Self {
+        assert!(directions >= 1);
+        Self(directions)
+    }
+
+    /// Returns the number of directions to snap to.
+    #[must_use]
+    #[inline]
+    pub fn directions(&self) -> u8 {
+        self.0
+    }
+
+    /// Rounds the direction of the `input_value` to the nearest allowed direction, preserving its magnitude.
+    #[must_use]
+    pub fn snap(&self, input_value: Vec2) -> Vec2 {
+        let magnitude = input_value.length();
+        if magnitude == 0.0 {
+            return Vec2::ZERO;
+        }
+
+        let step = TAU / f32::from(self.0);
+        let angle = input_value.to_angle();
+        let snapped_angle = (angle / step).round() * step;
+        Vec2::from_angle(snapped_angle) * magnitude
+    }
+}
+
+impl From<SnapToDirections> for DualAxisProcessor {
+    fn from(value: SnapToDirections) -> Self {
+        Self::SnapToDirections(value)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -931,4 +1091,64 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_snap_to_directions() {
+        use std::f32::consts::{FRAC_PI_2, FRAC_PI_4};
+
+        let close = |a: Vec2, b: Vec2| (a - b).length() <= 0.00001;
+
+        let eight_way = SnapToDirections::new(8);
+        assert_eq!(eight_way, SnapToDirections::EIGHT_WAY);
+        let processor = DualAxisProcessor::SnapToDirections(eight_way);
+        assert_eq!(DualAxisProcessor::from(eight_way), processor);
+
+        // 40° snaps to 45°, preserving the magnitude
+        let value = Vec2::from_angle(40f32.to_radians()) * 0.8;
+        assert!(close(
+            processor.process(value),
+            Vec2::from_angle(FRAC_PI_4) * 0.8
+        ));
+
+        // Angles on the other side of the circle snap correctly too
+        let value = Vec2::from_angle(-100f32.to_radians());
+        assert!(close(eight_way.snap(value), Vec2::NEG_Y));
+        let value = Vec2::from_angle(170f32.to_radians()) * 2.0;
+        assert!(close(eight_way.snap(value), Vec2::NEG_X * 2.0));
+
+        let four_way = SnapToDirections::FOUR_WAY;
+        let value = Vec2::from_angle(40f32.to_radians());
+        assert!(close(four_way.snap(value), Vec2::X));
+        let value = Vec2::from_angle(50f32.to_radians());
+        assert!(close(four_way.snap(value), Vec2::from_angle(FRAC_PI_2)));
+
+        // A single direction always points along the positive X-axis
+        let one_way = SnapToDirections::new(1);
+        assert!(close(
+            one_way.snap(Vec2::new(-0.3, 0.4)),
+            Vec2::new(0.5, 0.0)
+        ));
+
+        // Zero-length values stay zero
+        for snap in [one_way, four_way, eight_way] {
+            assert_eq!(snap.snap(Vec2::ZERO), Vec2::ZERO);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_snap_to_zero_directions() {
+        let _ = SnapToDirections::new(0);
+    }
+
+    #[test]
+    fn test_snap_to_directions_deserialization_is_validated() {
+        let serialized = ron::to_string(&SnapToDirections::EIGHT_WAY).unwrap();
+        assert_eq!(
+            ron::from_str::<SnapToDirections>(&serialized).unwrap(),
+            SnapToDirections::EIGHT_WAY
+        );
+
+        assert!(ron::from_str::<SnapToDirections>("(0)").is_err());
+    }
 }
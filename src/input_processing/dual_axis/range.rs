@@ -8,6 +8,7 @@ use serde::{Deserialize, Serialize};
 
 use super::DualAxisProcessor;
 use crate::input_processing::single_axis::*;
+use crate::input_processing::ProcessorError;
 
 /// Specifies a square-shaped region defining acceptable ranges for valid dual-axis inputs,
 /// with independent min-max ranges for each axis, restricting all values stay within intended limits
@@ -73,6 +74,23 @@ impl DualAxisBounds {
         }
     }
 
+    /// Creates a [`DualAxisBounds`] that restricts values within the range `[min, max]` on each axis,
+    /// like [`DualAxisBounds::new`] but without panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProcessorError::InvertedRange`] for the first axis whose `min` is greater than its `max`, or either is NaN.
+    #[inline]
+    pub fn try_new(
+        (x_min, x_max): (f32, f32),
+        (y_min, y_max): (f32, f32),
+    ) -> Result<Self, ProcessorError> {
+        Ok(Self {
+            bounds_x: AxisBounds::try_new(x_min, x_max)?,
+            bounds_y: AxisBounds::try_new(y_min, y_max)?,
+        })
+    }
+
     /// Creates a [`DualAxisBounds`] that restricts values within the same range `[min, max]` on both axes.
     ///
     /// # Requirements
@@ -1396,6 +1414,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_dual_axis_bounds_try_new() {
+        assert_eq!(
+            DualAxisBounds::try_new((-2.0, 2.5), (-1.0, 1.5)),
+            Ok(DualAxisBounds::new((-2.0, 2.5), (-1.0, 1.5)))
+        );
+        assert_eq!(
+            DualAxisBounds::try_new((-2.0, 2.5), (1.5, -1.0)),
+            Err(ProcessorError::InvertedRange {
+                min: 1.5,
+                max: -1.0
+            })
+        );
+    }
+
     #[test]
     fn test_dual_axis_exclusion() {
         fn test_exclusion(
@@ -7,14 +7,20 @@
 //!
 //! - [`AxisProcessor`]: Handles `f32` values for single-axis inputs.
 //! - [`DualAxisProcessor`]: Handles [`Vec2`](bevy::prelude::Vec2) values for dual-axis inputs.
+//! - [`TripleAxisProcessor`]: Handles [`Vec3`](bevy::prelude::Vec3) values for triple-axis inputs.
 //!
 //! Need something specific? You can also create your own processors by implementing these traits for specific needs.
 //!
 //! - [`CustomAxisProcessor`]: Handles `f32` values for single-axis inputs.
 //! - [`CustomDualAxisProcessor`]: Handles [`Vec2`](bevy::prelude::Vec2) values for dual-axis inputs.
+//! - [`CustomTripleAxisProcessor`]: Handles [`Vec3`](bevy::prelude::Vec3) values for triple-axis inputs.
 //!
 //! Feel free to suggest additions to the built-in processors if you have a common use case!
 //!
+//! Processors are usually attached to individual inputs,
+//! but they can also be attached to an action as an [`ActionProcessor`],
+//! processing the combined value of all of its inputs.
+//!
 //! # Built-in Processors
 //!
 //! ## Digital Conversion
@@ -24,6 +30,7 @@
 //!
 //! - [`AxisProcessor::Digital`]: Single-axis digital conversion.
 //! - [`DualAxisProcessor::Digital`]: Dual-axis digital conversion.
+//! - [`TripleAxisProcessor::Digital`]: Triple-axis digital conversion.
 //!
 //! ## Inversion
 //!
@@ -32,6 +39,7 @@
 //!
 //! - [`AxisProcessor::Inverted`]: Single-axis inversion.
 //! - [`DualAxisInverted`]: Dual-axis inversion, implemented [`Into<DualAxisProcessor>`].
+//! - [`TripleAxisInverted`]: Triple-axis inversion, implemented [`Into<TripleAxisProcessor>`].
 //!
 //! ## Sensitivity
 //!
@@ -40,6 +48,21 @@
 //!
 //! - [`AxisProcessor::Sensitivity`]: Single-axis scaling.
 //! - [`DualAxisSensitivity`]: Dual-axis scaling, implemented [`Into<DualAxisProcessor>`].
+//! - [`TripleAxisSensitivity`]: Triple-axis scaling, implemented [`Into<TripleAxisProcessor>`].
+//!
+//! ## Response Curves
+//!
+//! Response curves map input values along a non-linear curve,
+//! giving analog inputs like triggers and pedals a custom feel.
+//!
+//! - [`AxisCurve`]: A piecewise-linear response curve for single-axis inputs, implemented [`Into<AxisProcessor>`].
+//!
+//! ## Direction Snapping
+//!
+//! Direction snapping rounds the direction of dual-axis input values
+//! to the nearest of several evenly-spaced directions, while preserving their magnitude.
+//!
+//! - [`SnapToDirections`]: Dual-axis direction snapping, implemented [`Into<DualAxisProcessor>`].
 //!
 //! ## Value Bounds
 //!
@@ -53,6 +76,8 @@
 //!     with independent min-max ranges for each axis, implemented [`Into<DualAxisProcessor>`].
 //! - [`CircleBounds`]: A circular region for valid dual-axis inputs,
 //!     with a radius defining the maximum magnitude, implemented [`Into<DualAxisProcessor>`].
+//! - [`SphereBounds`]: A spherical region for valid triple-axis inputs,
+//!     with a radius defining the maximum magnitude, implemented [`Into<TripleAxisProcessor>`].
 //!
 //! ## Dead Zones
 //!
@@ -82,9 +107,86 @@
 //!     set to [`DualAxisBounds::symmetric_all(1.0)`](DualAxisBounds::default), implemented [`Into<DualAxisProcessor>`].
 //! - [`CircleDeadZone`]: A scaled version of [`CircleExclusion`] with the bounds
 //!     set to [`CircleBounds::new(1.0)`](CircleBounds::default), implemented [`Into<DualAxisProcessor>`].
+//! - [`DeadZonePreset`]: Standard [`CircleDeadZone`] sizes for gamepad sticks,
+//!     implemented [`Into<CircleDeadZone>`] and [`Into<DualAxisProcessor>`].
+//! - [`SphereDeadZone`]: The triple-axis counterpart of [`CircleDeadZone`] with the bounds
+//!     set to [`SphereBounds::new(1.0)`](SphereBounds::default), implemented [`Into<TripleAxisProcessor>`].
+//! - [`RadialDeadZone`]: A [`CircleDeadZone`] that applies a power curve to the magnitude of values within the live zone,
+//!     implemented [`Into<DualAxisProcessor>`].
+//! - [`AntiDeadzone`]: Boosts the magnitude of non-zero values to a minimum output,
+//!     implemented [`Into<DualAxisProcessor>`].
+//!
+//! ## Smoothing
+//!
+//! Smoothing blends each new input value with the previous value of the action,
+//! reducing jitter from noisy inputs like raw mouse deltas.
+//! As it depends on previous values, it is configured per-action on the [`ActionState`](crate::action_state::ActionState)
+//! rather than as part of a processing pipeline.
+//!
+//! - [`ExponentialSmoothing`]: An exponential low-pass filter for axislike and dual-axislike actions.
 
+pub use self::action_processor::*;
 pub use self::dual_axis::*;
 pub use self::single_axis::*;
+pub use self::smoothing::*;
+pub use self::triple_axis::*;
 
+pub mod action_processor;
 pub mod dual_axis;
 pub mod single_axis;
+pub mod smoothing;
+pub mod triple_axis;
+
+/// An error returned when a processor is created from invalid parameters,
+/// such as by [`CircleDeadZone::try_new`] or [`AxisBounds::try_new`].
+///
+/// This allows parameters that come from user settings, such as a slider,
+/// to be reported instead of panicking like the corresponding `new` constructors.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ProcessorError {
+    /// A radius or threshold that must be at least `0.0` was negative or NaN.
+    NegativeRadius(f32),
+
+    /// The minimum of a range was greater than its maximum, or either of them was NaN.
+    InvertedRange {
+        /// The provided minimum.
+        min: f32,
+        /// The provided maximum.
+        max: f32,
+    },
+
+    /// An exclusion range did not contain `0.0`, or either of its ends was NaN.
+    ExclusionExcludesZero {
+        /// The provided upper limit of the excluded negative values.
+        negative_max: f32,
+        /// The provided lower limit of the excluded positive values.
+        positive_min: f32,
+    },
+}
+
+impl std::fmt::Display for ProcessorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NegativeRadius(radius) => {
+                write!(f, "the radius must be at least 0.0, but was {radius}")
+            }
+            Self::InvertedRange { min, max } => {
+                write!(
+                    f,
+                    "the minimum must not be greater than the maximum, but the range was [{min}, {max}]"
+                )
+            }
+            Self::ExclusionExcludesZero {
+                negative_max,
+                positive_min,
+            } => {
+                write!(
+                    f,
+                    "the excluded range must contain 0.0, but was [{negative_max}, {positive_min}]"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ProcessorError {}
@@ -0,0 +1,190 @@
+//! Response curves for single-axis inputs
+
+use std::hash::{Hash, Hasher};
+
+use bevy::{
+    math::FloatOrd,
+    prelude::{FloatExt, Reflect},
+};
+use serde::{Deserialize, Serialize};
+
+use super::AxisProcessor;
+
+/// A piecewise-linear response curve for single-axis input values,
+/// defined by a sorted list of `(input, output)` breakpoints.
+///
+/// Input values between two breakpoints are linearly interpolated between their outputs,
+/// while input values outside the breakpoints are clamped to the output of the nearest endpoint.
+///
+/// This is useful for giving analog triggers and pedals a non-linear response,
+/// without writing a [`CustomAxisProcessor`](crate::input_processing::CustomAxisProcessor).
+///
+/// ```rust
+/// use leafwing_input_manager::prelude::*;
+///
+/// // A concave-up curve, giving finer control over small inputs
+/// let curve = AxisCurve::new([(0.0, 0.0), (0.5, 0.2), (0.8, 0.5), (1.0, 1.0)]);
+///
+/// // Breakpoints map exactly to their outputs
+/// assert_eq!(curve.evaluate(0.5), 0.2);
+/// assert_eq!(curve.evaluate(0.8), 0.5);
+///
+/// // Values in between are linearly interpolated
+/// assert!((curve.evaluate(0.25) - 0.1).abs() <= 0.00001);
+/// assert!((curve.evaluate(0.9) - 0.75).abs() <= 0.00001);
+///
+/// // Values outside the endpoints are clamped
+/// assert_eq!(curve.evaluate(-1.0), 0.0);
+/// assert_eq!(curve.evaluate(2.0), 1.0);
+///
+/// // Small inputs are attenuated more than large ones
+/// assert!(curve.evaluate(0.25) < 0.25);
+/// assert!(curve.evaluate(0.9) - curve.evaluate(0.8) > 0.1);
+/// ```
+#[derive(Debug, Clone, PartialEq, Reflect, Serialize, Deserialize)]
+#[serde(try_from = "UncheckedAxisCurve")]
+#[must_use]
+pub struct AxisCurve(pub(crate) Vec<(f32, f32)>);
+
+/// The serialized form of an [`AxisCurve`], validated before being converted into one.
+#[derive(Deserialize)]
+#[serde(rename = "AxisCurve")]
+struct UncheckedAxisCurve(Vec<(f32, f32)>);
+
+impl TryFrom<UncheckedAxisCurve> for AxisCurve {
+    type Error = &'static str;
+
+    fn try_from(UncheckedAxisCurve(points): UncheckedAxisCurve) -> Result<Self, Self::Error> {
+        if points.is_empty() {
+            return Err("an AxisCurve requires at least one breakpoint");
+        }
+        if !points.windows(2).all(|pair| pair[0].0 < pair[1].0) {
+            return Err("the inputs of an AxisCurve's breakpoints must be strictly increasing");
+        }
+        Ok(Self(points))
+    }
+}
+
+impl AxisCurve {
+    /// Creates an [`AxisCurve`] from the given `(input, output)` breakpoints.
+    ///
+    /// # Requirements
+    ///
+    /// - At least one breakpoint is provided.
+    /// - The inputs of the breakpoints are strictly increasing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the requirements aren't met.
+    #[inline]
+    pub fn new(points: impl IntoIterator<Item = (f32, f32)>) -> Self {
+        let points: Vec<(f32, f32)> = points.into_iter().collect();
+        assert!(!points.is_empty());
+        assert!(points.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        Self(points)
+    }
+
+    /// Returns the `(input, output)` breakpoints of this curve.
+    #[must_use]
+    #[inline]
+    pub fn points(&self) -> &[(f32, f32)] {
+        &self.0
+    }
+
+    /// Computes the output of the curve for the given `input_value`.
+    #[must_use]
+    pub fn evaluate(&self, input_value: f32) -> f32 {
+        // The first breakpoint whose input is greater than the input value
+        let index = self.0.partition_point(|&(input, _)| input <= input_value);
+
+        if index == 0 {
+            return self.0[0].1;
+        }
+
+        if index == self.0.len() {
+            return self.0[index - 1].1;
+        }
+
+        let (start_input, start_output) = self.0[index - 1];
+        let (end_input, end_output) = self.0[index];
+        let progress = f32::inverse_lerp(start_input, end_input, input_value);
+        start_output.lerp(end_output, progress)
+    }
+}
+
+impl From<AxisCurve> for AxisProcessor {
+    fn from(value: AxisCurve) -> Self {
+        Self::Curve(value)
+    }
+}
+
+impl Eq for AxisCurve {}
+
+impl Hash for AxisCurve {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for (input, output) in self.0.iter() {
+            FloatOrd(*input).hash(state);
+            FloatOrd(*output).hash(state);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_axis_curve() {
+        let curve = AxisCurve::new([(-1.0, -1.0), (0.0, 0.0), (0.5, 0.1), (1.0, 1.0)]);
+        assert_eq!(curve.points().len(), 4);
+
+        let processor = AxisProcessor::Curve(curve.clone());
+        assert_eq!(AxisProcessor::from(curve.clone()), processor);
+
+        for value in -300..300 {
+            let value = value as f32 * 0.01;
+            assert_eq!(processor.process(value), curve.evaluate(value));
+
+            let expected = if value <= -1.0 {
+                -1.0
+            } else if value <= 0.0 {
+                value
+            } else if value <= 0.5 {
+                value * 0.2
+            } else if value <= 1.0 {
+                0.1 + (value - 0.5) * 1.8
+            } else {
+                1.0
+            };
+            assert!((curve.evaluate(value) - expected).abs() <= 0.00001);
+        }
+
+        // A single breakpoint produces a constant output
+        let constant = AxisCurve::new([(0.5, 0.3)]);
+        assert_eq!(constant.evaluate(-1.0), 0.3);
+        assert_eq!(constant.evaluate(0.5), 0.3);
+        assert_eq!(constant.evaluate(1.0), 0.3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_axis_curve_requires_increasing_inputs() {
+        let _ = AxisCurve::new([(0.0, 0.0), (0.5, 0.5), (0.5, 1.0)]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_axis_curve_requires_points() {
+        let _ = AxisCurve::new([]);
+    }
+
+    #[test]
+    fn test_axis_curve_deserialization_is_validated() {
+        let curve = AxisCurve::new([(0.0, 0.0), (0.5, 0.2), (1.0, 1.0)]);
+        let serialized = ron::to_string(&curve).unwrap();
+        assert_eq!(ron::from_str::<AxisCurve>(&serialized).unwrap(), curve);
+
+        assert!(ron::from_str::<AxisCurve>("([])").is_err());
+        assert!(ron::from_str::<AxisCurve>("([(0.5, 0.0), (0.0, 1.0)])").is_err());
+    }
+}
@@ -5,9 +5,11 @@ use std::hash::{Hash, Hasher};
 use bevy::{math::FloatOrd, prelude::Reflect};
 use serde::{Deserialize, Serialize};
 
+pub use self::curve::*;
 pub use self::custom::*;
 pub use self::range::*;
 
+mod curve;
 mod custom;
 mod range;
 
@@ -72,6 +74,9 @@ pub enum AxisProcessor {
     /// A wrapper around [`AxisDeadZone`] to represent scaled deadzone.
     DeadZone(AxisDeadZone),
 
+    /// A wrapper around [`AxisCurve`] to represent a piecewise-linear response curve.
+    Curve(AxisCurve),
+
     /// A user-defined processor that implements [`CustomAxisProcessor`].
     Custom(Box<dyn CustomAxisProcessor>),
 }
@@ -94,6 +99,7 @@ impl AxisProcessor {
             Self::ValueBounds(bounds) => bounds.clamp(input_value),
             Self::Exclusion(exclusion) => exclusion.exclude(input_value),
             Self::DeadZone(deadzone) => deadzone.normalize(input_value),
+            Self::Curve(curve) => curve.evaluate(input_value),
             Self::Custom(processor) => processor.process(input_value),
         }
     }
@@ -111,6 +117,7 @@ impl Hash for AxisProcessor {
             Self::ValueBounds(bounds) => bounds.hash(state),
             Self::Exclusion(exclusion) => exclusion.hash(state),
             Self::DeadZone(deadzone) => deadzone.hash(state),
+            Self::Curve(curve) => curve.hash(state),
             Self::Custom(processor) => processor.hash(state),
         }
     }
@@ -308,6 +315,22 @@ pub trait WithAxisProcessingPipelineExt: Sized {
     fn only_negative_unscaled(self, negative_max: f32) -> Self {
         self.with_processor(AxisExclusion::only_negative(negative_max))
     }
+
+    /// Appends an [`AxisCurve`] processor as the next processing step,
+    /// mapping values along a piecewise-linear response curve defined by the `(input, output)` breakpoints.
+    ///
+    /// # Requirements
+    ///
+    /// - At least one breakpoint is provided.
+    /// - The inputs of the breakpoints are strictly increasing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the requirements aren't met.
+    #[inline]
+    fn with_curve(self, points: impl IntoIterator<Item = (f32, f32)>) -> Self {
+        self.with_processor(AxisCurve::new(points))
+    }
 }
 
 #[cfg(test)]
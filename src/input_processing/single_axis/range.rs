@@ -6,6 +6,7 @@ use bevy::{math::FloatOrd, prelude::Reflect};
 use serde::{Deserialize, Serialize};
 
 use super::AxisProcessor;
+use crate::input_processing::ProcessorError;
 
 /// Specifies an acceptable min-max range for valid single-axis inputs,
 /// restricting all value stays within intended limits
@@ -59,6 +60,21 @@ impl AxisBounds {
         Self { min, max }
     }
 
+    /// Creates an [`AxisBounds`] that restricts values to the given range `[min, max]`,
+    /// like [`AxisBounds::new`] but without panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProcessorError::InvertedRange`] if `min` is greater than `max`, or either is NaN.
+    #[inline]
+    pub fn try_new(min: f32, max: f32) -> Result<Self, ProcessorError> {
+        if min <= max {
+            Ok(Self::new(min, max))
+        } else {
+            Err(ProcessorError::InvertedRange { min, max })
+        }
+    }
+
     /// Creates an [`AxisBounds`] that restricts values within the range `[-threshold, threshold]`.
     ///
     /// # Requirements
@@ -221,6 +237,24 @@ impl AxisExclusion {
         }
     }
 
+    /// Creates an [`AxisExclusion`] that ignores values within the range `[negative_max, positive_min]`,
+    /// like [`AxisExclusion::new`] but without panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProcessorError::ExclusionExcludesZero`] if the range does not contain `0.0`, or either end is NaN.
+    #[inline]
+    pub fn try_new(negative_max: f32, positive_min: f32) -> Result<Self, ProcessorError> {
+        if negative_max <= 0.0 && positive_min >= 0.0 {
+            Ok(Self::new(negative_max, positive_min))
+        } else {
+            Err(ProcessorError::ExclusionExcludesZero {
+                negative_max,
+                positive_min,
+            })
+        }
+    }
+
     /// Creates an [`AxisExclusion`] that ignores values within the range `[-threshold, threshold]`.
     ///
     /// # Requirements
@@ -435,6 +469,18 @@ impl AxisDeadZone {
         }
     }
 
+    /// Creates an [`AxisDeadZone`] that excludes input values within the range `[negative_max, positive_min]`,
+    /// like [`AxisDeadZone::new`] but without panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProcessorError::ExclusionExcludesZero`] if the range does not contain `0.0`, or either end is NaN.
+    #[inline]
+    pub fn try_new(negative_max: f32, positive_min: f32) -> Result<Self, ProcessorError> {
+        AxisExclusion::try_new(negative_max, positive_min)
+            .map(|_| Self::new(negative_max, positive_min))
+    }
+
     /// Creates an [`AxisDeadZone`] that excludes input values within the range `[-threshold, threshold]`
     /// and then normalizes non-excluded input values into the valid range `[-1.0, 1.0]`.
     ///
@@ -632,6 +678,45 @@ mod tests {
         test_bounds(bounds, f32::MIN, 1.5);
     }
 
+    #[test]
+    fn test_axis_try_new() {
+        assert_eq!(
+            AxisBounds::try_new(-2.0, 1.5),
+            Ok(AxisBounds::new(-2.0, 1.5))
+        );
+        assert_eq!(
+            AxisExclusion::try_new(-0.2, 0.3),
+            Ok(AxisExclusion::new(-0.2, 0.3))
+        );
+        assert_eq!(
+            AxisDeadZone::try_new(-0.2, 0.3),
+            Ok(AxisDeadZone::new(-0.2, 0.3))
+        );
+
+        // Invalid ranges are reported instead of panicking
+        assert_eq!(
+            AxisBounds::try_new(1.0, -1.0),
+            Err(ProcessorError::InvertedRange {
+                min: 1.0,
+                max: -1.0
+            })
+        );
+        assert_eq!(
+            AxisExclusion::try_new(0.1, 0.3),
+            Err(ProcessorError::ExclusionExcludesZero {
+                negative_max: 0.1,
+                positive_min: 0.3
+            })
+        );
+        assert_eq!(
+            AxisDeadZone::try_new(-0.2, -0.1),
+            Err(ProcessorError::ExclusionExcludesZero {
+                negative_max: -0.2,
+                positive_min: -0.1
+            })
+        );
+    }
+
     #[test]
     fn test_axis_exclusion() {
         fn test_exclusion(exclusion: AxisExclusion, min: f32, max: f32) {
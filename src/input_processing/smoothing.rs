@@ -0,0 +1,194 @@
+//! Exponential smoothing for axislike and dual-axislike actions
+
+use std::hash::{Hash, Hasher};
+
+use bevy::{
+    math::FloatOrd,
+    prelude::{FloatExt, Reflect, Vec2},
+};
+use serde::{Deserialize, Serialize};
+
+/// A low-pass filter that blends each new input value with the previous output,
+/// reducing jitter from noisy inputs such as raw mouse deltas.
+///
+/// Each update, the output moves toward the new input value by the fraction `alpha` of the remaining distance:
+/// `output = previous + alpha * (input - previous)`.
+/// An `alpha` of `1.0` disables smoothing, while smaller values produce smoother (but laggier) results.
+///
+/// Unlike [`AxisProcessor`](crate::input_processing::AxisProcessor)s and [`DualAxisProcessor`](crate::input_processing::DualAxisProcessor)s,
+/// smoothing depends on previous values, so it is configured per-action
+/// using [`ActionState::set_smoothing`](crate::action_state::ActionState::set_smoothing),
+/// and applied as the [`ActionState`](crate::action_state::ActionState) is updated.
+/// The previous output is the current value of the action,
+/// so resetting the action using [`ActionState::reset`](crate::action_state::ActionState::reset) also resets the smoothing.
+///
+/// # Frame Rate Dependence
+///
+/// Smoothing is applied once per update, which typically happens once per frame.
+/// As a result, the same `alpha` converges faster at higher frame rates.
+/// If your frame rate varies, consider recomputing `alpha` each frame,
+/// for example using [`ExponentialSmoothing::from_half_life`].
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::prelude::*;
+///
+/// let smoothing = ExponentialSmoothing::new(0.5);
+///
+/// // Each step moves halfway toward the new value
+/// assert_eq!(smoothing.smooth_value(0.0, 1.0), 0.5);
+/// assert_eq!(smoothing.smooth_value(0.5, 1.0), 0.75);
+/// assert_eq!(smoothing.smooth_pair(Vec2::ZERO, Vec2::X), Vec2::new(0.5, 0.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Reflect, Serialize, Deserialize)]
+#[serde(try_from = "UncheckedExponentialSmoothing")]
+#[must_use]
+pub struct ExponentialSmoothing {
+    /// The fraction of the distance toward the new input value covered each update.
+    pub(crate) alpha: f32,
+}
+
+/// The serialized form of an [`ExponentialSmoothing`], validated before being converted into one.
+#[derive(Deserialize)]
+#[serde(rename = "ExponentialSmoothing")]
+struct UncheckedExponentialSmoothing {
+    alpha: f32,
+}
+
+impl TryFrom<UncheckedExponentialSmoothing> for ExponentialSmoothing {
+    type Error = &'static str;
+
+    fn try_from(
+        UncheckedExponentialSmoothing { alpha }: UncheckedExponentialSmoothing,
+    ) -> Result<Self, Self::Error> {
+        if !(0.0 < alpha && alpha <= 1.0) {
+            return Err("an ExponentialSmoothing requires 0.0 < alpha <= 1.0");
+        }
+        Ok(Self { alpha })
+    }
+}
+
+impl ExponentialSmoothing {
+    /// Creates an [`ExponentialSmoothing`] with the given `alpha`.
+    ///
+    /// # Requirements
+    ///
+    /// - `0.0` < `alpha` <= `1.0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the requirements aren't met.
+    #[inline]
+    pub fn new(alpha: f32) -> Self {
+        assert!(0.0 < alpha && alpha <= 1.0);
+        Self { alpha }
+    }
+
+    /// Creates an [`ExponentialSmoothing`] that covers half the distance toward the new input value
+    /// every `half_life` seconds, for updates that are `delta_seconds` apart.
+    ///
+    /// # Requirements
+    ///
+    /// - `half_life` > `0.0`.
+    /// - `delta_seconds` > `0.0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the requirements aren't met.
+    #[inline]
+    pub fn from_half_life(half_life: f32, delta_seconds: f32) -> Self {
+        assert!(half_life > 0.0);
+        assert!(delta_seconds > 0.0);
+        Self::new(1.0 - 0.5_f32.powf(delta_seconds / half_life))
+    }
+
+    /// Returns the fraction of the distance toward the new input value covered each update.
+    #[must_use]
+    #[inline]
+    pub fn alpha(&self) -> f32 {
+        self.alpha
+    }
+
+    /// Blends the `previous` output with the new `input_value`.
+    #[must_use]
+    #[inline]
+    pub fn smooth_value(&self, previous: f32, input_value: f32) -> f32 {
+        previous.lerp(input_value, self.alpha)
+    }
+
+    /// Blends the `previous` output with the new `input_value`.
+    #[must_use]
+    #[inline]
+    pub fn smooth_pair(&self, previous: Vec2, input_value: Vec2) -> Vec2 {
+        previous.lerp(input_value, self.alpha)
+    }
+}
+
+impl Eq for ExponentialSmoothing {}
+
+impl Hash for ExponentialSmoothing {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        FloatOrd(self.alpha).hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exponential_smoothing() {
+        let smoothing = ExponentialSmoothing::new(0.25);
+        assert_eq!(smoothing.alpha(), 0.25);
+
+        // A step input converges geometrically toward the target
+        let mut value = 0.0;
+        let mut pair = Vec2::ZERO;
+        for step in 1..20 {
+            value = smoothing.smooth_value(value, 1.0);
+            pair = smoothing.smooth_pair(pair, Vec2::new(2.0, -2.0));
+
+            let remaining = 0.75_f32.powi(step);
+            assert!((1.0 - value - remaining).abs() <= 0.00001);
+            assert!(
+                (Vec2::new(2.0, -2.0) - pair - 2.0 * remaining * Vec2::new(1.0, -1.0)).length()
+                    <= 0.00001
+            );
+        }
+
+        // An alpha of one disables smoothing
+        let smoothing = ExponentialSmoothing::new(1.0);
+        assert_eq!(smoothing.smooth_value(0.3, -0.7), -0.7);
+        assert_eq!(smoothing.smooth_pair(Vec2::X, Vec2::Y), Vec2::Y);
+
+        // After one half-life, half the distance has been covered
+        let smoothing = ExponentialSmoothing::from_half_life(0.1, 0.025);
+        let mut value = 0.0;
+        for _ in 0..4 {
+            value = smoothing.smooth_value(value, 1.0);
+        }
+        assert!((value - 0.5).abs() <= 0.00001);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_exponential_smoothing_requires_positive_alpha() {
+        let _ = ExponentialSmoothing::new(0.0);
+    }
+
+    #[test]
+    fn test_exponential_smoothing_deserialization_is_validated() {
+        let smoothing = ExponentialSmoothing::new(0.25);
+        let serialized = ron::to_string(&smoothing).unwrap();
+        assert_eq!(
+            ron::from_str::<ExponentialSmoothing>(&serialized).unwrap(),
+            smoothing
+        );
+
+        for alpha in [0.0, -0.5, 1.5, f32::NAN] {
+            let invalid = ExponentialSmoothing { alpha };
+            let serialized = ron::to_string(&invalid).unwrap();
+            assert!(ron::from_str::<ExponentialSmoothing>(&serialized).is_err());
+        }
+    }
+}
@@ -0,0 +1,349 @@
+use std::any::Any;
+use std::fmt::Debug;
+use std::sync::{LazyLock, RwLock};
+
+use bevy::app::App;
+use bevy::prelude::{FromReflect, Reflect, ReflectDeserialize, ReflectSerialize, TypePath, Vec3};
+use bevy::reflect::utility::{GenericTypePathCell, NonGenericTypeInfoCell};
+use bevy::reflect::{
+    erased_serde, FromType, GetTypeRegistration, OpaqueInfo, PartialReflect, ReflectFromPtr,
+    ReflectKind, ReflectMut, ReflectOwned, ReflectRef, TypeInfo, TypeRegistration, Typed,
+};
+use dyn_clone::DynClone;
+use dyn_eq::DynEq;
+use dyn_hash::DynHash;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_flexitos::ser::require_erased_serialize_impl;
+use serde_flexitos::{serialize_trait_object, Registry};
+
+use crate::input_processing::TripleAxisProcessor;
+use crate::typetag::{InfallibleMapRegistry, RegisterTypeTag};
+
+/// A trait for creating custom processor that handles triple-axis input values,
+/// accepting a [`Vec3`] input and producing a [`Vec3`] output.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::hash::{Hash, Hasher};
+/// use bevy::prelude::*;
+/// use bevy::math::FloatOrd;
+/// use serde::{Deserialize, Serialize};
+/// use leafwing_input_manager::prelude::*;
+///
+/// /// Flattens the input onto the horizontal plane,
+/// /// discarding the Y-axis and scaling the remaining axes.
+/// // If your processor includes fields not implemented Eq and Hash,
+/// // implementation is necessary as shown below.
+/// // Otherwise, you can derive Eq and Hash directly.
+/// #[derive(Debug, Clone, Copy, PartialEq, Reflect, Serialize, Deserialize)]
+/// pub struct FlattenY(pub f32);
+///
+/// // Add this attribute for ensuring proper serialization and deserialization.
+/// #[serde_typetag]
+/// impl CustomTripleAxisProcessor for FlattenY {
+///     fn process(&self, input_value: Vec3) -> Vec3 {
+///         // You can use other processors within this function.
+///         let value = TripleAxisSensitivity::all(self.0).scale(input_value);
+///         Vec3::new(value.x, 0.0, value.z)
+///     }
+/// }
+///
+/// // Unfortunately, manual implementation is required due to the float field.
+/// impl Eq for FlattenY {}
+/// impl Hash for FlattenY {
+///     fn hash<H: Hasher>(&self, state: &mut H) {
+///         // Encapsulate the float field for hashing.
+///         FloatOrd(self.0).hash(state);
+///     }
+/// }
+///
+/// // Remember to register your processor - it will ensure everything works smoothly!
+/// let mut app = App::new();
+/// app.register_triple_axis_processor::<FlattenY>();
+///
+/// // Now you can use it!
+/// let processor = FlattenY(2.0);
+/// assert_eq!(processor.process(Vec3::new(1.0, 2.0, 3.0)), Vec3::new(2.0, 0.0, 6.0));
+/// assert_eq!(processor.process(Vec3::ZERO), Vec3::ZERO);
+///
+/// // The ways to create a TripleAxisProcessor.
+/// let triple_axis_processor = TripleAxisProcessor::Custom(Box::new(processor));
+/// assert_eq!(triple_axis_processor, TripleAxisProcessor::from(processor));
+/// ```
+pub trait CustomTripleAxisProcessor:
+    Send + Sync + Debug + DynClone + DynEq + DynHash + Reflect + erased_serde::Serialize
+{
+    /// Computes the result by processing the `input_value`.
+    fn process(&self, input_value: Vec3) -> Vec3;
+}
+
+impl<P: CustomTripleAxisProcessor> From<P> for TripleAxisProcessor {
+    fn from(value: P) -> Self {
+        Self::Custom(Box::new(value))
+    }
+}
+
+dyn_clone::clone_trait_object!(CustomTripleAxisProcessor);
+dyn_eq::eq_trait_object!(CustomTripleAxisProcessor);
+dyn_hash::hash_trait_object!(CustomTripleAxisProcessor);
+
+impl PartialReflect for Box<dyn CustomTripleAxisProcessor> {
+    fn get_represented_type_info(&self) -> Option<&'static TypeInfo> {
+        Some(Self::type_info())
+    }
+
+    fn reflect_kind(&self) -> ReflectKind {
+        ReflectKind::Opaque
+    }
+
+    fn reflect_ref(&self) -> ReflectRef {
+        ReflectRef::Opaque(self)
+    }
+
+    fn reflect_mut(&mut self) -> ReflectMut {
+        ReflectMut::Opaque(self)
+    }
+
+    fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+        ReflectOwned::Opaque(self)
+    }
+
+    fn clone_value(&self) -> Box<dyn PartialReflect> {
+        Box::new(self.clone())
+    }
+
+    fn try_apply(&mut self, value: &dyn PartialReflect) -> Result<(), bevy::reflect::ApplyError> {
+        if let Some(value) = value.try_downcast_ref::<Self>() {
+            *self = value.clone();
+            Ok(())
+        } else {
+            Err(bevy::reflect::ApplyError::MismatchedTypes {
+                from_type: self
+                    .reflect_type_ident()
+                    .unwrap_or_default()
+                    .to_string()
+                    .into_boxed_str(),
+                to_type: self
+                    .reflect_type_ident()
+                    .unwrap_or_default()
+                    .to_string()
+                    .into_boxed_str(),
+            })
+        }
+    }
+
+    fn into_partial_reflect(self: Box<Self>) -> Box<dyn PartialReflect> {
+        self
+    }
+
+    fn as_partial_reflect(&self) -> &dyn PartialReflect {
+        self
+    }
+
+    fn as_partial_reflect_mut(&mut self) -> &mut dyn PartialReflect {
+        self
+    }
+
+    fn try_into_reflect(self: Box<Self>) -> Result<Box<dyn Reflect>, Box<dyn PartialReflect>> {
+        Ok(self)
+    }
+
+    fn try_as_reflect(&self) -> Option<&dyn Reflect> {
+        Some(self)
+    }
+
+    fn try_as_reflect_mut(&mut self) -> Option<&mut dyn Reflect> {
+        Some(self)
+    }
+}
+
+impl Reflect for Box<dyn CustomTripleAxisProcessor> {
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_reflect(self: Box<Self>) -> Box<dyn Reflect> {
+        self
+    }
+
+    fn as_reflect(&self) -> &dyn Reflect {
+        self
+    }
+
+    fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+        self
+    }
+
+    fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+        *self = value.take()?;
+        Ok(())
+    }
+}
+
+impl Typed for Box<dyn CustomTripleAxisProcessor> {
+    fn type_info() -> &'static TypeInfo {
+        static CELL: NonGenericTypeInfoCell = NonGenericTypeInfoCell::new();
+        CELL.get_or_set(|| TypeInfo::Opaque(OpaqueInfo::new::<Self>()))
+    }
+}
+
+impl TypePath for Box<dyn CustomTripleAxisProcessor> {
+    fn type_path() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| {
+            {
+                format!(
+                    "std::boxed::Box<dyn {}::CustomTripleAxisProcessor>",
+                    module_path!()
+                )
+            }
+        })
+    }
+
+    fn short_type_path() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| "Box<dyn CustomTripleAxisProcessor>".to_string())
+    }
+
+    fn type_ident() -> Option<&'static str> {
+        Some("Box<dyn CustomTripleAxisProcessor>")
+    }
+
+    fn crate_name() -> Option<&'static str> {
+        Some(module_path!().split(':').next().unwrap())
+    }
+
+    fn module_path() -> Option<&'static str> {
+        Some(module_path!())
+    }
+}
+
+impl GetTypeRegistration for Box<dyn CustomTripleAxisProcessor> {
+    fn get_type_registration() -> TypeRegistration {
+        let mut registration = TypeRegistration::of::<Self>();
+        registration.insert::<ReflectDeserialize>(FromType::<Self>::from_type());
+        registration.insert::<ReflectFromPtr>(FromType::<Self>::from_type());
+        registration.insert::<ReflectSerialize>(FromType::<Self>::from_type());
+        registration
+    }
+}
+
+impl FromReflect for Box<dyn CustomTripleAxisProcessor> {
+    fn from_reflect(reflect: &dyn PartialReflect) -> Option<Self> {
+        Some(reflect.try_downcast_ref::<Self>()?.clone())
+    }
+}
+
+impl Serialize for dyn CustomTripleAxisProcessor + '_ {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // Check that `CustomTripleAxisProcessor` has `erased_serde::Serialize` as a super trait,
+        // preventing infinite recursion at runtime.
+        const fn __check_erased_serialize_super_trait<T: ?Sized + CustomTripleAxisProcessor>() {
+            require_erased_serialize_impl::<T>();
+        }
+        serialize_trait_object(serializer, self.reflect_short_type_path(), self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Box<dyn CustomTripleAxisProcessor> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let registry = PROCESSOR_REGISTRY.read().unwrap();
+        registry.deserialize_trait_object(deserializer)
+    }
+}
+
+/// Registry of deserializers for [`CustomTripleAxisProcessor`]s.
+static PROCESSOR_REGISTRY: LazyLock<RwLock<InfallibleMapRegistry<dyn CustomTripleAxisProcessor>>> =
+    LazyLock::new(|| RwLock::new(InfallibleMapRegistry::new("CustomTripleAxisProcessor")));
+
+/// A trait for registering a specific [`CustomTripleAxisProcessor`].
+pub trait RegisterTripleAxisProcessorExt {
+    /// Registers the specified [`CustomTripleAxisProcessor`].
+    fn register_triple_axis_processor<'de, T>(&mut self) -> &mut Self
+    where
+        T: RegisterTypeTag<'de, dyn CustomTripleAxisProcessor> + GetTypeRegistration;
+}
+
+impl RegisterTripleAxisProcessorExt for App {
+    fn register_triple_axis_processor<'de, T>(&mut self) -> &mut Self
+    where
+        T: RegisterTypeTag<'de, dyn CustomTripleAxisProcessor> + GetTypeRegistration,
+    {
+        let mut registry = PROCESSOR_REGISTRY.write().unwrap();
+        T::register_typetag(&mut registry);
+        self.register_type::<T>();
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as leafwing_input_manager;
+    use leafwing_input_manager_macros::serde_typetag;
+    use serde_test::{assert_tokens, Token};
+
+    #[test]
+    fn test_custom_triple_axis_processor() {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+        struct CustomTripleAxisInverted;
+
+        #[serde_typetag]
+        impl CustomTripleAxisProcessor for CustomTripleAxisInverted {
+            fn process(&self, input_value: Vec3) -> Vec3 {
+                -input_value
+            }
+        }
+
+        let mut app = App::new();
+        app.register_triple_axis_processor::<CustomTripleAxisInverted>();
+
+        let custom: Box<dyn CustomTripleAxisProcessor> = Box::new(CustomTripleAxisInverted);
+        assert_tokens(
+            &custom,
+            &[
+                Token::Map { len: Some(1) },
+                Token::BorrowedStr("CustomTripleAxisInverted"),
+                Token::UnitStruct {
+                    name: "CustomTripleAxisInverted",
+                },
+                Token::MapEnd,
+            ],
+        );
+
+        let processor = TripleAxisProcessor::Custom(custom);
+        assert_eq!(
+            TripleAxisProcessor::from(CustomTripleAxisInverted),
+            processor
+        );
+
+        for x in -30..30 {
+            let x = x as f32 * 0.1;
+            for y in -30..30 {
+                let y = y as f32 * 0.1;
+                for z in -30..30 {
+                    let z = z as f32 * 0.1;
+                    let value = Vec3::new(x, y, z);
+
+                    assert_eq!(processor.process(value), -value);
+                    assert_eq!(CustomTripleAxisInverted.process(value), -value);
+                }
+            }
+        }
+    }
+}
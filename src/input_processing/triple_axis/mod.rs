@@ -0,0 +1,345 @@
+//! Processors for triple-axis input values
+
+use std::hash::{Hash, Hasher};
+
+use bevy::{
+    math::FloatOrd,
+    prelude::{BVec3, Reflect, Vec3},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::input_processing::AxisProcessor;
+
+pub use self::custom::*;
+pub use self::sphere::*;
+
+mod custom;
+mod sphere;
+
+/// A processor for triple-axis input values,
+/// accepting a [`Vec3`] input and producing a [`Vec3`] output.
+#[must_use]
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+pub enum TripleAxisProcessor {
+    /// Converts input values into three discrete values along each axis,
+    /// similar to [`Vec3::signum()`] but returning `0.0` for zero values.
+    ///
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// // 1.0 for positive values
+    /// assert_eq!(TripleAxisProcessor::Digital.process(Vec3::splat(2.5)), Vec3::ONE);
+    ///
+    /// // 0.0 for zero values
+    /// assert_eq!(TripleAxisProcessor::Digital.process(Vec3::ZERO), Vec3::ZERO);
+    ///
+    /// // -1.0 for negative values
+    /// assert_eq!(TripleAxisProcessor::Digital.process(Vec3::splat(-0.5)), Vec3::NEG_ONE);
+    ///
+    /// // Mixed digital values
+    /// assert_eq!(
+    ///     TripleAxisProcessor::Digital.process(Vec3::new(0.5, 0.0, -0.5)),
+    ///     Vec3::new(1.0, 0.0, -1.0)
+    /// );
+    /// ```
+    Digital,
+
+    /// A wrapper around [`TripleAxisInverted`] to represent inversion.
+    Inverted(TripleAxisInverted),
+
+    /// A wrapper around [`TripleAxisSensitivity`] to represent sensitivity.
+    Sensitivity(TripleAxisSensitivity),
+
+    /// A wrapper around [`SphereBounds`] to represent spherical value bounds.
+    SphereBounds(SphereBounds),
+
+    /// A wrapper around [`SphereDeadZone`] to represent scaled deadzone.
+    SphereDeadZone(SphereDeadZone),
+
+    /// A user-defined processor that implements [`CustomTripleAxisProcessor`].
+    Custom(Box<dyn CustomTripleAxisProcessor>),
+}
+
+impl TripleAxisProcessor {
+    /// Computes the result by processing the `input_value`.
+    #[must_use]
+    #[inline]
+    pub fn process(&self, input_value: Vec3) -> Vec3 {
+        match self {
+            Self::Digital => Vec3::new(
+                AxisProcessor::Digital.process(input_value.x),
+                AxisProcessor::Digital.process(input_value.y),
+                AxisProcessor::Digital.process(input_value.z),
+            ),
+            Self::Inverted(inversion) => inversion.invert(input_value),
+            Self::Sensitivity(sensitivity) => sensitivity.scale(input_value),
+            Self::SphereBounds(bounds) => bounds.clamp(input_value),
+            Self::SphereDeadZone(deadzone) => deadzone.normalize(input_value),
+            Self::Custom(processor) => processor.process(input_value),
+        }
+    }
+}
+
+/// Provides methods for configuring and manipulating the processing pipeline for triple-axis input.
+pub trait WithTripleAxisProcessingPipelineExt: Sized {
+    /// Resets the processing pipeline, removing any currently applied processors.
+    fn reset_processing_pipeline(self) -> Self;
+
+    /// Replaces the current processing pipeline with the given [`TripleAxisProcessor`]s.
+    fn replace_processing_pipeline(
+        self,
+        processors: impl IntoIterator<Item = TripleAxisProcessor>,
+    ) -> Self;
+
+    /// Appends the given [`TripleAxisProcessor`] as the next processing step.
+    fn with_processor(self, processor: impl Into<TripleAxisProcessor>) -> Self;
+
+    /// Appends an [`TripleAxisProcessor::Digital`] processor as the next processing step,
+    /// similar to [`Vec3::signum`] but returning `0.0` for zero values.
+    #[inline]
+    fn digital(self) -> Self {
+        self.with_processor(TripleAxisProcessor::Digital)
+    }
+
+    /// Appends a [`TripleAxisInverted::ALL`] processor as the next processing step,
+    /// flipping the sign of values on all three axes.
+    #[inline]
+    fn inverted(self) -> Self {
+        self.with_processor(TripleAxisInverted::ALL)
+    }
+
+    /// Appends a [`TripleAxisInverted::ONLY_X`] processor as the next processing step,
+    /// only flipping the sign of the X-axis values.
+    #[inline]
+    fn inverted_x(self) -> Self {
+        self.with_processor(TripleAxisInverted::ONLY_X)
+    }
+
+    /// Appends a [`TripleAxisInverted::ONLY_Y`] processor as the next processing step,
+    /// only flipping the sign of the Y-axis values.
+    #[inline]
+    fn inverted_y(self) -> Self {
+        self.with_processor(TripleAxisInverted::ONLY_Y)
+    }
+
+    /// Appends a [`TripleAxisInverted::ONLY_Z`] processor as the next processing step,
+    /// only flipping the sign of the Z-axis values.
+    #[inline]
+    fn inverted_z(self) -> Self {
+        self.with_processor(TripleAxisInverted::ONLY_Z)
+    }
+
+    /// Appends a [`TripleAxisSensitivity`] processor as the next processing step,
+    /// multiplying values on all three axes with the given sensitivity factor.
+    #[inline]
+    fn sensitivity(self, sensitivity: f32) -> Self {
+        self.with_processor(TripleAxisSensitivity::all(sensitivity))
+    }
+
+    /// Appends a [`SphereBounds`] processor as the next processing step,
+    /// restricting values to a `max` magnitude.
+    ///
+    /// # Requirements
+    ///
+    /// - `max` >= `0.0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the requirements aren't met.
+    #[inline]
+    fn with_sphere_bounds(self, max: f32) -> Self {
+        self.with_processor(SphereBounds::new(max))
+    }
+
+    /// Appends a [`SphereDeadZone`] processor as the next processing step,
+    /// ignoring values below a `min` magnitude, treating them as zeros,
+    /// then normalizing non-excluded input values into the "live zone",
+    /// the remaining range within the [`SphereBounds::new(1.0)`](SphereBounds::default)
+    /// after dead zone exclusion.
+    ///
+    /// # Requirements
+    ///
+    /// - `0.0` <= `min` < `1.0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the requirements aren't met.
+    #[inline]
+    fn with_sphere_deadzone(self, min: f32) -> Self {
+        self.with_processor(SphereDeadZone::new(min))
+    }
+}
+
+/// Flips the sign of triple-axis input values, resulting in a directional reversal of control.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::prelude::*;
+///
+/// let value = Vec3::new(1.5, 2.0, -0.5);
+/// let Vec3 { x, y, z } = value;
+///
+/// assert_eq!(TripleAxisInverted::ALL.invert(value), -value);
+/// assert_eq!(TripleAxisInverted::ONLY_X.invert(value), Vec3::new(-x, y, z));
+/// assert_eq!(TripleAxisInverted::ONLY_Y.invert(value), Vec3::new(x, -y, z));
+/// assert_eq!(TripleAxisInverted::ONLY_Z.invert(value), Vec3::new(x, y, -z));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Reflect, Serialize, Deserialize)]
+#[must_use]
+pub struct TripleAxisInverted(Vec3);
+
+impl TripleAxisInverted {
+    /// The [`TripleAxisInverted`] that inverts all three axes.
+    pub const ALL: Self = Self(Vec3::NEG_ONE);
+
+    /// The [`TripleAxisInverted`] that only inverts the X-axis inputs.
+    pub const ONLY_X: Self = Self(Vec3::new(-1.0, 1.0, 1.0));
+
+    /// The [`TripleAxisInverted`] that only inverts the Y-axis inputs.
+    pub const ONLY_Y: Self = Self(Vec3::new(1.0, -1.0, 1.0));
+
+    /// The [`TripleAxisInverted`] that only inverts the Z-axis inputs.
+    pub const ONLY_Z: Self = Self(Vec3::new(1.0, 1.0, -1.0));
+
+    /// Are inputs inverted on each axis?
+    #[must_use]
+    #[inline]
+    pub fn inverted(&self) -> BVec3 {
+        self.0.cmpeq(Vec3::NEG_ONE)
+    }
+
+    /// Multiples the `input_value` by the specified inversion vector.
+    #[must_use]
+    #[inline]
+    pub fn invert(&self, input_value: Vec3) -> Vec3 {
+        self.0 * input_value
+    }
+}
+
+impl From<TripleAxisInverted> for TripleAxisProcessor {
+    fn from(value: TripleAxisInverted) -> Self {
+        Self::Inverted(value)
+    }
+}
+
+impl Eq for TripleAxisInverted {}
+
+impl Hash for TripleAxisInverted {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        FloatOrd(self.0.x).hash(state);
+        FloatOrd(self.0.y).hash(state);
+        FloatOrd(self.0.z).hash(state);
+    }
+}
+
+/// Scales triple-axis input values using a specified multiplier to fine-tune the responsiveness of control.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::prelude::*;
+///
+/// let value = Vec3::new(1.5, 2.5, -1.0);
+///
+/// // Doubled on all axes
+/// let double = TripleAxisSensitivity::all(2.0);
+/// assert_eq!(double.scale(value), 2.0 * value);
+///
+/// // Negated X, halved Y and unchanged Z
+/// let mixed = TripleAxisSensitivity::new(-1.0, 0.5, 1.0);
+/// assert_eq!(mixed.scale(value), Vec3::new(-1.5, 1.25, -1.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Reflect, Serialize, Deserialize)]
+#[must_use]
+pub struct TripleAxisSensitivity(pub(crate) Vec3);
+
+impl TripleAxisSensitivity {
+    /// Creates a [`TripleAxisSensitivity`] with the given values for each axis separately.
+    #[inline]
+    pub const fn new(sensitivity_x: f32, sensitivity_y: f32, sensitivity_z: f32) -> Self {
+        Self(Vec3::new(sensitivity_x, sensitivity_y, sensitivity_z))
+    }
+
+    /// Creates a [`TripleAxisSensitivity`] with the same value for all three axes.
+    #[inline]
+    pub const fn all(sensitivity: f32) -> Self {
+        Self::new(sensitivity, sensitivity, sensitivity)
+    }
+
+    /// Returns the sensitivity values.
+    #[must_use]
+    #[inline]
+    pub fn sensitivities(&self) -> Vec3 {
+        self.0
+    }
+
+    /// Multiples the `input_value` by the specified sensitivity vector.
+    #[must_use]
+    #[inline]
+    pub fn scale(&self, input_value: Vec3) -> Vec3 {
+        self.0 * input_value
+    }
+}
+
+impl From<TripleAxisSensitivity> for TripleAxisProcessor {
+    fn from(value: TripleAxisSensitivity) -> Self {
+        Self::Sensitivity(value)
+    }
+}
+
+impl Eq for TripleAxisSensitivity {}
+
+impl Hash for TripleAxisSensitivity {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        FloatOrd(self.0.x).hash(state);
+        FloatOrd(self.0.y).hash(state);
+        FloatOrd(self.0.z).hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_triple_axis_inverted() {
+        assert_eq!(TripleAxisInverted::ALL.inverted(), BVec3::TRUE);
+        assert_eq!(
+            TripleAxisInverted::ONLY_X.inverted(),
+            BVec3::new(true, false, false)
+        );
+        assert_eq!(
+            TripleAxisInverted::ONLY_Y.inverted(),
+            BVec3::new(false, true, false)
+        );
+        assert_eq!(
+            TripleAxisInverted::ONLY_Z.inverted(),
+            BVec3::new(false, false, true)
+        );
+
+        let value = Vec3::new(0.5, -1.5, 2.0);
+        let all = TripleAxisInverted::ALL;
+        let processor = TripleAxisProcessor::Inverted(all);
+        assert_eq!(TripleAxisProcessor::from(all), processor);
+        assert_eq!(processor.process(value), -value);
+        assert_eq!(
+            TripleAxisInverted::ONLY_Z.invert(value),
+            Vec3::new(0.5, -1.5, -2.0)
+        );
+    }
+
+    #[test]
+    fn test_triple_axis_sensitivity() {
+        let value = Vec3::new(0.5, -1.5, 2.0);
+
+        let all = TripleAxisSensitivity::all(2.0);
+        let processor = TripleAxisProcessor::Sensitivity(all);
+        assert_eq!(TripleAxisProcessor::from(all), processor);
+        assert_eq!(all.sensitivities(), Vec3::splat(2.0));
+        assert_eq!(processor.process(value), 2.0 * value);
+
+        let separate = TripleAxisSensitivity::new(1.0, 0.0, -1.0);
+        assert_eq!(separate.scale(value), Vec3::new(0.5, 0.0, -2.0));
+    }
+}
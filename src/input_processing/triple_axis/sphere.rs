@@ -0,0 +1,347 @@
+//! Spherical range processors for triple-axis inputs
+
+use std::fmt::Debug;
+use std::hash::{Hash, Hasher};
+
+use bevy::{
+    math::FloatOrd,
+    prelude::{Reflect, Vec3},
+};
+use serde::{Deserialize, Serialize};
+
+use super::TripleAxisProcessor;
+
+/// Specifies a spherical region defining acceptable ranges for valid triple-axis inputs,
+/// with a radius defining the maximum threshold magnitude,
+/// restricting all values stay within intended limits
+/// to avoid unexpected behavior caused by extreme inputs.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::prelude::*;
+///
+/// // Restrict magnitudes to no greater than 2
+/// let bounds = SphereBounds::new(2.0);
+///
+/// for x in -30..30 {
+///     let x = x as f32 * 0.1;
+///     for y in -30..30 {
+///         let y = y as f32 * 0.1;
+///         for z in -30..30 {
+///             let z = z as f32 * 0.1;
+///             let value = Vec3::new(x, y, z);
+///             assert_eq!(bounds.clamp(value), value.clamp_length_max(2.0));
+///         }
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Reflect, Serialize, Deserialize)]
+#[must_use]
+pub struct SphereBounds {
+    /// The maximum radius of the sphere.
+    pub(crate) radius: f32,
+}
+
+impl SphereBounds {
+    /// Unlimited [`SphereBounds`].
+    pub const FULL_RANGE: Self = Self { radius: f32::MAX };
+
+    /// Creates a [`SphereBounds`] that restricts input values to a maximum magnitude.
+    ///
+    /// # Requirements
+    ///
+    /// - `max` >= `0.0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the requirements aren't met.
+    #[doc(alias = "magnitude")]
+    #[doc(alias = "from_radius")]
+    #[inline]
+    pub fn new(max: f32) -> Self {
+        assert!(max >= 0.0);
+        Self { radius: max }
+    }
+
+    /// Returns the radius of the bounds.
+    #[must_use]
+    #[inline]
+    pub fn radius(&self) -> f32 {
+        self.radius
+    }
+
+    /// Is the `input_value` is within the bounds?
+    #[must_use]
+    #[inline]
+    pub fn contains(&self, input_value: Vec3) -> bool {
+        input_value.length() <= self.radius
+    }
+
+    /// Clamps the magnitude of `input_value` within the bounds.
+    #[must_use]
+    #[inline]
+    pub fn clamp(&self, input_value: Vec3) -> Vec3 {
+        input_value.clamp_length_max(self.radius)
+    }
+}
+
+impl Default for SphereBounds {
+    /// Creates a [`SphereBounds`] that restricts the values to a maximum magnitude of `1.0`.
+    #[inline]
+    fn default() -> Self {
+        Self::new(1.0)
+    }
+}
+
+impl From<SphereBounds> for TripleAxisProcessor {
+    fn from(value: SphereBounds) -> Self {
+        Self::SphereBounds(value)
+    }
+}
+
+impl Eq for SphereBounds {}
+
+impl Hash for SphereBounds {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        FloatOrd(self.radius).hash(state);
+    }
+}
+
+/// A scaled version of a spherical dead zone that normalizes input values
+/// within the livezone, the region between the dead zone and
+/// the [`SphereBounds::new(1.0)`](SphereBounds::default).
+///
+/// It is the triple-axis counterpart of [`CircleDeadZone`](crate::input_processing::CircleDeadZone),
+/// treating values with a magnitude below the radius as zeros,
+/// and linearly rescaling the magnitude of the rest into `[0.0, 1.0]`.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::prelude::*;
+///
+/// // Exclude magnitudes less than or equal to 0.2
+/// let deadzone = SphereDeadZone::new(0.2);
+///
+/// // Small values are treated as zeros.
+/// assert!(deadzone.within_exclusion(Vec3::splat(0.1)));
+/// assert_eq!(deadzone.normalize(Vec3::splat(0.1)), Vec3::ZERO);
+///
+/// // Values within the live zone are scaled linearly, keeping their direction.
+/// let value = Vec3::new(0.0, 0.6, 0.0);
+/// assert!(deadzone.within_livezone(value));
+/// assert!((deadzone.normalize(value) - Vec3::new(0.0, 0.5, 0.0)).length() <= 0.00001);
+///
+/// // Values outside the bounds are restricted to the region.
+/// let value = Vec3::new(3.0, 0.0, 4.0);
+/// assert!(!deadzone.within_bounds(value));
+/// assert!((deadzone.normalize(value) - value.normalize()).length() <= 0.00001);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Reflect, Serialize, Deserialize)]
+#[must_use]
+pub struct SphereDeadZone {
+    /// The radius of the sphere.
+    pub(crate) radius: f32,
+
+    /// Pre-calculated reciprocal of the live zone size, preventing division during normalization.
+    pub(crate) livezone_recip: f32,
+}
+
+impl SphereDeadZone {
+    /// Zero-size [`SphereDeadZone`], only restricting values to a maximum magnitude of `1.0`.
+    pub const ZERO: Self = Self {
+        radius: 0.0,
+        livezone_recip: 1.0,
+    };
+
+    /// Creates a [`SphereDeadZone`] that excludes input values below a minimum magnitude.
+    ///
+    /// # Requirements
+    ///
+    /// - `0.0` <= `threshold` < `1.0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the requirements aren't met.
+    #[doc(alias = "magnitude")]
+    #[doc(alias = "from_radius")]
+    #[inline]
+    pub fn new(threshold: f32) -> Self {
+        let bounds = SphereBounds::default();
+        assert!((0.0..bounds.radius).contains(&threshold));
+        Self {
+            radius: threshold,
+            livezone_recip: (bounds.radius - threshold).recip(),
+        }
+    }
+
+    /// Returns the radius of the sphere.
+    #[must_use]
+    #[inline]
+    pub fn radius(&self) -> f32 {
+        self.radius
+    }
+
+    /// Returns the [`SphereBounds`] used by this deadzone.
+    #[inline]
+    pub fn bounds(&self) -> SphereBounds {
+        SphereBounds::default()
+    }
+
+    /// Returns the minimum and maximum radii of the live zone used by this deadzone.
+    ///
+    /// In simple terms, this returns `(self.radius, bounds.radius)`.
+    #[must_use]
+    #[inline]
+    pub fn livezone_min_max(&self) -> (f32, f32) {
+        (self.radius, self.bounds().radius)
+    }
+
+    /// Is the given `input_value` within the exclusion range?
+    #[must_use]
+    #[inline]
+    pub fn within_exclusion(&self, input_value: Vec3) -> bool {
+        input_value.length() <= self.radius
+    }
+
+    /// Is the given `input_value` within the bounds?
+    #[must_use]
+    #[inline]
+    pub fn within_bounds(&self, input_value: Vec3) -> bool {
+        self.bounds().contains(input_value)
+    }
+
+    /// Is the given `input_value` within the live zone?
+    #[must_use]
+    #[inline]
+    pub fn within_livezone(&self, input_value: Vec3) -> bool {
+        let input_length = input_value.length();
+        let (min, max) = self.livezone_min_max();
+        min <= input_length && input_length <= max
+    }
+
+    /// Normalizes input values into the live zone.
+    #[must_use]
+    pub fn normalize(&self, input_value: Vec3) -> Vec3 {
+        let input_length = input_value.length();
+        if input_length == 0.0 {
+            return Vec3::ZERO;
+        }
+
+        // Clamp out-of-bounds values to a maximum magnitude of 1.0,
+        // and then exclude values within the dead zone,
+        // and finally linearly scale the result to the live zone.
+        let (deadzone, bound) = self.livezone_min_max();
+        let clamped_input_length = input_length.min(bound);
+        let offset_to_deadzone = (clamped_input_length - deadzone).max(0.0);
+        let magnitude_scale = (offset_to_deadzone * self.livezone_recip) / input_length;
+        input_value * magnitude_scale
+    }
+}
+
+impl Default for SphereDeadZone {
+    /// Creates a [`SphereDeadZone`] that excludes input values below a minimum magnitude of `0.1`.
+    #[inline]
+    fn default() -> Self {
+        SphereDeadZone::new(0.1)
+    }
+}
+
+impl From<SphereDeadZone> for TripleAxisProcessor {
+    fn from(value: SphereDeadZone) -> Self {
+        Self::SphereDeadZone(value)
+    }
+}
+
+impl Eq for SphereDeadZone {}
+
+impl Hash for SphereDeadZone {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        FloatOrd(self.radius).hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::prelude::FloatExt;
+
+    fn for_each_value(mut f: impl FnMut(Vec3)) {
+        for x in -30..30 {
+            let x = x as f32 * 0.1;
+            for y in -30..30 {
+                let y = y as f32 * 0.1;
+                for z in -30..30 {
+                    let z = z as f32 * 0.1;
+                    f(Vec3::new(x, y, z));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_sphere_value_bounds() {
+        fn test_bounds(bounds: SphereBounds, radius: f32) {
+            assert_eq!(bounds.radius(), radius);
+
+            let processor = TripleAxisProcessor::SphereBounds(bounds);
+            assert_eq!(TripleAxisProcessor::from(bounds), processor);
+
+            for_each_value(|value| {
+                assert_eq!(processor.process(value), bounds.clamp(value));
+                assert_eq!(bounds.contains(value), value.length() <= radius);
+
+                let expected = value.clamp_length_max(radius);
+                assert!((bounds.clamp(value) - expected).length() <= f32::EPSILON);
+            });
+        }
+
+        test_bounds(SphereBounds::FULL_RANGE, f32::MAX);
+        test_bounds(SphereBounds::default(), 1.0);
+        test_bounds(SphereBounds::new(2.0), 2.0);
+    }
+
+    #[test]
+    fn test_sphere_deadzone() {
+        fn test_deadzone(deadzone: SphereDeadZone, radius: f32) {
+            assert_eq!(deadzone.radius(), radius);
+
+            let processor = TripleAxisProcessor::SphereDeadZone(deadzone);
+            assert_eq!(TripleAxisProcessor::from(deadzone), processor);
+
+            for_each_value(|value| {
+                assert_eq!(processor.process(value), deadzone.normalize(value));
+
+                // Values within the dead zone are treated as zeros.
+                if value.length() <= radius {
+                    assert!(deadzone.within_exclusion(value));
+                    assert_eq!(deadzone.normalize(value), Vec3::ZERO);
+                }
+                // Values within the live zone are scaled linearly.
+                else if value.length() <= 1.0 {
+                    assert!(deadzone.within_livezone(value));
+
+                    let expected_scale = f32::inverse_lerp(radius, 1.0, value.length());
+                    let expected = value.normalize() * expected_scale;
+                    assert!((deadzone.normalize(value) - expected).length() <= 0.00001);
+                }
+                // Values outside the bounds are restricted to the region.
+                else {
+                    assert!(!deadzone.within_bounds(value));
+
+                    let expected = value.normalize();
+                    assert!((deadzone.normalize(value) - expected).length() <= 0.00001);
+                }
+            });
+        }
+
+        test_deadzone(SphereDeadZone::ZERO, 0.0);
+        test_deadzone(SphereDeadZone::default(), 0.1);
+        test_deadzone(SphereDeadZone::new(0.5), 0.5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sphere_deadzone_out_of_range() {
+        let _ = SphereDeadZone::new(1.0);
+    }
+}
@@ -11,14 +11,19 @@ use std::fmt::Debug;
 use std::hash::Hash;
 
 pub mod action_diff;
+pub mod action_events;
 pub mod action_state;
 pub mod axislike;
 pub mod buttonlike;
 pub mod clashing_inputs;
 pub mod common_conditions;
+#[cfg(feature = "debug_overlay")]
+pub mod debug_overlay;
 pub mod input_map;
 pub mod input_processing;
 pub mod plugin;
+#[cfg(feature = "rumble")]
+pub mod rumble;
 pub mod systems;
 
 #[cfg(feature = "timing")]
@@ -102,11 +107,68 @@ pub mod prelude {
 ///    TakePhoto, // This action is controlled by buttons since we have overridden the default option
 /// }
 /// ```
+///
+/// # Grouping actions
+///
+/// Actions can be tagged with a named group using `#[actionlike(group = "name")]`,
+/// which can be combined with a control kind like `#[actionlike(DualAxis, group = "name")]`.
+/// When placed on the entire enum, the group applies to all variants without a group of their own.
+///
+/// Groups can be disabled all at once with [`ActionState::disable_group`].
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::prelude::*;
+///
+/// #[derive(Actionlike, Debug, PartialEq, Eq, Clone, Copy, Hash, Reflect)]
+/// enum PlayerAction {
+///    #[actionlike(DualAxis, group = "movement")]
+///    Move,
+///    #[actionlike(group = "combat")]
+///    Attack,
+///    Pause, // This action does not belong to any group
+/// }
+///
+/// assert_eq!(PlayerAction::Attack.group(), Some("combat"));
+/// assert_eq!(PlayerAction::Pause.group(), None);
+/// ```
 pub trait Actionlike:
     Debug + Eq + Hash + Send + Sync + Clone + Reflect + Typed + TypePath + FromReflect + 'static
 {
     /// Returns the kind of input control this action represents: buttonlike, axislike, or dual-axislike.
     fn input_control_kind(&self) -> InputControlKind;
+
+    /// Returns the name of the group this action belongs to, if any.
+    ///
+    /// Defaults to [`None`], and can be set using the `#[actionlike(group = "name")]` attribute.
+    fn group(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// Returns an iterator over every action of this type, in declaration order.
+    ///
+    /// The derive macro lists each variant of an enum,
+    /// and refuses to compile enums with fields, as their values can't be enumerated.
+    /// For structs, and types implementing [`Actionlike`] by hand, this defaults to an empty iterator.
+    ///
+    /// ```rust
+    /// use bevy::prelude::Reflect;
+    /// use leafwing_input_manager::Actionlike;
+    ///
+    /// #[derive(Actionlike, Debug, PartialEq, Eq, Clone, Copy, Hash, Reflect)]
+    /// enum Action {
+    ///     Run,
+    ///     Jump,
+    /// }
+    ///
+    /// assert_eq!(
+    ///     Action::variants().collect::<Vec<_>>(),
+    ///     [Action::Run, Action::Jump]
+    /// );
+    /// ```
+    fn variants() -> impl Iterator<Item = Self> {
+        core::iter::empty()
+    }
 }
 
 /// This [`Bundle`] allows entities to collect and interpret inputs from across input sources
@@ -141,7 +203,7 @@ impl<A: Actionlike> InputManagerBundle<A> {
 }
 
 /// Classifies [`UserInput`](crate::user_input::UserInput)s and [`Actionlike`] actions based on their behavior (buttons, analog axes, etc.).
-#[derive(Debug, Clone, Copy, PartialEq, Reflect, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
 #[must_use]
 pub enum InputControlKind {
     /// A single input with binary state (active or inactive), typically a button press (on or off).
@@ -14,13 +14,16 @@ use bevy::reflect::TypePath;
 use bevy::ui::UiSystem;
 use updating::CentralInputStore;
 
+use crate::action_diff::ActionDiffEvent;
+use crate::action_events::{ActionPressed, ActionReleased};
 use crate::action_state::{ActionState, ButtonData};
+use crate::buttonlike::BufferConfig;
 use crate::clashing_inputs::ClashStrategy;
-use crate::input_map::InputMap;
+use crate::input_map::{InputContexts, InputMap};
 use crate::input_processing::*;
 use crate::prelude::updating::register_standard_input_kinds;
 #[cfg(feature = "timing")]
-use crate::timing::Timing;
+use crate::timing::{RepeatConfig, Timing};
 use crate::user_input::*;
 use crate::Actionlike;
 
@@ -53,9 +56,16 @@ use crate::Actionlike;
 ///
 /// - [`tick_action_state`](crate::systems::tick_action_state), which resets the `pressed` and `just_pressed` fields of the [`ActionState`] each frame
 /// - [`update_action_state`](crate::systems::update_action_state), which collects [`ButtonInput`] resources to update the [`ActionState`]
+/// - [`apply_action_diffs`](crate::systems::apply_action_diffs), which applies incoming [`ActionDiffEvent`]s (only added by [`InputManagerPlugin::server`])
+/// - [`send_action_events`](crate::systems::send_action_events), which sends [`ActionPressed`] and [`ActionReleased`] events (only added by [`InputManagerPlugin::send_action_events`])
+/// - `draw_action_state_overlay`, which draws the state of each action in an `egui` window (only added with the `debug_overlay` feature, and runs during [`Update`])
 pub struct InputManagerPlugin<A: Actionlike> {
     _phantom: PhantomData<A>,
     machine: Machine,
+    #[cfg(feature = "mouse")]
+    reset_mouse_on_focus_change: bool,
+    record_triggering_inputs: bool,
+    send_action_events: bool,
 }
 
 // Deriving default induces an undesired bound on the generic
@@ -64,6 +74,10 @@ impl<A: Actionlike> Default for InputManagerPlugin<A> {
         Self {
             _phantom: PhantomData,
             machine: Machine::Client,
+            #[cfg(feature = "mouse")]
+            reset_mouse_on_focus_change: false,
+            record_triggering_inputs: false,
+            send_action_events: false,
         }
     }
 }
@@ -74,13 +88,55 @@ impl<A: Actionlike> InputManagerPlugin<A> {
     /// Inputs will not be processed; instead, [`ActionState`]
     /// should be copied directly from the state provided by the client,
     /// or constructed from [`ActionDiff`](crate::action_diff::ActionDiff) event streams.
+    ///
+    /// Only the [`tick_action_state`](crate::systems::tick_action_state) and
+    /// [`apply_action_diffs`](crate::systems::apply_action_diffs) systems are added,
+    /// so no input resources such as [`ButtonInput`] or [`CentralInputStore`] are required.
+    /// Any [`ActionDiffEvent`] sent to the app is applied during [`InputManagerSystem::Update`].
     #[must_use]
     pub fn server() -> Self {
         Self {
-            _phantom: PhantomData,
             machine: Machine::Server,
+            ..default()
         }
     }
+
+    /// Discards the mouse motion and scroll accumulated while a window is unfocused,
+    /// as well as on the frames where window focus is lost or regained.
+    ///
+    /// This prevents spurious spikes of mouse input when the game regains focus,
+    /// by adding the [`reset_mouse_accumulation_on_focus_change`](crate::systems::reset_mouse_accumulation_on_focus_change) system.
+    /// Disabled by default.
+    #[cfg(feature = "mouse")]
+    #[must_use]
+    pub fn reset_mouse_on_focus_change(mut self) -> Self {
+        self.reset_mouse_on_focus_change = true;
+        self
+    }
+
+    /// Records the input that caused each action to be pressed,
+    /// which can then be read using [`ActionState::triggering_input`].
+    ///
+    /// This is useful for "press any key" prompts or when rebinding controls,
+    /// and adds the [`record_triggering_inputs`](crate::systems::record_triggering_inputs) system.
+    /// Disabled by default, as each recorded input is allocated.
+    #[must_use]
+    pub fn record_triggering_inputs(mut self) -> Self {
+        self.record_triggering_inputs = true;
+        self
+    }
+
+    /// Sends an [`ActionPressed`] or [`ActionReleased`] event whenever a buttonlike action is just pressed or released,
+    /// which can be read using an [`EventReader`] or observed.
+    ///
+    /// The events are sent after [`InputManagerSystem::ManualControl`] in [`PreUpdate`],
+    /// by adding the [`send_action_events`](crate::systems::send_action_events) system.
+    /// Disabled by default, to avoid the overhead for apps that poll the [`ActionState`] instead.
+    #[must_use]
+    pub fn send_action_events(mut self) -> Self {
+        self.send_action_events = true;
+        self
+    }
 }
 
 /// Which machine is this plugin running on?
@@ -104,10 +160,8 @@ impl<A: Actionlike + TypePath + bevy::reflect::GetTypeRegistration> Plugin
                 // Main schedule
                 app.add_systems(
                     PreUpdate,
-                    (
-                        tick_action_state::<A>.in_set(TickActionStateSystem::<A>::new()),
-                        clear_central_input_store,
-                    )
+                    tick_action_state::<A>
+                        .in_set(TickActionStateSystem::<A>::new())
                         .in_set(InputManagerSystem::Tick)
                         .before(InputManagerSystem::Update),
                 )
@@ -115,7 +169,55 @@ impl<A: Actionlike + TypePath + bevy::reflect::GetTypeRegistration> Plugin
 
                 app.add_systems(
                     PreUpdate,
-                    update_action_state::<A>.in_set(InputManagerSystem::Update),
+                    (
+                        update_action_state::<A>,
+                        capture_input_bindings::<A>.after(update_action_state::<A>),
+                    )
+                        .in_set(InputManagerSystem::Update),
+                );
+
+                if self.record_triggering_inputs {
+                    app.add_systems(
+                        PreUpdate,
+                        record_triggering_inputs::<A>
+                            .after(update_action_state::<A>)
+                            .in_set(InputManagerSystem::Update),
+                    );
+                }
+
+                #[cfg(feature = "gamepad")]
+                app.add_systems(
+                    PreUpdate,
+                    assign_player_gamepads::<A>
+                        .before(update_action_state::<A>)
+                        .in_set(InputManagerSystem::Update),
+                );
+
+                #[cfg(feature = "rumble")]
+                app.register_type::<crate::rumble::ActionRumble<A>>()
+                    .add_systems(
+                        PreUpdate,
+                        crate::rumble::rumble_on_action::<A>
+                            .after(InputManagerSystem::ManualControl),
+                    );
+
+                #[cfg(feature = "debug_overlay")]
+                app.add_systems(Update, crate::debug_overlay::draw_action_state_overlay::<A>);
+
+                #[cfg(feature = "mouse")]
+                if self.reset_mouse_on_focus_change {
+                    app.add_event::<bevy::window::WindowFocused>().add_systems(
+                        PreUpdate,
+                        reset_mouse_accumulation_on_focus_change
+                            .in_set(InputManagerSystem::Accumulate),
+                    );
+                }
+
+                app.configure_sets(
+                    PreUpdate,
+                    InputManagerSystem::Accumulate
+                        .after(InputSystem)
+                        .before(InputManagerSystem::Unify),
                 );
 
                 app.configure_sets(
@@ -192,50 +294,47 @@ impl<A: Actionlike + TypePath + bevy::reflect::GetTypeRegistration> Plugin
                 );
             }
             Machine::Server => {
-                app.add_systems(
+                app.add_event::<ActionDiffEvent<A>>()
+                    .add_systems(
+                        PreUpdate,
+                        tick_action_state::<A>
+                            .in_set(TickActionStateSystem::<A>::new())
+                            .in_set(InputManagerSystem::Tick)
+                            .before(InputManagerSystem::Update),
+                    )
+                    .add_systems(
+                        PreUpdate,
+                        apply_action_diffs::<A>.in_set(InputManagerSystem::Update),
+                    );
+
+                app.configure_sets(
                     PreUpdate,
-                    tick_action_state::<A>
-                        .in_set(TickActionStateSystem::<A>::new())
-                        .in_set(InputManagerSystem::Tick),
+                    InputManagerSystem::ManualControl.after(InputManagerSystem::Update),
                 );
             }
         };
 
-        #[cfg(feature = "mouse")]
-        app.register_buttonlike_input::<MouseButton>()
-            .register_buttonlike_input::<MouseMoveDirection>()
-            .register_buttonlike_input::<MouseButton>()
-            .register_axislike_input::<MouseMoveAxis>()
-            .register_dual_axislike_input::<MouseMove>()
-            .register_buttonlike_input::<MouseScrollDirection>()
-            .register_axislike_input::<MouseScrollAxis>()
-            .register_dual_axislike_input::<MouseScroll>();
-
-        #[cfg(feature = "keyboard")]
-        app.register_buttonlike_input::<KeyCode>()
-            .register_buttonlike_input::<ModifierKey>();
+        if self.send_action_events {
+            app.add_event::<ActionPressed<A>>()
+                .add_event::<ActionReleased<A>>()
+                .add_systems(
+                    PreUpdate,
+                    crate::systems::send_action_events::<A>
+                        .after(InputManagerSystem::ManualControl),
+                );
+        }
 
-        #[cfg(feature = "gamepad")]
-        app.register_buttonlike_input::<GamepadControlDirection>()
-            .register_axislike_input::<GamepadControlAxis>()
-            .register_dual_axislike_input::<GamepadStick>()
-            .register_buttonlike_input::<GamepadButton>();
-
-        // Virtual Axes
-        app.register_axislike_input::<VirtualAxis>()
-            .register_dual_axislike_input::<VirtualDPad>()
-            .register_triple_axislike_input::<VirtualDPad3D>();
-
-        // Chords
-        app.register_buttonlike_input::<ButtonlikeChord>()
-            .register_axislike_input::<AxislikeChord>()
-            .register_dual_axislike_input::<DualAxislikeChord>()
-            .register_triple_axislike_input::<TripleAxislikeChord>();
+        // Inputs
+        app.register_default_inputs()
+            .register_type::<ChordActivation>();
+        #[cfg(all(feature = "gamepad", feature = "keyboard"))]
+        app.register_type::<InputDevice>();
 
         // General-purpose reflection
         app.register_type::<ActionState<A>>()
             .register_type::<InputMap<A>>()
             .register_type::<ButtonData>()
+            .register_type::<BufferConfig>()
             .register_type::<ActionState<A>>()
             .register_type::<CentralInputStore>();
 
@@ -244,21 +343,37 @@ impl<A: Actionlike + TypePath + bevy::reflect::GetTypeRegistration> Plugin
             .register_type::<AxisBounds>()
             .register_type::<AxisExclusion>()
             .register_type::<AxisDeadZone>()
+            .register_type::<AxisCurve>()
             .register_type::<DualAxisProcessor>()
             .register_type::<DualAxisInverted>()
+            .register_type::<SnapToDirections>()
             .register_type::<DualAxisSensitivity>()
             .register_type::<DualAxisBounds>()
             .register_type::<DualAxisExclusion>()
             .register_type::<DualAxisDeadZone>()
             .register_type::<CircleBounds>()
             .register_type::<CircleExclusion>()
-            .register_type::<CircleDeadZone>();
+            .register_type::<CircleDeadZone>()
+            .register_type::<DeadZonePreset>()
+            .register_type::<RadialDeadZone>()
+            .register_type::<AntiDeadzone>()
+            .register_type::<TripleAxisProcessor>()
+            .register_type::<TripleAxisInverted>()
+            .register_type::<TripleAxisSensitivity>()
+            .register_type::<SphereBounds>()
+            .register_type::<SphereDeadZone>()
+            .register_type::<ExponentialSmoothing>()
+            .register_type::<ActionProcessor>();
 
         // Resources
-        app.init_resource::<ClashStrategy>();
+        app.init_resource::<ClashStrategy>()
+            .register_type::<ClashStrategy>()
+            .init_resource::<InputContexts>()
+            .register_type::<InputContexts>();
 
         #[cfg(feature = "timing")]
-        app.register_type::<Timing>();
+        app.register_type::<Timing>()
+            .register_type::<RepeatConfig>();
     }
 }
 
@@ -322,6 +437,25 @@ impl Plugin for CentralInputStorePlugin {
 
         register_standard_input_kinds(app);
 
-        app.configure_sets(PreUpdate, InputManagerSystem::Unify.after(InputSystem));
+        app.add_systems(
+            PreUpdate,
+            crate::systems::clear_central_input_store.in_set(InputManagerSystem::Tick),
+        );
+
+        #[cfg(feature = "gamepad")]
+        app.init_resource::<GamepadRegistry>()
+            .register_type::<GamepadRegistry>()
+            .register_type::<PlayerIndex>()
+            .add_systems(
+                PreUpdate,
+                crate::systems::update_gamepad_registry.in_set(InputManagerSystem::Unify),
+            );
+
+        app.configure_sets(
+            PreUpdate,
+            InputManagerSystem::Unify
+                .after(InputSystem)
+                .after(InputManagerSystem::Tick),
+        );
     }
 }
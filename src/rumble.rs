@@ -0,0 +1,143 @@
+//! Gamepad rumble triggered by actions.
+//!
+//! While this crate is mostly concerned with input, it is often convenient to give haptic feedback
+//! in response to the actions of a player, such as rumbling the gamepad when firing a weapon.
+//!
+//! Add an [`ActionRumble`] component next to the [`ActionState`] of an entity,
+//! and each time one of its configured actions is [`just_pressed`](ActionState::just_pressed),
+//! a [`GamepadRumbleRequest`] is sent by the [`rumble_on_action`] system,
+//! which is added by the [`InputManagerPlugin`](crate::plugin::InputManagerPlugin).
+//!
+//! The gamepad to rumble is the one associated with the [`InputMap`] of the entity,
+//! as set by [`InputMap::set_gamepad`] or [`InputMap::set_player`].
+//! If no gamepad is associated, inputs are read from the first connected gamepad,
+//! so that is the gamepad that rumbles.
+
+use bevy::input::gamepad::{GamepadRumbleIntensity, GamepadRumbleRequest};
+use bevy::prelude::{Component, Entity, EventWriter, Gamepad, Query, Reflect, With};
+use bevy::utils::{Duration, HashMap};
+
+use crate::action_state::ActionState;
+use crate::input_map::InputMap;
+use crate::Actionlike;
+
+/// A rumble effect played on a gamepad, as sent by [`ActionRumble`].
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct RumbleEffect {
+    /// How intense the rumble should be.
+    pub intensity: GamepadRumbleIntensity,
+
+    /// How long the gamepad should rumble for.
+    pub duration: Duration,
+}
+
+impl RumbleEffect {
+    /// Creates a [`RumbleEffect`] with the given `intensity` and `duration`.
+    #[inline]
+    pub const fn new(intensity: GamepadRumbleIntensity, duration: Duration) -> Self {
+        Self {
+            intensity,
+            duration,
+        }
+    }
+}
+
+/// A component that rumbles the gamepad of an entity whenever one of the configured actions is just pressed.
+///
+/// The entity should also have an [`ActionState<A>`], and usually an [`InputMap<A>`]
+/// to determine which gamepad to rumble; see the [module documentation](self) for details.
+///
+/// ```rust
+/// use bevy::input::gamepad::GamepadRumbleIntensity;
+/// use bevy::prelude::*;
+/// use bevy::utils::Duration;
+/// use leafwing_input_manager::prelude::*;
+/// use leafwing_input_manager::rumble::{ActionRumble, RumbleEffect};
+///
+/// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+/// enum Action {
+///     Shoot,
+///     Jump,
+/// }
+///
+/// let rumble = ActionRumble::default().with(
+///     Action::Shoot,
+///     RumbleEffect::new(GamepadRumbleIntensity::MAX, Duration::from_millis(200)),
+/// );
+///
+/// assert!(rumble.effect(&Action::Shoot).is_some());
+/// assert!(rumble.effect(&Action::Jump).is_none());
+/// ```
+#[derive(Component, Debug, Clone, PartialEq, Reflect)]
+pub struct ActionRumble<A: Actionlike> {
+    /// The effect to play when each action is just pressed.
+    effects: HashMap<A, RumbleEffect>,
+}
+
+// Deriving default induces an undesired bound on the generic
+impl<A: Actionlike> Default for ActionRumble<A> {
+    fn default() -> Self {
+        Self {
+            effects: HashMap::default(),
+        }
+    }
+}
+
+impl<A: Actionlike> ActionRumble<A> {
+    /// Plays the given `effect` whenever the `action` is just pressed,
+    /// replacing any effect previously configured for it.
+    #[inline]
+    #[must_use]
+    pub fn with(mut self, action: A, effect: RumbleEffect) -> Self {
+        self.insert(action, effect);
+        self
+    }
+
+    /// Plays the given `effect` whenever the `action` is just pressed,
+    /// replacing any effect previously configured for it.
+    #[inline]
+    pub fn insert(&mut self, action: A, effect: RumbleEffect) -> &mut Self {
+        self.effects.insert(action, effect);
+        self
+    }
+
+    /// Stops playing an effect when the `action` is just pressed, returning the removed effect if any.
+    #[inline]
+    pub fn remove(&mut self, action: &A) -> Option<RumbleEffect> {
+        self.effects.remove(action)
+    }
+
+    /// Returns the effect played when the `action` is just pressed, if any.
+    #[inline]
+    #[must_use]
+    pub fn effect(&self, action: &A) -> Option<&RumbleEffect> {
+        self.effects.get(action)
+    }
+}
+
+/// Sends a [`GamepadRumbleRequest`] for each action configured in an [`ActionRumble`] that was just pressed.
+///
+/// Requests are only sent while a gamepad can be found for the entity.
+pub fn rumble_on_action<A: Actionlike>(
+    query: Query<(&ActionState<A>, &ActionRumble<A>, Option<&InputMap<A>>)>,
+    gamepads: Query<Entity, With<Gamepad>>,
+    mut requests: EventWriter<GamepadRumbleRequest>,
+) {
+    let first_gamepad = gamepads.iter().next();
+
+    for (action_state, rumble, input_map) in query.iter() {
+        let Some(gamepad) = input_map.and_then(InputMap::gamepad).or(first_gamepad) else {
+            continue;
+        };
+
+        for (action, effect) in rumble.effects.iter() {
+            if action_state.just_pressed(action) {
+                requests.send(GamepadRumbleRequest::Add {
+                    duration: effect.duration,
+                    intensity: effect.intensity,
+                    gamepad,
+                });
+            }
+        }
+    }
+}
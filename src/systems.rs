@@ -1,21 +1,35 @@
 //! The systems that power each [`InputManagerPlugin`](crate::plugin::InputManagerPlugin).
 
 use crate::prelude::updating::CentralInputStore;
+#[cfg(feature = "gamepad")]
+use crate::user_input::gamepad::GamepadRegistry;
+use crate::user_input::Buttonlike;
 use bevy::ecs::query::QueryFilter;
 use bevy::log::debug;
+use bevy::utils::HashSet;
 
 use crate::{
-    action_state::ActionState, clashing_inputs::ClashStrategy, input_map::InputMap, Actionlike,
+    action_state::ActionState,
+    clashing_inputs::ClashStrategy,
+    input_map::{InputContexts, InputMap},
+    Actionlike,
 };
 
 use bevy::ecs::prelude::*;
+#[cfg(feature = "gamepad")]
+use bevy::input::gamepad::GamepadConnectionEvent;
+#[cfg(feature = "mouse")]
+use bevy::input::mouse::{AccumulatedMouseMotion, AccumulatedMouseScroll};
 use bevy::prelude::Gamepad;
+#[cfg(feature = "mouse")]
+use bevy::window::WindowFocused;
 use bevy::{
     time::{Real, Time},
     utils::Instant,
 };
 
-use crate::action_diff::{ActionDiffEvent, SummarizedActionState};
+use crate::action_diff::{ActionDiffEpsilon, ActionDiffEvent, SummarizedActionState};
+use crate::action_events::{ActionPressed, ActionReleased};
 
 /// We are about to enter the `Main` schedule, so we:
 /// - save all the changes applied to `state` into the `fixed_update_state`
@@ -53,6 +67,8 @@ pub fn swap_to_fixed_update<A: Actionlike>(
 ///
 /// Clears the just-pressed and just-released values of all [`ActionState`]s.
 /// Also resets the internal `pressed_this_tick` field, used to track whether to release an action.
+///
+/// [`ActionState`] components are ticked in parallel using [`ActionState::tick_all_entities`].
 pub fn tick_action_state<A: Actionlike>(
     mut query: Query<&mut ActionState<A>>,
     action_state: Option<ResMut<ActionState<A>>>,
@@ -69,11 +85,9 @@ pub fn tick_action_state<A: Actionlike>(
     }
 
     // Only tick the ActionState components if they exist
-    for mut action_state in query.iter_mut() {
-        // If `Time` has not ever been advanced, something has gone horribly wrong
-        // and the user probably forgot to add the `core_plugin`.
-        action_state.tick(current_instant, previous_instant);
-    }
+    // If `Time` has not ever been advanced, something has gone horribly wrong
+    // and the user probably forgot to add the `core_plugin`.
+    ActionState::tick_all_entities(&mut query, current_instant, previous_instant);
 
     // Store the previous time in the system
     *stored_previous_instant = time.last_update();
@@ -82,10 +96,15 @@ pub fn tick_action_state<A: Actionlike>(
 /// Fetches the [`CentralInputStore`]
 /// to update [`ActionState`] according to the [`InputMap`].
 ///
-/// Clashes will be resolved according to the [`ClashStrategy`] resource.
+/// Clashes will be resolved according to the [`ClashStrategy`] resource,
+/// and bindings restricted to a context are only considered while it is active in the [`InputContexts`] resource.
+/// If that resource doesn't exist, no context is active.
+///
+/// The inputs of disabled actions are not evaluated: see [`InputMap::process_actions_with_state`].
 pub fn update_action_state<A: Actionlike>(
     input_store: Res<CentralInputStore>,
     clash_strategy: Res<ClashStrategy>,
+    contexts: Option<Res<InputContexts>>,
     mut gamepads: Query<Entity, With<Gamepad>>,
     action_state: Option<ResMut<ActionState<A>>>,
     input_map: Option<Res<InputMap<A>>>,
@@ -94,14 +113,242 @@ pub fn update_action_state<A: Actionlike>(
     let resources = input_map
         .zip(action_state)
         .map(|(input_map, action_state)| (Mut::from(action_state), input_map.into_inner()));
+    let no_contexts = InputContexts::default();
+    let contexts = contexts.as_deref().unwrap_or(&no_contexts);
 
     for (mut action_state, input_map) in query.iter_mut().chain(resources) {
-        action_state.update(input_map.process_actions(
+        let updated_actions = input_map.process_actions_with_state(
             Some(gamepads.reborrow()),
             &input_store,
             *clash_strategy,
-        ));
+            contexts,
+            &action_state,
+        );
+        action_state.update(updated_actions);
+    }
+}
+
+/// Records the input that caused each just pressed buttonlike action to be pressed,
+/// as returned by [`ActionState::triggering_input`].
+///
+/// This system is added by [`InputManagerPlugin::record_triggering_inputs`](crate::plugin::InputManagerPlugin::record_triggering_inputs).
+pub fn record_triggering_inputs<A: Actionlike>(
+    input_store: Res<CentralInputStore>,
+    contexts: Option<Res<InputContexts>>,
+    mut gamepads: Query<Entity, With<Gamepad>>,
+    action_state: Option<ResMut<ActionState<A>>>,
+    input_map: Option<Res<InputMap<A>>>,
+    mut query: Query<(&mut ActionState<A>, &InputMap<A>)>,
+) {
+    let resources = input_map
+        .zip(action_state)
+        .map(|(input_map, action_state)| (Mut::from(action_state), input_map.into_inner()));
+    let no_contexts = InputContexts::default();
+    let contexts = contexts.as_deref().unwrap_or(&no_contexts);
+
+    for (mut action_state, input_map) in query.iter_mut().chain(resources) {
+        let just_pressed: Vec<A> = action_state.just_pressed_actions().cloned().collect();
+        for action in just_pressed {
+            let Some(input) =
+                input_map.pressed_input(&action, Some(gamepads.reborrow()), &input_store, contexts)
+            else {
+                continue;
+            };
+
+            action_state
+                .button_data_mut_or_default(&action)
+                .triggering_input = Some(dyn_clone::clone_box(input));
+        }
+    }
+}
+
+/// Sends an [`ActionPressed`] or [`ActionReleased`] event for each buttonlike action that was just pressed or released.
+///
+/// Each event is written to its [`Events`](bevy::prelude::Events) resource and triggered for observers,
+/// targeting the entity of the [`ActionState`] if it is a component.
+///
+/// This system is added by [`InputManagerPlugin::send_action_events`](crate::plugin::InputManagerPlugin::send_action_events).
+pub fn send_action_events<A: Actionlike>(
+    mut commands: Commands,
+    action_state: Option<Res<ActionState<A>>>,
+    query: Query<(Entity, &ActionState<A>)>,
+    mut pressed_events: EventWriter<ActionPressed<A>>,
+    mut released_events: EventWriter<ActionReleased<A>>,
+) {
+    let resource = action_state
+        .as_deref()
+        .map(|action_state| (None, action_state));
+
+    for (entity, action_state) in query
+        .iter()
+        .map(|(entity, action_state)| (Some(entity), action_state))
+        .chain(resource)
+    {
+        for action in action_state.just_pressed_actions() {
+            let event = ActionPressed {
+                entity,
+                action: action.clone(),
+            };
+            match entity {
+                Some(entity) => commands.trigger_targets(event.clone(), entity),
+                None => commands.trigger(event.clone()),
+            }
+            pressed_events.send(event);
+        }
+
+        for action in action_state.just_released_actions() {
+            let event = ActionReleased {
+                entity,
+                action: action.clone(),
+            };
+            match entity {
+                Some(entity) => commands.trigger_targets(event.clone(), entity),
+                None => commands.trigger(event.clone()),
+            }
+            released_events.send(event);
+        }
+    }
+}
+
+/// Clears the [`AccumulatedMouseMotion`] and [`AccumulatedMouseScroll`] resources
+/// while a window is unfocused, and on the frames where a window loses or regains focus.
+///
+/// Mouse events that pile up around focus changes would otherwise be read as a single large spike of input.
+///
+/// This system is added by [`InputManagerPlugin::reset_mouse_on_focus_change`](crate::plugin::InputManagerPlugin::reset_mouse_on_focus_change).
+#[cfg(feature = "mouse")]
+pub fn reset_mouse_accumulation_on_focus_change(
+    mut focus_events: EventReader<WindowFocused>,
+    mut unfocused: Local<bool>,
+    mut accumulated_motion: ResMut<AccumulatedMouseMotion>,
+    mut accumulated_scroll: ResMut<AccumulatedMouseScroll>,
+) {
+    let mut focus_changed = false;
+    for event in focus_events.read() {
+        focus_changed = true;
+        *unfocused = !event.focused;
+    }
+
+    if focus_changed || *unfocused {
+        *accumulated_motion = AccumulatedMouseMotion::default();
+        *accumulated_scroll = AccumulatedMouseScroll::default();
+    }
+}
+
+/// Assigns newly connected gamepads to a [`PlayerIndex`](crate::user_input::gamepad::PlayerIndex) in the [`GamepadRegistry`],
+/// freeing the indexes of disconnected gamepads.
+///
+/// Gamepads that were already connected before this system first ran are assigned too,
+/// in the order of their entities.
+#[cfg(feature = "gamepad")]
+pub fn update_gamepad_registry(
+    mut registry: ResMut<GamepadRegistry>,
+    mut connection_events: EventReader<GamepadConnectionEvent>,
+    added_gamepads: Query<Entity, Added<Gamepad>>,
+) {
+    for event in connection_events.read() {
+        if event.connected() {
+            registry.connect(event.gamepad);
+        } else {
+            registry.disconnect(event.gamepad);
+        }
+    }
+
+    let mut added_gamepads: Vec<Entity> = added_gamepads.iter().collect();
+    added_gamepads.sort();
+    for gamepad in added_gamepads {
+        registry.connect(gamepad);
+    }
+}
+
+/// Associates each [`InputMap`] assigned to a player using [`InputMap::set_player`]
+/// with the gamepad assigned to that player in the [`GamepadRegistry`].
+///
+/// Players without a gamepad have no associated gamepad,
+/// and do not accept input from other players' gamepads.
+#[cfg(feature = "gamepad")]
+pub fn assign_player_gamepads<A: Actionlike>(
+    registry: Res<GamepadRegistry>,
+    input_map: Option<ResMut<InputMap<A>>>,
+    mut query: Query<&mut InputMap<A>>,
+) {
+    let resource = input_map.map(Mut::from);
+    for mut input_map in query.iter_mut().chain(resource) {
+        let Some(player) = input_map.player() else {
+            continue;
+        };
+
+        let gamepad = registry.gamepad(player);
+        if input_map.gamepad() != gamepad {
+            match gamepad {
+                Some(gamepad) => input_map.set_gamepad(gamepad),
+                None => input_map.clear_gamepad(),
+            };
+        }
+    }
+}
+
+/// Completes any capture started with [`InputMap::begin_capture`],
+/// binding the first newly pressed [`Buttonlike`](crate::user_input::Buttonlike) input to the pending action.
+///
+/// Inputs that were already pressed on the previous frame are ignored,
+/// so that the key used to start the capture is not immediately bound.
+///
+/// If several inputs are newly pressed on the same frame,
+/// the one whose [`Debug`] representation sorts first is bound, so that the choice is deterministic.
+///
+/// Input maps with an [associated gamepad](InputMap::gamepad) ignore the buttons of other gamepads.
+pub fn capture_input_bindings<A: Actionlike>(
+    input_store: Res<CentralInputStore>,
+    mut previously_pressed: Local<HashSet<Box<dyn Buttonlike>>>,
+    input_map: Option<ResMut<InputMap<A>>>,
+    mut query: Query<&mut InputMap<A>>,
+) {
+    let mut newly_pressed: Vec<&dyn Buttonlike> = input_store
+        .pressed_buttonlikes()
+        .filter(|input| !previously_pressed.contains(*input))
+        .collect();
+    newly_pressed.sort_by_cached_key(|input| format!("{input:?}"));
+
+    if !newly_pressed.is_empty() {
+        let resource = input_map.map(Mut::from);
+        for mut input_map in query.iter_mut().chain(resource) {
+            if input_map.pending_capture().is_none() {
+                continue;
+            }
+
+            let gamepad = input_map.gamepad();
+            if let Some(input) = newly_pressed
+                .iter()
+                .find(|input| is_capturable_by(**input, gamepad))
+            {
+                input_map.complete_capture(dyn_clone::clone_box(*input));
+            }
+        }
     }
+
+    previously_pressed.clear();
+    previously_pressed.extend(input_store.pressed_buttonlikes().map(dyn_clone::clone_box));
+}
+
+/// Checks if the `input` can be captured by an [`InputMap`] associated with the given `gamepad`.
+///
+/// Buttons of a specific gamepad can only be captured by maps without a gamepad or associated with that gamepad.
+fn is_capturable_by(input: &dyn Buttonlike, gamepad: Option<Entity>) -> bool {
+    #[cfg(feature = "gamepad")]
+    if let (Some(gamepad), Some(button)) = (
+        gamepad,
+        input
+            .as_reflect()
+            .downcast_ref::<crate::user_input::gamepad::SpecificGamepadButton>(),
+    ) {
+        return button.gamepad == gamepad;
+    }
+
+    #[cfg(not(feature = "gamepad"))]
+    let _ = (input, gamepad);
+
+    true
 }
 
 #[cfg(any(feature = "egui", feature = "ui"))]
@@ -142,15 +389,19 @@ pub fn filter_captured_input(
 /// This system is not part of the [`InputManagerPlugin`](crate::plugin::InputManagerPlugin) and must be added manually.
 /// Generally speaking, this should be added as part of [`PostUpdate`](bevy::prelude::PostUpdate),
 /// to ensure that all inputs have been processed and any manual actions have been sent.
+///
+/// Changes to axislike values smaller than the [`ActionDiffEpsilon`] resource are not sent until they accumulate.
 pub fn generate_action_diffs<A: Actionlike>(
     global_action_state: Option<Res<ActionState<A>>>,
     action_state_query: Query<(Entity, &ActionState<A>)>,
+    epsilon: Option<Res<ActionDiffEpsilon>>,
     previous_action_state: Local<SummarizedActionState<A>>,
     action_diff_events: EventWriter<ActionDiffEvent<A>>,
 ) {
     generate_action_diffs_filtered(
         global_action_state,
         action_state_query,
+        epsilon,
         previous_action_state,
         action_diff_events,
     )
@@ -163,20 +414,56 @@ pub fn generate_action_diffs<A: Actionlike>(
 /// to ensure that all inputs have been processed and any manual actions have been sent.
 ///
 /// This system accepts a [`QueryFilter`] to limit which entities should have action diffs generated.
+/// Changes to axislike values smaller than the [`ActionDiffEpsilon`] resource are not sent until they accumulate.
 pub fn generate_action_diffs_filtered<A: Actionlike, F: QueryFilter>(
     global_action_state: Option<Res<ActionState<A>>>,
     action_state_query: Query<(Entity, &ActionState<A>), F>,
+    epsilon: Option<Res<ActionDiffEpsilon>>,
     mut previous_action_state: Local<SummarizedActionState<A>>,
     mut action_diff_events: EventWriter<ActionDiffEvent<A>>,
 ) {
-    let current_action_state =
+    let epsilon = epsilon.map(|epsilon| *epsilon).unwrap_or_default();
+    let mut current_action_state =
         SummarizedActionState::summarize_filtered(global_action_state, action_state_query);
+    // Small changes are merged into the previously sent values,
+    // so they accumulate until they are large enough to be sent
+    current_action_state.coalesce_axes(&previous_action_state, epsilon.0);
     current_action_state.send_diffs(&previous_action_state, &mut action_diff_events);
     debug!("previous_action_state: {:?}", previous_action_state);
     debug!("current_action_state: {:?}", current_action_state);
     *previous_action_state = current_action_state;
 }
 
+/// Applies every incoming [`ActionDiffEvent`] to the matching [`ActionState`].
+///
+/// Events without an owner are applied to the [`ActionState`] resource,
+/// while events with an owner are applied to the [`ActionState`] component of that entity.
+/// Events whose target does not exist are ignored.
+///
+/// By default, [`InputManagerPlugin::server`](crate::plugin::InputManagerPlugin::server) will run this on [`PreUpdate`](bevy::prelude::PreUpdate).
+/// Entities are not remapped: if the client and server worlds differ,
+/// map the [`ActionDiffEvent::owner`] before sending the events.
+pub fn apply_action_diffs<A: Actionlike>(
+    mut action_diff_events: EventReader<ActionDiffEvent<A>>,
+    mut action_state_query: Query<&mut ActionState<A>>,
+    mut action_state: Option<ResMut<ActionState<A>>>,
+) {
+    for action_diff_event in action_diff_events.read() {
+        match action_diff_event.owner {
+            Some(owner) => {
+                if let Ok(mut action_state) = action_state_query.get_mut(owner) {
+                    action_state.apply_diffs(&action_diff_event.action_diffs);
+                }
+            }
+            None => {
+                if let Some(action_state) = action_state.as_mut() {
+                    action_state.apply_diffs(&action_diff_event.action_diffs);
+                }
+            }
+        }
+    }
+}
+
 /// Release all inputs when an [`InputMap<A>`] is removed to prevent them from being held forever.
 ///
 /// By default, [`InputManagerPlugin<A>`](crate::plugin::InputManagerPlugin) will run this on [`PostUpdate`](bevy::prelude::PostUpdate).
@@ -213,6 +500,15 @@ pub fn release_on_input_map_removed<A: Actionlike>(
 
 /// Clears all values from the [`CentralInputStore`],
 /// making sure that it can read fresh inputs for the frame.
-pub fn clear_central_input_store(mut input_store: ResMut<CentralInputStore>) {
+///
+/// Also records the time elapsed according to [`Time<Real>`], if it is available.
+pub fn clear_central_input_store(
+    mut input_store: ResMut<CentralInputStore>,
+    time: Option<Res<Time<Real>>>,
+) {
     input_store.clear();
+
+    if let Some(time) = time {
+        input_store.set_elapsed(time.elapsed());
+    }
 }
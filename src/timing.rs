@@ -23,6 +23,15 @@ pub struct Timing {
     pub current_duration: Duration,
     /// The [`Duration`] for which the button was pressed or released before the state last changed.
     pub previous_duration: Duration,
+    /// The [`Instant`] at which the button was most recently pressed.
+    /// Recorded as the [`Time`](bevy::time::Time) at the start of the tick after it was pressed.
+    /// If this is none, the button has not been pressed, or has not been ticked since it was last pressed.
+    #[serde(skip)]
+    pub instant_pressed: Option<Instant>,
+    /// The [`Instant`] at which the button was consumed, if it has been consumed since it was last pressed.
+    /// Recorded as the [`Time`](bevy::time::Time) of the most recent tick before it was consumed.
+    #[serde(skip)]
+    pub instant_consumed: Option<Instant>,
 }
 
 impl Timing {
@@ -31,6 +40,8 @@ impl Timing {
         instant_started: None,
         current_duration: Duration::ZERO,
         previous_duration: Duration::ZERO,
+        instant_pressed: None,
+        instant_consumed: None,
     };
 }
 
@@ -54,6 +65,43 @@ impl Timing {
         }
     }
 
+    /// The [`Duration`] between the most recent press and the last tick
+    ///
+    /// This will be [`None`] if the button was never pressed,
+    /// or if it has not been ticked since it was last pressed.
+    #[must_use]
+    pub fn time_since_pressed(&self) -> Option<Duration> {
+        let instant_pressed = self.instant_pressed?;
+        let last_tick = self.instant_started? + self.current_duration;
+        Some(last_tick.saturating_duration_since(instant_pressed))
+    }
+
+    /// The [`Duration`] between the most recent press and its consumption,
+    /// or between the most recent press and the most recent tick if it has not been consumed yet
+    ///
+    /// This will be [`None`] if the button was never pressed,
+    /// or if it has not been ticked since it was last pressed.
+    #[must_use]
+    pub fn latency_since_pressed(&self) -> Option<Duration> {
+        let instant_pressed = self.instant_pressed?;
+        let instant_handled = match self.instant_consumed {
+            Some(instant_consumed) => instant_consumed,
+            None => self.instant_started? + self.current_duration,
+        };
+        Some(instant_handled.saturating_duration_since(instant_pressed))
+    }
+
+    /// Records the time of the most recent tick as the moment the current press was consumed
+    ///
+    /// Does nothing if the button has not been ticked since it was last pressed.
+    pub fn consume(&mut self) {
+        if self.instant_pressed.is_some() {
+            self.instant_consumed = self
+                .instant_started
+                .map(|instant_started| instant_started + self.current_duration);
+        }
+    }
+
     /// Flips the metaphorical hourglass, storing `current_duration` in `previous_duration` and resetting `instant_started`
     ///
     /// This method is called whenever actions are pressed or released
@@ -64,6 +112,79 @@ impl Timing {
     }
 }
 
+/// Configures how a held buttonlike action repeats, like a key held down in a text field.
+///
+/// After the action has been held for the `initial_delay`,
+/// it repeats once every `interval` for as long as it remains held.
+/// Each repeat makes the action [`just_pressed`](crate::action_state::ActionState::just_pressed) again
+/// for a single tick, checked when the [`ActionState`](crate::action_state::ActionState) is ticked.
+/// An action repeats at most once per tick, even if its `interval` is shorter than the frame time,
+/// and releasing it restarts the `initial_delay`.
+///
+/// Set this on an action using [`ActionState::set_repeat`](crate::action_state::ActionState::set_repeat).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Reflect)]
+pub struct RepeatConfig {
+    /// How long the action must be held before it first repeats.
+    pub initial_delay: Duration,
+    /// How long to wait between each subsequent repeat.
+    pub interval: Duration,
+}
+
+impl RepeatConfig {
+    /// Creates a new [`RepeatConfig`] with the given `initial_delay` and `interval`.
+    #[inline]
+    pub const fn new(initial_delay: Duration, interval: Duration) -> Self {
+        Self {
+            initial_delay,
+            interval,
+        }
+    }
+
+    /// Returns the remaining time until the next repeat,
+    /// for an action that has been held for the `held` duration.
+    ///
+    /// An `interval` of [`Duration::ZERO`] repeats continuously once the `initial_delay` has elapsed.
+    #[must_use]
+    pub fn time_until_repeat(&self, held: Duration) -> Duration {
+        if held < self.initial_delay {
+            return self.initial_delay - held;
+        }
+
+        let interval = self.interval.as_nanos();
+        if interval == 0 {
+            return Duration::ZERO;
+        }
+
+        let since_last_repeat = (held - self.initial_delay).as_nanos() % interval;
+        Duration::from_nanos((interval - since_last_repeat) as u64)
+    }
+
+    /// Returns `true` if an action held for the `previously_held` duration
+    /// repeats before it has been held for the `held` duration.
+    ///
+    /// An `interval` of [`Duration::ZERO`] repeats every time that the held duration increases
+    /// once the `initial_delay` has elapsed.
+    #[must_use]
+    pub fn repeats_between(&self, previously_held: Duration, held: Duration) -> bool {
+        if held <= previously_held || held < self.initial_delay {
+            return false;
+        }
+
+        if previously_held < self.initial_delay {
+            return true;
+        }
+
+        let interval = self.interval.as_nanos();
+        if interval == 0 {
+            return true;
+        }
+
+        let previous_repeats = (previously_held - self.initial_delay).as_nanos() / interval;
+        let repeats = (held - self.initial_delay).as_nanos() / interval;
+        repeats > previous_repeats
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate as leafwing_input_manager;
@@ -157,4 +278,335 @@ mod tests {
         assert_eq!(action_state.current_duration(&Action::Jump), Duration::ZERO);
         assert_eq!(action_state.previous_duration(&Action::Jump), t2 - t0);
     }
+
+    #[test]
+    fn time_until_repeat() {
+        use crate::action_state::ActionState;
+        use crate::timing::RepeatConfig;
+        use bevy::utils::{Duration, Instant};
+
+        let mut action_state = ActionState::<Action>::default();
+        let repeat = RepeatConfig::new(Duration::from_millis(100), Duration::from_millis(50));
+        action_state.set_repeat(&Action::Run, Some(repeat));
+        assert_eq!(action_state.repeat(&Action::Run), Some(repeat));
+
+        // Released actions and actions without a repeat never repeat
+        assert_eq!(action_state.time_until_repeat(&Action::Run), None);
+        action_state.press(&Action::Jump);
+        assert_eq!(action_state.time_until_repeat(&Action::Jump), None);
+
+        // Before the initial delay has elapsed
+        let t0 = Instant::now();
+        action_state.press(&Action::Run);
+        action_state.tick(t0 + Duration::from_millis(40), t0);
+        assert_eq!(
+            action_state.time_until_repeat(&Action::Run),
+            Some(Duration::from_millis(60))
+        );
+
+        // Mid-way through the first repeat interval
+        action_state.tick(t0 + Duration::from_millis(125), t0);
+        assert_eq!(
+            action_state.time_until_repeat(&Action::Run),
+            Some(Duration::from_millis(25))
+        );
+
+        // Mid-way through a later repeat interval
+        action_state.tick(t0 + Duration::from_millis(260), t0);
+        assert_eq!(
+            action_state.time_until_repeat(&Action::Run),
+            Some(Duration::from_millis(40))
+        );
+
+        // Releasing the action stops it from repeating
+        action_state.release(&Action::Run);
+        assert_eq!(action_state.time_until_repeat(&Action::Run), None);
+
+        action_state.set_repeat(&Action::Run, None);
+        assert_eq!(action_state.repeat(&Action::Run), None);
+    }
+
+    #[test]
+    fn held_actions_repeat() {
+        use crate::action_state::ActionState;
+        use crate::timing::RepeatConfig;
+        use bevy::utils::{Duration, Instant};
+
+        let mut action_state = ActionState::<Action>::default();
+        let repeat = RepeatConfig::new(Duration::from_millis(100), Duration::from_millis(50));
+        action_state.set_repeat(&Action::Run, Some(repeat));
+
+        let t0 = Instant::now();
+        action_state.press(&Action::Run);
+        assert!(action_state.just_pressed(&Action::Run));
+
+        // Hold the action across several repeat intervals, ticking every 20 ms
+        let mut previous_instant = t0;
+        let mut repeated_at = Vec::new();
+        for frame in 1..=14 {
+            let current_instant = t0 + Duration::from_millis(20 * frame);
+            action_state.tick(current_instant, previous_instant);
+            previous_instant = current_instant;
+
+            assert!(action_state.pressed(&Action::Run));
+            if action_state.just_pressed(&Action::Run) {
+                repeated_at.push(20 * frame);
+            }
+        }
+
+        // The first repeat happens after the initial delay, and the rest once every interval
+        assert_eq!(repeated_at, vec![100, 160, 200, 260]);
+
+        // Released actions stop repeating
+        action_state.release(&Action::Run);
+        for frame in 15..=20 {
+            let current_instant = t0 + Duration::from_millis(20 * frame);
+            action_state.tick(current_instant, previous_instant);
+            previous_instant = current_instant;
+
+            assert!(!action_state.just_pressed(&Action::Run));
+        }
+
+        // Actions without a repeat config are only just pressed once
+        action_state.press(&Action::Jump);
+        for frame in 21..=40 {
+            let current_instant = t0 + Duration::from_millis(20 * frame);
+            action_state.tick(current_instant, previous_instant);
+            previous_instant = current_instant;
+
+            assert!(!action_state.just_pressed(&Action::Jump));
+        }
+    }
+
+    #[test]
+    fn repeats_reset_when_released() {
+        use crate::action_state::ActionState;
+        use crate::timing::RepeatConfig;
+        use bevy::utils::{Duration, Instant};
+
+        // An interval much shorter than the 20 ms frame time
+        let mut action_state = ActionState::<Action>::default();
+        let repeat = RepeatConfig::new(Duration::from_millis(100), Duration::from_millis(5));
+        action_state.set_repeat(&Action::Run, Some(repeat));
+
+        let t0 = Instant::now();
+        let mut previous_instant = t0;
+        let mut tick = |action_state: &mut ActionState<Action>, frame: u64| {
+            let current_instant = t0 + Duration::from_millis(20 * frame);
+            action_state.tick(current_instant, previous_instant);
+            previous_instant = current_instant;
+        };
+
+        // Repeats that happen within the same frame are collapsed into a single pulse
+        action_state.press(&Action::Run);
+        let mut repeated_at = Vec::new();
+        for frame in 1..=8 {
+            tick(&mut action_state, frame);
+            if action_state.just_pressed(&Action::Run) {
+                repeated_at.push(20 * frame);
+            }
+        }
+        assert_eq!(repeated_at, vec![100, 120, 140, 160]);
+
+        // Releasing mid-repeat restarts the initial delay once pressed again
+        action_state.release(&Action::Run);
+        tick(&mut action_state, 9);
+        action_state.press(&Action::Run);
+
+        let mut repeated_at = Vec::new();
+        for frame in 10..=15 {
+            tick(&mut action_state, frame);
+            assert!(action_state.pressed(&Action::Run));
+            if action_state.just_pressed(&Action::Run) {
+                repeated_at.push(20 * frame);
+            }
+        }
+        assert_eq!(repeated_at, vec![280, 300]);
+    }
+
+    #[test]
+    fn time_since_just_pressed() {
+        use crate::action_state::ActionState;
+        use bevy::utils::{Duration, Instant};
+
+        let mut action_state = ActionState::<Action>::default();
+        let close = |elapsed: Option<Duration>, expected: Duration| {
+            elapsed.is_some_and(|elapsed| elapsed.abs_diff(expected) <= Duration::from_micros(1))
+        };
+
+        // Actions that were never pressed have no press to measure from
+        let t0 = Instant::now();
+        action_state.tick(t0, t0 - Duration::from_millis(16));
+        assert_eq!(action_state.time_since_just_pressed(&Action::Jump), None);
+
+        // Timing information is only populated once the action is ticked
+        action_state.press(&Action::Jump);
+        assert_eq!(action_state.time_since_just_pressed(&Action::Jump), None);
+
+        let t1 = t0 + Duration::from_millis(16);
+        action_state.tick(t1, t0);
+        assert!(close(
+            action_state.time_since_just_pressed(&Action::Jump),
+            Duration::from_millis(16)
+        ));
+
+        let t2 = t1 + Duration::from_millis(250);
+        action_state.tick(t2, t1);
+        assert!(close(
+            action_state.time_since_just_pressed(&Action::Jump),
+            Duration::from_millis(266)
+        ));
+
+        // Releasing the action keeps measuring from the most recent press
+        action_state.release(&Action::Jump);
+        let t3 = t2 + Duration::from_millis(100);
+        action_state.tick(t3, t2);
+        assert!(close(
+            action_state.time_since_just_pressed(&Action::Jump),
+            Duration::from_millis(366)
+        ));
+
+        // Pressing the action again measures from the new press
+        action_state.press(&Action::Jump);
+        assert_eq!(action_state.time_since_just_pressed(&Action::Jump), None);
+        let t4 = t3 + Duration::from_millis(10);
+        action_state.tick(t4, t3);
+        assert!(close(
+            action_state.time_since_just_pressed(&Action::Jump),
+            Duration::from_millis(10)
+        ));
+    }
+
+    #[test]
+    fn cooldowns() {
+        use crate::action_state::ActionState;
+        use bevy::utils::{Duration, Instant};
+
+        let mut action_state = ActionState::<Action>::default();
+        action_state.set_cooldown(&Action::Jump, Duration::from_millis(500));
+        assert_eq!(
+            action_state.cooldown(&Action::Jump),
+            Duration::from_millis(500)
+        );
+
+        // Firing the action starts its cooldown
+        let t0 = Instant::now();
+        action_state.press(&Action::Jump);
+        assert!(action_state.try_fire(&Action::Jump));
+        assert_eq!(
+            action_state.cooldown_remaining(&Action::Jump),
+            Duration::from_millis(500)
+        );
+
+        // Pressing during the cooldown is ignored
+        let t1 = t0 + Duration::from_millis(200);
+        action_state.tick(t1, t0);
+        action_state.release(&Action::Jump);
+        action_state.press(&Action::Jump);
+        assert!(action_state.pressed(&Action::Jump));
+        assert!(!action_state.just_pressed(&Action::Jump));
+        assert!(!action_state.try_fire(&Action::Jump));
+        assert_eq!(
+            action_state.cooldown_remaining(&Action::Jump),
+            Duration::from_millis(300)
+        );
+
+        // Pressing after the cooldown works again
+        let t2 = t1 + Duration::from_millis(300);
+        action_state.tick(t2, t1);
+        action_state.release(&Action::Jump);
+        action_state.press(&Action::Jump);
+        assert_eq!(
+            action_state.cooldown_remaining(&Action::Jump),
+            Duration::ZERO
+        );
+        assert!(action_state.just_pressed(&Action::Jump));
+        assert!(action_state.try_fire(&Action::Jump));
+
+        // Actions without a cooldown can always be fired
+        action_state.press(&Action::Run);
+        assert!(action_state.try_fire(&Action::Run));
+        assert!(action_state.just_pressed(&Action::Run));
+        assert!(!action_state.try_fire(&Action::Hide));
+    }
+
+    #[test]
+    fn cooldowns_are_tracked_per_schedule() {
+        use crate::action_state::ActionState;
+        use bevy::utils::{Duration, Instant};
+
+        let mut action_state = ActionState::<Action>::default();
+        action_state.set_cooldown(&Action::Jump, Duration::from_millis(500));
+
+        // Fire the action in the `Main` schedule
+        let t0 = Instant::now();
+        action_state.press(&Action::Jump);
+        assert!(action_state.try_fire(&Action::Jump));
+
+        // Ticking the `FixedMain` schedule doesn't count down the cooldown of the `Main` schedule
+        action_state.swap_to_fixed_update_state();
+        let t1 = t0 + Duration::from_millis(200);
+        action_state.tick(t1, t0);
+        assert_eq!(
+            action_state.cooldown_remaining(&Action::Jump),
+            Duration::ZERO
+        );
+        action_state.swap_to_update_state();
+        assert_eq!(
+            action_state.cooldown_remaining(&Action::Jump),
+            Duration::from_millis(500)
+        );
+
+        action_state.tick(t1, t0);
+        assert_eq!(
+            action_state.cooldown_remaining(&Action::Jump),
+            Duration::from_millis(300)
+        );
+    }
+
+    #[test]
+    fn latency_since_press() {
+        use crate::action_state::ActionState;
+        use bevy::utils::{Duration, Instant};
+
+        let mut action_state = ActionState::<Action>::default();
+        let delay = Duration::from_millis(50);
+
+        // Actions that were never pressed have no latency
+        assert_eq!(action_state.latency_since_press(&Action::Jump), None);
+
+        // The press is recorded at the start of the tick after it was pressed
+        action_state.press(&Action::Jump);
+        assert_eq!(action_state.latency_since_press(&Action::Jump), None);
+        let now = Instant::now();
+        action_state.tick(now, now - delay);
+
+        // Before the action is consumed, the latency grows with each tick
+        assert_eq!(action_state.latency_since_press(&Action::Jump), Some(delay));
+        action_state.tick(now + delay, now);
+        assert_eq!(
+            action_state.latency_since_press(&Action::Jump),
+            Some(delay * 2)
+        );
+
+        // Consuming the action stops the clock at the most recent tick
+        action_state.consume(&Action::Jump);
+        assert_eq!(
+            action_state.latency_since_press(&Action::Jump),
+            Some(delay * 2)
+        );
+
+        // Later ticks and repeated consumption do not change the measured latency
+        action_state.tick(now + delay * 2, now + delay);
+        action_state.consume(&Action::Jump);
+        assert_eq!(
+            action_state.latency_since_press(&Action::Jump),
+            Some(delay * 2)
+        );
+
+        // Pressing the action again starts a new measurement
+        action_state.release(&Action::Jump);
+        action_state.press(&Action::Jump);
+        assert_eq!(action_state.latency_since_press(&Action::Jump), None);
+    }
 }
@@ -0,0 +1,421 @@
+//! This module contains [`AxisInRange`] and its impls.
+
+use bevy::prelude::{Entity, Reflect, World};
+use leafwing_input_manager_macros::serde_typetag;
+use serde::{Deserialize, Serialize};
+
+use crate as leafwing_input_manager;
+use crate::clashing_inputs::BasicInputs;
+use crate::input_processing::{AxisBounds, ProcessorError};
+use crate::user_input::stateful::hysteresis_latched;
+use crate::user_input::{Axislike, Buttonlike, UserInput};
+use crate::InputControlKind;
+
+use super::updating::CentralInputStore;
+
+/// A buttonlike input that is pressed while the value of an [`Axislike`] is within a range,
+/// such as a trigger held between a third and two thirds of the way down.
+///
+/// Both ends of the range are inclusive.
+/// The range can be any part of the axis, including negative values,
+/// so this can also be used to press a button when a stick is pushed partway in one direction.
+///
+/// To keep analog inputs resting near the edge of the range from rapidly toggling the button on and off,
+/// use [`AxisInRange::with_release_range`] to add hysteresis.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::prelude::*;
+///
+/// #[derive(Actionlike, PartialEq, Eq, Clone, Copy, Hash, Debug, Reflect)]
+/// enum Action {
+///     HalfThrottle,
+/// }
+///
+/// // Pressed while the right trigger is held between 30% and 70% of the way down
+/// let input = AxisInRange::new(GamepadControlAxis::RIGHT_Z, 0.3, 0.7);
+/// assert_eq!(input.range(), AxisBounds::new(0.3, 0.7));
+///
+/// let input_map = InputMap::new([(Action::HalfThrottle, input)]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+#[serde(try_from = "UncheckedAxisInRange")]
+#[must_use]
+pub struct AxisInRange {
+    /// The [`Axislike`] whose value is checked.
+    pub(crate) axis: Box<dyn Axislike>,
+
+    /// The range of values for which the input is pressed.
+    pub(crate) range: AxisBounds,
+
+    /// The range of values that keeps the input pressed once it has been pressed, containing the `range`.
+    ///
+    /// When `None`, the input is released as soon as the value leaves the `range`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) release_range: Option<AxisBounds>,
+}
+
+/// The serialized form of an [`AxisInRange`], validated before being converted into one.
+#[derive(Deserialize)]
+#[serde(rename = "AxisInRange")]
+struct UncheckedAxisInRange {
+    axis: Box<dyn Axislike>,
+    range: AxisBounds,
+    #[serde(default)]
+    release_range: Option<AxisBounds>,
+}
+
+impl TryFrom<UncheckedAxisInRange> for AxisInRange {
+    type Error = &'static str;
+
+    fn try_from(
+        UncheckedAxisInRange {
+            axis,
+            range,
+            release_range,
+        }: UncheckedAxisInRange,
+    ) -> Result<Self, Self::Error> {
+        if release_range.is_some_and(|release_range| !contains_range(release_range, range)) {
+            return Err("an AxisInRange requires a release range containing its range");
+        }
+        Ok(Self {
+            axis,
+            range,
+            release_range,
+        })
+    }
+}
+
+/// Checks if the `outer` range contains the whole `inner` range.
+#[must_use]
+#[inline]
+fn contains_range(outer: AxisBounds, inner: AxisBounds) -> bool {
+    outer.min() <= inner.min() && inner.max() <= outer.max()
+}
+
+impl AxisInRange {
+    /// Creates an [`AxisInRange`] that is pressed while the value of the `axis` is within `[min, max]`.
+    ///
+    /// # Requirements
+    ///
+    /// - `min` <= `max`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the requirements aren't met.
+    #[track_caller]
+    #[inline]
+    pub fn new(axis: impl Axislike, min: f32, max: f32) -> Self {
+        Self {
+            axis: Box::new(axis),
+            range: AxisBounds::new(min, max),
+            release_range: None,
+        }
+    }
+
+    /// Creates an [`AxisInRange`] that is pressed while the value of the `axis` is within `[min, max]`,
+    /// like [`AxisInRange::new`] but without panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProcessorError::InvertedRange`] if `min` is greater than `max`, or either is NaN.
+    #[inline]
+    pub fn try_new(axis: impl Axislike, min: f32, max: f32) -> Result<Self, ProcessorError> {
+        Ok(Self {
+            axis: Box::new(axis),
+            range: AxisBounds::try_new(min, max)?,
+            release_range: None,
+        })
+    }
+
+    /// Adds hysteresis to this input: once pressed, it stays pressed until the value of the axis
+    /// leaves `[release_min, release_max]`, rather than as soon as it leaves the range.
+    ///
+    /// The latched state is stored using [`CentralInputStore::with_input_memory`].
+    /// If the input isn't read for [`CentralInputStore::INPUT_MEMORY_LIFETIME`] frames,
+    /// such as while its action is disabled, its state is forgotten and it is released again.
+    ///
+    /// # Requirements
+    ///
+    /// - `release_min` <= `min` of the range.
+    /// - `release_max` >= `max` of the range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the requirements aren't met.
+    ///
+    /// ```rust
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// // Pressed once the right trigger is 60% of the way down, until it is let back up past 40%
+    /// let input = AxisInRange::new(GamepadControlAxis::RIGHT_Z, 0.6, 1.0).with_release_range(0.4, 1.0);
+    /// assert_eq!(input.range(), AxisBounds::new(0.6, 1.0));
+    /// assert_eq!(input.release_range(), AxisBounds::new(0.4, 1.0));
+    /// ```
+    #[track_caller]
+    #[inline]
+    pub fn with_release_range(mut self, release_min: f32, release_max: f32) -> Self {
+        let release_range = AxisBounds::new(release_min, release_max);
+        assert!(
+            contains_range(release_range, self.range),
+            "the release range {release_range:?} of an AxisInRange must contain its range {:?}",
+            self.range
+        );
+        self.release_range = Some(release_range);
+        self
+    }
+
+    /// Returns the [`Axislike`] whose value is checked.
+    #[must_use]
+    #[inline]
+    pub fn axis(&self) -> &dyn Axislike {
+        self.axis.as_ref()
+    }
+
+    /// Returns the range of values for which this input is pressed.
+    #[inline]
+    pub fn range(&self) -> AxisBounds {
+        self.range
+    }
+
+    /// Returns the range of values that keeps this input pressed once it has been pressed.
+    ///
+    /// Without [hysteresis](AxisInRange::with_release_range), this is the same as the [range](AxisInRange::range).
+    #[inline]
+    pub fn release_range(&self) -> AxisBounds {
+        self.release_range.unwrap_or(self.range)
+    }
+}
+
+impl UserInput for AxisInRange {
+    /// [`AxisInRange`] acts as a button.
+    #[inline]
+    fn kind(&self) -> InputControlKind {
+        InputControlKind::Button
+    }
+
+    /// Returns the [`BasicInputs`] of the inner [`Axislike`].
+    #[inline]
+    fn decompose(&self) -> BasicInputs {
+        self.axis.decompose()
+    }
+}
+
+#[serde_typetag]
+impl Buttonlike for AxisInRange {
+    /// Checks if the value of the inner [`Axislike`] is within the range,
+    /// or still within the release range since it entered the range.
+    #[inline]
+    fn pressed(&self, input_store: &CentralInputStore, gamepad: Entity) -> bool {
+        let value = self.axis.value(input_store, gamepad);
+        match self.release_range {
+            None => self.range.contains(value),
+            Some(release_range) => hysteresis_latched(
+                input_store,
+                self,
+                gamepad,
+                self.range.contains(value),
+                release_range.contains(value),
+            ),
+        }
+    }
+
+    /// Sets the value of the inner [`Axislike`] to the middle of the range.
+    fn press_as_gamepad(&self, world: &mut World, gamepad: Option<Entity>) {
+        let (min, max) = self.range.min_max();
+        self.axis
+            .set_value_as_gamepad(world, min + (max - min) / 2.0, gamepad);
+    }
+
+    /// Sets the value of the inner [`Axislike`] to `0.0`,
+    /// or to just above the release range if it contains `0.0`.
+    fn release_as_gamepad(&self, world: &mut World, gamepad: Option<Entity>) {
+        let release_range = self.release_range();
+        let value = if release_range.contains(0.0) {
+            release_range.max() + 1.0
+        } else {
+            0.0
+        };
+        self.axis.set_value_as_gamepad(world, value, gamepad);
+    }
+
+    /// Presses the input if `value > 0.0`, and releases it otherwise.
+    fn set_value_as_gamepad(&self, world: &mut World, value: f32, gamepad: Option<Entity>) {
+        if value > 0.0 {
+            self.press_as_gamepad(world, gamepad);
+        } else {
+            self.release_as_gamepad(world, gamepad);
+        }
+    }
+}
+
+#[cfg(feature = "gamepad")]
+#[cfg(test)]
+mod tests {
+    use super::AxisInRange;
+    use crate::input_processing::{AxisBounds, ProcessorError};
+    use crate::plugin::CentralInputStorePlugin;
+    use crate::user_input::testing_utils::FetchUserInput;
+    use crate::user_input::{Axislike, Buttonlike, GamepadControlAxis};
+    use bevy::input::gamepad::{GamepadConnection, GamepadConnectionEvent};
+    use bevy::input::InputPlugin;
+    use bevy::prelude::*;
+
+    fn test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_plugins(InputPlugin)
+            .add_plugins(CentralInputStorePlugin);
+
+        let gamepad = app.world_mut().spawn(()).id();
+        let mut gamepad_events = app
+            .world_mut()
+            .resource_mut::<Events<GamepadConnectionEvent>>();
+        gamepad_events.send(GamepadConnectionEvent {
+            gamepad,
+            connection: GamepadConnection::Connected {
+                name: "TestController".into(),
+                vendor_id: None,
+                product_id: None,
+            },
+        });
+
+        // Ensure that the gamepad is picked up by the appropriate system
+        app.update();
+        // Ensure that the connection event is flushed through
+        app.update();
+        app
+    }
+
+    #[test]
+    fn test_axis_in_range() {
+        let input = AxisInRange::new(GamepadControlAxis::RIGHT_Z, 0.3, 0.7);
+        let mut app = test_app();
+
+        // Sweep the axis across the range, including both boundaries
+        for (value, pressed) in [
+            (-1.0, false),
+            (0.0, false),
+            (0.25, false),
+            (0.3, true),
+            (0.5, true),
+            (0.7, true),
+            (0.75, false),
+            (1.0, false),
+            (0.7, true),
+            (0.25, false),
+        ] {
+            GamepadControlAxis::RIGHT_Z.set_value(app.world_mut(), value);
+            app.update();
+            assert_eq!(app.read_pressed(input.clone()), pressed, "value {value}");
+        }
+    }
+
+    #[test]
+    fn test_axis_in_range_press_and_release() {
+        let input = AxisInRange::new(GamepadControlAxis::LEFT_X, -0.5, 0.5);
+        let mut app = test_app();
+
+        input.press(app.world_mut());
+        app.update();
+        assert!(app.read_pressed(input.clone()));
+
+        // The range contains 0.0, so the axis is moved past it instead
+        input.release(app.world_mut());
+        app.update();
+        assert!(!app.read_pressed(input));
+    }
+
+    #[test]
+    fn test_axis_in_range_invalid() {
+        assert_eq!(
+            AxisInRange::try_new(GamepadControlAxis::RIGHT_Z, 0.7, 0.3),
+            Err(ProcessorError::InvertedRange { min: 0.7, max: 0.3 })
+        );
+        assert!(AxisInRange::try_new(GamepadControlAxis::RIGHT_Z, 0.3, 0.3).is_ok());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_axis_in_range_inverted_panics() {
+        let _ = AxisInRange::new(GamepadControlAxis::RIGHT_Z, 0.7, 0.3);
+    }
+
+    #[test]
+    fn test_axis_in_range_hysteresis() {
+        let input =
+            AxisInRange::new(GamepadControlAxis::RIGHT_Z, 0.6, 1.0).with_release_range(0.4, 1.0);
+        let mut app = test_app();
+
+        // Oscillating between the thresholds doesn't chatter,
+        // keeping the input released until the range is reached, and pressed until the release range is left
+        for (value, pressed) in [
+            (0.45, false),
+            (0.55, false),
+            (0.45, false),
+            (0.6, true),
+            (0.45, true),
+            (0.55, true),
+            (0.45, true),
+            (0.35, false),
+            (0.55, false),
+            (0.45, false),
+            (1.0, true),
+            (0.0, false),
+        ] {
+            GamepadControlAxis::RIGHT_Z.set_value(app.world_mut(), value);
+            app.update();
+            assert_eq!(app.read_pressed(input.clone()), pressed, "value {value}");
+        }
+    }
+
+    #[test]
+    fn test_axis_in_range_hysteresis_press_and_release() {
+        let input =
+            AxisInRange::new(GamepadControlAxis::LEFT_X, -0.5, 0.5).with_release_range(-0.7, 0.7);
+        assert_eq!(input.release_range(), AxisBounds::new(-0.7, 0.7));
+        let mut app = test_app();
+
+        input.press(app.world_mut());
+        app.update();
+        assert!(app.read_pressed(input.clone()));
+
+        // The release range contains 0.0, so the axis is moved past it instead
+        input.release(app.world_mut());
+        app.update();
+        assert!(!app.read_pressed(input));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_axis_in_range_release_range_not_containing_range_panics() {
+        let _ =
+            AxisInRange::new(GamepadControlAxis::RIGHT_Z, 0.4, 0.6).with_release_range(0.5, 1.0);
+    }
+
+    #[test]
+    fn test_axis_in_range_deserialization_is_validated() {
+        use crate::user_input::RegisterUserInput;
+
+        // Register the deserializer of the inner axis
+        App::new().register_default_inputs();
+
+        let input =
+            AxisInRange::new(GamepadControlAxis::RIGHT_Z, 0.6, 1.0).with_release_range(0.4, 1.0);
+        let serialized = ron::to_string(&input).unwrap();
+        assert_eq!(ron::from_str::<AxisInRange>(&serialized).unwrap(), input);
+
+        // Inputs serialized without hysteresis are still accepted
+        let input = AxisInRange::new(GamepadControlAxis::RIGHT_Z, 0.6, 1.0);
+        let serialized = ron::to_string(&input).unwrap();
+        assert!(!serialized.contains("release_range"));
+        assert_eq!(ron::from_str::<AxisInRange>(&serialized).unwrap(), input);
+
+        let invalid = AxisInRange {
+            release_range: Some(AxisBounds::new(0.7, 1.0)),
+            ..input
+        };
+        let serialized = ron::to_string(&invalid).unwrap();
+        assert!(ron::from_str::<AxisInRange>(&serialized).is_err());
+    }
+}
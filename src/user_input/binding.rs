@@ -0,0 +1,246 @@
+//! This module contains [`InputBinding`] and its impls.
+
+#[cfg(feature = "gamepad")]
+use bevy::prelude::GamepadButton;
+#[cfg(feature = "keyboard")]
+use bevy::prelude::KeyCode;
+#[cfg(feature = "mouse")]
+use bevy::prelude::MouseButton;
+use bevy::prelude::Reflect;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "gamepad")]
+use crate::user_input::{GamepadControlAxis, GamepadControlDirection, GamepadStick};
+#[cfg(feature = "keyboard")]
+use crate::user_input::{ModifierKey, VirtualAxis, VirtualDPad};
+#[cfg(feature = "mouse")]
+use crate::user_input::{
+    MouseMove, MouseMoveAxis, MouseMoveDirection, MouseScroll, MouseScrollAxis,
+    MouseScrollDirection,
+};
+use crate::user_input::{UserInput, UserInputWrapper};
+use crate::InputControlKind;
+
+/// A binding to one of the built-in inputs, for defining controls in typed, data-driven configs.
+///
+/// Unlike the boxed [`UserInput`] trait objects stored in an [`InputMap`](crate::input_map::InputMap),
+/// this is a plain enum, so it (de)serializes without registering input types,
+/// and its format stays stable no matter which custom inputs an app defines.
+///
+/// Bindings are converted into inputs using [`InputMap::from_config`](crate::input_map::InputMap::from_config)
+/// or [`InputMap::insert_binding`](crate::input_map::InputMap::insert_binding).
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy::utils::HashMap;
+/// use leafwing_input_manager::prelude::*;
+///
+/// #[derive(Actionlike, Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+/// enum Action {
+///     Jump,
+///     #[actionlike(DualAxis)]
+///     Move,
+/// }
+///
+/// let mut config = HashMap::default();
+/// config.insert(
+///     Action::Jump,
+///     vec![InputBinding::Key(KeyCode::Space), InputBinding::GamepadButton(GamepadButton::South)],
+/// );
+/// config.insert(
+///     Action::Move,
+///     vec![InputBinding::KeyDPad {
+///         up: KeyCode::KeyW,
+///         down: KeyCode::KeyS,
+///         left: KeyCode::KeyA,
+///         right: KeyCode::KeyD,
+///     }],
+/// );
+///
+/// let input_map = InputMap::from_config(config);
+/// assert_eq!(input_map.get_buttonlike(&Action::Jump).unwrap().len(), 2);
+/// assert_eq!(input_map.get_dual_axislike(&Action::Move).unwrap().len(), 1);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+pub enum InputBinding {
+    /// A [`KeyCode`], acting as a button.
+    #[cfg(feature = "keyboard")]
+    Key(KeyCode),
+
+    /// A [`ModifierKey`], acting as a button.
+    #[cfg(feature = "keyboard")]
+    Modifier(ModifierKey),
+
+    /// Two [`KeyCode`]s acting as an axis, using [`VirtualAxis`].
+    #[cfg(feature = "keyboard")]
+    KeyAxis {
+        /// The key for the negative direction.
+        negative: KeyCode,
+        /// The key for the positive direction.
+        positive: KeyCode,
+    },
+
+    /// Four [`KeyCode`]s acting as a dual-axis, using [`VirtualDPad`].
+    #[cfg(feature = "keyboard")]
+    KeyDPad {
+        /// The key for the upward direction.
+        up: KeyCode,
+        /// The key for the downward direction.
+        down: KeyCode,
+        /// The key for the leftward direction.
+        left: KeyCode,
+        /// The key for the rightward direction.
+        right: KeyCode,
+    },
+
+    /// A [`MouseButton`], acting as a button.
+    #[cfg(feature = "mouse")]
+    MouseButton(MouseButton),
+
+    /// A [`MouseMoveDirection`], acting as a button.
+    #[cfg(feature = "mouse")]
+    MouseMoveDirection(MouseMoveDirection),
+
+    /// A [`MouseMoveAxis`], acting as an axis.
+    #[cfg(feature = "mouse")]
+    MouseMoveAxis(MouseMoveAxis),
+
+    /// A [`MouseMove`], acting as a dual-axis.
+    #[cfg(feature = "mouse")]
+    MouseMove(MouseMove),
+
+    /// A [`MouseScrollDirection`], acting as a button.
+    #[cfg(feature = "mouse")]
+    MouseScrollDirection(MouseScrollDirection),
+
+    /// A [`MouseScrollAxis`], acting as an axis.
+    #[cfg(feature = "mouse")]
+    MouseScrollAxis(MouseScrollAxis),
+
+    /// A [`MouseScroll`], acting as a dual-axis.
+    #[cfg(feature = "mouse")]
+    MouseScroll(MouseScroll),
+
+    /// A [`GamepadButton`], acting as a button.
+    #[cfg(feature = "gamepad")]
+    GamepadButton(GamepadButton),
+
+    /// A [`GamepadControlDirection`], acting as a button.
+    #[cfg(feature = "gamepad")]
+    GamepadControlDirection(GamepadControlDirection),
+
+    /// A [`GamepadControlAxis`], acting as an axis.
+    #[cfg(feature = "gamepad")]
+    GamepadControlAxis(GamepadControlAxis),
+
+    /// A [`GamepadStick`], acting as a dual-axis.
+    #[cfg(feature = "gamepad")]
+    GamepadStick(GamepadStick),
+}
+
+impl InputBinding {
+    /// Returns the kind of input that this binding creates.
+    pub fn kind(&self) -> InputControlKind {
+        UserInputWrapper::from(self.clone()).kind()
+    }
+}
+
+impl From<InputBinding> for UserInputWrapper {
+    fn from(binding: InputBinding) -> Self {
+        match binding {
+            #[cfg(feature = "keyboard")]
+            InputBinding::Key(key) => Self::Button(Box::new(key)),
+            #[cfg(feature = "keyboard")]
+            InputBinding::Modifier(modifier) => Self::Button(Box::new(modifier)),
+            #[cfg(feature = "keyboard")]
+            InputBinding::KeyAxis { negative, positive } => {
+                Self::Axis(Box::new(VirtualAxis::new(negative, positive)))
+            }
+            #[cfg(feature = "keyboard")]
+            InputBinding::KeyDPad {
+                up,
+                down,
+                left,
+                right,
+            } => Self::DualAxis(Box::new(VirtualDPad::new(up, down, left, right))),
+            #[cfg(feature = "mouse")]
+            InputBinding::MouseButton(button) => Self::Button(Box::new(button)),
+            #[cfg(feature = "mouse")]
+            InputBinding::MouseMoveDirection(direction) => Self::Button(Box::new(direction)),
+            #[cfg(feature = "mouse")]
+            InputBinding::MouseMoveAxis(axis) => Self::Axis(Box::new(axis)),
+            #[cfg(feature = "mouse")]
+            InputBinding::MouseMove(mouse_move) => Self::DualAxis(Box::new(mouse_move)),
+            #[cfg(feature = "mouse")]
+            InputBinding::MouseScrollDirection(direction) => Self::Button(Box::new(direction)),
+            #[cfg(feature = "mouse")]
+            InputBinding::MouseScrollAxis(axis) => Self::Axis(Box::new(axis)),
+            #[cfg(feature = "mouse")]
+            InputBinding::MouseScroll(scroll) => Self::DualAxis(Box::new(scroll)),
+            #[cfg(feature = "gamepad")]
+            InputBinding::GamepadButton(button) => Self::Button(Box::new(button)),
+            #[cfg(feature = "gamepad")]
+            InputBinding::GamepadControlDirection(direction) => Self::Button(Box::new(direction)),
+            #[cfg(feature = "gamepad")]
+            InputBinding::GamepadControlAxis(axis) => Self::Axis(Box::new(axis)),
+            #[cfg(feature = "gamepad")]
+            InputBinding::GamepadStick(stick) => Self::DualAxis(Box::new(stick)),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "keyboard", feature = "mouse", feature = "gamepad"))]
+mod tests {
+    use super::*;
+    use crate::user_input::Buttonlike;
+
+    #[test]
+    fn test_input_binding_kind() {
+        assert_eq!(
+            InputBinding::Key(KeyCode::Space).kind(),
+            InputControlKind::Button
+        );
+        assert_eq!(
+            InputBinding::KeyAxis {
+                negative: KeyCode::KeyA,
+                positive: KeyCode::KeyD,
+            }
+            .kind(),
+            InputControlKind::Axis
+        );
+        assert_eq!(
+            InputBinding::MouseMove(MouseMove::default()).kind(),
+            InputControlKind::DualAxis
+        );
+        assert_eq!(
+            InputBinding::GamepadStick(GamepadStick::LEFT).kind(),
+            InputControlKind::DualAxis
+        );
+    }
+
+    #[test]
+    fn test_input_binding_serde() {
+        let bindings = vec![
+            InputBinding::Key(KeyCode::Space),
+            InputBinding::MouseButton(MouseButton::Left),
+            InputBinding::GamepadStick(GamepadStick::LEFT),
+            InputBinding::KeyDPad {
+                up: KeyCode::KeyW,
+                down: KeyCode::KeyS,
+                left: KeyCode::KeyA,
+                right: KeyCode::KeyD,
+            },
+        ];
+
+        // No input types need to be registered
+        let serialized = ron::to_string(&bindings).unwrap();
+        let deserialized: Vec<InputBinding> = ron::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, bindings);
+
+        let wrapper = UserInputWrapper::from(InputBinding::Key(KeyCode::Space));
+        assert_eq!(
+            wrapper,
+            UserInputWrapper::Button(Box::new(KeyCode::Space) as Box<dyn Buttonlike>)
+        );
+    }
+}
@@ -1,12 +1,17 @@
 //! This module contains [`ButtonlikeChord`] and its impls.
 
+use std::fmt;
+
 use bevy::math::{Vec2, Vec3};
 use bevy::prelude::{Entity, Reflect, World};
 use leafwing_input_manager_macros::serde_typetag;
-use serde::{Deserialize, Serialize};
+use serde::de::value::{MapAccessDeserializer, SeqAccessDeserializer};
+use serde::de::{MapAccess, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use crate as leafwing_input_manager;
 use crate::clashing_inputs::BasicInputs;
+use crate::input_processing::{AxisProcessor, DualAxisProcessor, TripleAxisProcessor};
 use crate::user_input::{Buttonlike, TripleAxislike, UserInput};
 use crate::InputControlKind;
 
@@ -45,16 +50,133 @@ use super::{Axislike, DualAxislike};
 /// app.update();
 /// assert!(app.read_pressed(input.clone()));
 /// ```
-#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+///
+/// By default, the chord stays pressed for as long as all of its buttons are held.
+/// Use [`ButtonlikeChord::with_activation`] to change this using a [`ChordActivation`].
+///
+/// # Mixing devices
+///
+/// Chords can mix buttons from different devices, such as a [`KeyCode`](bevy::prelude::KeyCode)
+/// and a [`GamepadButton`](bevy::prelude::GamepadButton).
+/// Gamepad buttons in the chord are read from the gamepad associated with the [`InputMap`](crate::input_map::InputMap),
+/// so pressing the key along with the same button on another gamepad doesn't press the chord.
+///
+/// # Serialization
+///
+/// Chords using the default [`ChordActivation::WhileAllHeld`] are serialized as a plain list of their buttons,
+/// exactly like chords saved before [`ChordActivation`] was introduced.
+/// Other chords are serialized with their buttons and activation as named fields.
+/// Both forms can be deserialized.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, Reflect)]
 #[must_use]
-pub struct ButtonlikeChord(
+pub struct ButtonlikeChord {
     // Note: We can't use a HashSet here because of
     // https://users.rust-lang.org/t/hash-not-implemented-why-cant-it-be-derived/92416/8
     // We can't use a BTreeSet because the underlying types don't impl Ord
     // We don't want to use a PetitSet here because of memory bloat
     // So a vec it is!
-    pub(crate) Vec<Box<dyn Buttonlike>>,
-);
+    pub(crate) inputs: Vec<Box<dyn Buttonlike>>,
+    pub(crate) activation: ChordActivation,
+}
+
+/// The serialized form of a [`ButtonlikeChord`] whose [`ChordActivation`] isn't the default.
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "ButtonlikeChord")]
+struct ActivatedChord<Inputs> {
+    inputs: Inputs,
+    activation: ChordActivation,
+}
+
+impl Serialize for ButtonlikeChord {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if self.activation == ChordActivation::default() {
+            serializer.serialize_newtype_struct("ButtonlikeChord", &self.inputs)
+        } else {
+            let chord = ActivatedChord {
+                inputs: &self.inputs,
+                activation: self.activation,
+            };
+            serializer.serialize_newtype_struct("ButtonlikeChord", &chord)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ButtonlikeChord {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_newtype_struct("ButtonlikeChord", ButtonlikeChordVisitor)
+    }
+}
+
+/// Deserializes a [`ButtonlikeChord`] from either a list of buttons or an [`ActivatedChord`].
+struct ButtonlikeChordVisitor;
+
+impl<'de> Visitor<'de> for ButtonlikeChordVisitor {
+    type Value = ButtonlikeChord;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a list of buttons, or a map of buttons and their activation")
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_any(self)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+        let inputs = Vec::deserialize(SeqAccessDeserializer::new(seq))?;
+        Ok(ButtonlikeChord {
+            inputs,
+            activation: ChordActivation::default(),
+        })
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+        let ActivatedChord { inputs, activation } =
+            ActivatedChord::deserialize(MapAccessDeserializer::new(map))?;
+        Ok(ButtonlikeChord { inputs, activation })
+    }
+}
+
+/// Controls when a [`ButtonlikeChord`] is considered pressed.
+///
+/// # Clashes
+///
+/// Under [`ClashStrategy::PrioritizeLongest`](crate::clashing_inputs::ClashStrategy::PrioritizeLongest),
+/// a chord only takes priority over the shorter inputs it contains while it is pressed.
+/// As a [`ChordActivation::AllDownEdge`] chord is only pressed for a single frame,
+/// the actions bound to any of its buttons that are still held afterwards
+/// will be pressed from the following frame onwards.
+/// For example, holding Ctrl+S will trigger a "Save" action bound to the chord once,
+/// followed by an action bound to S alone on the next frame.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+pub enum ChordActivation {
+    /// The chord is pressed for as long as all of its buttons are held,
+    /// and released as soon as any of them is released.
+    #[default]
+    WhileAllHeld,
+
+    /// The chord is only pressed on the frame when all of its buttons first become held simultaneously,
+    /// regardless of the order in which they were pressed.
+    ///
+    /// The chord will not be pressed again until at least one of its buttons has been released,
+    /// and all of them are held once more.
+    /// This is useful for shortcuts like Ctrl+S, which should trigger once per press.
+    AllDownEdge,
+}
+
+/// The state of a [`ButtonlikeChord`] using [`ChordActivation::AllDownEdge`],
+/// stored in the [`CentralInputStore`].
+#[derive(Debug, Default, Clone)]
+struct ChordMemory {
+    /// The last frame in which the chord was read.
+    last_frame: Option<u64>,
+    /// Whether all the buttons were held during `last_frame`.
+    all_held: bool,
+    /// Whether all the buttons first became held simultaneously during `last_frame`.
+    activated: bool,
+}
 
 impl ButtonlikeChord {
     /// Creates a [`ButtonlikeChord`] from multiple [`Buttonlike`]s, avoiding duplicates.
@@ -85,6 +207,20 @@ impl ButtonlikeChord {
         self
     }
 
+    /// Sets the [`ChordActivation`] that controls when this chord is pressed.
+    #[inline]
+    pub fn with_activation(mut self, activation: ChordActivation) -> Self {
+        self.activation = activation;
+        self
+    }
+
+    /// Returns the [`ChordActivation`] that controls when this chord is pressed.
+    #[must_use]
+    #[inline]
+    pub fn activation(&self) -> ChordActivation {
+        self.activation
+    }
+
     /// Adds multiple [`Buttonlike`]s into this chord, avoiding duplicates.
     /// Note that all elements within the iterator must be of the same type (homogeneous).
     #[inline]
@@ -98,8 +234,8 @@ impl ButtonlikeChord {
     /// Adds the given boxed dyn [`Buttonlike`] to this chord, avoiding duplicates.
     #[inline]
     fn push_boxed_unique(&mut self, input: Box<dyn Buttonlike>) {
-        if !self.0.contains(&input) {
-            self.0.push(input);
+        if !self.inputs.contains(&input) {
+            self.inputs.push(input);
         }
     }
 }
@@ -117,45 +253,73 @@ impl UserInput for ButtonlikeChord {
     #[inline]
     fn decompose(&self) -> BasicInputs {
         let inputs = self
-            .0
+            .inputs
             .iter()
             .flat_map(|input| input.decompose().inputs())
             .collect();
         BasicInputs::Chord(inputs)
     }
+
+    /// Returns the names of the buttons joined by `" + "`, such as `"Control + S"`.
+    fn display_name(&self) -> String {
+        self.inputs
+            .iter()
+            .map(|input| input.display_name())
+            .collect::<Vec<_>>()
+            .join(" + ")
+    }
 }
 
 #[serde_typetag]
 impl Buttonlike for ButtonlikeChord {
-    /// Checks if all the inner inputs within the chord are active simultaneously.
+    /// Checks if all the inner inputs within the chord are active simultaneously,
+    /// according to the [`ChordActivation`] of the chord.
     #[must_use]
     #[inline]
     fn pressed(&self, input_store: &CentralInputStore, gamepad: Entity) -> bool {
-        self.0
+        let all_held = self
+            .inputs
             .iter()
-            .all(|input| input.pressed(input_store, gamepad))
+            .all(|input| input.pressed(input_store, gamepad));
+
+        match self.activation {
+            ChordActivation::WhileAllHeld => all_held,
+            ChordActivation::AllDownEdge => {
+                let frame = input_store.frame();
+                input_store.with_input_memory(self, gamepad, |memory: &mut ChordMemory| {
+                    if memory.last_frame != Some(frame) {
+                        let consecutive = memory.last_frame == frame.checked_sub(1);
+                        memory.activated = all_held && !(consecutive && memory.all_held);
+                        memory.all_held = all_held;
+                        memory.last_frame = Some(frame);
+                    }
+
+                    memory.activated
+                })
+            }
+        }
     }
 
     fn press(&self, world: &mut World) {
-        for input in &self.0 {
+        for input in &self.inputs {
             input.press(world);
         }
     }
 
     fn release(&self, world: &mut World) {
-        for input in &self.0 {
+        for input in &self.inputs {
             input.release(world);
         }
     }
 
     fn press_as_gamepad(&self, world: &mut World, gamepad: Option<Entity>) {
-        for input in &self.0 {
+        for input in &self.inputs {
             input.press_as_gamepad(world, gamepad);
         }
     }
 
     fn release_as_gamepad(&self, world: &mut World, gamepad: Option<Entity>) {
-        for input in &self.0 {
+        for input in &self.inputs {
             input.release_as_gamepad(world, gamepad);
         }
     }
@@ -205,6 +369,15 @@ impl UserInput for AxislikeChord {
     fn decompose(&self) -> BasicInputs {
         BasicInputs::compose(self.button.decompose(), self.axis.decompose())
     }
+
+    /// Returns the names of the button and the axis joined by `" + "`.
+    fn display_name(&self) -> String {
+        format!(
+            "{} + {}",
+            self.button.display_name(),
+            self.axis.display_name()
+        )
+    }
 }
 
 #[serde_typetag]
@@ -224,6 +397,91 @@ impl Axislike for AxislikeChord {
     fn set_value_as_gamepad(&self, world: &mut World, value: f32, gamepad: Option<Entity>) {
         self.axis.set_value_as_gamepad(world, value, gamepad);
     }
+
+    /// Returns the processors applied to the value of the inner input, in the order they are applied.
+    #[inline]
+    fn processors(&self) -> &[AxisProcessor] {
+        self.axis.processors()
+    }
+}
+
+/// A combined input that groups two [`Buttonlike`]s together,
+/// reading the analog value of the `button` as an axis while the `modifier` is pressed.
+///
+/// This is useful for analog triggers that should only drive an action while a modifier is held.
+/// Unlike an [`AxislikeChord`], the axis is read from a [`Buttonlike`],
+/// and so the value ranges from `0.0` (released) to `1.0` (fully pressed).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+#[must_use]
+pub struct ButtonValueChord {
+    /// The button that must be pressed to read the value of the `button`.
+    pub modifier: Box<dyn Buttonlike>,
+    /// The button whose value is read as an axis when the `modifier` is pressed.
+    pub button: Box<dyn Buttonlike>,
+}
+
+impl ButtonValueChord {
+    /// Creates a new [`ButtonValueChord`] that reads the value of the `button` while the `modifier` is pressed.
+    #[inline]
+    pub fn new(modifier: impl Buttonlike, button: impl Buttonlike) -> Self {
+        Self {
+            modifier: Box::new(modifier),
+            button: Box::new(button),
+        }
+    }
+}
+
+impl UserInput for ButtonValueChord {
+    /// [`ButtonValueChord`] acts as a virtual axis.
+    #[inline]
+    fn kind(&self) -> InputControlKind {
+        InputControlKind::Axis
+    }
+
+    /// Retrieves a list of simple, atomic [`Buttonlike`]s that compose the chord.
+    ///
+    /// Both buttons must be pressed for the chord to be active,
+    /// so this is a [`BasicInputs::Chord`] containing the basic inputs of the `modifier`, followed by those of the `button`.
+    #[inline]
+    fn decompose(&self) -> BasicInputs {
+        let inputs = self
+            .modifier
+            .decompose()
+            .inputs()
+            .into_iter()
+            .chain(self.button.decompose().inputs())
+            .collect();
+        BasicInputs::Chord(inputs)
+    }
+
+    /// Returns the names of the modifier and the button joined by `" + "`.
+    fn display_name(&self) -> String {
+        format!(
+            "{} + {}",
+            self.modifier.display_name(),
+            self.button.display_name()
+        )
+    }
+}
+
+#[serde_typetag]
+impl Axislike for ButtonValueChord {
+    /// Returns the value of the `button` if the `modifier` is pressed, or `0.0` otherwise.
+    fn value(&self, input_store: &CentralInputStore, gamepad: Entity) -> f32 {
+        if self.modifier.pressed(input_store, gamepad) {
+            self.button.value(input_store, gamepad)
+        } else {
+            0.0
+        }
+    }
+
+    fn set_value(&self, world: &mut World, value: f32) {
+        self.button.set_value(world, value);
+    }
+
+    fn set_value_as_gamepad(&self, world: &mut World, value: f32, gamepad: Option<Entity>) {
+        self.button.set_value_as_gamepad(world, value, gamepad);
+    }
 }
 
 /// A combined input that groups a [`Buttonlike`] and a [`DualAxislike`] together,
@@ -260,6 +518,15 @@ impl UserInput for DualAxislikeChord {
     fn decompose(&self) -> BasicInputs {
         BasicInputs::compose(self.button.decompose(), self.dual_axis.decompose())
     }
+
+    /// Returns the names of the button and the dual-axis joined by `" + "`.
+    fn display_name(&self) -> String {
+        format!(
+            "{} + {}",
+            self.button.display_name(),
+            self.dual_axis.display_name()
+        )
+    }
 }
 
 #[serde_typetag]
@@ -285,6 +552,12 @@ impl DualAxislike for DualAxislikeChord {
         self.dual_axis
             .set_axis_pair_as_gamepad(world, axis_pair, gamepad);
     }
+
+    /// Returns the processors applied to the value of the inner input, in the order they are applied.
+    #[inline]
+    fn processors(&self) -> &[DualAxisProcessor] {
+        self.dual_axis.processors()
+    }
 }
 
 /// A combined input that groups a [`Buttonlike`] and a [`TripleAxislike`] together,
@@ -321,6 +594,15 @@ impl UserInput for TripleAxislikeChord {
     fn decompose(&self) -> BasicInputs {
         BasicInputs::compose(self.button.decompose(), self.triple_axis.decompose())
     }
+
+    /// Returns the names of the button and the triple-axis joined by `" + "`.
+    fn display_name(&self) -> String {
+        format!(
+            "{} + {}",
+            self.button.display_name(),
+            self.triple_axis.display_name()
+        )
+    }
 }
 
 #[serde_typetag]
@@ -346,6 +628,12 @@ impl TripleAxislike for TripleAxislikeChord {
         self.triple_axis
             .set_axis_triple_as_gamepad(world, axis_triple, gamepad);
     }
+
+    /// Returns the processors applied to the value of the inner input, in the order they are applied.
+    #[inline]
+    fn processors(&self) -> &[TripleAxisProcessor] {
+        self.triple_axis.processors()
+    }
 }
 
 #[cfg(feature = "keyboard")]
@@ -404,7 +692,7 @@ mod tests {
             .iter()
             .map(|key| Box::new(*key) as Box<dyn Buttonlike>)
             .collect::<Vec<_>>();
-        assert_eq!(chord.0, expected_inners);
+        assert_eq!(chord.inputs, expected_inners);
 
         // No keys pressed, resulting in a released chord with a value of zero.
         let mut app = test_app();
@@ -445,4 +733,185 @@ mod tests {
         let inputs = app.world().resource::<CentralInputStore>();
         assert!(!chord.pressed(inputs, gamepad));
     }
+
+    #[cfg(feature = "gamepad")]
+    #[test]
+    fn test_button_value_chord() {
+        use crate::buttonlike::ButtonValue;
+        use crate::clashing_inputs::{BasicInputs, ClashStrategy};
+        use crate::input_map::{InputMap, UpdatedValue};
+        use crate::user_input::{ButtonValueChord, SpecificGamepadButton, UserInput};
+        use crate::{self as leafwing_input_manager, Actionlike};
+
+        #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+        enum Action {
+            #[actionlike(Axis)]
+            Throttle,
+        }
+
+        let chord = ButtonValueChord::new(KeyCode::ShiftLeft, GamepadButton::RightTrigger2);
+        let basic_inputs = chord.decompose();
+        assert!(matches!(basic_inputs, BasicInputs::Chord(_)));
+        assert_eq!(basic_inputs.len(), 2);
+        let expected: Vec<Box<dyn Buttonlike>> = vec![
+            Box::new(KeyCode::ShiftLeft),
+            Box::new(GamepadButton::RightTrigger2),
+        ];
+        assert_eq!(basic_inputs.inputs(), expected);
+
+        let gamepad = Entity::from_raw(0);
+        let input_map = InputMap::default()
+            .with_axis(Action::Throttle, chord)
+            .with_gamepad(gamepad);
+        let trigger = SpecificGamepadButton::new(gamepad, GamepadButton::RightTrigger2);
+        let throttle = |input_store: &CentralInputStore| {
+            let updated_actions =
+                input_map.process_actions(None, input_store, ClashStrategy::PressAll);
+            updated_actions.get(&Action::Throttle).copied()
+        };
+
+        // The trigger alone does nothing
+        let mut input_store = CentralInputStore::default();
+        input_store.update_buttonlike(trigger, ButtonValue::new(true, 0.6));
+        assert_eq!(throttle(&input_store), Some(UpdatedValue::Axis(0.0)));
+
+        // Holding the modifier reads the analog value of the trigger
+        input_store.update_buttonlike(KeyCode::ShiftLeft, ButtonValue::from_pressed(true));
+        assert_eq!(throttle(&input_store), Some(UpdatedValue::Axis(0.6)));
+
+        input_store.update_buttonlike(trigger, ButtonValue::new(true, 0.25));
+        assert_eq!(throttle(&input_store), Some(UpdatedValue::Axis(0.25)));
+
+        // Releasing the modifier stops reading the trigger
+        input_store.update_buttonlike(KeyCode::ShiftLeft, ButtonValue::from_pressed(false));
+        assert_eq!(throttle(&input_store), Some(UpdatedValue::Axis(0.0)));
+    }
+
+    #[test]
+    fn test_chord_all_down_edge() {
+        use super::ChordActivation;
+        use crate::buttonlike::ButtonValue;
+
+        let chord = ButtonlikeChord::new([KeyCode::ControlLeft, KeyCode::KeyS])
+            .with_activation(ChordActivation::AllDownEdge);
+        assert_eq!(chord.activation(), ChordActivation::AllDownEdge);
+        assert_eq!(
+            ButtonlikeChord::new([KeyCode::KeyA]).activation(),
+            ChordActivation::WhileAllHeld
+        );
+
+        let gamepad = Entity::PLACEHOLDER;
+        let mut input_store = CentralInputStore::default();
+        let mut advance = |held: &[KeyCode]| {
+            input_store.clear();
+            for key in held {
+                input_store.update_buttonlike(*key, ButtonValue::from_pressed(true));
+            }
+            chord.pressed(&input_store, gamepad)
+        };
+
+        // Pressing the members in staggered order only activates the chord once all are held
+        assert!(!advance(&[KeyCode::ControlLeft]));
+        assert!(advance(&[KeyCode::ControlLeft, KeyCode::KeyS]));
+
+        // Holding the chord doesn't activate it again
+        assert!(!advance(&[KeyCode::ControlLeft, KeyCode::KeyS]));
+        assert!(!advance(&[KeyCode::ControlLeft, KeyCode::KeyS]));
+
+        // Releasing either member first allows the chord to be activated again
+        assert!(!advance(&[KeyCode::ControlLeft]));
+        assert!(advance(&[KeyCode::ControlLeft, KeyCode::KeyS]));
+        assert!(!advance(&[KeyCode::KeyS]));
+        assert!(advance(&[KeyCode::KeyS, KeyCode::ControlLeft]));
+
+        // Pressing the members in the opposite order behaves the same way
+        assert!(!advance(&[]));
+        assert!(!advance(&[KeyCode::KeyS]));
+        assert!(!advance(&[KeyCode::KeyS]));
+        assert!(advance(&[KeyCode::KeyS, KeyCode::ControlLeft]));
+        assert!(!advance(&[KeyCode::KeyS, KeyCode::ControlLeft]));
+    }
+
+    #[test]
+    fn test_chord_all_down_edge_clashes() {
+        use super::ChordActivation;
+        use crate::buttonlike::ButtonValue;
+        use crate::clashing_inputs::ClashStrategy;
+        use crate::input_map::{InputMap, UpdatedValue};
+        use crate::{self as leafwing_input_manager, Actionlike};
+
+        #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+        enum Action {
+            Save,
+            Shoot,
+        }
+
+        let save = ButtonlikeChord::new([KeyCode::ControlLeft, KeyCode::KeyS])
+            .with_activation(ChordActivation::AllDownEdge);
+        let input_map = InputMap::new([(Action::Shoot, KeyCode::KeyS)]).with(Action::Save, save);
+
+        let mut input_store = CentralInputStore::default();
+        let mut advance = |held: &[KeyCode]| {
+            input_store.clear();
+            for key in held {
+                input_store.update_buttonlike(*key, ButtonValue::from_pressed(true));
+            }
+            let updated_actions =
+                input_map.process_actions(None, &input_store, ClashStrategy::PrioritizeLongest);
+            let pressed = |action| {
+                matches!(
+                    updated_actions.get(&action),
+                    Some(UpdatedValue::Button(true))
+                )
+            };
+            (pressed(Action::Save), pressed(Action::Shoot))
+        };
+
+        // The chord takes priority on the frame it is activated
+        assert_eq!(advance(&[KeyCode::ControlLeft]), (false, false));
+        assert_eq!(
+            advance(&[KeyCode::ControlLeft, KeyCode::KeyS]),
+            (true, false)
+        );
+
+        // Afterwards, the shorter input is no longer suppressed
+        assert_eq!(
+            advance(&[KeyCode::ControlLeft, KeyCode::KeyS]),
+            (false, true)
+        );
+    }
+
+    #[test]
+    fn test_chord_serde() {
+        use super::ChordActivation;
+        use crate::user_input::RegisterUserInput;
+        use serde::{Deserialize, Serialize};
+
+        /// The format of chords saved before activations were introduced.
+        #[derive(Serialize, Deserialize)]
+        #[serde(rename = "ButtonlikeChord")]
+        struct LegacyChord(Vec<Box<dyn Buttonlike>>);
+
+        let mut app = App::new();
+        app.register_default_inputs();
+
+        // Chords with the default activation keep the legacy format
+        let chord = ButtonlikeChord::new([KeyCode::ControlLeft, KeyCode::KeyS]);
+        let legacy = LegacyChord(chord.inputs.clone());
+        let serialized = ron::to_string(&chord).unwrap();
+        assert_eq!(serialized, ron::to_string(&legacy).unwrap());
+        assert_eq!(
+            ron::from_str::<ButtonlikeChord>(&serialized).unwrap(),
+            chord
+        );
+
+        // Other activations are saved alongside the buttons
+        let chord = chord.with_activation(ChordActivation::AllDownEdge);
+        let serialized = ron::to_string(&chord).unwrap();
+        assert_eq!(
+            ron::from_str::<ButtonlikeChord>(&serialized).unwrap(),
+            chord
+        );
+        assert!(ron::from_str::<LegacyChord>(&serialized).is_err());
+    }
 }
@@ -0,0 +1,214 @@
+//! This module contains [`FirstNonZero`] and its impls.
+
+use bevy::prelude::{Entity, Reflect, World};
+use leafwing_input_manager_macros::serde_typetag;
+use serde::{Deserialize, Serialize};
+
+use crate as leafwing_input_manager;
+use crate::clashing_inputs::BasicInputs;
+use crate::user_input::{Axislike, UserInput};
+use crate::InputControlKind;
+
+use super::updating::CentralInputStore;
+
+/// A combined input that reads the value of the first of several [`Axislike`]s that is non-zero.
+///
+/// The inputs are checked in the order they were added,
+/// so earlier inputs take priority over later ones.
+/// If every input is zero, the value is `0.0`.
+///
+/// Unlike binding several [`Axislike`]s to the same action, whose values are summed together,
+/// this reads a single input at a time,
+/// so that the value of a gamepad stick is never added to that of the keyboard.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy::input::InputPlugin;
+/// use leafwing_input_manager::plugin::CentralInputStorePlugin;
+/// use leafwing_input_manager::prelude::*;
+/// use leafwing_input_manager::user_input::testing_utils::FetchUserInput;
+///
+/// let mut app = App::new();
+/// app.add_plugins((InputPlugin, CentralInputStorePlugin));
+///
+/// // The A and D keys take priority over the arrow keys
+/// let input = FirstNonZero::new([VirtualAxis::ad(), VirtualAxis::horizontal_arrow_keys()]);
+///
+/// // The first input is zero, so the value is read from the arrow keys
+/// KeyCode::ArrowLeft.press(app.world_mut());
+/// app.update();
+/// assert_eq!(app.read_axis_value(input.clone()), -1.0);
+///
+/// // Once the first input is non-zero, it is used instead
+/// KeyCode::KeyD.press(app.world_mut());
+/// app.update();
+/// assert_eq!(app.read_axis_value(input), 1.0);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+#[must_use]
+pub struct FirstNonZero(pub(crate) Vec<Box<dyn Axislike>>);
+
+impl FirstNonZero {
+    /// Creates a [`FirstNonZero`] from multiple [`Axislike`]s, in decreasing order of priority.
+    /// Note that all elements within the iterator must be of the same type (homogeneous).
+    /// You can still use [`FirstNonZero::with`] to add different types of inputs.
+    #[inline]
+    pub fn new<U: Axislike>(inputs: impl IntoIterator<Item = U>) -> Self {
+        Self(
+            inputs
+                .into_iter()
+                .map(|input| Box::new(input) as Box<dyn Axislike>)
+                .collect(),
+        )
+    }
+
+    /// Adds the given [`Axislike`] with a lower priority than all existing inputs.
+    #[inline]
+    pub fn with(mut self, input: impl Axislike) -> Self {
+        self.0.push(Box::new(input));
+        self
+    }
+
+    /// Returns the inputs of this [`FirstNonZero`], in decreasing order of priority.
+    #[must_use]
+    #[inline]
+    pub fn inputs(&self) -> &[Box<dyn Axislike>] {
+        &self.0
+    }
+}
+
+impl UserInput for FirstNonZero {
+    /// [`FirstNonZero`] acts as a virtual axis.
+    #[inline]
+    fn kind(&self) -> InputControlKind {
+        InputControlKind::Axis
+    }
+
+    /// Retrieves the [`Buttonlike`](crate::user_input::Buttonlike)s of all the inner inputs,
+    /// as any of them can drive the value independently.
+    #[inline]
+    fn decompose(&self) -> BasicInputs {
+        let inputs = self
+            .0
+            .iter()
+            .flat_map(|input| input.decompose().inputs())
+            .collect();
+        BasicInputs::Composite(inputs)
+    }
+}
+
+#[serde_typetag]
+impl Axislike for FirstNonZero {
+    /// Returns the value of the first inner input that is non-zero, or `0.0` if there is none.
+    fn value(&self, input_store: &CentralInputStore, gamepad: Entity) -> f32 {
+        self.0
+            .iter()
+            .map(|input| input.value(input_store, gamepad))
+            .find(|value| *value != 0.0)
+            .unwrap_or(0.0)
+    }
+
+    /// Sets the value of the first inner input, which has the highest priority.
+    fn set_value(&self, world: &mut World, value: f32) {
+        if let Some(input) = self.0.first() {
+            input.set_value(world, value);
+        }
+    }
+
+    /// Sets the value of the first inner input, which has the highest priority.
+    fn set_value_as_gamepad(&self, world: &mut World, value: f32, gamepad: Option<Entity>) {
+        if let Some(input) = self.0.first() {
+            input.set_value_as_gamepad(world, value, gamepad);
+        }
+    }
+}
+
+impl<U: Axislike> FromIterator<U> for FirstNonZero {
+    /// Creates a [`FirstNonZero`] from an iterator over multiple [`Axislike`]s, in decreasing order of priority.
+    /// Note that all elements within the iterator must be of the same type (homogeneous).
+    #[inline]
+    fn from_iter<T: IntoIterator<Item = U>>(iter: T) -> Self {
+        Self::new(iter)
+    }
+}
+
+#[cfg(all(feature = "gamepad", feature = "keyboard"))]
+#[cfg(test)]
+mod tests {
+    use super::FirstNonZero;
+    use crate::clashing_inputs::BasicInputs;
+    use crate::plugin::CentralInputStorePlugin;
+    use crate::user_input::testing_utils::FetchUserInput;
+    use crate::user_input::{Axislike, Buttonlike, GamepadControlAxis, UserInput, VirtualAxis};
+    use bevy::input::gamepad::{GamepadConnection, GamepadConnectionEvent};
+    use bevy::input::InputPlugin;
+    use bevy::prelude::*;
+
+    fn test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_plugins(InputPlugin)
+            .add_plugins(CentralInputStorePlugin);
+
+        let gamepad = app.world_mut().spawn(()).id();
+        let mut gamepad_events = app
+            .world_mut()
+            .resource_mut::<Events<GamepadConnectionEvent>>();
+        gamepad_events.send(GamepadConnectionEvent {
+            gamepad,
+            connection: GamepadConnection::Connected {
+                name: "TestController".into(),
+                vendor_id: None,
+                product_id: None,
+            },
+        });
+
+        // Ensure that the gamepad is picked up by the appropriate system
+        app.update();
+        // Ensure that the connection event is flushed through
+        app.update();
+        app
+    }
+
+    #[test]
+    fn test_first_non_zero() {
+        let input = FirstNonZero::new([GamepadControlAxis::LEFT_X]).with(VirtualAxis::ad());
+
+        // No inputs are active
+        let mut app = test_app();
+        assert_eq!(app.read_axis_value(input.clone()), 0.0);
+
+        // The first input is zero, so the second supplies the value
+        let mut app = test_app();
+        KeyCode::KeyA.press(app.world_mut());
+        app.update();
+        assert_eq!(app.read_axis_value(input.clone()), -1.0);
+
+        // The first input is non-zero, so it takes priority
+        let mut app = test_app();
+        GamepadControlAxis::LEFT_X.set_value(app.world_mut(), 0.5);
+        KeyCode::KeyA.press(app.world_mut());
+        app.update();
+        assert_eq!(app.read_axis_value(input.clone()), 0.5);
+
+        // Once the first input returns to zero, the second one is used again
+        GamepadControlAxis::LEFT_X.set_value(app.world_mut(), 0.0);
+        app.update();
+        assert_eq!(app.read_axis_value(input), -1.0);
+    }
+
+    #[test]
+    fn test_first_non_zero_decompose() {
+        let input = FirstNonZero::new([VirtualAxis::ad(), VirtualAxis::horizontal_arrow_keys()]);
+
+        let basic_inputs = input.decompose();
+        assert!(matches!(basic_inputs, BasicInputs::Composite(_)));
+        let expected: Vec<Box<dyn Buttonlike>> = vec![
+            Box::new(KeyCode::KeyA),
+            Box::new(KeyCode::KeyD),
+            Box::new(KeyCode::ArrowLeft),
+            Box::new(KeyCode::ArrowRight),
+        ];
+        assert_eq!(basic_inputs.inputs(), expected);
+    }
+}
@@ -10,8 +10,8 @@ use bevy::input::gamepad::{
 use bevy::input::{Axis, ButtonInput};
 use bevy::math::FloatOrd;
 use bevy::prelude::{
-    Entity, Events, Gamepad, GamepadAxis, GamepadButton, Query, Reflect, Res, ResMut, Vec2, With,
-    World,
+    Entity, Events, Gamepad, GamepadAxis, GamepadButton, Query, Reflect, Res, ResMut, Resource,
+    Vec2, With, World,
 };
 use leafwing_input_manager_macros::serde_typetag;
 use serde::{Deserialize, Serialize};
@@ -24,7 +24,8 @@ use crate::input_processing::{
     AxisProcessor, DualAxisProcessor, WithAxisProcessingPipelineExt,
     WithDualAxisProcessingPipelineExt,
 };
-use crate::user_input::UserInput;
+use crate::user_input::stateful::hysteresis_latched;
+use crate::user_input::{split_words, UserInput};
 use crate::InputControlKind;
 
 use super::updating::{CentralInputStore, UpdatableInput};
@@ -41,6 +42,72 @@ pub fn find_gamepad(gamepads: Option<Query<Entity, With<Gamepad>>>) -> Entity {
     }
 }
 
+/// The index of a local player, used to associate an [`InputMap`](crate::input_map::InputMap)
+/// with a gamepad by the order in which gamepads were connected, rather than by a gamepad [`Entity`].
+///
+/// Player indexes are resolved into gamepads using the [`GamepadRegistry`] resource.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Reflect, Serialize, Deserialize,
+)]
+pub struct PlayerIndex(pub usize);
+
+/// A [`Resource`] that assigns each connected gamepad to a [`PlayerIndex`], in the order they were connected.
+///
+/// When a gamepad is disconnected, its [`PlayerIndex`] is freed and given to the next gamepad to be connected,
+/// so the remaining players keep their gamepads.
+///
+/// This is kept up to date by the [`update_gamepad_registry`](crate::systems::update_gamepad_registry) system,
+/// which is added by the [`CentralInputStorePlugin`](crate::plugin::CentralInputStorePlugin).
+#[derive(Resource, Debug, Default, Clone, PartialEq, Eq, Reflect)]
+pub struct GamepadRegistry {
+    /// The gamepad assigned to each player, indexed by [`PlayerIndex`].
+    players: Vec<Option<Entity>>,
+}
+
+impl GamepadRegistry {
+    /// Assigns the `gamepad` to the lowest free [`PlayerIndex`], returning it.
+    ///
+    /// If the `gamepad` has already been assigned, its existing [`PlayerIndex`] is returned instead.
+    pub fn connect(&mut self, gamepad: Entity) -> PlayerIndex {
+        if let Some(player) = self.player(gamepad) {
+            return player;
+        }
+
+        match self.players.iter().position(Option::is_none) {
+            Some(index) => {
+                self.players[index] = Some(gamepad);
+                PlayerIndex(index)
+            }
+            None => {
+                self.players.push(Some(gamepad));
+                PlayerIndex(self.players.len() - 1)
+            }
+        }
+    }
+
+    /// Frees the [`PlayerIndex`] assigned to the `gamepad`, returning it if there was one.
+    pub fn disconnect(&mut self, gamepad: Entity) -> Option<PlayerIndex> {
+        let player = self.player(gamepad)?;
+        self.players[player.0] = None;
+        Some(player)
+    }
+
+    /// Returns the gamepad [`Entity`] assigned to the `player`, if any.
+    #[must_use]
+    pub fn gamepad(&self, player: PlayerIndex) -> Option<Entity> {
+        self.players.get(player.0).copied().flatten()
+    }
+
+    /// Returns the [`PlayerIndex`] assigned to the `gamepad`, if any.
+    #[must_use]
+    pub fn player(&self, gamepad: Entity) -> Option<PlayerIndex> {
+        self.players
+            .iter()
+            .position(|assigned| *assigned == Some(gamepad))
+            .map(PlayerIndex)
+    }
+}
+
 /// Retrieves the current value of the specified `axis`.
 #[must_use]
 #[inline]
@@ -197,6 +264,15 @@ impl UserInput for GamepadControlDirection {
     fn decompose(&self) -> BasicInputs {
         BasicInputs::Simple(Box::new((*self).threshold(0.0)))
     }
+
+    /// Returns the name of the axis and direction, such as `"Gamepad Left Stick Y+"`.
+    fn display_name(&self) -> String {
+        let sign = match self.direction {
+            AxisDirection::Negative => '-',
+            AxisDirection::Positive => '+',
+        };
+        format!("{}{sign}", self.axis.display_name())
+    }
 }
 
 #[serde_typetag]
@@ -293,6 +369,11 @@ impl UserInput for GamepadAxis {
             Box::new(GamepadControlDirection::positive(*self)),
         ])
     }
+
+    /// Returns the name of the axis, such as `"Gamepad Left Stick X"`.
+    fn display_name(&self) -> String {
+        format!("Gamepad {}", split_words(&format!("{self:?}")))
+    }
 }
 
 #[serde_typetag]
@@ -414,6 +495,11 @@ impl UserInput for GamepadControlAxis {
             Box::new(GamepadControlDirection::positive(self.axis)),
         ])
     }
+
+    /// Returns the name of the axis, such as `"Gamepad Left Stick X"`.
+    fn display_name(&self) -> String {
+        self.axis.display_name()
+    }
 }
 
 #[serde_typetag]
@@ -441,6 +527,12 @@ impl Axislike for GamepadControlAxis {
         });
         world.resource_mut::<Events<RawGamepadEvent>>().send(event);
     }
+
+    /// Returns the processors applied to the value of this input, in the order they are applied.
+    #[inline]
+    fn processors(&self) -> &[AxisProcessor] {
+        &self.processors
+    }
 }
 
 impl WithAxisProcessingPipelineExt for GamepadControlAxis {
@@ -496,6 +588,20 @@ impl WithAxisProcessingPipelineExt for GamepadControlAxis {
 /// let doubled = GamepadStick::LEFT.sensitivity_y(2.0);
 /// assert_eq!(app.read_axis_values(doubled), [2.0]);
 /// ```
+///
+/// # Inverting Axes
+///
+/// Each axis can be inverted inline using [`inverted_x`](WithDualAxisProcessingPipelineExt::inverted_x),
+/// [`inverted_y`](WithDualAxisProcessingPipelineExt::inverted_y) or [`inverted`](WithDualAxisProcessingPipelineExt::inverted),
+/// which append a [`DualAxisInverted`](crate::input_processing::DualAxisInverted) processor.
+///
+/// ```rust
+/// use leafwing_input_manager::prelude::*;
+///
+/// // The "invert look Y" setting popular with flight-sim players
+/// let look = GamepadStick::right().inverted_y();
+/// assert_eq!(look.processors, vec![DualAxisInverted::ONLY_Y.into()]);
+/// ```
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
 #[must_use]
 pub struct GamepadStick {
@@ -523,6 +629,20 @@ impl GamepadStick {
         y: GamepadAxis::RightStickY,
         processors: Vec::new(),
     };
+
+    /// Creates a [`GamepadStick`] for the left stick, without any processing,
+    /// to be configured inline, such as with [`inverted_y`](WithDualAxisProcessingPipelineExt::inverted_y).
+    #[inline]
+    pub const fn left() -> Self {
+        Self::LEFT
+    }
+
+    /// Creates a [`GamepadStick`] for the right stick, without any processing,
+    /// to be configured inline, such as with [`inverted_y`](WithDualAxisProcessingPipelineExt::inverted_y).
+    #[inline]
+    pub const fn right() -> Self {
+        Self::RIGHT
+    }
 }
 
 impl UserInput for GamepadStick {
@@ -542,6 +662,17 @@ impl UserInput for GamepadStick {
             Box::new(GamepadControlDirection::positive(self.y)),
         ])
     }
+
+    /// Returns the name of the stick, such as `"Gamepad Left Stick"`, or of both of its axes.
+    fn display_name(&self) -> String {
+        match (self.x, self.y) {
+            (GamepadAxis::LeftStickX, GamepadAxis::LeftStickY) => "Gamepad Left Stick".to_string(),
+            (GamepadAxis::RightStickX, GamepadAxis::RightStickY) => {
+                "Gamepad Right Stick".to_string()
+            }
+            (x, y) => format!("{} / {}", x.display_name(), y.display_name()),
+        }
+    }
 }
 
 #[serde_typetag]
@@ -577,6 +708,12 @@ impl DualAxislike for GamepadStick {
         });
         world.resource_mut::<Events<RawGamepadEvent>>().send(event);
     }
+
+    /// Returns the processors applied to the value of this input, in the order they are applied.
+    #[inline]
+    fn processors(&self) -> &[DualAxisProcessor] {
+        &self.processors
+    }
 }
 
 impl WithDualAxisProcessingPipelineExt for GamepadStick {
@@ -640,24 +777,20 @@ impl UpdatableInput for GamepadButton {
         source_data: StaticSystemParam<Self::SourceData>,
     ) {
         for (gamepad_entity, gamepad) in source_data.iter() {
-            for key in gamepad.get_pressed() {
-                let specific_button = SpecificGamepadButton {
-                    gamepad: gamepad_entity,
-                    button: *key,
+            // Analog values are recorded even while the button is released,
+            // so that triggers can be read continuously by `GamepadTriggerAxis`.
+            for input in gamepad.get_analog_axes() {
+                let GamepadInput::Button(button) = input else {
+                    continue;
                 };
-                let value = specific_button.value(&central_input_store, gamepad_entity);
-                central_input_store
-                    .update_buttonlike(specific_button, ButtonValue::new(true, value));
-            }
-
-            for key in gamepad.get_just_released() {
                 let specific_button = SpecificGamepadButton {
                     gamepad: gamepad_entity,
-                    button: *key,
+                    button: *button,
                 };
-                let value = specific_button.value(&central_input_store, gamepad_entity);
+                let pressed = gamepad.pressed(*button);
+                let value = gamepad.get(*button).unwrap_or_default();
                 central_input_store
-                    .update_buttonlike(specific_button, ButtonValue::new(false, value));
+                    .update_buttonlike(specific_button, ButtonValue::new(pressed, value));
             }
         }
     }
@@ -720,6 +853,11 @@ impl UserInput for GamepadButton {
     fn decompose(&self) -> BasicInputs {
         BasicInputs::Simple(Box::new(*self))
     }
+
+    /// Returns the name of the button, such as `"Gamepad South"` or `"Gamepad Left Trigger 2"`.
+    fn display_name(&self) -> String {
+        format!("Gamepad {}", split_words(&format!("{self:?}")))
+    }
 }
 
 #[serde_typetag]
@@ -768,6 +906,308 @@ impl Buttonlike for GamepadButton {
     }
 }
 
+/// An analog trigger on a gamepad (e.g., `LT` and `RT` on an Xbox controller), read as an axis.
+///
+/// Triggers report both a button and an analog value.
+/// This input reads the analog value, which ranges over `0.0..=1.0`:
+/// `0.0` when the trigger is released and `1.0` when it is fully pulled.
+///
+/// Use [`GamepadTriggerAxis::as_button_with_threshold`] to bind the same trigger to a button action,
+/// which is pressed once the trigger is pulled past a threshold.
+///
+/// By default, it reads from **any connected gamepad**.
+/// Use the [`InputMap::set_gamepad`](crate::input_map::InputMap::set_gamepad) for specific ones.
+///
+/// ```rust,ignore
+/// use bevy::prelude::*;
+/// use bevy::input::InputPlugin;
+/// use leafwing_input_manager::prelude::*;
+///
+/// let mut app = App::new();
+/// app.add_plugins(InputPlugin);
+///
+/// let throttle = GamepadTriggerAxis::RIGHT;
+/// let fire = GamepadTriggerAxis::RIGHT.as_button_with_threshold(0.8);
+///
+/// // Pulling the trigger part of the way only moves the axis
+/// throttle.set_value(app.world_mut(), 0.5);
+/// app.update();
+/// assert_eq!(app.read_axis_value(throttle), 0.5);
+/// assert!(!app.read_pressed(fire));
+///
+/// // Pulling it past the threshold also presses the button
+/// throttle.set_value(app.world_mut(), 0.9);
+/// app.update();
+/// assert_eq!(app.read_axis_value(throttle), 0.9);
+/// assert!(app.read_pressed(fire));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+#[must_use]
+pub struct GamepadTriggerAxis {
+    /// The button of the trigger.
+    pub trigger: GamepadButton,
+}
+
+impl GamepadTriggerAxis {
+    /// Creates a [`GamepadTriggerAxis`] reading the analog value of the given `trigger` button.
+    #[inline]
+    pub const fn new(trigger: GamepadButton) -> Self {
+        Self { trigger }
+    }
+
+    /// The left trigger (`LT` on an Xbox controller).
+    pub const LEFT: Self = Self::new(GamepadButton::LeftTrigger2);
+
+    /// The right trigger (`RT` on an Xbox controller).
+    pub const RIGHT: Self = Self::new(GamepadButton::RightTrigger2);
+
+    /// Creates a [`GamepadTriggerButton`] for the same trigger,
+    /// which is pressed while the trigger value is at least `threshold`.
+    ///
+    /// # Requirements
+    ///
+    /// - `threshold` is within the range `(0.0, 1.0]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the requirement isn't met.
+    #[track_caller]
+    #[inline]
+    pub fn as_button_with_threshold(&self, threshold: f32) -> GamepadTriggerButton {
+        assert!(
+            is_valid_trigger_threshold(threshold),
+            "the threshold of a GamepadTriggerButton must be within (0.0, 1.0], got {threshold}"
+        );
+        GamepadTriggerButton {
+            trigger: self.trigger,
+            threshold,
+            release_threshold: None,
+        }
+    }
+}
+
+impl UserInput for GamepadTriggerAxis {
+    /// [`GamepadTriggerAxis`] acts as an axis input.
+    #[inline]
+    fn kind(&self) -> InputControlKind {
+        InputControlKind::Axis
+    }
+
+    /// [`GamepadTriggerAxis`] represents the trigger button itself.
+    #[inline]
+    fn decompose(&self) -> BasicInputs {
+        BasicInputs::Simple(Box::new(self.trigger))
+    }
+
+    /// Returns the name of the trigger, such as `"Gamepad Left Trigger 2"`.
+    fn display_name(&self) -> String {
+        self.trigger.display_name()
+    }
+}
+
+#[serde_typetag]
+impl Axislike for GamepadTriggerAxis {
+    /// Retrieves how far the trigger is pulled, between `0.0` and `1.0`.
+    #[must_use]
+    #[inline]
+    fn value(&self, input_store: &CentralInputStore, gamepad: Entity) -> f32 {
+        button_value(input_store, gamepad, self.trigger)
+    }
+
+    /// Sends a [`RawGamepadEvent::Button`] event with the specified value on the provided gamepad.
+    fn set_value_as_gamepad(&self, world: &mut World, value: f32, gamepad: Option<Entity>) {
+        self.trigger.set_value_as_gamepad(world, value, gamepad);
+    }
+}
+
+/// Checks if `threshold` is within `(0.0, 1.0]`.
+#[must_use]
+#[inline]
+fn is_valid_trigger_threshold(threshold: f32) -> bool {
+    threshold > 0.0 && threshold <= 1.0
+}
+
+/// A view of a [`GamepadTriggerAxis`] as a button,
+/// pressed while the trigger is pulled at least as far as its `threshold`.
+///
+/// Create it using [`GamepadTriggerAxis::as_button_with_threshold`],
+/// and use [`GamepadTriggerButton::with_release_threshold`] to add hysteresis.
+#[derive(Debug, Clone, Copy, PartialEq, Reflect, Serialize, Deserialize)]
+#[serde(try_from = "UncheckedGamepadTriggerButton")]
+#[must_use]
+pub struct GamepadTriggerButton {
+    /// The button of the trigger.
+    pub trigger: GamepadButton,
+
+    /// The trigger value at which the button is considered pressed, within `(0.0, 1.0]`.
+    pub threshold: f32,
+
+    /// The trigger value below which the button is released once pressed, within `(0.0, threshold]`.
+    ///
+    /// When `None`, the button is released as soon as the trigger is let up past the `threshold`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) release_threshold: Option<f32>,
+}
+
+/// The serialized form of a [`GamepadTriggerButton`], validated before being converted into one.
+#[derive(Deserialize)]
+#[serde(rename = "GamepadTriggerButton")]
+struct UncheckedGamepadTriggerButton {
+    trigger: GamepadButton,
+    threshold: f32,
+    #[serde(default)]
+    release_threshold: Option<f32>,
+}
+
+impl TryFrom<UncheckedGamepadTriggerButton> for GamepadTriggerButton {
+    type Error = &'static str;
+
+    fn try_from(
+        UncheckedGamepadTriggerButton {
+            trigger,
+            threshold,
+            release_threshold,
+        }: UncheckedGamepadTriggerButton,
+    ) -> Result<Self, Self::Error> {
+        if !is_valid_trigger_threshold(threshold) {
+            return Err("a GamepadTriggerButton requires a threshold within (0.0, 1.0]");
+        }
+        if release_threshold.is_some_and(|release| release <= 0.0 || release > threshold) {
+            return Err(
+                "a GamepadTriggerButton requires a release threshold within (0.0, threshold]",
+            );
+        }
+        Ok(Self {
+            trigger,
+            threshold,
+            release_threshold,
+        })
+    }
+}
+
+impl GamepadTriggerButton {
+    /// Adds hysteresis to this button: once pressed, it stays pressed until the trigger is let up
+    /// below `release_threshold`, rather than as soon as it is let up past the `threshold`.
+    ///
+    /// The latched state is stored using [`CentralInputStore::with_input_memory`].
+    /// If the button isn't read for [`CentralInputStore::INPUT_MEMORY_LIFETIME`] frames,
+    /// such as while its action is disabled, its state is forgotten and it is released again.
+    ///
+    /// # Requirements
+    ///
+    /// - `release_threshold` is within the range `(0.0, threshold]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the requirement isn't met.
+    ///
+    /// ```rust
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// // Fires once the trigger is pulled 80% of the way, until it is let back up below 60%
+    /// let fire = GamepadTriggerAxis::RIGHT
+    ///     .as_button_with_threshold(0.8)
+    ///     .with_release_threshold(0.6);
+    /// assert_eq!(fire.release_threshold(), 0.6);
+    /// ```
+    #[track_caller]
+    #[inline]
+    pub fn with_release_threshold(mut self, release_threshold: f32) -> Self {
+        assert!(
+            release_threshold > 0.0 && release_threshold <= self.threshold,
+            "the release threshold of a GamepadTriggerButton must be within (0.0, {}], got {release_threshold}",
+            self.threshold
+        );
+        self.release_threshold = Some(release_threshold);
+        self
+    }
+
+    /// Returns the trigger value below which this button is released once pressed.
+    ///
+    /// Without [hysteresis](GamepadTriggerButton::with_release_threshold), this is the same as the `threshold`.
+    #[must_use]
+    #[inline]
+    pub fn release_threshold(&self) -> f32 {
+        self.release_threshold.unwrap_or(self.threshold)
+    }
+}
+
+impl UserInput for GamepadTriggerButton {
+    /// [`GamepadTriggerButton`] acts as a virtual button.
+    #[inline]
+    fn kind(&self) -> InputControlKind {
+        InputControlKind::Button
+    }
+
+    /// [`GamepadTriggerButton`] represents the trigger button itself.
+    #[inline]
+    fn decompose(&self) -> BasicInputs {
+        BasicInputs::Simple(Box::new(self.trigger))
+    }
+
+    /// Returns the name of the trigger, such as `"Gamepad Left Trigger 2"`.
+    fn display_name(&self) -> String {
+        self.trigger.display_name()
+    }
+}
+
+#[serde_typetag]
+impl Buttonlike for GamepadTriggerButton {
+    /// Checks if the trigger is pulled at least as far as the threshold,
+    /// or still at least as far as the release threshold since it was.
+    #[must_use]
+    #[inline]
+    fn pressed(&self, input_store: &CentralInputStore, gamepad: Entity) -> bool {
+        let value = button_value(input_store, gamepad, self.trigger);
+        match self.release_threshold {
+            None => value >= self.threshold,
+            Some(release_threshold) => hysteresis_latched(
+                input_store,
+                self,
+                gamepad,
+                value >= self.threshold,
+                value >= release_threshold,
+            ),
+        }
+    }
+
+    /// Retrieves the value of the trigger while pressed, or `0.0` otherwise.
+    #[must_use]
+    #[inline]
+    fn value(&self, input_store: &CentralInputStore, gamepad: Entity) -> f32 {
+        if self.pressed(input_store, gamepad) {
+            button_value(input_store, gamepad, self.trigger)
+        } else {
+            0.0
+        }
+    }
+
+    /// Sends a [`RawGamepadEvent::Button`] event fully pulling the trigger on the provided gamepad.
+    fn press_as_gamepad(&self, world: &mut World, gamepad: Option<Entity>) {
+        self.trigger.set_value_as_gamepad(world, 1.0, gamepad);
+    }
+
+    /// Sends a [`RawGamepadEvent::Button`] event releasing the trigger on the provided gamepad.
+    fn release_as_gamepad(&self, world: &mut World, gamepad: Option<Entity>) {
+        self.trigger.set_value_as_gamepad(world, 0.0, gamepad);
+    }
+
+    /// Sends a [`RawGamepadEvent::Button`] event with the specified value on the provided gamepad.
+    fn set_value_as_gamepad(&self, world: &mut World, value: f32, gamepad: Option<Entity>) {
+        self.trigger.set_value_as_gamepad(world, value, gamepad);
+    }
+}
+
+impl Eq for GamepadTriggerButton {}
+
+impl Hash for GamepadTriggerButton {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.trigger.hash(state);
+        FloatOrd(self.threshold).hash(state);
+        self.release_threshold.map(FloatOrd).hash(state);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -805,6 +1245,20 @@ mod tests {
         app
     }
 
+    #[test]
+    fn test_gamepad_stick_constructors() {
+        use crate::input_processing::DualAxisInverted;
+
+        assert_eq!(GamepadStick::left(), GamepadStick::LEFT);
+        assert_eq!(GamepadStick::right(), GamepadStick::RIGHT);
+
+        // Inverting an axis inline only appends a processor
+        let inverted = GamepadStick::right().inverted_y();
+        assert_eq!(inverted.x, GamepadAxis::RightStickX);
+        assert_eq!(inverted.y, GamepadAxis::RightStickY);
+        assert_eq!(inverted.processors, vec![DualAxisInverted::ONLY_Y.into()]);
+    }
+
     #[test]
     fn test_gamepad_axes() {
         let left_up = GamepadControlDirection::LEFT_UP;
@@ -920,6 +1374,117 @@ mod tests {
         assert_eq!(right.axis_pair(inputs, gamepad), Vec2::ZERO);
     }
 
+    #[test]
+    fn test_gamepad_trigger_axis() {
+        let axis = GamepadTriggerAxis::RIGHT;
+        assert_eq!(axis.kind(), InputControlKind::Axis);
+
+        let button = axis.as_button_with_threshold(0.5);
+        assert_eq!(button.kind(), InputControlKind::Button);
+
+        let mut app = test_app();
+        let gamepad = app
+            .world_mut()
+            .query_filtered::<Entity, With<Gamepad>>()
+            .iter(app.world())
+            .next()
+            .unwrap();
+
+        // Sweep the trigger from released to fully pulled and back again
+        for value in [0.0, 0.25, 0.45, 0.5, 0.75, 1.0, 0.6, 0.3, 0.0] {
+            axis.set_value_as_gamepad(app.world_mut(), value, Some(gamepad));
+            app.update();
+            let inputs = app.world().resource::<CentralInputStore>();
+
+            assert_eq!(axis.value(inputs, gamepad), value);
+            assert_eq!(button.pressed(inputs, gamepad), value >= 0.5);
+            let expected = if value >= 0.5 { value } else { 0.0 };
+            assert_eq!(Buttonlike::value(&button, inputs, gamepad), expected);
+        }
+
+        // The other trigger is unaffected
+        let inputs = app.world().resource::<CentralInputStore>();
+        assert_eq!(GamepadTriggerAxis::LEFT.value(inputs, gamepad), 0.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_gamepad_trigger_threshold_out_of_range() {
+        let _ = GamepadTriggerAxis::LEFT.as_button_with_threshold(1.5);
+    }
+
+    #[test]
+    fn test_gamepad_trigger_button_hysteresis() {
+        let axis = GamepadTriggerAxis::RIGHT;
+        let button = axis
+            .as_button_with_threshold(0.6)
+            .with_release_threshold(0.4);
+        assert_eq!(button.release_threshold(), 0.4);
+
+        let mut app = test_app();
+        let gamepad = app
+            .world_mut()
+            .query_filtered::<Entity, With<Gamepad>>()
+            .iter(app.world())
+            .next()
+            .unwrap();
+
+        // Hovering between the thresholds keeps the previous state
+        for (value, pressed) in [
+            (0.5, false),
+            (0.45, false),
+            (0.6, true),
+            (0.5, true),
+            (0.4, true),
+            (0.55, true),
+            (0.35, false),
+            (0.55, false),
+        ] {
+            axis.set_value_as_gamepad(app.world_mut(), value, Some(gamepad));
+            app.update();
+            let inputs = app.world().resource::<CentralInputStore>();
+
+            assert_eq!(button.pressed(inputs, gamepad), pressed, "value {value}");
+            let expected = if pressed { value } else { 0.0 };
+            assert_eq!(Buttonlike::value(&button, inputs, gamepad), expected);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_gamepad_trigger_release_threshold_above_threshold() {
+        let _ = GamepadTriggerAxis::LEFT
+            .as_button_with_threshold(0.5)
+            .with_release_threshold(0.7);
+    }
+
+    #[test]
+    fn test_gamepad_trigger_button_deserialization_is_validated() {
+        let button = GamepadTriggerAxis::LEFT
+            .as_button_with_threshold(0.8)
+            .with_release_threshold(0.6);
+        let serialized = ron::to_string(&button).unwrap();
+        assert_eq!(
+            ron::from_str::<GamepadTriggerButton>(&serialized).unwrap(),
+            button
+        );
+
+        let invalid = GamepadTriggerButton {
+            release_threshold: Some(0.9),
+            ..button
+        };
+        let serialized = ron::to_string(&invalid).unwrap();
+        assert!(ron::from_str::<GamepadTriggerButton>(&serialized).is_err());
+
+        let invalid = GamepadTriggerButton {
+            threshold: 0.0,
+            release_threshold: None,
+            ..button
+        };
+        let serialized = ron::to_string(&invalid).unwrap();
+        assert!(ron::from_str::<GamepadTriggerButton>(&serialized).is_err());
+    }
+
     #[test]
     #[ignore = "Input mocking is subtly broken: https://github.com/Leafwing-Studios/leafwing-input-manager/issues/516"]
     fn test_gamepad_buttons() {
@@ -957,4 +1522,96 @@ mod tests {
         assert!(!down.pressed(inputs, gamepad));
         assert!(!right.pressed(inputs, gamepad));
     }
+
+    #[test]
+    fn test_processors_getter() {
+        use crate::input_processing::*;
+        use crate::user_input::{DualAxislike, DualAxislikeChord};
+
+        let stick: Box<dyn DualAxislike> = Box::new(
+            GamepadStick::LEFT
+                .sensitivity(1.5)
+                .with_circle_deadzone(0.1),
+        );
+        assert_eq!(
+            stick.processors(),
+            &[
+                DualAxisProcessor::from(DualAxisSensitivity::all(1.5)),
+                DualAxisProcessor::from(CircleDeadZone::new(0.1)),
+            ]
+        );
+
+        let axis: Box<dyn Axislike> = Box::new(
+            GamepadControlAxis::LEFT_X
+                .sensitivity(1.5)
+                .with_deadzone_symmetric(0.1),
+        );
+        assert_eq!(
+            axis.processors(),
+            &[
+                AxisProcessor::Sensitivity(1.5),
+                AxisProcessor::from(AxisDeadZone::symmetric(0.1)),
+            ]
+        );
+
+        // Chords report the pipeline of their inner input
+        let chord: Box<dyn DualAxislike> = Box::new(DualAxislikeChord::new(
+            GamepadButton::LeftTrigger,
+            GamepadStick::LEFT.sensitivity(1.5),
+        ));
+        assert_eq!(
+            chord.processors(),
+            &[DualAxisProcessor::from(DualAxisSensitivity::all(1.5))]
+        );
+
+        // Inputs without a processing pipeline have no processors
+        let trigger: Box<dyn Axislike> = Box::new(GamepadTriggerAxis::LEFT);
+        assert!(trigger.processors().is_empty());
+        assert!(GamepadStick::RIGHT.processors().is_empty());
+    }
+
+    #[test]
+    fn test_gamepad_registry() {
+        let first = Entity::from_raw(1);
+        let second = Entity::from_raw(2);
+        let third = Entity::from_raw(3);
+
+        let mut registry = GamepadRegistry::default();
+        assert_eq!(registry.gamepad(PlayerIndex(0)), None);
+
+        // Gamepads are assigned in connection order
+        assert_eq!(registry.connect(first), PlayerIndex(0));
+        assert_eq!(registry.connect(second), PlayerIndex(1));
+        assert_eq!(registry.connect(first), PlayerIndex(0));
+        assert_eq!(registry.gamepad(PlayerIndex(1)), Some(second));
+        assert_eq!(registry.player(second), Some(PlayerIndex(1)));
+
+        // Disconnecting frees the index without affecting other players
+        assert_eq!(registry.disconnect(first), Some(PlayerIndex(0)));
+        assert_eq!(registry.disconnect(first), None);
+        assert_eq!(registry.gamepad(PlayerIndex(0)), None);
+        assert_eq!(registry.gamepad(PlayerIndex(1)), Some(second));
+
+        // The freed index is reused by the next gamepad to connect
+        assert_eq!(registry.connect(third), PlayerIndex(0));
+        assert_eq!(registry.player(third), Some(PlayerIndex(0)));
+    }
+
+    #[test]
+    fn test_gamepad_display_name() {
+        assert_eq!(GamepadButton::South.display_name(), "Gamepad South");
+        assert_eq!(
+            GamepadButton::LeftTrigger2.display_name(),
+            "Gamepad Left Trigger 2"
+        );
+        assert_eq!(
+            GamepadControlAxis::LEFT_X.display_name(),
+            "Gamepad Left Stick X"
+        );
+        assert_eq!(
+            GamepadControlDirection::LEFT_UP.display_name(),
+            "Gamepad Left Stick Y+"
+        );
+        assert_eq!(GamepadStick::RIGHT.display_name(), "Gamepad Right Stick");
+    }
 }
@@ -0,0 +1,231 @@
+//! This module contains inputs that combine several input devices, such as [`PreferredDeviceButton`].
+
+use bevy::prelude::{Entity, GamepadButton, KeyCode, Reflect, World};
+use leafwing_input_manager_macros::serde_typetag;
+use serde::{Deserialize, Serialize};
+
+use crate as leafwing_input_manager;
+use crate::clashing_inputs::BasicInputs;
+use crate::user_input::{Buttonlike, UserInput};
+use crate::InputControlKind;
+
+use super::updating::CentralInputStore;
+
+/// The kinds of input devices that a [`PreferredDeviceButton`] can be pressed with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+pub enum InputDevice {
+    /// A gamepad, pressing a [`GamepadButton`].
+    Gamepad,
+    /// A keyboard, pressing a [`KeyCode`].
+    Keyboard,
+}
+
+/// A [`Buttonlike`] for hybrid controls, pressed by either a [`GamepadButton`] or a fallback [`KeyCode`].
+///
+/// This behaves like binding both buttons to the same action,
+/// but as a single input that remembers which device was last used to press it,
+/// making it easy to display the button glyph of the device the player is actually using.
+///
+/// The active device is stored using [`CentralInputStore::with_input_memory`],
+/// and is kept for as long as this input is read every frame, such as while its action is processed,
+/// even if it isn't pressed again.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy::input::InputPlugin;
+/// use leafwing_input_manager::plugin::CentralInputStorePlugin;
+/// use leafwing_input_manager::prelude::*;
+/// use leafwing_input_manager::user_input::testing_utils::FetchUserInput;
+///
+/// let mut app = App::new();
+/// app.add_plugins((InputPlugin, CentralInputStorePlugin));
+///
+/// // Jump with the South button, or with the space bar
+/// let input = PreferredDeviceButton::new(GamepadButton::South, KeyCode::Space);
+///
+/// KeyCode::Space.press(app.world_mut());
+/// app.update();
+/// assert!(app.read_pressed(input.clone()));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+#[must_use]
+pub struct PreferredDeviceButton {
+    /// The button that presses this input on a gamepad.
+    pub gamepad: GamepadButton,
+
+    /// The key that presses this input on a keyboard.
+    pub keyboard: KeyCode,
+}
+
+/// The state of a [`PreferredDeviceButton`], stored in the [`CentralInputStore`].
+#[derive(Debug, Default, Clone)]
+struct PreferredDeviceMemory {
+    /// The device that was last used to press the input.
+    active_device: Option<InputDevice>,
+}
+
+impl PreferredDeviceButton {
+    /// Creates a [`PreferredDeviceButton`] pressed by either the `gamepad` button or the `keyboard` key.
+    #[inline]
+    pub const fn new(gamepad: GamepadButton, keyboard: KeyCode) -> Self {
+        Self { gamepad, keyboard }
+    }
+
+    /// Returns the [`InputDevice`] that is currently pressing this input,
+    /// or the one that last pressed it if neither is pressed.
+    ///
+    /// If both devices are pressing this input, the gamepad is preferred.
+    /// This will be [`None`] if this input has never been pressed.
+    #[must_use]
+    pub fn active_device(
+        &self,
+        input_store: &CentralInputStore,
+        gamepad: Entity,
+    ) -> Option<InputDevice> {
+        let pressed_device = if self.gamepad.pressed(input_store, gamepad) {
+            Some(InputDevice::Gamepad)
+        } else if self.keyboard.pressed(input_store, gamepad) {
+            Some(InputDevice::Keyboard)
+        } else {
+            None
+        };
+
+        input_store.with_input_memory(self, gamepad, |memory: &mut PreferredDeviceMemory| {
+            if pressed_device.is_some() {
+                memory.active_device = pressed_device;
+            }
+
+            memory.active_device
+        })
+    }
+}
+
+impl UserInput for PreferredDeviceButton {
+    /// [`PreferredDeviceButton`] acts as a button.
+    #[inline]
+    fn kind(&self) -> InputControlKind {
+        InputControlKind::Button
+    }
+
+    /// [`PreferredDeviceButton`] represents a composition of its gamepad button and keyboard key.
+    #[inline]
+    fn decompose(&self) -> BasicInputs {
+        BasicInputs::Composite(vec![Box::new(self.gamepad), Box::new(self.keyboard)])
+    }
+}
+
+#[serde_typetag]
+impl Buttonlike for PreferredDeviceButton {
+    /// Checks if either the gamepad button or the keyboard key is pressed,
+    /// remembering which device was used.
+    #[must_use]
+    fn pressed(&self, input_store: &CentralInputStore, gamepad: Entity) -> bool {
+        let pressed = self.gamepad.pressed(input_store, gamepad)
+            || self.keyboard.pressed(input_store, gamepad);
+
+        // Read the active device even while released, so that it isn't evicted from the input memory
+        let _ = self.active_device(input_store, gamepad);
+
+        pressed
+    }
+
+    /// Retrieves the larger value of the gamepad button and the keyboard key.
+    #[must_use]
+    fn value(&self, input_store: &CentralInputStore, gamepad: Entity) -> f32 {
+        let gamepad_value = self.gamepad.value(input_store, gamepad);
+        let keyboard_value = self.keyboard.value(input_store, gamepad);
+        gamepad_value.max(keyboard_value)
+    }
+
+    /// Presses the gamepad button on the provided gamepad [`Entity`].
+    fn press_as_gamepad(&self, world: &mut World, gamepad: Option<Entity>) {
+        self.gamepad.press_as_gamepad(world, gamepad);
+    }
+
+    /// Releases both the gamepad button and the keyboard key.
+    fn release_as_gamepad(&self, world: &mut World, gamepad: Option<Entity>) {
+        self.gamepad.release_as_gamepad(world, gamepad);
+        self.keyboard.release_as_gamepad(world, gamepad);
+    }
+
+    /// Sets the value of the gamepad button on the provided gamepad [`Entity`].
+    fn set_value_as_gamepad(&self, world: &mut World, value: f32, gamepad: Option<Entity>) {
+        self.gamepad.set_value_as_gamepad(world, value, gamepad);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::buttonlike::ButtonValue;
+    use crate::user_input::gamepad::SpecificGamepadButton;
+
+    #[test]
+    fn preferred_device_button() {
+        let input = PreferredDeviceButton::new(GamepadButton::South, KeyCode::Space);
+        let gamepad = Entity::PLACEHOLDER;
+        let mut input_store = CentralInputStore::default();
+
+        // Released
+        assert!(!input.pressed(&input_store, gamepad));
+        assert_eq!(input.value(&input_store, gamepad), 0.0);
+        assert_eq!(input.active_device(&input_store, gamepad), None);
+
+        // The keyboard key triggers the input
+        input_store.update_buttonlike(KeyCode::Space, ButtonValue::from_pressed(true));
+        assert!(input.pressed(&input_store, gamepad));
+        assert_eq!(input.value(&input_store, gamepad), 1.0);
+        assert_eq!(
+            input.active_device(&input_store, gamepad),
+            Some(InputDevice::Keyboard)
+        );
+
+        // The active device is remembered after the input is released
+        input_store.clear();
+        assert!(!input.pressed(&input_store, gamepad));
+        assert_eq!(
+            input.active_device(&input_store, gamepad),
+            Some(InputDevice::Keyboard)
+        );
+
+        // The gamepad button triggers the input too
+        input_store.update_buttonlike(
+            SpecificGamepadButton::new(gamepad, GamepadButton::South),
+            ButtonValue::new(true, 0.8),
+        );
+        assert!(input.pressed(&input_store, gamepad));
+        assert_eq!(input.value(&input_store, gamepad), 0.8);
+        assert_eq!(
+            input.active_device(&input_store, gamepad),
+            Some(InputDevice::Gamepad)
+        );
+
+        input_store.clear();
+        assert!(!input.pressed(&input_store, gamepad));
+        assert_eq!(
+            input.active_device(&input_store, gamepad),
+            Some(InputDevice::Gamepad)
+        );
+    }
+
+    #[test]
+    fn preferred_device_outlives_input_memory() {
+        let input = PreferredDeviceButton::new(GamepadButton::South, KeyCode::Space);
+        let gamepad = Entity::PLACEHOLDER;
+        let mut input_store = CentralInputStore::default();
+
+        input_store.update_buttonlike(KeyCode::Space, ButtonValue::from_pressed(true));
+        assert!(input.pressed(&input_store, gamepad));
+
+        // The input is read every frame while its action is processed, but never pressed again
+        for _ in 0..=2 * CentralInputStore::INPUT_MEMORY_LIFETIME {
+            input_store.clear();
+            assert!(!input.pressed(&input_store, gamepad));
+        }
+
+        assert_eq!(
+            input.active_device(&input_store, gamepad),
+            Some(InputDevice::Keyboard)
+        );
+    }
+}
@@ -2,16 +2,16 @@
 
 use bevy::ecs::system::lifetimeless::SRes;
 use bevy::ecs::system::StaticSystemParam;
-use bevy::input::keyboard::{Key, KeyboardInput, NativeKey};
+use bevy::input::keyboard::{Key, KeyboardFocusLost, KeyboardInput, NativeKey, NativeKeyCode};
 use bevy::input::{ButtonInput, ButtonState};
-use bevy::prelude::{Entity, Events, KeyCode, Reflect, ResMut, World};
+use bevy::prelude::{Entity, EventReader, Events, KeyCode, Local, Reflect, ResMut, World};
 use leafwing_input_manager_macros::serde_typetag;
 use serde::{Deserialize, Serialize};
 
 use crate as leafwing_input_manager;
 use crate::buttonlike::ButtonValue;
 use crate::clashing_inputs::BasicInputs;
-use crate::user_input::{ButtonlikeChord, UserInput};
+use crate::user_input::{split_words, ButtonlikeChord, UserInput};
 use crate::InputControlKind;
 
 use super::updating::{CentralInputStore, UpdatableInput};
@@ -31,6 +31,27 @@ impl UserInput for KeyCode {
     fn decompose(&self) -> BasicInputs {
         BasicInputs::Simple(Box::new(*self))
     }
+
+    /// Returns the name of the key, such as `"A"`, `"1"`, `"Left Shift"` or `"Up Arrow"`.
+    fn display_name(&self) -> String {
+        let name = format!("{self:?}");
+        if let Some(key) = name
+            .strip_prefix("Key")
+            .or_else(|| name.strip_prefix("Digit"))
+            .filter(|key| key.len() == 1)
+        {
+            return key.to_string();
+        }
+        if let Some(direction) = name.strip_prefix("Arrow") {
+            return format!("{direction} Arrow");
+        }
+        for side in ["Left", "Right"] {
+            if let Some(key) = name.strip_suffix(side).filter(|key| !key.is_empty()) {
+                return format!("{side} {}", split_words(key));
+            }
+        }
+        split_words(&name)
+    }
 }
 
 impl UpdatableInput for KeyCode {
@@ -217,6 +238,206 @@ impl Buttonlike for ModifierKey {
     }
 }
 
+/// A key identified by the character it types on the user's keyboard layout, rather than by its physical position.
+///
+/// This reads the logical [`Key`] of each [`KeyboardInput`] event,
+/// so `LogicalKey::character("q")` is pressed by whichever key types "q":
+/// the key labeled Q on a QWERTY keyboard, but the key labeled A on an AZERTY keyboard.
+/// Letters are matched regardless of case, so holding Shift does not change which logical key is pressed.
+///
+/// # Logical Keys vs Key Codes
+///
+/// Prefer logical keys for mnemonic bindings, such as "I" for the inventory,
+/// where the displayed letter should match what the user sees on their keyboard.
+///
+/// Prefer [`KeyCode`] for positional bindings, such as WASD movement:
+/// these are chosen for their layout on the keyboard, which [`KeyCode`] preserves across keyboard layouts.
+/// Binding movement to the logical keys W, A, S and D would scatter them across an AZERTY or Dvorak keyboard.
+///
+/// As logical keys are tracked separately from [`KeyCode`]s,
+/// clashes between a [`LogicalKey`] and a [`KeyCode`] on the same physical key are not detected.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy::input::InputPlugin;
+/// use leafwing_input_manager::plugin::CentralInputStorePlugin;
+/// use leafwing_input_manager::prelude::*;
+/// use leafwing_input_manager::user_input::testing_utils::FetchUserInput;
+///
+/// let mut app = App::new();
+/// app.add_plugins((InputPlugin, CentralInputStorePlugin));
+///
+/// // Open the inventory with the key that types "i"
+/// let input = LogicalKey::character("i");
+///
+/// input.press(app.world_mut());
+/// app.update();
+/// assert!(app.read_pressed(input.clone()));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+#[serde(from = "Key", into = "Key")]
+#[must_use]
+pub struct LogicalKey(Key);
+
+impl LogicalKey {
+    /// Creates a [`LogicalKey`] pressed by the key that produces the logical `key`.
+    ///
+    /// [`Key::Character`]s are converted to lowercase, so that they match regardless of case.
+    #[inline]
+    pub fn new(key: Key) -> Self {
+        match key {
+            Key::Character(character) => Self(Key::Character(character.to_lowercase().into())),
+            key => Self(key),
+        }
+    }
+
+    /// Creates a [`LogicalKey`] pressed by the key that types the `character`, regardless of case.
+    #[inline]
+    pub fn character(character: &str) -> Self {
+        Self::new(Key::Character(character.into()))
+    }
+
+    /// Returns the logical [`Key`] that presses this input.
+    #[must_use]
+    #[inline]
+    pub fn key(&self) -> &Key {
+        &self.0
+    }
+}
+
+impl From<Key> for LogicalKey {
+    fn from(key: Key) -> Self {
+        Self::new(key)
+    }
+}
+
+impl From<LogicalKey> for Key {
+    fn from(logical_key: LogicalKey) -> Self {
+        logical_key.0
+    }
+}
+
+impl UserInput for LogicalKey {
+    /// [`LogicalKey`] acts as a button.
+    #[inline]
+    fn kind(&self) -> InputControlKind {
+        InputControlKind::Button
+    }
+
+    /// Returns a [`BasicInputs`] that only contains the [`LogicalKey`] itself.
+    #[inline]
+    fn decompose(&self) -> BasicInputs {
+        BasicInputs::Simple(Box::new(self.clone()))
+    }
+
+    /// Returns the character typed by the key in uppercase, such as `"A"`, or the name of the key, such as `"Page Up"`.
+    fn display_name(&self) -> String {
+        match &self.0 {
+            Key::Character(character) => character.to_uppercase(),
+            key => split_words(&format!("{key:?}")),
+        }
+    }
+}
+
+impl UpdatableInput for LogicalKey {
+    type SourceData = (
+        EventReader<'static, 'static, KeyboardInput>,
+        EventReader<'static, 'static, KeyboardFocusLost>,
+        Local<'static, Vec<(KeyCode, LogicalKey)>>,
+    );
+
+    fn compute(
+        mut central_input_store: ResMut<CentralInputStore>,
+        source_data: StaticSystemParam<Self::SourceData>,
+    ) {
+        let (mut keyboard_events, mut focus_lost_events, mut held_keys) = source_data.into_inner();
+        let mut released_keys = Vec::new();
+
+        for event in keyboard_events.read() {
+            let logical_key = LogicalKey::new(event.logical_key.clone());
+            // Keys are released by their physical key code when it is known,
+            // in case the logical key changed while the key was held, such as when pressing Shift
+            let identified = !matches!(event.key_code, KeyCode::Unidentified(_));
+            held_keys.retain(|(key_code, held_key)| {
+                let released =
+                    held_key == &logical_key || (identified && key_code == &event.key_code);
+                if released {
+                    released_keys.push(held_key.clone());
+                }
+                !released
+            });
+
+            if event.state == ButtonState::Pressed {
+                held_keys.push((event.key_code, logical_key));
+            }
+        }
+
+        // Mirror `ButtonInput<KeyCode>`, which releases all keys when the window loses focus
+        if focus_lost_events.read().count() > 0 {
+            released_keys.extend(held_keys.drain(..).map(|(_, held_key)| held_key));
+        }
+
+        for key in released_keys {
+            central_input_store.update_buttonlike(key, ButtonValue::from_pressed(false));
+        }
+
+        for (_, key) in held_keys.iter() {
+            central_input_store.update_buttonlike(key.clone(), ButtonValue::from_pressed(true));
+        }
+    }
+}
+
+#[serde_typetag]
+impl Buttonlike for LogicalKey {
+    /// Checks if the key producing this logical key is currently pressed down.
+    #[must_use]
+    #[inline]
+    fn pressed(&self, input_store: &CentralInputStore, _gamepad: Entity) -> bool {
+        input_store.pressed(self)
+    }
+
+    /// Sends a fake [`KeyboardInput`] event to the world with [`ButtonState::Pressed`].
+    ///
+    /// # Note
+    ///
+    /// The `key_code` and `window` fields will be filled with placeholder values.
+    fn press(&self, world: &mut World) {
+        let mut events = world.resource_mut::<Events<KeyboardInput>>();
+        events.send(KeyboardInput {
+            key_code: KeyCode::Unidentified(NativeKeyCode::Unidentified),
+            logical_key: self.0.clone(),
+            state: ButtonState::Pressed,
+            repeat: false,
+            window: Entity::PLACEHOLDER,
+        });
+    }
+
+    /// Sends a fake [`KeyboardInput`] event to the world with [`ButtonState::Released`].
+    ///
+    /// # Note
+    ///
+    /// The `key_code` and `window` fields will be filled with placeholder values.
+    fn release(&self, world: &mut World) {
+        let mut events = world.resource_mut::<Events<KeyboardInput>>();
+        events.send(KeyboardInput {
+            key_code: KeyCode::Unidentified(NativeKeyCode::Unidentified),
+            logical_key: self.0.clone(),
+            state: ButtonState::Released,
+            repeat: false,
+            window: Entity::PLACEHOLDER,
+        });
+    }
+
+    /// If the value is greater than `0.0`, press the key; otherwise release it.
+    fn set_value(&self, world: &mut World, value: f32) {
+        if value > 0.0 {
+            self.press(world);
+        } else {
+            self.release(world);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -324,4 +545,78 @@ mod tests {
         assert!(!left.pressed(inputs, gamepad));
         assert!(alt.pressed(inputs, gamepad));
     }
+
+    #[test]
+    fn test_logical_key() {
+        let q = LogicalKey::character("q");
+        assert_eq!(q.kind(), InputControlKind::Button);
+        assert_eq!(q, LogicalKey::character("Q"));
+
+        // Deserialized keys are normalized too
+        let serialized = ron::to_string(&q).unwrap();
+        assert_eq!(ron::from_str::<LogicalKey>(&serialized).unwrap(), q);
+        assert_eq!(ron::from_str::<LogicalKey>("Character(\"Q\")").unwrap(), q);
+
+        let mut app = test_app();
+        let gamepad = app.world_mut().spawn(()).id();
+
+        // The physical key labeled A on an AZERTY keyboard types "q"
+        let send = |app: &mut App, key_code: KeyCode, character: &str, state: ButtonState| {
+            app.world_mut().send_event(KeyboardInput {
+                key_code,
+                logical_key: Key::Character(character.into()),
+                state,
+                repeat: false,
+                window: Entity::PLACEHOLDER,
+            });
+            app.update();
+        };
+        send(&mut app, KeyCode::KeyA, "q", ButtonState::Pressed);
+        let inputs = app.world().resource::<CentralInputStore>();
+        assert!(q.pressed(inputs, gamepad));
+        assert!(!LogicalKey::character("a").pressed(inputs, gamepad));
+        assert!(KeyCode::KeyA.pressed(inputs, gamepad));
+        assert!(!KeyCode::KeyQ.pressed(inputs, gamepad));
+
+        // Logical keys stay pressed while held
+        app.update();
+        let inputs = app.world().resource::<CentralInputStore>();
+        assert!(q.pressed(inputs, gamepad));
+
+        // Releasing the physical key releases the logical key, even if Shift changed its case
+        send(&mut app, KeyCode::KeyA, "Q", ButtonState::Released);
+        let inputs = app.world().resource::<CentralInputStore>();
+        assert!(!q.pressed(inputs, gamepad));
+
+        // Simulated presses and releases
+        q.press(app.world_mut());
+        app.update();
+        let inputs = app.world().resource::<CentralInputStore>();
+        assert!(q.pressed(inputs, gamepad));
+
+        q.release(app.world_mut());
+        app.update();
+        let inputs = app.world().resource::<CentralInputStore>();
+        assert!(!q.pressed(inputs, gamepad));
+
+        // Losing focus releases every held key
+        q.press(app.world_mut());
+        app.update();
+        app.world_mut().send_event(KeyboardFocusLost);
+        app.update();
+        let inputs = app.world().resource::<CentralInputStore>();
+        assert!(!q.pressed(inputs, gamepad));
+    }
+
+    #[test]
+    fn test_keyboard_display_name() {
+        assert_eq!(KeyCode::KeyA.display_name(), "A");
+        assert_eq!(KeyCode::Digit1.display_name(), "1");
+        assert_eq!(KeyCode::ShiftLeft.display_name(), "Left Shift");
+        assert_eq!(KeyCode::ArrowUp.display_name(), "Up Arrow");
+        assert_eq!(KeyCode::PageDown.display_name(), "Page Down");
+        assert_eq!(KeyCode::F12.display_name(), "F12");
+        assert_eq!(ModifierKey::Control.display_name(), "Control");
+        assert_eq!(LogicalKey::character("q").display_name(), "Q");
+    }
 }
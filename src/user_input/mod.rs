@@ -40,11 +40,13 @@
 //!
 //! - Check gamepad button presses using Bevy's [`GamepadButton`] directly.
 //! - Access physical sticks using [`GamepadStick`], [`GamepadControlAxis`], and [`GamepadControlDirection`].
+//! - Read analog triggers using [`GamepadTriggerAxis`], and as buttons using [`GamepadTriggerButton`].
 //!
 //! ### Keyboard Inputs
 //!
 //! - Check physical keys presses using Bevy's [`KeyCode`] directly.
 //! - Use [`ModifierKey`] to check for either left or right modifier keys is pressed.
+//! - Use [`LogicalKey`] to check for the key that types a character on the user's keyboard layout.
 //!
 //! ### Mouse Inputs
 //!
@@ -64,16 +66,53 @@
 //!
 //! - [`ButtonlikeChord`]: A combined input that groups multiple [`Buttonlike`]s together,
 //!   allowing you to define complex input combinations like hotkeys, shortcuts, and macros.
+//!   Its [`ChordActivation`] controls whether it stays pressed while held, or only triggers once per press.
 //!
 //! - [`AxislikeChord`]: A combined input that groups a [`Buttonlike`] and an [`Axislike`] together,
 //!   allowing you to only read the dual axis data when the button is pressed.
 //!
+//! - [`ButtonValueChord`]: A combined input that groups two [`Buttonlike`]s together,
+//!   allowing you to read the analog value of one button as an axis when the other is pressed.
+//!
 //! - [`DualAxislikeChord`]: A combined input that groups a [`Buttonlike`] and a [`DualAxislike`] together,
 //!   allowing you to only read the dual axis data when the button is pressed.
 //!
 //! - [`TripleAxislikeChord`]: A combined input that groups a [`Buttonlike`] and a [`TripleAxislike`] together,
 //!   allowing you to only read the dual axis data when the button is pressed.
 //!
+//! ### Fallback Inputs
+//!
+//! - [`FirstNonZero`]: An axis that reads the value of the first of several [`Axislike`]s that is non-zero,
+//!   in order of priority.
+//!
+//! ### Combined Axes
+//!
+//! - [`AxisSum`]: An axis that adds together the values of several [`Axislike`]s,
+//!   optionally clamped to a range.
+//!
+//! ### Axis Ranges
+//!
+//! - [`AxisInRange`]: A button that is pressed while the value of an [`Axislike`] is within a range.
+//!
+//! ### Hybrid Inputs
+//!
+//! - [`PreferredDeviceButton`]: A button pressed by either a gamepad button or a keyboard key,
+//!   which remembers the device that was last used to press it.
+//!
+//! ### Stateful Inputs
+//!
+//! - [`DebouncedButton`]: A button that is only pressed once its input has been held for several consecutive frames.
+//! - [`OnReleaseButton`]: A button that is pressed for a single frame when its input is released.
+//! - [`DoubleTap`]: A button that is pressed for a single frame when its input is pressed twice in quick succession.
+//! - [`SequenceInput`]: A button that is pressed for a single frame when several inputs are pressed in order, like a combo.
+//! - [`Toggle`]: A button that latches like caps lock, flipping between pressed and released each time its input is pressed.
+//! - [`FlickStick`]: An axis reporting how far to turn the camera when a stick is flicked and rotated.
+//!
+//! ### Typed Bindings
+//!
+//! - [`InputBinding`]: A serializable enum over the built-in inputs, for defining controls in typed configs
+//!   without boxed trait objects.
+//!
 //! [`GamepadButton`]: bevy::prelude::GamepadButton
 //! [`KeyCode`]: bevy::prelude::KeyCode
 //! [`MouseButton`]: bevy::prelude::MouseButton
@@ -86,29 +125,44 @@ use bevy::reflect::{erased_serde, Reflect};
 use dyn_clone::DynClone;
 use dyn_eq::DynEq;
 use dyn_hash::DynHash;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use updating::CentralInputStore;
 
 use crate::clashing_inputs::BasicInputs;
+use crate::input_processing::{AxisProcessor, DualAxisProcessor, TripleAxisProcessor};
 use crate::InputControlKind;
 
+pub use self::axis_range::*;
+pub use self::binding::*;
 pub use self::chord::*;
+pub use self::fallback::*;
 #[cfg(feature = "gamepad")]
 pub use self::gamepad::*;
+#[cfg(all(feature = "gamepad", feature = "keyboard"))]
+pub use self::hybrid::*;
 #[cfg(feature = "keyboard")]
 pub use self::keyboard::*;
 #[cfg(feature = "mouse")]
 pub use self::mouse::*;
+pub use self::stateful::*;
+pub use self::sum::*;
 pub use self::trait_serde::RegisterUserInput;
 pub use self::virtual_axial::*;
 
+pub mod axis_range;
+pub mod binding;
 pub mod chord;
+pub mod fallback;
 #[cfg(feature = "gamepad")]
 pub mod gamepad;
+#[cfg(all(feature = "gamepad", feature = "keyboard"))]
+pub mod hybrid;
 #[cfg(feature = "keyboard")]
 pub mod keyboard;
 #[cfg(feature = "mouse")]
 pub mod mouse;
+pub mod stateful;
+pub mod sum;
 pub mod testing_utils;
 mod trait_reflection;
 mod trait_serde;
@@ -128,6 +182,39 @@ pub trait UserInput: Send + Sync + Debug {
     /// For inputs that represent a simple, atomic control,
     /// this method should always return a [`BasicInputs::Simple`] that only contains the input itself.
     fn decompose(&self) -> BasicInputs;
+
+    /// Returns a human-readable name for the input, such as `"Left Shift"` or `"Gamepad South"`,
+    /// suitable for "controls" screens like [`InputMap::cheat_sheet`](crate::input_map::InputMap::cheat_sheet).
+    ///
+    /// Defaults to the [`Debug`] representation of the input.
+    fn display_name(&self) -> String {
+        format!("{self:?}")
+    }
+}
+
+/// Splits a `PascalCase` name into space-separated words, such as `"LeftTrigger2"` into `"Left Trigger 2"`.
+///
+/// Names that aren't simple identifiers, such as `"Other(5)"`, are returned unchanged.
+pub(crate) fn split_words(name: &str) -> String {
+    if !name.chars().all(char::is_alphanumeric) {
+        return name.to_string();
+    }
+
+    let mut words = String::with_capacity(name.len() + 4);
+    let mut previous: Option<char> = None;
+    for character in name.chars() {
+        if let Some(previous) = previous {
+            let new_word = (character.is_uppercase()
+                && (previous.is_lowercase() || previous.is_ascii_digit()))
+                || (character.is_ascii_digit() && previous.is_lowercase());
+            if new_word {
+                words.push(' ');
+            }
+        }
+        words.push(character);
+        previous = Some(character);
+    }
+    words
 }
 
 /// A trait used for buttonlike user inputs, which can be pressed or released
@@ -243,6 +330,15 @@ pub trait Axislike:
     fn set_value_as_gamepad(&self, world: &mut World, value: f32, _gamepad: Option<Entity>) {
         self.set_value(world, value);
     }
+
+    /// Returns the [`AxisProcessor`]s applied to the value of this input, in the order they are applied.
+    ///
+    /// This lets the processing pipeline be read back from a boxed [`Axislike`],
+    /// for example to show its sensitivity and dead zone in a settings menu.
+    /// Inputs without a processing pipeline return an empty slice.
+    fn processors(&self) -> &[AxisProcessor] {
+        &[]
+    }
 }
 
 /// A trait used for dual-axis-like user inputs, which provide separate X and Y values.
@@ -270,6 +366,15 @@ pub trait DualAxislike:
     fn set_axis_pair_as_gamepad(&self, world: &mut World, value: Vec2, _gamepad: Option<Entity>) {
         self.set_axis_pair(world, value);
     }
+
+    /// Returns the [`DualAxisProcessor`]s applied to the value of this input, in the order they are applied.
+    ///
+    /// This lets the processing pipeline be read back from a boxed [`DualAxislike`],
+    /// for example to show its sensitivity and dead zone in a settings menu.
+    /// Inputs without a processing pipeline return an empty slice.
+    fn processors(&self) -> &[DualAxisProcessor] {
+        &[]
+    }
 }
 
 /// A trait used for triple-axis-like user inputs, which provide separate X, Y, and Z values.
@@ -297,13 +402,22 @@ pub trait TripleAxislike:
     fn set_axis_triple_as_gamepad(&self, world: &mut World, value: Vec3, _gamepad: Option<Entity>) {
         self.set_axis_triple(world, value);
     }
+
+    /// Returns the [`TripleAxisProcessor`]s applied to the value of this input, in the order they are applied.
+    ///
+    /// This lets the processing pipeline be read back from a boxed [`TripleAxislike`],
+    /// for example to show its sensitivity and dead zone in a settings menu.
+    /// Inputs without a processing pipeline return an empty slice.
+    fn processors(&self) -> &[TripleAxisProcessor] {
+        &[]
+    }
 }
 
 /// A wrapper type to get around the lack of [trait upcasting coercion](https://github.com/rust-lang/rust/issues/65991).
 ///
 /// To return a generic [`UserInput`] trait object from a function, you can use this wrapper type.
 
-#[derive(Reflect, Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+#[derive(Reflect, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum UserInputWrapper {
     /// Wraps a [`Buttonlike`] input.
     Button(Box<dyn Buttonlike>),
@@ -346,4 +460,37 @@ impl UserInput for UserInputWrapper {
             UserInputWrapper::TripleAxis(input) => input.decompose(),
         }
     }
+
+    fn display_name(&self) -> String {
+        match self {
+            UserInputWrapper::Button(input) => input.display_name(),
+            UserInputWrapper::Axis(input) => input.display_name(),
+            UserInputWrapper::DualAxis(input) => input.display_name(),
+            UserInputWrapper::TripleAxis(input) => input.display_name(),
+        }
+    }
+}
+
+impl<T: Buttonlike> From<T> for Box<dyn Buttonlike> {
+    fn from(input: T) -> Self {
+        Box::new(input)
+    }
+}
+
+impl<T: Axislike> From<T> for Box<dyn Axislike> {
+    fn from(input: T) -> Self {
+        Box::new(input)
+    }
+}
+
+impl<T: DualAxislike> From<T> for Box<dyn DualAxislike> {
+    fn from(input: T) -> Self {
+        Box::new(input)
+    }
+}
+
+impl<T: TripleAxislike> From<T> for Box<dyn TripleAxislike> {
+    fn from(input: T) -> Self {
+        Box::new(input)
+    }
 }
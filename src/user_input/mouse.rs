@@ -6,15 +6,16 @@ use crate::buttonlike::ButtonValue;
 use crate::clashing_inputs::BasicInputs;
 use crate::input_processing::*;
 use crate::user_input::{InputControlKind, UserInput};
-use bevy::ecs::system::lifetimeless::SRes;
+use bevy::ecs::system::lifetimeless::{Read, SQuery, SRes};
 use bevy::ecs::system::StaticSystemParam;
 use bevy::input::mouse::{
     AccumulatedMouseMotion, AccumulatedMouseScroll, MouseButton, MouseButtonInput, MouseMotion,
-    MouseWheel,
+    MouseScrollUnit, MouseWheel,
 };
 use bevy::input::{ButtonInput, ButtonState};
 use bevy::math::FloatOrd;
-use bevy::prelude::{Entity, Events, Reflect, ResMut, Vec2, World};
+use bevy::prelude::{Entity, Events, Reflect, ResMut, Vec2, With, World};
+use bevy::window::{PrimaryWindow, Window};
 use leafwing_input_manager_macros::serde_typetag;
 use serde::{Deserialize, Serialize};
 use std::hash::{Hash, Hasher};
@@ -36,6 +37,17 @@ impl UserInput for MouseButton {
     fn decompose(&self) -> BasicInputs {
         BasicInputs::Simple(Box::new(*self))
     }
+
+    /// Returns the name of the button, such as `"Left Mouse Button"` or `"Mouse Back"`.
+    fn display_name(&self) -> String {
+        match self {
+            MouseButton::Left | MouseButton::Right | MouseButton::Middle => {
+                format!("{self:?} Mouse Button")
+            }
+            MouseButton::Back | MouseButton::Forward => format!("Mouse {self:?}"),
+            MouseButton::Other(button) => format!("Mouse Button {button}"),
+        }
+    }
 }
 
 impl UpdatableInput for MouseButton {
@@ -125,6 +137,13 @@ impl Buttonlike for MouseButton {
 /// MouseMoveAxis::Y.set_value(app.world_mut(), 5.0);
 /// app.update();
 /// assert!(app.read_pressed(input));
+///
+/// // Ignore small jitters by requiring a minimum movement
+/// let input = MouseMoveDirection::up().with_threshold(10.0);
+///
+/// MouseMoveAxis::Y.set_value(app.world_mut(), 5.0);
+/// app.update();
+/// assert!(!app.read_pressed(input));
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Reflect, Serialize, Deserialize)]
 #[must_use]
@@ -138,7 +157,36 @@ pub struct MouseMoveDirection {
 }
 
 impl MouseMoveDirection {
-    /// Sets the `threshold` value.
+    /// Creates a [`MouseMoveDirection`] for movement in the upward direction,
+    /// pressed by any movement until a threshold is set using [`MouseMoveDirection::with_threshold`].
+    #[inline]
+    pub const fn up() -> Self {
+        Self::UP
+    }
+
+    /// Creates a [`MouseMoveDirection`] for movement in the downward direction,
+    /// pressed by any movement until a threshold is set using [`MouseMoveDirection::with_threshold`].
+    #[inline]
+    pub const fn down() -> Self {
+        Self::DOWN
+    }
+
+    /// Creates a [`MouseMoveDirection`] for movement in the leftward direction,
+    /// pressed by any movement until a threshold is set using [`MouseMoveDirection::with_threshold`].
+    #[inline]
+    pub const fn left() -> Self {
+        Self::LEFT
+    }
+
+    /// Creates a [`MouseMoveDirection`] for movement in the rightward direction,
+    /// pressed by any movement until a threshold is set using [`MouseMoveDirection::with_threshold`].
+    #[inline]
+    pub const fn right() -> Self {
+        Self::RIGHT
+    }
+
+    /// Sets the `threshold` value, which the movement accumulated during the frame
+    /// in this direction must exceed for the input to be pressed.
     ///
     /// # Requirements
     ///
@@ -147,13 +195,30 @@ impl MouseMoveDirection {
     /// # Panics
     ///
     /// Panics if the requirement isn't met.
+    #[track_caller]
     #[inline]
-    pub fn threshold(mut self, threshold: f32) -> Self {
-        assert!(threshold >= 0.0);
+    pub fn with_threshold(mut self, threshold: f32) -> Self {
+        assert!(
+            threshold >= 0.0,
+            "the threshold of a MouseMoveDirection must be non-negative, got {threshold}"
+        );
         self.threshold = threshold;
         self
     }
 
+    /// Sets the `threshold` value.
+    ///
+    /// This is an alias of [`MouseMoveDirection::with_threshold`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `threshold` is negative.
+    #[track_caller]
+    #[inline]
+    pub fn threshold(self, threshold: f32) -> Self {
+        self.with_threshold(threshold)
+    }
+
     /// Movement in the upward direction.
     pub const UP: Self = Self {
         direction: DualAxisDirection::Up,
@@ -191,6 +256,11 @@ impl UserInput for MouseMoveDirection {
     fn decompose(&self) -> BasicInputs {
         BasicInputs::Simple(Box::new((*self).threshold(0.0)))
     }
+
+    /// Returns the name of the direction, such as `"Mouse Move Up"`.
+    fn display_name(&self) -> String {
+        format!("Mouse Move {:?}", self.direction)
+    }
 }
 
 #[serde_typetag]
@@ -263,6 +333,10 @@ pub struct MouseMoveAxis {
     /// The specified axis that this input tracks.
     pub axis: DualAxisType,
 
+    /// How mouse motion is scaled to be independent of the window size, before processing.
+    #[serde(default)]
+    pub normalization: MouseMotionNormalization,
+
     /// A processing pipeline that handles input values.
     pub processors: Vec<AxisProcessor>,
 }
@@ -271,14 +345,23 @@ impl MouseMoveAxis {
     /// Movement on the X-axis. No processing is applied to raw data from the mouse.
     pub const X: Self = Self {
         axis: DualAxisType::X,
+        normalization: MouseMotionNormalization::Raw,
         processors: Vec::new(),
     };
 
     /// Movement on the Y-axis. No processing is applied to raw data from the mouse.
     pub const Y: Self = Self {
         axis: DualAxisType::Y,
+        normalization: MouseMotionNormalization::Raw,
         processors: Vec::new(),
     };
+
+    /// Sets how mouse motion is scaled to be independent of the window size, before processing.
+    #[inline]
+    pub fn with_normalization(mut self, normalization: MouseMotionNormalization) -> Self {
+        self.normalization = normalization;
+        self
+    }
 }
 
 impl UserInput for MouseMoveAxis {
@@ -302,6 +385,11 @@ impl UserInput for MouseMoveAxis {
             }),
         ])
     }
+
+    /// Returns the name of the axis, such as `"Mouse Move X"`.
+    fn display_name(&self) -> String {
+        format!("Mouse Move {:?}", self.axis)
+    }
 }
 
 #[serde_typetag]
@@ -311,7 +399,10 @@ impl Axislike for MouseMoveAxis {
     #[must_use]
     #[inline]
     fn value(&self, input_store: &CentralInputStore, _gamepad: Entity) -> f32 {
-        let movement = input_store.pair(&MouseMove::default());
+        let movement = self.normalization.normalize(
+            input_store.pair(&MouseMove::default()),
+            input_store.primary_window_size(),
+        );
         let value = self.axis.get_value(movement);
         self.processors
             .iter()
@@ -328,6 +419,12 @@ impl Axislike for MouseMoveAxis {
         };
         world.resource_mut::<Events<MouseMotion>>().send(event);
     }
+
+    /// Returns the processors applied to the value of this input, in the order they are applied.
+    #[inline]
+    fn processors(&self) -> &[AxisProcessor] {
+        &self.processors
+    }
 }
 
 impl WithAxisProcessingPipelineExt for MouseMoveAxis {
@@ -384,18 +481,41 @@ impl WithAxisProcessingPipelineExt for MouseMoveAxis {
 #[derive(Debug, Default, Clone, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
 #[must_use]
 pub struct MouseMove {
+    /// How mouse motion is scaled to be independent of the window size, before processing.
+    #[serde(default)]
+    pub normalization: MouseMotionNormalization,
+
     /// A processing pipeline that handles input values.
     pub processors: Vec<DualAxisProcessor>,
 }
 
+impl MouseMove {
+    /// Sets how mouse motion is scaled to be independent of the window size, before processing.
+    #[inline]
+    pub fn with_normalization(mut self, normalization: MouseMotionNormalization) -> Self {
+        self.normalization = normalization;
+        self
+    }
+}
+
 impl UpdatableInput for MouseMove {
-    type SourceData = SRes<AccumulatedMouseMotion>;
+    type SourceData = (
+        SRes<AccumulatedMouseMotion>,
+        SQuery<Read<Window>, With<PrimaryWindow>>,
+    );
 
     fn compute(
         mut central_input_store: ResMut<CentralInputStore>,
         source_data: StaticSystemParam<Self::SourceData>,
     ) {
-        central_input_store.update_dualaxislike(Self::default(), source_data.delta);
+        let (mouse_motion, primary_window) = source_data.into_inner();
+        central_input_store.update_dualaxislike(Self::default(), mouse_motion.delta);
+
+        let window_size = primary_window
+            .get_single()
+            .ok()
+            .map(|window| window.physical_size().as_vec2());
+        central_input_store.set_primary_window_size(window_size);
     }
 }
 
@@ -416,6 +536,11 @@ impl UserInput for MouseMove {
             Box::new(MouseMoveDirection::RIGHT),
         ])
     }
+
+    /// Returns `"Mouse Move"`.
+    fn display_name(&self) -> String {
+        "Mouse Move".to_string()
+    }
 }
 
 #[serde_typetag]
@@ -424,7 +549,10 @@ impl DualAxislike for MouseMove {
     #[must_use]
     #[inline]
     fn axis_pair(&self, input_store: &CentralInputStore, _gamepad: Entity) -> Vec2 {
-        let movement = input_store.pair(&MouseMove::default());
+        let movement = self.normalization.normalize(
+            input_store.pair(&MouseMove::default()),
+            input_store.primary_window_size(),
+        );
         self.processors
             .iter()
             .fold(movement, |value, processor| processor.process(value))
@@ -436,6 +564,12 @@ impl DualAxislike for MouseMove {
             .resource_mut::<Events<MouseMotion>>()
             .send(MouseMotion { delta: value });
     }
+
+    /// Returns the processors applied to the value of this input, in the order they are applied.
+    #[inline]
+    fn processors(&self) -> &[DualAxisProcessor] {
+        &self.processors
+    }
 }
 
 impl WithDualAxisProcessingPipelineExt for MouseMove {
@@ -551,6 +685,11 @@ impl UserInput for MouseScrollDirection {
     fn decompose(&self) -> BasicInputs {
         BasicInputs::Simple(Box::new((*self).threshold(0.0)))
     }
+
+    /// Returns the name of the direction, such as `"Mouse Scroll Up"`.
+    fn display_name(&self) -> String {
+        format!("Mouse Scroll {:?}", self.direction)
+    }
 }
 
 #[serde_typetag]
@@ -630,6 +769,10 @@ pub struct MouseScrollAxis {
     /// The axis that this input tracks.
     pub axis: DualAxisType,
 
+    /// How scrolling in different [`MouseScrollUnit`]s is converted, before processing.
+    #[serde(default)]
+    pub normalization: ScrollUnitNormalization,
+
     /// A processing pipeline that handles input values.
     pub processors: Vec<AxisProcessor>,
 }
@@ -638,14 +781,23 @@ impl MouseScrollAxis {
     /// Horizontal scrolling of the mouse wheel. No processing is applied to raw data from the mouse.
     pub const X: Self = Self {
         axis: DualAxisType::X,
+        normalization: ScrollUnitNormalization::Raw,
         processors: Vec::new(),
     };
 
     /// Vertical scrolling of the mouse wheel. No processing is applied to raw data from the mouse.
     pub const Y: Self = Self {
         axis: DualAxisType::Y,
+        normalization: ScrollUnitNormalization::Raw,
         processors: Vec::new(),
     };
+
+    /// Sets how scrolling in different [`MouseScrollUnit`]s is converted, before processing.
+    #[inline]
+    pub fn with_normalization(mut self, normalization: ScrollUnitNormalization) -> Self {
+        self.normalization = normalization;
+        self
+    }
 }
 
 impl UserInput for MouseScrollAxis {
@@ -669,6 +821,11 @@ impl UserInput for MouseScrollAxis {
             }),
         ])
     }
+
+    /// Returns the name of the axis, such as `"Mouse Scroll Y"`.
+    fn display_name(&self) -> String {
+        format!("Mouse Scroll {:?}", self.axis)
+    }
 }
 
 #[serde_typetag]
@@ -678,7 +835,10 @@ impl Axislike for MouseScrollAxis {
     #[must_use]
     #[inline]
     fn value(&self, input_store: &CentralInputStore, _gamepad: Entity) -> f32 {
-        let movement = input_store.pair(&MouseScroll::default());
+        let movement = self.normalization.normalize(
+            input_store.pair(&MouseScroll::default()),
+            input_store.scroll_unit(),
+        );
         let value = self.axis.get_value(movement);
         self.processors
             .iter()
@@ -707,6 +867,12 @@ impl Axislike for MouseScrollAxis {
         };
         world.resource_mut::<Events<MouseWheel>>().send(event);
     }
+
+    /// Returns the processors applied to the value of this input, in the order they are applied.
+    #[inline]
+    fn processors(&self) -> &[AxisProcessor] {
+        &self.processors
+    }
 }
 
 impl WithAxisProcessingPipelineExt for MouseScrollAxis {
@@ -763,10 +929,23 @@ impl WithAxisProcessingPipelineExt for MouseScrollAxis {
 #[derive(Debug, Default, Clone, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
 #[must_use]
 pub struct MouseScroll {
+    /// How scrolling in different [`MouseScrollUnit`]s is converted, before processing.
+    #[serde(default)]
+    pub normalization: ScrollUnitNormalization,
+
     /// A processing pipeline that handles input values.
     pub processors: Vec<DualAxisProcessor>,
 }
 
+impl MouseScroll {
+    /// Sets how scrolling in different [`MouseScrollUnit`]s is converted, before processing.
+    #[inline]
+    pub fn with_normalization(mut self, normalization: ScrollUnitNormalization) -> Self {
+        self.normalization = normalization;
+        self
+    }
+}
+
 impl UpdatableInput for MouseScroll {
     type SourceData = SRes<AccumulatedMouseScroll>;
 
@@ -775,6 +954,7 @@ impl UpdatableInput for MouseScroll {
         source_data: StaticSystemParam<Self::SourceData>,
     ) {
         central_input_store.update_dualaxislike(Self::default(), source_data.delta);
+        central_input_store.set_scroll_unit(Some(source_data.unit));
     }
 }
 
@@ -795,6 +975,11 @@ impl UserInput for MouseScroll {
             Box::new(MouseScrollDirection::RIGHT),
         ])
     }
+
+    /// Returns `"Mouse Scroll"`.
+    fn display_name(&self) -> String {
+        "Mouse Scroll".to_string()
+    }
 }
 
 #[serde_typetag]
@@ -803,7 +988,10 @@ impl DualAxislike for MouseScroll {
     #[must_use]
     #[inline]
     fn axis_pair(&self, input_store: &CentralInputStore, _gamepad: Entity) -> Vec2 {
-        let movement = input_store.pair(&MouseScroll::default());
+        let movement = self.normalization.normalize(
+            input_store.pair(&MouseScroll::default()),
+            input_store.scroll_unit(),
+        );
         self.processors
             .iter()
             .fold(movement, |value, processor| processor.process(value))
@@ -821,6 +1009,12 @@ impl DualAxislike for MouseScroll {
             window: Entity::PLACEHOLDER,
         });
     }
+
+    /// Returns the processors applied to the value of this input, in the order they are applied.
+    #[inline]
+    fn processors(&self) -> &[DualAxisProcessor] {
+        &self.processors
+    }
 }
 
 impl WithDualAxisProcessingPipelineExt for MouseScroll {
@@ -846,6 +1040,204 @@ impl WithDualAxisProcessingPipelineExt for MouseScroll {
     }
 }
 
+/// How [`MouseScroll`] and [`MouseScrollAxis`] convert scrolling between [`MouseScrollUnit`]s.
+///
+/// Mouse wheels usually scroll by lines, while trackpads scroll by pixels,
+/// so the same gesture may produce values that differ by an order of magnitude depending on the device.
+/// Converting both units into one keeps the behavior of scroll-driven actions, like zooming, consistent.
+///
+/// The unit of the [`MouseWheel`] events read during each frame is recorded by [`MouseScroll`].
+/// If several units are mixed in a single frame, the unit of the last event is used.
+///
+/// ```rust
+/// use bevy::input::mouse::MouseScrollUnit;
+/// use bevy::prelude::Vec2;
+/// use leafwing_input_manager::prelude::*;
+///
+/// let normalization = ScrollUnitNormalization::lines(20.0);
+///
+/// // Pixels are converted into lines
+/// let pixels = Vec2::new(0.0, 40.0);
+/// assert_eq!(normalization.normalize(pixels, Some(MouseScrollUnit::Pixel)), Vec2::new(0.0, 2.0));
+///
+/// // Lines are left as is
+/// let lines = Vec2::new(0.0, 2.0);
+/// assert_eq!(normalization.normalize(lines, Some(MouseScrollUnit::Line)), lines);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Reflect, Serialize, Deserialize)]
+#[must_use]
+pub enum ScrollUnitNormalization {
+    /// Scrolling is reported as is, regardless of its unit.
+    ///
+    /// This is the default.
+    #[default]
+    Raw,
+
+    /// Scrolling in pixels is converted into lines, dividing it by the number of pixels per line.
+    Lines(f32),
+
+    /// Scrolling in lines is converted into pixels, multiplying it by the number of pixels per line.
+    Pixels(f32),
+}
+
+impl ScrollUnitNormalization {
+    /// Converts scrolling into lines, assuming that each line is `pixels_per_line` pixels tall.
+    ///
+    /// # Requirements
+    ///
+    /// - `pixels_per_line` > `0.0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the requirement isn't met.
+    #[inline]
+    pub fn lines(pixels_per_line: f32) -> Self {
+        assert!(pixels_per_line > 0.0);
+        Self::Lines(pixels_per_line)
+    }
+
+    /// Converts scrolling into pixels, assuming that each line is `pixels_per_line` pixels tall.
+    ///
+    /// # Requirements
+    ///
+    /// - `pixels_per_line` > `0.0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the requirement isn't met.
+    #[inline]
+    pub fn pixels(pixels_per_line: f32) -> Self {
+        assert!(pixels_per_line > 0.0);
+        Self::Pixels(pixels_per_line)
+    }
+
+    /// Converts the scrolling `delta`, measured in the given `unit`.
+    ///
+    /// Scrolling of an unknown unit, or with a `pixels_per_line` that isn't positive, is left as is.
+    #[must_use]
+    #[inline]
+    pub fn normalize(&self, delta: Vec2, unit: Option<MouseScrollUnit>) -> Vec2 {
+        match (self, unit) {
+            (Self::Lines(pixels_per_line), Some(MouseScrollUnit::Pixel))
+                if *pixels_per_line > 0.0 =>
+            {
+                delta / *pixels_per_line
+            }
+            (Self::Pixels(pixels_per_line), Some(MouseScrollUnit::Line))
+                if *pixels_per_line > 0.0 =>
+            {
+                delta * *pixels_per_line
+            }
+            _ => delta,
+        }
+    }
+}
+
+impl Eq for ScrollUnitNormalization {}
+
+impl Hash for ScrollUnitNormalization {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::Raw => {}
+            Self::Lines(pixels_per_line) | Self::Pixels(pixels_per_line) => {
+                FloatOrd(*pixels_per_line).hash(state);
+            }
+        }
+    }
+}
+
+/// How [`MouseMove`] and [`MouseMoveAxis`] scale mouse motion to be independent of the window size.
+///
+/// Raw mouse motion is measured in pixels, so the same physical motion moves a camera
+/// by a different fraction of the screen depending on the resolution.
+/// Dividing the motion by the size of the window measures it in fractions of the window instead,
+/// where moving across the entire width of the window is `1.0`.
+///
+/// The physical size of the [`PrimaryWindow`] is recorded by [`MouseMove`] each frame,
+/// and can be read using [`CentralInputStore::primary_window_size`].
+/// As it is read from the [`Window`] component, tests and headless apps can get deterministic results
+/// by spawning a [`Window`] with a fixed resolution and the [`PrimaryWindow`] marker,
+/// or by using [`MouseMotionNormalization::size`] to ignore the window entirely.
+///
+/// ```rust
+/// use bevy::prelude::Vec2;
+/// use leafwing_input_manager::prelude::*;
+///
+/// let normalization = MouseMotionNormalization::PrimaryWindow;
+///
+/// // Moving across a tenth of the window gives the same value at any resolution
+/// let hd = Vec2::new(1920.0, 1080.0);
+/// let uhd = Vec2::new(3840.0, 2160.0);
+/// assert_eq!(normalization.normalize(Vec2::new(192.0, 0.0), Some(hd)), Vec2::new(0.1, 0.0));
+/// assert_eq!(normalization.normalize(Vec2::new(384.0, 0.0), Some(uhd)), Vec2::new(0.1, 0.0));
+///
+/// // Without a window, motion is left as is
+/// assert_eq!(normalization.normalize(Vec2::new(192.0, 0.0), None), Vec2::new(192.0, 0.0));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Reflect, Serialize, Deserialize)]
+#[must_use]
+pub enum MouseMotionNormalization {
+    /// Mouse motion is reported in pixels, as is.
+    ///
+    /// This is the default.
+    #[default]
+    Raw,
+
+    /// Mouse motion is divided by the physical size of the [`PrimaryWindow`].
+    ///
+    /// Motion is left as is while there is no primary window.
+    PrimaryWindow,
+
+    /// Mouse motion is divided by a fixed size in pixels, regardless of the window,
+    /// such as the reference resolution that the sensitivity was tuned for.
+    Size(Vec2),
+}
+
+impl MouseMotionNormalization {
+    /// Divides mouse motion by the given `size` in pixels, regardless of the window.
+    ///
+    /// # Requirements
+    ///
+    /// - `size.x` > `0.0` and `size.y` > `0.0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the requirements aren't met.
+    #[inline]
+    pub fn size(size: Vec2) -> Self {
+        assert!(size.x > 0.0 && size.y > 0.0);
+        Self::Size(size)
+    }
+
+    /// Scales the mouse motion `delta`, given the physical size of the primary window if there is one.
+    ///
+    /// Motion is left as is if the size to divide by isn't positive.
+    #[must_use]
+    #[inline]
+    pub fn normalize(&self, delta: Vec2, window_size: Option<Vec2>) -> Vec2 {
+        match (self, window_size) {
+            (Self::PrimaryWindow, Some(window_size)) if window_size.cmpgt(Vec2::ZERO).all() => {
+                delta / window_size
+            }
+            (Self::Size(size), _) if size.cmpgt(Vec2::ZERO).all() => delta / *size,
+            _ => delta,
+        }
+    }
+}
+
+impl Eq for MouseMotionNormalization {}
+
+impl Hash for MouseMotionNormalization {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        if let Self::Size(size) = self {
+            FloatOrd(size.x).hash(state);
+            FloatOrd(size.y).hash(state);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -989,6 +1381,135 @@ mod tests {
         assert_eq!(mouse_move.axis_pair(inputs, gamepad), data);
     }
 
+    #[test]
+    fn test_mouse_move_normalization() {
+        use bevy::window::WindowResolution;
+
+        let gamepad = Entity::PLACEHOLDER;
+        let normalized =
+            MouseMove::default().with_normalization(MouseMotionNormalization::PrimaryWindow);
+        let normalized_x =
+            MouseMoveAxis::X.with_normalization(MouseMotionNormalization::PrimaryWindow);
+
+        // Moves the mouse across a fifth of the width and a tenth of the height of the window
+        let move_in_window = |width: f32, height: f32| {
+            let mut app = test_app();
+            app.world_mut().spawn((
+                Window {
+                    resolution: WindowResolution::new(width, height),
+                    ..Default::default()
+                },
+                PrimaryWindow,
+            ));
+            MouseMove::default()
+                .set_axis_pair(app.world_mut(), Vec2::new(width / 5.0, height / 10.0));
+            app.update();
+            app
+        };
+
+        let expected = Vec2::new(0.2, 0.1);
+        for (width, height) in [(1920.0, 1080.0), (3840.0, 2160.0)] {
+            let app = move_in_window(width, height);
+            let inputs = app.world().resource::<CentralInputStore>();
+            assert_eq!(normalized.axis_pair(inputs, gamepad), expected);
+            assert_eq!(normalized_x.value(inputs, gamepad), expected.x);
+
+            // Raw motion still depends on the window size
+            assert_eq!(
+                MouseMove::default().axis_pair(inputs, gamepad),
+                Vec2::new(width / 5.0, height / 10.0)
+            );
+
+            // Fixed sizes ignore the window
+            let fixed = MouseMove::default()
+                .with_normalization(MouseMotionNormalization::size(Vec2::new(width, height)));
+            assert_eq!(fixed.axis_pair(inputs, gamepad), expected);
+        }
+
+        // Without a primary window, motion is left as is
+        let mut app = test_app();
+        MouseMove::default().set_axis_pair(app.world_mut(), Vec2::new(5.0, 3.0));
+        app.update();
+        let inputs = app.world().resource::<CentralInputStore>();
+        assert_eq!(normalized.axis_pair(inputs, gamepad), Vec2::new(5.0, 3.0));
+
+        // As are invalid sizes, such as deserialized ones
+        let delta = Vec2::new(5.0, 3.0);
+        for size in [Vec2::ZERO, Vec2::new(1920.0, -1080.0), Vec2::NAN] {
+            assert_eq!(
+                MouseMotionNormalization::Size(size).normalize(delta, None),
+                delta
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_mouse_move_normalization_invalid_size() {
+        let _ = MouseMotionNormalization::size(Vec2::new(0.0, 1080.0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_mouse_move_negative_threshold() {
+        let _ = MouseMoveDirection::up().with_threshold(-1.0);
+    }
+
+    #[test]
+    fn test_mouse_move_threshold() {
+        let mouse_move_up = MouseMoveDirection::up().with_threshold(5.0);
+        assert_eq!(mouse_move_up, MouseMoveDirection::UP.threshold(5.0));
+        assert_eq!(MouseMoveDirection::up(), MouseMoveDirection::UP);
+        assert_eq!(MouseMoveDirection::down(), MouseMoveDirection::DOWN);
+        assert_eq!(MouseMoveDirection::left(), MouseMoveDirection::LEFT);
+        assert_eq!(MouseMoveDirection::right(), MouseMoveDirection::RIGHT);
+        assert_eq!(MouseMoveDirection::UP.threshold, 0.0);
+        let gamepad = Entity::PLACEHOLDER;
+
+        // Small movements are ignored
+        let mut app = test_app();
+        MouseMoveAxis::Y.set_value(app.world_mut(), 2.0);
+        app.update();
+        let inputs = app.world().resource::<CentralInputStore>();
+
+        assert!(!mouse_move_up.pressed(inputs, gamepad));
+        assert!(MouseMoveDirection::UP.pressed(inputs, gamepad));
+
+        // Movements at the threshold are ignored
+        let mut app = test_app();
+        MouseMoveAxis::Y.set_value(app.world_mut(), 5.0);
+        app.update();
+        let inputs = app.world().resource::<CentralInputStore>();
+
+        assert!(!mouse_move_up.pressed(inputs, gamepad));
+
+        // Large movements press the input
+        let mut app = test_app();
+        MouseMoveAxis::Y.set_value(app.world_mut(), 8.0);
+        app.update();
+        let inputs = app.world().resource::<CentralInputStore>();
+
+        assert!(mouse_move_up.pressed(inputs, gamepad));
+
+        // Small movements within a single frame accumulate
+        let mut app = test_app();
+        for _ in 0..3 {
+            MouseMoveAxis::Y.set_value(app.world_mut(), 2.0);
+        }
+        app.update();
+        let inputs = app.world().resource::<CentralInputStore>();
+
+        assert!(mouse_move_up.pressed(inputs, gamepad));
+
+        // Large movements in the opposite direction are ignored
+        let mut app = test_app();
+        MouseMoveAxis::Y.set_value(app.world_mut(), -8.0);
+        app.update();
+        let inputs = app.world().resource::<CentralInputStore>();
+
+        assert!(!mouse_move_up.pressed(inputs, gamepad));
+    }
+
     #[test]
     fn test_mouse_scroll() {
         let mouse_scroll_up = MouseScrollDirection::UP;
@@ -1054,6 +1575,62 @@ mod tests {
         assert_eq!(mouse_scroll.axis_pair(inputs, gamepad), data);
     }
 
+    #[test]
+    fn mouse_scroll_normalization() {
+        let gamepad = Entity::PLACEHOLDER;
+        let raw = MouseScroll::default();
+        let lines = MouseScroll::default().with_normalization(ScrollUnitNormalization::lines(20.0));
+        let pixels =
+            MouseScroll::default().with_normalization(ScrollUnitNormalization::pixels(20.0));
+        let lines_y = MouseScrollAxis::Y.with_normalization(ScrollUnitNormalization::lines(20.0));
+
+        let scroll = |unit: MouseScrollUnit, y: f32| {
+            let mut app = test_app();
+            app.world_mut()
+                .resource_mut::<Events<MouseWheel>>()
+                .send(MouseWheel {
+                    unit,
+                    x: 0.0,
+                    y,
+                    window: Entity::PLACEHOLDER,
+                });
+            app.update();
+            app
+        };
+
+        // A trackpad scrolling by pixels
+        let app = scroll(MouseScrollUnit::Pixel, 60.0);
+        let inputs = app.world().resource::<CentralInputStore>();
+        assert_eq!(raw.axis_pair(inputs, gamepad), Vec2::new(0.0, 60.0));
+        assert_eq!(lines.axis_pair(inputs, gamepad), Vec2::new(0.0, 3.0));
+        assert_eq!(pixels.axis_pair(inputs, gamepad), Vec2::new(0.0, 60.0));
+        assert_eq!(lines_y.value(inputs, gamepad), 3.0);
+
+        // A mouse wheel scrolling by lines
+        let app = scroll(MouseScrollUnit::Line, 3.0);
+        let inputs = app.world().resource::<CentralInputStore>();
+        assert_eq!(raw.axis_pair(inputs, gamepad), Vec2::new(0.0, 3.0));
+        assert_eq!(lines.axis_pair(inputs, gamepad), Vec2::new(0.0, 3.0));
+        assert_eq!(pixels.axis_pair(inputs, gamepad), Vec2::new(0.0, 60.0));
+        assert_eq!(lines_y.value(inputs, gamepad), 3.0);
+
+        // Invalid conversions, such as deserialized ones, leave scrolling as is
+        let delta = Vec2::new(0.0, 60.0);
+        for normalization in [
+            ScrollUnitNormalization::Lines(0.0),
+            ScrollUnitNormalization::Lines(f32::NAN),
+        ] {
+            assert_eq!(
+                normalization.normalize(delta, Some(MouseScrollUnit::Pixel)),
+                delta
+            );
+        }
+        assert_eq!(
+            ScrollUnitNormalization::Pixels(-1.0).normalize(delta, Some(MouseScrollUnit::Line)),
+            delta
+        );
+    }
+
     #[test]
     fn one_frame_accumulate_mouse_movement() {
         let mut app = test_app();
@@ -1113,4 +1690,14 @@ mod tests {
             "No movement was expected. Is the position in the event stream being cleared properly?"
         );
     }
+
+    #[test]
+    fn test_mouse_display_name() {
+        assert_eq!(MouseButton::Left.display_name(), "Left Mouse Button");
+        assert_eq!(MouseButton::Back.display_name(), "Mouse Back");
+        assert_eq!(MouseButton::Other(7).display_name(), "Mouse Button 7");
+        assert_eq!(MouseMoveDirection::UP.display_name(), "Mouse Move Up");
+        assert_eq!(MouseScrollAxis::Y.display_name(), "Mouse Scroll Y");
+        assert_eq!(MouseMove::default().display_name(), "Mouse Move");
+    }
 }
@@ -0,0 +1,1477 @@
+//! This module contains inputs whose state depends on previous frames,
+//! such as [`DebouncedButton`], [`OnReleaseButton`], [`DoubleTap`], [`SequenceInput`], [`Toggle`]
+//! and [`FlickStick`].
+//!
+//! These inputs remember what happened using [`CentralInputStore::with_input_memory`].
+
+use std::f32::consts::{PI, TAU};
+use std::hash::{Hash, Hasher};
+use std::time::Duration;
+
+use bevy::math::FloatOrd;
+use bevy::prelude::{Entity, Reflect, Vec2, World};
+use leafwing_input_manager_macros::serde_typetag;
+use serde::{Deserialize, Serialize};
+
+use crate as leafwing_input_manager;
+use crate::clashing_inputs::BasicInputs;
+use crate::user_input::{Axislike, Buttonlike, DualAxislike, UserInput};
+use crate::InputControlKind;
+
+use super::updating::CentralInputStore;
+
+/// A [`Buttonlike`] that only reports as pressed once the wrapped `input`
+/// has been continuously pressed for at least the given number of `frames`.
+///
+/// This is useful to debounce noisy inputs, such as worn-out switches that flicker between states.
+/// As soon as the wrapped input is released, the count starts over.
+///
+/// Frames are counted by calls to [`CentralInputStore::clear`].
+/// If the input isn't read for a few frames, such as while its action is disabled,
+/// and the wrapped input is pressed both before and after, it is assumed to have been held throughout.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy::input::InputPlugin;
+/// use leafwing_input_manager::plugin::CentralInputStorePlugin;
+/// use leafwing_input_manager::prelude::*;
+/// use leafwing_input_manager::user_input::testing_utils::FetchUserInput;
+///
+/// let mut app = App::new();
+/// app.add_plugins((InputPlugin, CentralInputStorePlugin));
+///
+/// let input = DebouncedButton::new(KeyCode::Space, 2);
+///
+/// // A single frame of input isn't enough
+/// KeyCode::Space.press(app.world_mut());
+/// app.update();
+/// assert!(!app.read_pressed(input.clone()));
+///
+/// // But holding the key for a second frame is
+/// app.update();
+/// assert!(app.read_pressed(input.clone()));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+#[serde(try_from = "UncheckedDebouncedButton")]
+#[must_use]
+pub struct DebouncedButton {
+    /// The wrapped input.
+    pub input: Box<dyn Buttonlike>,
+
+    /// The number of consecutive frames that the `input` must be pressed for.
+    pub(crate) frames: u32,
+}
+
+/// The serialized form of a [`DebouncedButton`], validated before being converted into one.
+#[derive(Deserialize)]
+#[serde(rename = "DebouncedButton")]
+struct UncheckedDebouncedButton {
+    input: Box<dyn Buttonlike>,
+    frames: u32,
+}
+
+impl TryFrom<UncheckedDebouncedButton> for DebouncedButton {
+    type Error = &'static str;
+
+    fn try_from(
+        UncheckedDebouncedButton { input, frames }: UncheckedDebouncedButton,
+    ) -> Result<Self, Self::Error> {
+        if frames == 0 {
+            return Err("a DebouncedButton requires at least one frame");
+        }
+        Ok(Self { input, frames })
+    }
+}
+
+impl DebouncedButton {
+    /// Creates a [`DebouncedButton`] that is pressed once the `input` has been held for the given number of `frames`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `frames` is `0`.
+    #[inline]
+    pub fn new(input: impl Buttonlike, frames: u32) -> Self {
+        assert!(frames > 0, "a DebouncedButton needs at least one frame");
+
+        Self {
+            input: Box::new(input),
+            frames,
+        }
+    }
+
+    /// Returns the number of consecutive frames that the `input` must be pressed for.
+    #[must_use]
+    #[inline]
+    pub fn frames(&self) -> u32 {
+        self.frames
+    }
+}
+
+/// The state of a [`DebouncedButton`], stored in the [`CentralInputStore`].
+#[derive(Debug, Default, Clone)]
+struct DebounceMemory {
+    /// The last frame in which the input was read.
+    last_frame: Option<u64>,
+    /// The number of consecutive frames, up to and including `last_frame`, for which the input was pressed.
+    held_frames: u32,
+}
+
+impl UserInput for DebouncedButton {
+    /// [`DebouncedButton`] acts as a virtual button.
+    #[inline]
+    fn kind(&self) -> InputControlKind {
+        InputControlKind::Button
+    }
+
+    /// Returns the [`BasicInputs`] of the wrapped input.
+    #[inline]
+    fn decompose(&self) -> BasicInputs {
+        self.input.decompose()
+    }
+
+    /// Returns the name of the wrapped input.
+    fn display_name(&self) -> String {
+        self.input.display_name()
+    }
+}
+
+#[serde_typetag]
+impl Buttonlike for DebouncedButton {
+    /// Checks if the wrapped input has been pressed for at least `frames` consecutive frames.
+    #[must_use]
+    fn pressed(&self, input_store: &CentralInputStore, gamepad: Entity) -> bool {
+        let input_pressed = self.input.pressed(input_store, gamepad);
+        let frame = input_store.frame();
+
+        input_store.with_input_memory(self, gamepad, |memory: &mut DebounceMemory| {
+            if memory.last_frame != Some(frame) {
+                memory.held_frames = match (input_pressed, memory.last_frame) {
+                    (false, _) => 0,
+                    // Frames in which the input wasn't read are assumed to have been held throughout
+                    (true, Some(last_frame)) if memory.held_frames > 0 => {
+                        let elapsed =
+                            u32::try_from(frame.saturating_sub(last_frame)).unwrap_or(u32::MAX);
+                        memory.held_frames.saturating_add(elapsed)
+                    }
+                    (true, _) => 1,
+                };
+                memory.last_frame = Some(frame);
+            }
+
+            memory.held_frames >= self.frames
+        })
+    }
+
+    /// Returns the value of the wrapped input if this input is pressed, or `0.0` otherwise.
+    #[must_use]
+    fn value(&self, input_store: &CentralInputStore, gamepad: Entity) -> f32 {
+        if self.pressed(input_store, gamepad) {
+            self.input.value(input_store, gamepad)
+        } else {
+            0.0
+        }
+    }
+
+    fn press_as_gamepad(&self, world: &mut World, gamepad: Option<Entity>) {
+        self.input.press_as_gamepad(world, gamepad);
+    }
+
+    fn release_as_gamepad(&self, world: &mut World, gamepad: Option<Entity>) {
+        self.input.release_as_gamepad(world, gamepad);
+    }
+
+    fn set_value_as_gamepad(&self, world: &mut World, value: f32, gamepad: Option<Entity>) {
+        self.input.set_value_as_gamepad(world, value, gamepad);
+    }
+}
+
+/// A [`Buttonlike`] that reports as pressed for a single frame when the wrapped input is released.
+///
+/// This is useful for actions that trigger on release, such as charged shots or drawing a bow,
+/// without having to check [`ActionState::just_released`](crate::action_state::ActionState::just_released) in gameplay logic.
+///
+/// Frames are counted by calls to [`CentralInputStore::clear`],
+/// and only frames in which this input is read are taken into account.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy::input::InputPlugin;
+/// use leafwing_input_manager::plugin::CentralInputStorePlugin;
+/// use leafwing_input_manager::prelude::*;
+/// use leafwing_input_manager::user_input::testing_utils::FetchUserInput;
+///
+/// let mut app = App::new();
+/// app.add_plugins((InputPlugin, CentralInputStorePlugin));
+///
+/// let input = OnReleaseButton::new(KeyCode::Space);
+///
+/// // Holding the key doesn't press the input
+/// KeyCode::Space.press(app.world_mut());
+/// app.update();
+/// assert!(!app.read_pressed(input.clone()));
+///
+/// // Releasing it does, for a single frame
+/// KeyCode::Space.release(app.world_mut());
+/// app.update();
+/// assert!(app.read_pressed(input.clone()));
+///
+/// app.update();
+/// assert!(!app.read_pressed(input.clone()));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+#[must_use]
+pub struct OnReleaseButton(pub Box<dyn Buttonlike>);
+
+impl OnReleaseButton {
+    /// Creates an [`OnReleaseButton`] that is pressed on the frame the `input` is released.
+    #[inline]
+    pub fn new(input: impl Buttonlike) -> Self {
+        Self(Box::new(input))
+    }
+}
+
+/// The state of an [`OnReleaseButton`], stored in the [`CentralInputStore`].
+#[derive(Debug, Default, Clone)]
+struct OnReleaseMemory {
+    /// The last frame in which the input was read.
+    last_frame: Option<u64>,
+    /// Whether the wrapped input was pressed during `last_frame`.
+    input_pressed: bool,
+    /// Whether the wrapped input was released during `last_frame`.
+    released: bool,
+}
+
+impl UserInput for OnReleaseButton {
+    /// [`OnReleaseButton`] acts as a virtual button.
+    #[inline]
+    fn kind(&self) -> InputControlKind {
+        InputControlKind::Button
+    }
+
+    /// Returns the [`BasicInputs`] of the wrapped input.
+    #[inline]
+    fn decompose(&self) -> BasicInputs {
+        self.0.decompose()
+    }
+
+    /// Returns the name of the wrapped input.
+    fn display_name(&self) -> String {
+        self.0.display_name()
+    }
+}
+
+#[serde_typetag]
+impl Buttonlike for OnReleaseButton {
+    /// Checks if the wrapped input was pressed during the previous frame, but is no longer pressed.
+    #[must_use]
+    fn pressed(&self, input_store: &CentralInputStore, gamepad: Entity) -> bool {
+        let input_pressed = self.0.pressed(input_store, gamepad);
+        let frame = input_store.frame();
+
+        input_store.with_input_memory(self, gamepad, |memory: &mut OnReleaseMemory| {
+            if memory.last_frame != Some(frame) {
+                let consecutive = memory.last_frame == frame.checked_sub(1);
+                memory.released = consecutive && memory.input_pressed && !input_pressed;
+                memory.input_pressed = input_pressed;
+                memory.last_frame = Some(frame);
+            }
+
+            memory.released
+        })
+    }
+
+    fn press_as_gamepad(&self, world: &mut World, gamepad: Option<Entity>) {
+        self.0.press_as_gamepad(world, gamepad);
+    }
+
+    fn release_as_gamepad(&self, world: &mut World, gamepad: Option<Entity>) {
+        self.0.release_as_gamepad(world, gamepad);
+    }
+
+    fn set_value_as_gamepad(&self, world: &mut World, value: f32, gamepad: Option<Entity>) {
+        self.0.set_value_as_gamepad(world, value, gamepad);
+    }
+}
+
+/// A [`Buttonlike`] that reports as pressed for a single frame when the wrapped input
+/// is pressed twice within the given time `window`, such as a double-click or a double-tap.
+///
+/// The window is measured between the frames in which the wrapped input starts being pressed,
+/// using [`CentralInputStore::elapsed`], which follows [`Time<Real>`](bevy::time::Real).
+/// It defaults to [`DoubleTap::DEFAULT_WINDOW`] (300 milliseconds).
+/// Once a double tap is reported, the next press starts a new sequence,
+/// so tapping three times in quick succession only reports a single double tap.
+///
+/// As the input is only pressed for a single frame, the corresponding action is
+/// [`just_pressed`](crate::action_state::ActionState::just_pressed) when the double tap happens,
+/// and released again when the [`ActionState`](crate::action_state::ActionState) is next ticked.
+/// As such, it is best suited for triggering one-off actions, like dodging or sprinting.
+///
+/// Only frames in which this input is read are taken into account.
+///
+/// ```rust
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy::input::InputPlugin;
+/// use leafwing_input_manager::plugin::CentralInputStorePlugin;
+/// use leafwing_input_manager::prelude::*;
+/// use leafwing_input_manager::user_input::testing_utils::FetchUserInput;
+///
+/// let mut app = App::new();
+/// app.add_plugins((InputPlugin, CentralInputStorePlugin));
+///
+/// let input = DoubleTap::new(KeyCode::ShiftLeft);
+/// assert_eq!(input.window, Duration::from_millis(300));
+///
+/// // A single tap isn't enough
+/// KeyCode::ShiftLeft.press(app.world_mut());
+/// app.update();
+/// assert!(!app.read_pressed(input.clone()));
+///
+/// KeyCode::ShiftLeft.release(app.world_mut());
+/// app.update();
+///
+/// // But tapping again straight away is
+/// KeyCode::ShiftLeft.press(app.world_mut());
+/// app.update();
+/// assert!(app.read_pressed(input.clone()));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+#[must_use]
+pub struct DoubleTap {
+    /// The wrapped input.
+    pub input: Box<dyn Buttonlike>,
+
+    /// The maximum time between the two presses of the `input`.
+    pub window: Duration,
+}
+
+impl DoubleTap {
+    /// The default maximum time between the two presses of a [`DoubleTap`].
+    pub const DEFAULT_WINDOW: Duration = Duration::from_millis(300);
+
+    /// Creates a [`DoubleTap`] that is pressed when the `input` is pressed twice
+    /// within the [`DoubleTap::DEFAULT_WINDOW`].
+    #[inline]
+    pub fn new(input: impl Buttonlike) -> Self {
+        Self::with_window(input, Self::DEFAULT_WINDOW)
+    }
+
+    /// Creates a [`DoubleTap`] that is pressed when the `input` is pressed twice within the given `window`.
+    #[inline]
+    pub fn with_window(input: impl Buttonlike, window: Duration) -> Self {
+        Self {
+            input: Box::new(input),
+            window,
+        }
+    }
+}
+
+/// The state of a [`DoubleTap`], stored in the [`CentralInputStore`].
+#[derive(Debug, Default, Clone)]
+struct DoubleTapMemory {
+    /// The last frame in which the input was read.
+    last_frame: Option<u64>,
+    /// Whether the wrapped input was pressed during `last_frame`.
+    input_pressed: bool,
+    /// The time at which the first press of a potential double tap started.
+    first_press: Option<Duration>,
+    /// Whether a double tap was completed during `last_frame`.
+    tapped: bool,
+}
+
+impl UserInput for DoubleTap {
+    /// [`DoubleTap`] acts as a virtual button.
+    #[inline]
+    fn kind(&self) -> InputControlKind {
+        InputControlKind::Button
+    }
+
+    /// Returns the [`BasicInputs`] of the wrapped input.
+    #[inline]
+    fn decompose(&self) -> BasicInputs {
+        self.input.decompose()
+    }
+
+    /// Returns the name of the wrapped input prefixed by `"Double Tap "`, such as `"Double Tap W"`.
+    fn display_name(&self) -> String {
+        format!("Double Tap {}", self.input.display_name())
+    }
+}
+
+#[serde_typetag]
+impl Buttonlike for DoubleTap {
+    /// Checks if the wrapped input was just pressed for the second time within the `window`.
+    #[must_use]
+    fn pressed(&self, input_store: &CentralInputStore, gamepad: Entity) -> bool {
+        let input_pressed = self.input.pressed(input_store, gamepad);
+        let frame = input_store.frame();
+        let now = input_store.elapsed();
+
+        input_store.with_input_memory(self, gamepad, |memory: &mut DoubleTapMemory| {
+            if memory.last_frame != Some(frame) {
+                let consecutive = memory.last_frame == frame.checked_sub(1);
+                let just_pressed = input_pressed && !(consecutive && memory.input_pressed);
+
+                memory.tapped = false;
+                if just_pressed {
+                    match memory.first_press {
+                        Some(first_press) if now.saturating_sub(first_press) <= self.window => {
+                            memory.tapped = true;
+                            memory.first_press = None;
+                        }
+                        _ => memory.first_press = Some(now),
+                    }
+                }
+
+                memory.input_pressed = input_pressed;
+                memory.last_frame = Some(frame);
+            }
+
+            memory.tapped
+        })
+    }
+
+    fn press_as_gamepad(&self, world: &mut World, gamepad: Option<Entity>) {
+        self.input.press_as_gamepad(world, gamepad);
+    }
+
+    fn release_as_gamepad(&self, world: &mut World, gamepad: Option<Entity>) {
+        self.input.release_as_gamepad(world, gamepad);
+    }
+
+    fn set_value_as_gamepad(&self, world: &mut World, value: f32, gamepad: Option<Entity>) {
+        self.input.set_value_as_gamepad(world, value, gamepad);
+    }
+}
+
+/// A [`Buttonlike`] that reports as pressed for a single frame when its `steps` are pressed in order,
+/// such as the motion inputs of fighting games (Down, Down-Forward, Forward + Punch).
+///
+/// Each step must start being pressed within the `step_window` of the previous one,
+/// measured using [`CentralInputStore::elapsed`], which follows [`Time<Real>`](bevy::time::Real).
+/// It defaults to [`SequenceInput::DEFAULT_STEP_WINDOW`] (250 milliseconds).
+/// Pressing a step out of order, or waiting too long between steps, restarts the sequence.
+/// Steps that are held while the next step is pressed, like Down while pressing Down-Forward, don't break the sequence.
+///
+/// As the input is only pressed for a single frame, the corresponding action is
+/// [`just_pressed`](crate::action_state::ActionState::just_pressed) when the sequence completes,
+/// and released again when the [`ActionState`](crate::action_state::ActionState) is next ticked.
+///
+/// When checking for clashes, the sequence is treated like a chord of all of its steps,
+/// so that completing it takes priority over actions bound to its final step
+/// under [`ClashStrategy::PrioritizeLongest`](crate::clashing_inputs::ClashStrategy::PrioritizeLongest).
+///
+/// Only frames in which this input is read are taken into account.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy::input::InputPlugin;
+/// use leafwing_input_manager::plugin::CentralInputStorePlugin;
+/// use leafwing_input_manager::prelude::*;
+/// use leafwing_input_manager::user_input::testing_utils::FetchUserInput;
+///
+/// let mut app = App::new();
+/// app.add_plugins((InputPlugin, CentralInputStorePlugin));
+///
+/// let input = SequenceInput::new([KeyCode::KeyS, KeyCode::KeyD, KeyCode::KeyJ]);
+///
+/// // Pressing the first steps isn't enough
+/// KeyCode::KeyS.press(app.world_mut());
+/// app.update();
+/// assert!(!app.read_pressed(input.clone()));
+///
+/// KeyCode::KeyD.press(app.world_mut());
+/// app.update();
+/// assert!(!app.read_pressed(input.clone()));
+///
+/// // But completing the sequence is
+/// KeyCode::KeyJ.press(app.world_mut());
+/// app.update();
+/// assert!(app.read_pressed(input.clone()));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+#[serde(try_from = "UncheckedSequenceInput")]
+#[must_use]
+pub struct SequenceInput {
+    /// The inputs that must be pressed, in order.
+    pub(crate) steps: Vec<Box<dyn Buttonlike>>,
+
+    /// The maximum time between pressing each step and the next.
+    pub step_window: Duration,
+}
+
+/// The serialized form of a [`SequenceInput`], validated before being converted into one.
+#[derive(Deserialize)]
+#[serde(rename = "SequenceInput")]
+struct UncheckedSequenceInput {
+    steps: Vec<Box<dyn Buttonlike>>,
+    step_window: Duration,
+}
+
+impl TryFrom<UncheckedSequenceInput> for SequenceInput {
+    type Error = &'static str;
+
+    fn try_from(
+        UncheckedSequenceInput { steps, step_window }: UncheckedSequenceInput,
+    ) -> Result<Self, Self::Error> {
+        if steps.is_empty() {
+            return Err("a SequenceInput requires at least one step");
+        }
+        Ok(Self { steps, step_window })
+    }
+}
+
+impl SequenceInput {
+    /// The default maximum time between the steps of a [`SequenceInput`].
+    pub const DEFAULT_STEP_WINDOW: Duration = Duration::from_millis(250);
+
+    /// Creates a [`SequenceInput`] that is pressed when the `steps` are pressed in order,
+    /// each within the [`SequenceInput::DEFAULT_STEP_WINDOW`] of the previous one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no `steps`.
+    #[inline]
+    pub fn new<U: Buttonlike>(steps: impl IntoIterator<Item = U>) -> Self {
+        let steps: Vec<Box<dyn Buttonlike>> = steps
+            .into_iter()
+            .map(|step| Box::new(step) as Box<dyn Buttonlike>)
+            .collect();
+        assert!(!steps.is_empty(), "a sequence needs at least one step");
+
+        Self {
+            steps,
+            step_window: Self::DEFAULT_STEP_WINDOW,
+        }
+    }
+
+    /// Sets the maximum time between pressing each step and the next.
+    #[inline]
+    pub fn with_step_window(mut self, step_window: Duration) -> Self {
+        self.step_window = step_window;
+        self
+    }
+
+    /// Returns the inputs that must be pressed, in order.
+    #[must_use]
+    #[inline]
+    pub fn steps(&self) -> &[Box<dyn Buttonlike>] {
+        &self.steps
+    }
+}
+
+/// The state of a [`SequenceInput`], stored in the [`CentralInputStore`].
+#[derive(Debug, Default, Clone)]
+struct SequenceMemory {
+    /// The last frame in which the input was read.
+    last_frame: Option<u64>,
+    /// Whether each step was pressed during `last_frame`.
+    steps_pressed: Vec<bool>,
+    /// The number of steps completed so far.
+    progress: usize,
+    /// The time at which the last completed step was pressed.
+    last_step: Duration,
+    /// Whether the sequence was completed during `last_frame`.
+    completed: bool,
+}
+
+impl UserInput for SequenceInput {
+    /// [`SequenceInput`] acts as a virtual button.
+    #[inline]
+    fn kind(&self) -> InputControlKind {
+        InputControlKind::Button
+    }
+
+    /// Returns a [`BasicInputs::Chord`] of the [`BasicInputs`] of every step.
+    #[inline]
+    fn decompose(&self) -> BasicInputs {
+        let inputs = self
+            .steps
+            .iter()
+            .flat_map(|step| step.decompose().inputs())
+            .collect();
+        BasicInputs::Chord(inputs)
+    }
+
+    /// Returns the names of the steps joined by `", "`, such as `"Down, Right, J"`.
+    fn display_name(&self) -> String {
+        self.steps
+            .iter()
+            .map(|step| step.display_name())
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+#[serde_typetag]
+impl Buttonlike for SequenceInput {
+    /// Checks if the last step was just pressed, completing the sequence in order and in time.
+    #[must_use]
+    fn pressed(&self, input_store: &CentralInputStore, gamepad: Entity) -> bool {
+        let steps_pressed: Vec<bool> = self
+            .steps
+            .iter()
+            .map(|step| step.pressed(input_store, gamepad))
+            .collect();
+        if steps_pressed.is_empty() {
+            return false;
+        }
+
+        let frame = input_store.frame();
+        let now = input_store.elapsed();
+
+        input_store.with_input_memory(self, gamepad, |memory: &mut SequenceMemory| {
+            if memory.last_frame != Some(frame) {
+                let consecutive = memory.last_frame == frame.checked_sub(1);
+                let just_pressed: Vec<bool> = steps_pressed
+                    .iter()
+                    .enumerate()
+                    .map(|(index, &pressed)| {
+                        pressed && !(consecutive && memory.steps_pressed.get(index) == Some(&true))
+                    })
+                    .collect();
+
+                memory.completed = false;
+                if memory.progress > 0 && now.saturating_sub(memory.last_step) > self.step_window {
+                    memory.progress = 0;
+                }
+
+                if just_pressed[memory.progress] {
+                    memory.progress += 1;
+                    memory.last_step = now;
+                } else if just_pressed.contains(&true) {
+                    // A step was pressed out of order, which may start the sequence over
+                    memory.progress = usize::from(just_pressed[0]);
+                    memory.last_step = now;
+                }
+
+                if memory.progress == self.steps.len() {
+                    memory.completed = true;
+                    memory.progress = 0;
+                }
+
+                memory.steps_pressed = steps_pressed;
+                memory.last_frame = Some(frame);
+            }
+
+            memory.completed
+        })
+    }
+
+    /// Presses every step in order, completing the sequence.
+    fn press_as_gamepad(&self, world: &mut World, gamepad: Option<Entity>) {
+        for step in self.steps.iter() {
+            step.press_as_gamepad(world, gamepad);
+        }
+    }
+
+    /// Releases every step.
+    fn release_as_gamepad(&self, world: &mut World, gamepad: Option<Entity>) {
+        for step in self.steps.iter() {
+            step.release_as_gamepad(world, gamepad);
+        }
+    }
+}
+
+/// A [`Buttonlike`] that latches like caps lock: each press of the wrapped input
+/// toggles it between pressed and released, and it stays in that state until the input is pressed again.
+///
+/// The toggle starts out released, and only flips on the frame that the wrapped input starts being pressed,
+/// so holding the wrapped input doesn't toggle it repeatedly.
+/// Presses that start and end during frames in which this input isn't read are missed.
+///
+/// The latched state is stored using [`CentralInputStore::with_input_memory`].
+/// If the toggle isn't read for [`CentralInputStore::INPUT_MEMORY_LIFETIME`] frames,
+/// such as while its action is disabled, its state is forgotten and it is released again.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy::input::InputPlugin;
+/// use leafwing_input_manager::plugin::CentralInputStorePlugin;
+/// use leafwing_input_manager::prelude::*;
+/// use leafwing_input_manager::user_input::testing_utils::FetchUserInput;
+///
+/// let mut app = App::new();
+/// app.add_plugins((InputPlugin, CentralInputStorePlugin));
+///
+/// let input = Toggle::new(KeyCode::CapsLock);
+///
+/// // Pressing the key latches the toggle
+/// KeyCode::CapsLock.press(app.world_mut());
+/// app.update();
+/// assert!(app.read_pressed(input.clone()));
+///
+/// // It stays pressed once the key is released
+/// KeyCode::CapsLock.release(app.world_mut());
+/// app.update();
+/// assert!(app.read_pressed(input.clone()));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+#[must_use]
+pub struct Toggle(pub Box<dyn Buttonlike>);
+
+impl Toggle {
+    /// Creates a [`Toggle`] that flips between pressed and released each time the `input` is pressed.
+    #[inline]
+    pub fn new(input: impl Buttonlike) -> Self {
+        Self(Box::new(input))
+    }
+}
+
+/// The state of a [`Toggle`], stored in the [`CentralInputStore`].
+#[derive(Debug, Default, Clone)]
+struct ToggleMemory {
+    /// The last frame in which the input was read.
+    last_frame: Option<u64>,
+    /// Whether the wrapped input was pressed during `last_frame`.
+    input_pressed: bool,
+    /// Whether the toggle is latched.
+    latched: bool,
+}
+
+impl UserInput for Toggle {
+    /// [`Toggle`] acts as a virtual button.
+    #[inline]
+    fn kind(&self) -> InputControlKind {
+        InputControlKind::Button
+    }
+
+    /// Returns the [`BasicInputs`] of the wrapped input.
+    #[inline]
+    fn decompose(&self) -> BasicInputs {
+        self.0.decompose()
+    }
+
+    /// Returns the name of the wrapped input.
+    fn display_name(&self) -> String {
+        self.0.display_name()
+    }
+}
+
+#[serde_typetag]
+impl Buttonlike for Toggle {
+    /// Checks if the toggle is latched, flipping it if the wrapped input was just pressed.
+    #[must_use]
+    fn pressed(&self, input_store: &CentralInputStore, gamepad: Entity) -> bool {
+        let input_pressed = self.0.pressed(input_store, gamepad);
+        let frame = input_store.frame();
+
+        input_store.with_input_memory(self, gamepad, |memory: &mut ToggleMemory| {
+            if memory.last_frame != Some(frame) {
+                if input_pressed && !memory.input_pressed {
+                    memory.latched = !memory.latched;
+                }
+                memory.input_pressed = input_pressed;
+                memory.last_frame = Some(frame);
+            }
+
+            memory.latched
+        })
+    }
+
+    fn press_as_gamepad(&self, world: &mut World, gamepad: Option<Entity>) {
+        self.0.press_as_gamepad(world, gamepad);
+    }
+
+    fn release_as_gamepad(&self, world: &mut World, gamepad: Option<Entity>) {
+        self.0.release_as_gamepad(world, gamepad);
+    }
+
+    fn set_value_as_gamepad(&self, world: &mut World, value: f32, gamepad: Option<Entity>) {
+        self.0.set_value_as_gamepad(world, value, gamepad);
+    }
+}
+
+/// The latched state of an axis-to-button adapter with hysteresis,
+/// such as [`AxisInRange::with_release_range`](crate::user_input::AxisInRange::with_release_range),
+/// stored in the [`CentralInputStore`].
+#[derive(Debug, Default, Clone)]
+struct HysteresisMemory {
+    /// The last frame in which the input was read.
+    last_frame: Option<u64>,
+    /// Whether the input is latched.
+    latched: bool,
+}
+
+/// Checks if the `input` is latched with hysteresis, updating its state using [`CentralInputStore::with_input_memory`].
+///
+/// The input is latched once `press` holds, and stays latched for as long as `hold` does,
+/// so `press` should imply `hold`.
+/// If the input isn't read for [`CentralInputStore::INPUT_MEMORY_LIFETIME`] frames,
+/// its state is forgotten and it is released again.
+pub(crate) fn hysteresis_latched<K>(
+    input_store: &CentralInputStore,
+    input: &K,
+    gamepad: Entity,
+    press: bool,
+    hold: bool,
+) -> bool
+where
+    K: Clone + Eq + Hash + Send + 'static,
+{
+    let frame = input_store.frame();
+
+    input_store.with_input_memory(input, gamepad, |memory: &mut HysteresisMemory| {
+        if memory.last_frame != Some(frame) {
+            if press {
+                memory.latched = true;
+            } else if !hold {
+                memory.latched = false;
+            }
+            memory.last_frame = Some(frame);
+        }
+
+        memory.latched
+    })
+}
+
+/// An [`Axislike`] derived from a [`DualAxislike`] stick for flick-stick camera controls,
+/// reporting how far the camera should turn this frame, in radians.
+///
+/// Angles are measured clockwise from the forward direction of the stick (up, or positive Y),
+/// so flicking right reports a positive angle and flicking left reports a negative one.
+///
+/// - When the stick is flicked, snapping from within the `center_radius` to beyond the `edge_radius`
+///   between two consecutive frames, the angle of the stick is reported, so the camera can turn to face that direction.
+/// - While the stick is then held beyond the `edge_radius`, the change in its angle since the previous frame is reported,
+///   so that rotating the stick rotates the camera.
+/// - Otherwise, the value is `0.0`.
+///
+/// Only frames in which this input is read are taken into account.
+/// As flicks are detected between consecutive frames, very high frame rates may require a smaller `center_radius`.
+///
+/// ```rust
+/// use leafwing_input_manager::prelude::*;
+///
+/// // Turn the camera using the right stick
+/// let input = FlickStick::new(GamepadStick::RIGHT);
+/// assert_eq!(input.center_radius(), 0.2);
+/// assert_eq!(input.edge_radius(), 0.9);
+/// ```
+#[derive(Debug, Clone, PartialEq, Reflect, Serialize, Deserialize)]
+#[serde(try_from = "UncheckedFlickStick")]
+#[must_use]
+pub struct FlickStick {
+    /// The stick used to perform flicks.
+    pub stick: Box<dyn DualAxislike>,
+
+    /// The magnitude that the stick must start from for its movement to count as a flick.
+    pub(crate) center_radius: f32,
+
+    /// The magnitude that the stick must reach to flick and turn.
+    pub(crate) edge_radius: f32,
+}
+
+/// The serialized form of a [`FlickStick`], validated before being converted into one.
+#[derive(Deserialize)]
+#[serde(rename = "FlickStick")]
+struct UncheckedFlickStick {
+    stick: Box<dyn DualAxislike>,
+    center_radius: f32,
+    edge_radius: f32,
+}
+
+impl TryFrom<UncheckedFlickStick> for FlickStick {
+    type Error = &'static str;
+
+    fn try_from(
+        UncheckedFlickStick {
+            stick,
+            center_radius,
+            edge_radius,
+        }: UncheckedFlickStick,
+    ) -> Result<Self, Self::Error> {
+        if !(0.0 <= center_radius && center_radius < edge_radius) {
+            return Err("a FlickStick requires 0.0 <= center_radius < edge_radius");
+        }
+        Ok(Self {
+            stick,
+            center_radius,
+            edge_radius,
+        })
+    }
+}
+
+impl FlickStick {
+    /// Creates a [`FlickStick`] from the given `stick`,
+    /// with a `center_radius` of `0.2` and an `edge_radius` of `0.9`.
+    #[inline]
+    pub fn new(stick: impl DualAxislike) -> Self {
+        Self {
+            stick: Box::new(stick),
+            center_radius: 0.2,
+            edge_radius: 0.9,
+        }
+    }
+
+    /// Sets the magnitudes that the stick must start within and move beyond to perform a flick.
+    ///
+    /// # Requirements
+    ///
+    /// - `0.0` <= `center_radius` < `edge_radius`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the requirements aren't met.
+    #[inline]
+    pub fn with_radii(mut self, center_radius: f32, edge_radius: f32) -> Self {
+        assert!(
+            0.0 <= center_radius && center_radius < edge_radius,
+            "a FlickStick requires 0.0 <= center_radius < edge_radius, got {center_radius} and {edge_radius}"
+        );
+        self.center_radius = center_radius;
+        self.edge_radius = edge_radius;
+        self
+    }
+
+    /// Returns the magnitude that the stick must start from for its movement to count as a flick.
+    #[must_use]
+    #[inline]
+    pub fn center_radius(&self) -> f32 {
+        self.center_radius
+    }
+
+    /// Returns the magnitude that the stick must reach to flick and turn.
+    #[must_use]
+    #[inline]
+    pub fn edge_radius(&self) -> f32 {
+        self.edge_radius
+    }
+
+    /// Computes the turn for the frame in which the stick moved from `previous` to `current`.
+    #[must_use]
+    fn turn(&self, previous: Vec2, current: Vec2) -> f32 {
+        if current.length() < self.edge_radius {
+            return 0.0;
+        }
+
+        let previous_length = previous.length();
+        if previous_length <= self.center_radius {
+            // Flicked from the center: turn to face the stick
+            flick_angle(current)
+        } else if previous_length >= self.edge_radius {
+            // Rotating at the edge: follow the stick
+            let delta = flick_angle(current) - flick_angle(previous);
+            (delta + PI).rem_euclid(TAU) - PI
+        } else {
+            // Moved slowly from the center, which isn't a flick
+            0.0
+        }
+    }
+}
+
+/// The angle of the `direction`, measured clockwise from the positive Y-axis.
+#[must_use]
+#[inline]
+fn flick_angle(direction: Vec2) -> f32 {
+    direction.x.atan2(direction.y)
+}
+
+/// The state of a [`FlickStick`], stored in the [`CentralInputStore`].
+#[derive(Debug, Default, Clone)]
+struct FlickMemory {
+    /// The last frame in which the input was read.
+    last_frame: Option<u64>,
+    /// The position of the stick during `last_frame`.
+    previous: Vec2,
+    /// The turn computed during `last_frame`.
+    turn: f32,
+}
+
+impl UserInput for FlickStick {
+    /// [`FlickStick`] acts as a virtual axis.
+    #[inline]
+    fn kind(&self) -> InputControlKind {
+        InputControlKind::Axis
+    }
+
+    /// Returns the [`BasicInputs`] of the wrapped stick.
+    #[inline]
+    fn decompose(&self) -> BasicInputs {
+        self.stick.decompose()
+    }
+}
+
+#[serde_typetag]
+impl Axislike for FlickStick {
+    /// Retrieves how far the camera should turn this frame, in radians.
+    #[must_use]
+    fn value(&self, input_store: &CentralInputStore, gamepad: Entity) -> f32 {
+        let current = self.stick.axis_pair(input_store, gamepad);
+        let frame = input_store.frame();
+
+        input_store.with_input_memory(self, gamepad, |memory: &mut FlickMemory| {
+            if memory.last_frame != Some(frame) {
+                let consecutive = memory.last_frame == frame.checked_sub(1);
+                let previous = if consecutive {
+                    memory.previous
+                } else {
+                    Vec2::ZERO
+                };
+
+                memory.turn = self.turn(previous, current);
+                memory.previous = current;
+                memory.last_frame = Some(frame);
+            }
+
+            memory.turn
+        })
+    }
+
+    /// Simulates a flick toward the given angle by fully deflecting the stick in that direction.
+    fn set_value_as_gamepad(&self, world: &mut World, value: f32, gamepad: Option<Entity>) {
+        let (sin, cos) = value.sin_cos();
+        self.stick
+            .set_axis_pair_as_gamepad(world, Vec2::new(sin, cos), gamepad);
+    }
+}
+
+impl Eq for FlickStick {}
+
+impl Hash for FlickStick {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.stick.hash(state);
+        FloatOrd(self.center_radius).hash(state);
+        FloatOrd(self.edge_radius).hash(state);
+    }
+}
+
+#[cfg(feature = "keyboard")]
+#[cfg(test)]
+mod tests {
+    use super::DebouncedButton;
+    use crate::plugin::CentralInputStorePlugin;
+    use crate::user_input::testing_utils::FetchUserInput;
+    use crate::user_input::Buttonlike;
+    use bevy::input::InputPlugin;
+    use bevy::prelude::*;
+
+    fn test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_plugins(InputPlugin)
+            .add_plugins(CentralInputStorePlugin);
+        app
+    }
+
+    #[test]
+    fn debounced_button_ignores_intermittent_presses() {
+        let mut app = test_app();
+        let input = DebouncedButton::new(KeyCode::Space, 3);
+
+        // Flickering presses never last long enough
+        for _ in 0..3 {
+            KeyCode::Space.press(app.world_mut());
+            app.update();
+            assert!(!app.read_pressed(input.clone()));
+
+            KeyCode::Space.release(app.world_mut());
+            app.update();
+            assert!(!app.read_pressed(input.clone()));
+        }
+
+        // Two frames of input are not enough either
+        KeyCode::Space.press(app.world_mut());
+        app.update();
+        assert!(!app.read_pressed(input.clone()));
+        app.update();
+        assert!(!app.read_pressed(input.clone()));
+
+        // Reading the input several times in the same frame doesn't advance the count
+        assert!(!app.read_pressed(input.clone()));
+        assert_eq!(app.read_button_value(input.clone()), 0.0);
+
+        // A third consecutive frame is
+        app.update();
+        assert!(app.read_pressed(input.clone()));
+        assert_eq!(app.read_button_value(input.clone()), 1.0);
+
+        // Releasing the input resets the count immediately
+        KeyCode::Space.release(app.world_mut());
+        app.update();
+        assert!(!app.read_pressed(input.clone()));
+
+        KeyCode::Space.press(app.world_mut());
+        app.update();
+        assert!(!app.read_pressed(input.clone()));
+    }
+
+    #[test]
+    fn debounced_button_counts_unread_frames() {
+        let mut app = test_app();
+        let input = DebouncedButton::new(KeyCode::Space, 3);
+
+        KeyCode::Space.press(app.world_mut());
+        app.update();
+        assert!(!app.read_pressed(input.clone()));
+
+        // The input isn't read while the wrapped input is held
+        app.update();
+        app.update();
+
+        // But those frames still count towards the debounce
+        assert!(app.read_pressed(input.clone()));
+    }
+
+    #[test]
+    fn debounced_button_survives_rewinds() {
+        use crate::user_input::updating::CentralInputStore;
+        use crate::user_input::RegisterUserInput;
+
+        // Register the deserializers of the snapshot's inputs
+        App::new().register_default_inputs();
+
+        let mut app = test_app();
+        let input = DebouncedButton::new(KeyCode::Space, 2);
+
+        app.update();
+        // Deserialized snapshots don't carry the memory of stateful inputs
+        let serialized =
+            ron::to_string(&app.world().resource::<CentralInputStore>().snapshot()).unwrap();
+        let snapshot = ron::from_str(&serialized).unwrap();
+
+        KeyCode::Space.press(app.world_mut());
+        for _ in 0..3 {
+            app.update();
+            let _ = app.read_pressed(input.clone());
+        }
+
+        // Rewinding to an earlier frame keeps the input held
+        app.world_mut()
+            .resource_mut::<CentralInputStore>()
+            .restore(&snapshot);
+        app.update();
+        assert!(app.read_pressed(input.clone()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn debounced_button_requires_frames() {
+        let _ = DebouncedButton::new(KeyCode::Space, 0);
+    }
+
+    #[test]
+    fn debounced_button_deserialization_is_validated() {
+        use crate::user_input::RegisterUserInput;
+
+        // Register the deserializers of the wrapped input
+        App::new().register_default_inputs();
+
+        let input = DebouncedButton::new(KeyCode::Space, 2);
+        let serialized = ron::to_string(&input).unwrap();
+        assert_eq!(
+            ron::from_str::<DebouncedButton>(&serialized).unwrap(),
+            input
+        );
+
+        let invalid = DebouncedButton { frames: 0, ..input };
+        let serialized = ron::to_string(&invalid).unwrap();
+        assert!(ron::from_str::<DebouncedButton>(&serialized).is_err());
+    }
+
+    #[test]
+    fn on_release_button_pulses_once() {
+        use super::OnReleaseButton;
+
+        let mut app = test_app();
+        let input = OnReleaseButton::new(KeyCode::Space);
+
+        // Released inputs don't press the input
+        app.update();
+        assert!(!app.read_pressed(input.clone()));
+
+        // Neither does holding the wrapped input
+        KeyCode::Space.press(app.world_mut());
+        app.update();
+        assert!(!app.read_pressed(input.clone()));
+        app.update();
+        assert!(!app.read_pressed(input.clone()));
+
+        // Releasing it presses the input for a single frame
+        KeyCode::Space.release(app.world_mut());
+        app.update();
+        assert!(app.read_pressed(input.clone()));
+        assert_eq!(app.read_button_value(input.clone()), 1.0);
+
+        app.update();
+        assert!(!app.read_pressed(input.clone()));
+        app.update();
+        assert!(!app.read_pressed(input.clone()));
+    }
+
+    #[test]
+    fn toggle_flips_on_each_press() {
+        use super::Toggle;
+
+        let mut app = test_app();
+        let input = Toggle::new(KeyCode::Space);
+
+        // The toggle starts out released
+        app.update();
+        assert!(!app.read_pressed(input.clone()));
+
+        // The first press latches it
+        KeyCode::Space.press(app.world_mut());
+        app.update();
+        assert!(app.read_pressed(input.clone()));
+        assert_eq!(app.read_button_value(input.clone()), 1.0);
+
+        // Holding or releasing the input doesn't flip it again
+        app.update();
+        assert!(app.read_pressed(input.clone()));
+        KeyCode::Space.release(app.world_mut());
+        app.update();
+        assert!(app.read_pressed(input.clone()));
+
+        // The second press releases it
+        KeyCode::Space.press(app.world_mut());
+        app.update();
+        assert!(!app.read_pressed(input.clone()));
+        assert_eq!(app.read_button_value(input.clone()), 0.0);
+
+        KeyCode::Space.release(app.world_mut());
+        app.update();
+        assert!(!app.read_pressed(input.clone()));
+    }
+
+    #[test]
+    fn double_tap_requires_presses_within_window() {
+        use super::DoubleTap;
+        use crate::buttonlike::ButtonValue;
+        use crate::user_input::updating::CentralInputStore;
+        use std::time::Duration;
+
+        let input = DoubleTap::with_window(KeyCode::Space, Duration::from_millis(200));
+        let gamepad = Entity::PLACEHOLDER;
+        let mut input_store = CentralInputStore::default();
+        let next_frame = |input_store: &mut CentralInputStore, millis: u64, pressed: bool| {
+            input_store.clear();
+            input_store.set_elapsed(Duration::from_millis(millis));
+            input_store.update_buttonlike(KeyCode::Space, ButtonValue::from_pressed(pressed));
+            input.pressed(input_store, gamepad)
+        };
+
+        // Two presses within the window report a double tap, for a single frame
+        assert!(!next_frame(&mut input_store, 0, true));
+        assert!(!next_frame(&mut input_store, 50, false));
+        assert!(next_frame(&mut input_store, 150, true));
+        assert!(input.pressed(&input_store, gamepad));
+        assert!(!next_frame(&mut input_store, 200, true));
+
+        // A third quick press starts a new sequence instead of tapping again
+        assert!(!next_frame(&mut input_store, 250, false));
+        assert!(!next_frame(&mut input_store, 300, true));
+
+        // Two presses outside the window don't
+        assert!(!next_frame(&mut input_store, 1000, false));
+        assert!(!next_frame(&mut input_store, 1100, true));
+        assert!(!next_frame(&mut input_store, 1200, false));
+        assert!(!next_frame(&mut input_store, 1400, true));
+
+        // But the late press can start a new double tap
+        assert!(!next_frame(&mut input_store, 1450, false));
+        assert!(next_frame(&mut input_store, 1550, true));
+    }
+
+    #[test]
+    fn sequence_input_requires_steps_in_order_and_in_time() {
+        use super::SequenceInput;
+        use crate::buttonlike::ButtonValue;
+        use crate::user_input::updating::CentralInputStore;
+        use std::time::Duration;
+
+        let input = SequenceInput::new([KeyCode::KeyS, KeyCode::KeyD, KeyCode::KeyJ])
+            .with_step_window(Duration::from_millis(100));
+        let gamepad = Entity::PLACEHOLDER;
+        let mut input_store = CentralInputStore::default();
+        let next_frame = |input_store: &mut CentralInputStore, millis: u64, keys: &[KeyCode]| {
+            input_store.clear();
+            input_store.set_elapsed(Duration::from_millis(millis));
+            for key in keys {
+                input_store.update_buttonlike(*key, ButtonValue::from_pressed(true));
+            }
+            input.pressed(input_store, gamepad)
+        };
+
+        // Completing the sequence in time presses the input, for a single frame
+        assert!(!next_frame(&mut input_store, 0, &[KeyCode::KeyS]));
+        assert!(!next_frame(
+            &mut input_store,
+            50,
+            &[KeyCode::KeyS, KeyCode::KeyD]
+        ));
+        assert!(next_frame(
+            &mut input_store,
+            100,
+            &[KeyCode::KeyD, KeyCode::KeyJ]
+        ));
+        assert!(input.pressed(&input_store, gamepad));
+        assert!(!next_frame(&mut input_store, 150, &[KeyCode::KeyJ]));
+        assert!(!next_frame(&mut input_store, 200, &[]));
+
+        // Pressing the steps out of order doesn't
+        assert!(!next_frame(&mut input_store, 1000, &[KeyCode::KeyD]));
+        assert!(!next_frame(&mut input_store, 1050, &[KeyCode::KeyS]));
+        assert!(!next_frame(
+            &mut input_store,
+            1100,
+            &[KeyCode::KeyS, KeyCode::KeyJ]
+        ));
+        assert!(!next_frame(&mut input_store, 1150, &[]));
+
+        // Neither does pressing a wrong step in the middle of the sequence
+        assert!(!next_frame(&mut input_store, 2000, &[KeyCode::KeyS]));
+        assert!(!next_frame(&mut input_store, 2050, &[KeyCode::KeyJ]));
+        assert!(!next_frame(&mut input_store, 2100, &[KeyCode::KeyD]));
+        assert!(!next_frame(&mut input_store, 2150, &[KeyCode::KeyJ]));
+        assert!(!next_frame(&mut input_store, 2200, &[]));
+
+        // Nor does completing the sequence too slowly
+        assert!(!next_frame(&mut input_store, 3000, &[KeyCode::KeyS]));
+        assert!(!next_frame(&mut input_store, 3050, &[KeyCode::KeyD]));
+        assert!(!next_frame(&mut input_store, 3200, &[KeyCode::KeyJ]));
+        assert!(!next_frame(&mut input_store, 3250, &[]));
+
+        // But starting over straight away can still complete it
+        assert!(!next_frame(&mut input_store, 4000, &[KeyCode::KeyS]));
+        assert!(!next_frame(&mut input_store, 4050, &[]));
+        assert!(!next_frame(&mut input_store, 4100, &[KeyCode::KeyS]));
+        assert!(!next_frame(&mut input_store, 4150, &[KeyCode::KeyD]));
+        assert!(next_frame(&mut input_store, 4200, &[KeyCode::KeyJ]));
+    }
+
+    #[test]
+    fn sequence_input_without_steps_is_never_pressed() {
+        use super::SequenceInput;
+        use crate::user_input::updating::CentralInputStore;
+
+        let input = SequenceInput {
+            steps: Vec::new(),
+            step_window: SequenceInput::DEFAULT_STEP_WINDOW,
+        };
+        let input_store = CentralInputStore::default();
+        assert!(!input.pressed(&input_store, Entity::PLACEHOLDER));
+    }
+
+    #[test]
+    fn sequence_input_deserialization_is_validated() {
+        use super::SequenceInput;
+        use crate::user_input::RegisterUserInput;
+
+        // Register the deserializers of the steps
+        App::new().register_default_inputs();
+
+        let input = SequenceInput::new([KeyCode::KeyS, KeyCode::KeyD]);
+        let serialized = ron::to_string(&input).unwrap();
+        assert_eq!(ron::from_str::<SequenceInput>(&serialized).unwrap(), input);
+
+        let invalid = SequenceInput {
+            steps: Vec::new(),
+            ..input
+        };
+        let serialized = ron::to_string(&invalid).unwrap();
+        assert!(ron::from_str::<SequenceInput>(&serialized).is_err());
+    }
+
+    #[cfg(feature = "mouse")]
+    #[test]
+    fn flick_stick_reports_flicks() {
+        use super::FlickStick;
+        use crate::user_input::updating::CentralInputStore;
+        use crate::user_input::{Axislike, MouseMove};
+        use std::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+        let input = FlickStick::new(MouseMove::default());
+        let gamepad = Entity::PLACEHOLDER;
+        let mut input_store = CentralInputStore::default();
+        let next_frame = |input_store: &mut CentralInputStore, stick: Vec2| {
+            input_store.clear();
+            input_store.update_dualaxislike(MouseMove::default(), stick);
+            input.value(input_store, gamepad)
+        };
+        let close = |a: f32, b: f32| (a - b).abs() <= 0.00001;
+
+        // Resting in the center doesn't turn
+        assert_eq!(next_frame(&mut input_store, Vec2::ZERO), 0.0);
+
+        // Flicking right turns to face right
+        assert!(close(next_frame(&mut input_store, Vec2::X), FRAC_PI_2));
+
+        // Reading the input again in the same frame doesn't report the flick twice
+        assert!(close(input.value(&input_store, gamepad), FRAC_PI_2));
+
+        // Holding the stick still doesn't turn any further
+        assert_eq!(next_frame(&mut input_store, Vec2::X), 0.0);
+
+        // Rotating the stick at the edge follows it, wrapping around behind the player
+        let down_right = Vec2::new(1.0, -1.0).normalize();
+        assert!(close(next_frame(&mut input_store, down_right), FRAC_PI_4));
+        let down_left = Vec2::new(-1.0, -1.0).normalize();
+        assert!(close(next_frame(&mut input_store, down_left), FRAC_PI_2));
+
+        // Releasing the stick stops turning
+        assert_eq!(next_frame(&mut input_store, Vec2::ZERO), 0.0);
+
+        // Flicking backwards turns around
+        assert!(close(next_frame(&mut input_store, Vec2::NEG_Y).abs(), PI));
+        assert_eq!(next_frame(&mut input_store, Vec2::ZERO), 0.0);
+
+        // Moving slowly from the center isn't a flick
+        assert_eq!(next_frame(&mut input_store, Vec2::new(-0.5, 0.0)), 0.0);
+        assert_eq!(next_frame(&mut input_store, Vec2::NEG_X), 0.0);
+    }
+
+    #[cfg(feature = "mouse")]
+    #[test]
+    #[should_panic]
+    fn flick_stick_invalid_radii() {
+        use super::FlickStick;
+        use crate::user_input::MouseMove;
+
+        let _ = FlickStick::new(MouseMove::default()).with_radii(0.9, 0.2);
+    }
+
+    #[cfg(feature = "mouse")]
+    #[test]
+    fn flick_stick_deserialization_is_validated() {
+        use super::FlickStick;
+        use crate::user_input::{MouseMove, RegisterUserInput};
+
+        // Register the deserializer of the wrapped stick
+        App::new().register_default_inputs();
+
+        let input = FlickStick::new(MouseMove::default()).with_radii(0.1, 0.8);
+        let serialized = ron::to_string(&input).unwrap();
+        assert_eq!(ron::from_str::<FlickStick>(&serialized).unwrap(), input);
+
+        for (center_radius, edge_radius) in [(0.8, 0.1), (0.5, 0.5), (-0.1, 0.8), (f32::NAN, 0.8)] {
+            let invalid = FlickStick {
+                center_radius,
+                edge_radius,
+                ..input.clone()
+            };
+            let serialized = ron::to_string(&invalid).unwrap();
+            assert!(ron::from_str::<FlickStick>(&serialized).is_err());
+        }
+    }
+
+    #[test]
+    fn wrapper_display_names_delegate_to_wrapped_inputs() {
+        use super::{DoubleTap, OnReleaseButton, SequenceInput, Toggle};
+        use crate::user_input::UserInput;
+
+        assert_eq!(
+            DebouncedButton::new(KeyCode::ShiftLeft, 3).display_name(),
+            "Left Shift"
+        );
+        assert_eq!(OnReleaseButton::new(KeyCode::KeyE).display_name(), "E");
+        assert_eq!(Toggle::new(KeyCode::CapsLock).display_name(), "Caps Lock");
+        assert_eq!(DoubleTap::new(KeyCode::KeyW).display_name(), "Double Tap W");
+        assert_eq!(
+            SequenceInput::new([KeyCode::ArrowDown, KeyCode::ArrowRight, KeyCode::KeyJ])
+                .display_name(),
+            "Down Arrow, Right Arrow, J"
+        );
+    }
+}
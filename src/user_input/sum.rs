@@ -0,0 +1,267 @@
+//! This module contains [`AxisSum`] and its impls.
+
+use bevy::prelude::{Entity, Reflect, World};
+use leafwing_input_manager_macros::serde_typetag;
+use serde::{Deserialize, Serialize};
+
+use crate as leafwing_input_manager;
+use crate::clashing_inputs::BasicInputs;
+use crate::input_processing::AxisBounds;
+use crate::user_input::{Axislike, UserInput};
+use crate::InputControlKind;
+
+use super::updating::CentralInputStore;
+
+/// A combined input that adds together the values of several [`Axislike`]s,
+/// optionally clamping the total to a range.
+///
+/// This is the same as binding each [`Axislike`] to the same action, whose values are summed together,
+/// but as a single input that can be stored, serialized and nested in other inputs,
+/// such as a throttle controlled by both a gamepad trigger and keyboard keys.
+///
+/// Without bounds, the sum is not clamped,
+/// so pressing two inputs at once can exceed the range of each input.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy::input::InputPlugin;
+/// use leafwing_input_manager::plugin::CentralInputStorePlugin;
+/// use leafwing_input_manager::prelude::*;
+/// use leafwing_input_manager::user_input::testing_utils::FetchUserInput;
+///
+/// let mut app = App::new();
+/// app.add_plugins((InputPlugin, CentralInputStorePlugin));
+///
+/// // Both the A and D keys and the arrow keys move the throttle, up to full speed
+/// let input = AxisSum::new([VirtualAxis::ad(), VirtualAxis::horizontal_arrow_keys()])
+///     .with_bounds(-1.0, 1.0);
+///
+/// KeyCode::KeyD.press(app.world_mut());
+/// app.update();
+/// assert_eq!(app.read_axis_value(input.clone()), 1.0);
+///
+/// // The sum of both inputs is clamped to the bounds
+/// KeyCode::ArrowRight.press(app.world_mut());
+/// app.update();
+/// assert_eq!(app.read_axis_value(input.clone()), 1.0);
+///
+/// // Opposing inputs cancel out
+/// KeyCode::ArrowRight.release(app.world_mut());
+/// KeyCode::ArrowLeft.press(app.world_mut());
+/// app.update();
+/// assert_eq!(app.read_axis_value(input), 0.0);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+#[must_use]
+pub struct AxisSum {
+    /// The [`Axislike`]s whose values are added together.
+    pub(crate) inputs: Vec<Box<dyn Axislike>>,
+
+    /// The range that the sum is clamped to, if any.
+    pub(crate) bounds: Option<AxisBounds>,
+}
+
+impl AxisSum {
+    /// Creates an [`AxisSum`] from multiple [`Axislike`]s, without clamping their sum.
+    /// Note that all elements within the iterator must be of the same type (homogeneous).
+    /// You can still use [`AxisSum::with`] to add different types of inputs.
+    #[inline]
+    pub fn new<U: Axislike>(inputs: impl IntoIterator<Item = U>) -> Self {
+        Self {
+            inputs: inputs
+                .into_iter()
+                .map(|input| Box::new(input) as Box<dyn Axislike>)
+                .collect(),
+            bounds: None,
+        }
+    }
+
+    /// Adds the given [`Axislike`] to the inputs that are summed.
+    #[inline]
+    pub fn with(mut self, input: impl Axislike) -> Self {
+        self.inputs.push(Box::new(input));
+        self
+    }
+
+    /// Clamps the sum of the inputs to the range `[min, max]`.
+    ///
+    /// # Requirements
+    ///
+    /// - `min` <= `max`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the requirements aren't met.
+    #[track_caller]
+    #[inline]
+    pub fn with_bounds(mut self, min: f32, max: f32) -> Self {
+        self.bounds = Some(AxisBounds::new(min, max));
+        self
+    }
+
+    /// Returns the inputs that are summed by this [`AxisSum`].
+    #[must_use]
+    #[inline]
+    pub fn inputs(&self) -> &[Box<dyn Axislike>] {
+        &self.inputs
+    }
+
+    /// Returns the range that the sum is clamped to, if any.
+    #[must_use]
+    #[inline]
+    pub fn bounds(&self) -> Option<AxisBounds> {
+        self.bounds
+    }
+}
+
+impl UserInput for AxisSum {
+    /// [`AxisSum`] acts as a virtual axis.
+    #[inline]
+    fn kind(&self) -> InputControlKind {
+        InputControlKind::Axis
+    }
+
+    /// Retrieves the [`Buttonlike`](crate::user_input::Buttonlike)s of all the inner inputs,
+    /// as any of them can drive the value independently.
+    #[inline]
+    fn decompose(&self) -> BasicInputs {
+        let inputs = self
+            .inputs
+            .iter()
+            .flat_map(|input| input.decompose().inputs())
+            .collect();
+        BasicInputs::Composite(inputs)
+    }
+}
+
+#[serde_typetag]
+impl Axislike for AxisSum {
+    /// Returns the sum of the values of all inner inputs, clamped to the bounds if there are any.
+    fn value(&self, input_store: &CentralInputStore, gamepad: Entity) -> f32 {
+        let sum = self
+            .inputs
+            .iter()
+            .map(|input| input.value(input_store, gamepad))
+            .sum();
+
+        match self.bounds {
+            Some(bounds) => bounds.clamp(sum),
+            None => sum,
+        }
+    }
+
+    /// Sets the value of the first inner input, leaving the others untouched.
+    fn set_value(&self, world: &mut World, value: f32) {
+        if let Some(input) = self.inputs.first() {
+            input.set_value(world, value);
+        }
+    }
+
+    /// Sets the value of the first inner input, leaving the others untouched.
+    fn set_value_as_gamepad(&self, world: &mut World, value: f32, gamepad: Option<Entity>) {
+        if let Some(input) = self.inputs.first() {
+            input.set_value_as_gamepad(world, value, gamepad);
+        }
+    }
+}
+
+impl<U: Axislike> FromIterator<U> for AxisSum {
+    /// Creates an [`AxisSum`] from an iterator over multiple [`Axislike`]s, without clamping their sum.
+    /// Note that all elements within the iterator must be of the same type (homogeneous).
+    #[inline]
+    fn from_iter<T: IntoIterator<Item = U>>(iter: T) -> Self {
+        Self::new(iter)
+    }
+}
+
+#[cfg(all(feature = "gamepad", feature = "keyboard"))]
+#[cfg(test)]
+mod tests {
+    use super::AxisSum;
+    use crate::plugin::CentralInputStorePlugin;
+    use crate::user_input::testing_utils::FetchUserInput;
+    use crate::user_input::{Axislike, Buttonlike, GamepadControlAxis, VirtualAxis};
+    use bevy::input::gamepad::{GamepadConnection, GamepadConnectionEvent};
+    use bevy::input::InputPlugin;
+    use bevy::prelude::*;
+
+    fn test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_plugins(InputPlugin)
+            .add_plugins(CentralInputStorePlugin);
+
+        let gamepad = app.world_mut().spawn(()).id();
+        let mut gamepad_events = app
+            .world_mut()
+            .resource_mut::<Events<GamepadConnectionEvent>>();
+        gamepad_events.send(GamepadConnectionEvent {
+            gamepad,
+            connection: GamepadConnection::Connected {
+                name: "TestController".into(),
+                vendor_id: None,
+                product_id: None,
+            },
+        });
+
+        // Ensure that the gamepad is picked up by the appropriate system
+        app.update();
+        // Ensure that the connection event is flushed through
+        app.update();
+        app
+    }
+
+    #[test]
+    fn test_axis_sum() {
+        let input = AxisSum::new([GamepadControlAxis::LEFT_X]).with(VirtualAxis::ad());
+        assert_eq!(input.bounds(), None);
+
+        // No inputs are active
+        let mut app = test_app();
+        assert_eq!(app.read_axis_value(input.clone()), 0.0);
+
+        // The values of both inputs are added together
+        let mut app = test_app();
+        GamepadControlAxis::LEFT_X.set_value(app.world_mut(), 0.25);
+        KeyCode::KeyD.press(app.world_mut());
+        app.update();
+        assert_eq!(app.read_axis_value(input.clone()), 1.25);
+
+        // And can cancel each other out
+        KeyCode::KeyD.release(app.world_mut());
+        KeyCode::KeyA.press(app.world_mut());
+        app.update();
+        assert_eq!(app.read_axis_value(input), -0.75);
+    }
+
+    #[test]
+    fn test_axis_sum_bounds() {
+        let input = AxisSum::new([GamepadControlAxis::LEFT_X])
+            .with(VirtualAxis::ad())
+            .with_bounds(-1.0, 1.0);
+
+        // The sum exceeds the bounds, so it is clamped
+        let mut app = test_app();
+        GamepadControlAxis::LEFT_X.set_value(app.world_mut(), 0.5);
+        KeyCode::KeyD.press(app.world_mut());
+        app.update();
+        assert_eq!(app.read_axis_value(input.clone()), 1.0);
+
+        // Sums within the bounds are left as is
+        KeyCode::KeyD.release(app.world_mut());
+        KeyCode::KeyA.press(app.world_mut());
+        app.update();
+        assert_eq!(app.read_axis_value(input.clone()), -0.5);
+
+        // Including sums exceeding the lower bound
+        GamepadControlAxis::LEFT_X.set_value(app.world_mut(), -0.5);
+        app.update();
+        assert_eq!(app.read_axis_value(input), -1.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_axis_sum_invalid_bounds() {
+        let _ = AxisSum::new([VirtualAxis::ad()]).with_bounds(1.0, -1.0);
+    }
+}
@@ -8,6 +8,7 @@ use bevy::{
 };
 
 use super::{updating::CentralInputStore, Axislike, Buttonlike, DualAxislike};
+use crate::buttonlike::ButtonValue;
 
 #[cfg(feature = "gamepad")]
 use crate::user_input::gamepad::find_gamepad;
@@ -89,3 +90,86 @@ impl FetchUserInput for App {
         self.world_mut().read_dual_axis_values(input)
     }
 }
+
+/// Helpers to inject raw input values directly into the [`CentralInputStore`],
+/// bypassing Bevy's input events.
+///
+/// Values are overwritten whenever the [`CentralInputStore`] is updated from Bevy's input resources,
+/// so these are best suited to tests that process actions directly
+/// using [`InputMap::process_actions`](crate::input_map::InputMap::process_actions).
+impl CentralInputStore {
+    /// Sets the raw `value` of a [`Buttonlike`] input,
+    /// which is pressed if the `value` is greater than `0.0`.
+    pub fn set_button(&mut self, button: impl Buttonlike, value: f32) -> &mut Self {
+        self.update_buttonlike(button, ButtonValue::new(value > 0.0, value));
+        self
+    }
+
+    /// Sets the raw `value` of an [`Axislike`] input.
+    pub fn set_axis(&mut self, axis: impl Axislike, value: f32) -> &mut Self {
+        self.update_axislike(axis, value);
+        self
+    }
+
+    /// Sets the raw `pair` of values of a [`DualAxislike`] input.
+    pub fn set_dual_axis(&mut self, dual_axis: impl DualAxislike, pair: Vec2) -> &mut Self {
+        self.update_dualaxislike(dual_axis, pair);
+        self
+    }
+}
+
+#[cfg(all(test, feature = "keyboard", feature = "gamepad", feature = "mouse"))]
+mod tests {
+    use bevy::prelude::{GamepadAxis, GamepadButton, KeyCode, Reflect};
+
+    use crate::user_input::gamepad::{SpecificGamepadAxis, SpecificGamepadButton};
+    use leafwing_input_manager_macros::Actionlike;
+
+    use super::*;
+    use crate as leafwing_input_manager;
+    use crate::prelude::*;
+
+    #[test]
+    fn injected_inputs_trigger_actions() {
+        #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+        enum Action {
+            Jump,
+            Accelerate,
+            #[actionlike(Axis)]
+            Steer,
+            #[actionlike(DualAxis)]
+            Look,
+        }
+
+        let input_map = InputMap::new([(Action::Jump, KeyCode::Space)])
+            .with(Action::Accelerate, GamepadButton::RightTrigger2)
+            .with_axis(
+                Action::Steer,
+                GamepadControlAxis::new(GamepadAxis::LeftStickX),
+            )
+            .with_dual_axis(Action::Look, MouseMove::default());
+
+        // Gamepad inputs are stored for the specific gamepad they were read from
+        let gamepad = Entity::PLACEHOLDER;
+        let mut input_store = CentralInputStore::default();
+        input_store
+            .set_button(KeyCode::Space, 1.0)
+            .set_button(
+                SpecificGamepadButton::new(gamepad, GamepadButton::RightTrigger2),
+                0.4,
+            )
+            .set_axis(
+                SpecificGamepadAxis::new(gamepad, GamepadAxis::LeftStickX),
+                -0.5,
+            )
+            .set_dual_axis(MouseMove::default(), Vec2::new(3.0, 4.0));
+
+        let mut action_state = ActionState::<Action>::default();
+        action_state.update(input_map.process_actions(None, &input_store, ClashStrategy::PressAll));
+
+        assert!(action_state.pressed(&Action::Jump));
+        assert!(action_state.pressed(&Action::Accelerate));
+        assert_eq!(action_state.value(&Action::Steer), -0.5);
+        assert_eq!(action_state.axis_pair(&Action::Look), Vec2::new(3.0, 4.0));
+    }
+}
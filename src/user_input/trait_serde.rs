@@ -9,7 +9,12 @@ use serde_flexitos::ser::require_erased_serialize_impl;
 use serde_flexitos::{serialize_trait_object, Registry};
 use std::sync::LazyLock;
 
-use super::{Axislike, Buttonlike, DualAxislike, TripleAxislike};
+use super::{
+    AxisInRange, AxisSum, Axislike, AxislikeChord, ButtonValueChord, Buttonlike, ButtonlikeChord,
+    DebouncedButton, DoubleTap, DualAxislike, DualAxislikeChord, FirstNonZero, FlickStick,
+    OnReleaseButton, SequenceInput, Toggle, TripleAxislike, TripleAxislikeChord, VirtualAxis,
+    VirtualDPad, VirtualDPad3D,
+};
 use crate::typetag::{InfallibleMapRegistry, RegisterTypeTag};
 
 /// Registry of deserializers for [`Buttonlike`]s.
@@ -49,6 +54,88 @@ pub trait RegisterUserInput {
     fn register_triple_axislike_input<'de, T>(&mut self) -> &mut Self
     where
         T: RegisterTypeTag<'de, dyn TripleAxislike> + GetTypeRegistration;
+
+    /// Registers every built-in input type, so that any [`InputMap`](crate::input_map::InputMap)
+    /// made of standard inputs can be deserialized.
+    ///
+    /// The set of inputs registered by this method is controlled by the features enabled.
+    /// This is called by [`InputManagerPlugin`](crate::plugin::InputManagerPlugin),
+    /// so it only needs to be called manually when the plugin is not added.
+    ///
+    /// # Ordering
+    ///
+    /// Inputs are deserialized according to their short type path,
+    /// and only the first type registered under each name is kept.
+    /// Custom inputs sharing a name with a built-in input must therefore be registered
+    /// before calling this method or adding the plugin;
+    /// all other custom inputs can be registered at any point.
+    fn register_default_inputs(&mut self) -> &mut Self {
+        #[cfg(feature = "mouse")]
+        {
+            use crate::user_input::mouse::*;
+            self.register_buttonlike_input::<bevy::input::mouse::MouseButton>()
+                .register_buttonlike_input::<MouseMoveDirection>()
+                .register_axislike_input::<MouseMoveAxis>()
+                .register_dual_axislike_input::<MouseMove>()
+                .register_buttonlike_input::<MouseScrollDirection>()
+                .register_axislike_input::<MouseScrollAxis>()
+                .register_dual_axislike_input::<MouseScroll>();
+        }
+
+        #[cfg(feature = "keyboard")]
+        {
+            use crate::user_input::keyboard::*;
+            self.register_buttonlike_input::<bevy::input::keyboard::KeyCode>()
+                .register_buttonlike_input::<ModifierKey>()
+                .register_buttonlike_input::<LogicalKey>();
+        }
+
+        #[cfg(feature = "gamepad")]
+        {
+            use crate::user_input::gamepad::*;
+            self.register_buttonlike_input::<GamepadControlDirection>()
+                .register_axislike_input::<GamepadControlAxis>()
+                .register_dual_axislike_input::<GamepadStick>()
+                .register_buttonlike_input::<bevy::input::gamepad::GamepadButton>()
+                .register_buttonlike_input::<SpecificGamepadButton>()
+                .register_axislike_input::<SpecificGamepadAxis>()
+                .register_axislike_input::<GamepadTriggerAxis>()
+                .register_buttonlike_input::<GamepadTriggerButton>();
+        }
+
+        // Virtual axes
+        self.register_axislike_input::<VirtualAxis>()
+            .register_dual_axislike_input::<VirtualDPad>()
+            .register_triple_axislike_input::<VirtualDPad3D>();
+
+        // Chords
+        self.register_buttonlike_input::<ButtonlikeChord>()
+            .register_axislike_input::<AxislikeChord>()
+            .register_axislike_input::<ButtonValueChord>()
+            .register_dual_axislike_input::<DualAxislikeChord>()
+            .register_triple_axislike_input::<TripleAxislikeChord>();
+
+        // Fallback inputs
+        self.register_axislike_input::<FirstNonZero>();
+
+        // Combined axes
+        self.register_axislike_input::<AxisSum>();
+
+        // Axis ranges
+        self.register_buttonlike_input::<AxisInRange>();
+
+        // Hybrid inputs
+        #[cfg(all(feature = "gamepad", feature = "keyboard"))]
+        self.register_buttonlike_input::<crate::user_input::hybrid::PreferredDeviceButton>();
+
+        // Stateful inputs
+        self.register_buttonlike_input::<DebouncedButton>()
+            .register_buttonlike_input::<OnReleaseButton>()
+            .register_buttonlike_input::<DoubleTap>()
+            .register_buttonlike_input::<SequenceInput>()
+            .register_buttonlike_input::<Toggle>()
+            .register_axislike_input::<FlickStick>()
+    }
 }
 
 impl RegisterUserInput for App {
@@ -297,7 +384,7 @@ mod tests {
                 Token::BorrowedStr("MouseScrollAxis"),
                 Token::Struct {
                     name: "MouseScrollAxis",
-                    len: 2,
+                    len: 3,
                 },
                 Token::BorrowedStr("axis"),
                 Token::Enum {
@@ -305,6 +392,11 @@ mod tests {
                 },
                 Token::Str("Y"),
                 Token::Unit,
+                Token::BorrowedStr("normalization"),
+                Token::UnitVariant {
+                    name: "ScrollUnitNormalization",
+                    variant: "Raw",
+                },
                 Token::BorrowedStr("processors"),
                 Token::Seq { len: Some(0) },
                 Token::SeqEnd,
@@ -330,7 +422,12 @@ mod tests {
                 Token::BorrowedStr("MouseMove"),
                 Token::Struct {
                     name: "MouseMove",
-                    len: 1,
+                    len: 2,
+                },
+                Token::Str("normalization"),
+                Token::UnitVariant {
+                    name: "MouseMotionNormalization",
+                    variant: "Raw",
                 },
                 Token::Str("processors"),
                 Token::Seq { len: Some(0) },
@@ -365,7 +462,7 @@ mod tests {
                 Token::BorrowedStr("VirtualDPad3D"),
                 Token::Struct {
                     name: "VirtualDPad3D",
-                    len: 6,
+                    len: 10,
                 },
                 Token::Str("up"),
                 Token::Map { len: Some(1) },
@@ -415,6 +512,18 @@ mod tests {
                     variant: "KeyB",
                 },
                 Token::MapEnd,
+                Token::Str("processors_x"),
+                Token::Seq { len: Some(0) },
+                Token::SeqEnd,
+                Token::Str("processors_y"),
+                Token::Seq { len: Some(0) },
+                Token::SeqEnd,
+                Token::Str("processors_z"),
+                Token::Seq { len: Some(0) },
+                Token::SeqEnd,
+                Token::Str("processors"),
+                Token::Seq { len: Some(0) },
+                Token::SeqEnd,
                 Token::StructEnd,
                 Token::MapEnd,
             ],
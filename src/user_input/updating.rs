@@ -1,16 +1,20 @@
 //! Logic for updating user input based on the state of the world.
 
-use std::any::TypeId;
+use std::any::{Any, TypeId};
 use std::hash::Hash;
+use std::sync::Mutex;
+use std::time::Duration;
 
 use bevy::{
     app::{App, PreUpdate},
     ecs::system::{StaticSystemParam, SystemParam},
+    input::mouse::MouseScrollUnit,
     math::{Vec2, Vec3},
-    prelude::{IntoSystemConfigs, ResMut, Resource},
+    prelude::{Entity, IntoSystemConfigs, ResMut, Resource},
     reflect::Reflect,
     utils::{HashMap, HashSet},
 };
+use serde::{Deserialize, Serialize};
 
 use super::{Axislike, Buttonlike, DualAxislike, TripleAxislike};
 use crate::buttonlike::ButtonValue;
@@ -28,15 +32,47 @@ pub struct CentralInputStore {
     updated_values: HashMap<TypeId, UpdatedValues>,
     /// Tracks the input kinds that have been registered, to avoid redundant system additions.
     registered_input_kinds: HashSet<TypeId>,
+    /// The number of times that [`CentralInputStore::clear`] has been called.
+    frame: u64,
+    /// The time elapsed since the app started, as of the current frame.
+    elapsed: Duration,
+    /// The physical size of the primary window, as of the current frame.
+    primary_window_size: Option<Vec2>,
+    /// The unit of the most recent mouse scrolling.
+    scroll_unit: Option<MouseScrollUnit>,
+    /// Stores the state of inputs that depend on previous frames, keyed by the type of the input and its state.
+    ///
+    /// See [`CentralInputStore::with_input_memory`] for details.
+    #[reflect(ignore)]
+    input_memory: Mutex<HashMap<TypeId, Box<dyn ErasedInputMemory>>>,
 }
 
 impl CentralInputStore {
+    /// The number of frames after which the memory of an input that hasn't been read is discarded.
+    ///
+    /// See [`CentralInputStore::with_input_memory`] for details.
+    pub const INPUT_MEMORY_LIFETIME: u64 = 600;
+
     /// Clears all existing values.
     ///
     /// This should be called once at the start of each frame, before polling for new input.
+    /// The memory of inputs that haven't been read for [`CentralInputStore::INPUT_MEMORY_LIFETIME`] frames is discarded.
     pub fn clear(&mut self) {
-        // Clear the values inside of each map:
-        // the base maps can be reused, but the values inside them need to be replaced each frame.
+        self.frame += 1;
+        self.clear_values();
+
+        if let Some(oldest_frame) = self.frame.checked_sub(Self::INPUT_MEMORY_LIFETIME) {
+            let input_memory = self.input_memory.get_mut().unwrap();
+            input_memory
+                .values_mut()
+                .for_each(|memory| memory.evict(oldest_frame));
+            input_memory.retain(|_, memory| !memory.is_empty());
+        }
+    }
+
+    /// Clears the values inside of each map:
+    /// the base maps can be reused, but the values inside them need to be replaced.
+    fn clear_values(&mut self) {
         for map in self.updated_values.values_mut() {
             match map {
                 UpdatedValues::Buttonlike(buttonlikes) => buttonlikes.clear(),
@@ -47,6 +83,248 @@ impl CentralInputStore {
         }
     }
 
+    /// Captures the values of every input stored this frame, across all registered input kinds,
+    /// along with the [`frame`](CentralInputStore::frame) count, the [`elapsed`](CentralInputStore::elapsed) time
+    /// and the memory of stateful inputs (see [`CentralInputStore::with_input_memory`]).
+    ///
+    /// Pass the returned [`InputSnapshot`] to [`CentralInputStore::restore`] to return to this state later,
+    /// for example to replay recorded inputs deterministically, or to roll back in networked games.
+    ///
+    /// The inputs of each kind are sorted by their [`Debug`] representation,
+    /// so that the same state always produces the same snapshot.
+    #[must_use]
+    pub fn snapshot(&self) -> InputSnapshot {
+        let input_memory = self.input_memory.lock().unwrap();
+        let mut snapshot = InputSnapshot {
+            frame: self.frame,
+            elapsed: self.elapsed,
+            primary_window_size: self.primary_window_size,
+            scroll_unit: self.scroll_unit,
+            input_memory: Some(
+                input_memory
+                    .iter()
+                    .map(|(type_id, memory)| (*type_id, memory.clone_box()))
+                    .collect(),
+            ),
+            ..Default::default()
+        };
+
+        for map in self.updated_values.values() {
+            match map {
+                UpdatedValues::Buttonlike(buttonlikes) => snapshot.buttonlikes.extend(
+                    buttonlikes
+                        .iter()
+                        .map(|(input, value)| (input.clone(), *value)),
+                ),
+                UpdatedValues::Axislike(axislikes) => snapshot.axislikes.extend(
+                    axislikes
+                        .iter()
+                        .map(|(input, value)| (input.clone(), *value)),
+                ),
+                UpdatedValues::Dualaxislike(dualaxislikes) => snapshot.dual_axislikes.extend(
+                    dualaxislikes
+                        .iter()
+                        .map(|(input, value)| (input.clone(), *value)),
+                ),
+                UpdatedValues::Tripleaxislike(tripleaxislikes) => snapshot.triple_axislikes.extend(
+                    tripleaxislikes
+                        .iter()
+                        .map(|(input, value)| (input.clone(), *value)),
+                ),
+            }
+        }
+
+        fn sort_by_debug<I: std::fmt::Debug, V>(inputs: &mut [(I, V)]) {
+            inputs.sort_by_cached_key(|(input, _)| format!("{input:?}"));
+        }
+        sort_by_debug(&mut snapshot.buttonlikes);
+        sort_by_debug(&mut snapshot.axislikes);
+        sort_by_debug(&mut snapshot.dual_axislikes);
+        sort_by_debug(&mut snapshot.triple_axislikes);
+        snapshot
+    }
+
+    /// Replaces the values of every stored input with those captured by [`CentralInputStore::snapshot`],
+    /// along with the [`frame`](CentralInputStore::frame) count, the [`elapsed`](CentralInputStore::elapsed) time,
+    /// the [`primary_window_size`](CentralInputStore::primary_window_size)
+    /// and the [`scroll_unit`](CentralInputStore::scroll_unit).
+    ///
+    /// The memory of stateful inputs (see [`CentralInputStore::with_input_memory`]) is restored too,
+    /// unless the snapshot was deserialized, as this memory isn't serialized.
+    /// In that case, the current memory is left unchanged.
+    pub fn restore(&mut self, snapshot: &InputSnapshot) {
+        self.clear_values();
+        self.frame = snapshot.frame;
+        self.elapsed = snapshot.elapsed;
+        self.primary_window_size = snapshot.primary_window_size;
+        self.scroll_unit = snapshot.scroll_unit;
+
+        if let Some(input_memory) = &snapshot.input_memory {
+            *self.input_memory.get_mut().unwrap() = input_memory
+                .iter()
+                .map(|(type_id, memory)| (*type_id, memory.clone_box()))
+                .collect();
+        }
+
+        for (input, value) in snapshot.buttonlikes.iter() {
+            let updated_values = self
+                .updated_values
+                .entry(Reflect::as_any(input.as_ref()).type_id())
+                .or_insert_with(|| UpdatedValues::Buttonlike(HashMap::new()));
+
+            let UpdatedValues::Buttonlike(buttonlikes) = updated_values else {
+                panic!("Expected Buttonlike, found {:?}", updated_values);
+            };
+
+            buttonlikes.insert(input.clone(), *value);
+        }
+
+        for (input, value) in snapshot.axislikes.iter() {
+            let updated_values = self
+                .updated_values
+                .entry(Reflect::as_any(input.as_ref()).type_id())
+                .or_insert_with(|| UpdatedValues::Axislike(HashMap::new()));
+
+            let UpdatedValues::Axislike(axislikes) = updated_values else {
+                panic!("Expected Axislike, found {:?}", updated_values);
+            };
+
+            axislikes.insert(input.clone(), *value);
+        }
+
+        for (input, value) in snapshot.dual_axislikes.iter() {
+            let updated_values = self
+                .updated_values
+                .entry(Reflect::as_any(input.as_ref()).type_id())
+                .or_insert_with(|| UpdatedValues::Dualaxislike(HashMap::new()));
+
+            let UpdatedValues::Dualaxislike(dualaxislikes) = updated_values else {
+                panic!("Expected DualAxislike, found {:?}", updated_values);
+            };
+
+            dualaxislikes.insert(input.clone(), *value);
+        }
+
+        for (input, value) in snapshot.triple_axislikes.iter() {
+            let updated_values = self
+                .updated_values
+                .entry(Reflect::as_any(input.as_ref()).type_id())
+                .or_insert_with(|| UpdatedValues::Tripleaxislike(HashMap::new()));
+
+            let UpdatedValues::Tripleaxislike(tripleaxislikes) = updated_values else {
+                panic!("Expected TripleAxislike, found {:?}", updated_values);
+            };
+
+            tripleaxislikes.insert(input.clone(), *value);
+        }
+    }
+
+    /// The number of frames that have elapsed, counted by calls to [`CentralInputStore::clear`].
+    #[inline]
+    #[must_use]
+    pub fn frame(&self) -> u64 {
+        self.frame
+    }
+
+    /// The time elapsed since the app started, as of the current frame.
+    ///
+    /// This is set from [`Time<Real>`](bevy::time::Real) by [`clear_central_input_store`](crate::systems::clear_central_input_store),
+    /// and is used by inputs that depend on timing, such as [`DoubleTap`](crate::user_input::DoubleTap).
+    #[inline]
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Sets the time elapsed since the app started, as returned by [`CentralInputStore::elapsed`].
+    ///
+    /// This is done automatically each frame when [`Time<Real>`](bevy::time::Real) is available,
+    /// but can be called manually to drive timing-dependent inputs, such as in tests.
+    #[inline]
+    pub fn set_elapsed(&mut self, elapsed: Duration) {
+        self.elapsed = elapsed;
+    }
+
+    /// The physical size of the [`PrimaryWindow`](bevy::window::PrimaryWindow), as of the current frame,
+    /// or [`None`] if there is no primary window.
+    ///
+    /// This is recorded by [`MouseMove`](crate::user_input::MouseMove) each frame,
+    /// and is used to normalize mouse motion (see [`MouseMotionNormalization`](crate::user_input::MouseMotionNormalization)).
+    #[inline]
+    #[must_use]
+    pub fn primary_window_size(&self) -> Option<Vec2> {
+        self.primary_window_size
+    }
+
+    /// Sets the physical size of the primary window, as returned by [`CentralInputStore::primary_window_size`].
+    ///
+    /// This is done automatically each frame when the `mouse` feature is enabled,
+    /// but can be called manually to normalize mouse motion without a window, such as in tests.
+    #[inline]
+    pub fn set_primary_window_size(&mut self, size: Option<Vec2>) {
+        self.primary_window_size = size;
+    }
+
+    /// The [`MouseScrollUnit`] of the most recent mouse scrolling, or [`None`] if the mouse hasn't been scrolled yet.
+    ///
+    /// This is recorded by [`MouseScroll`](crate::user_input::MouseScroll) each frame,
+    /// and is used to convert scrolling between units (see [`ScrollUnitNormalization`](crate::user_input::ScrollUnitNormalization)).
+    #[inline]
+    #[must_use]
+    pub fn scroll_unit(&self) -> Option<MouseScrollUnit> {
+        self.scroll_unit
+    }
+
+    /// Sets the unit of mouse scrolling, as returned by [`CentralInputStore::scroll_unit`].
+    ///
+    /// This is done automatically each frame when the `mouse` feature is enabled,
+    /// but can be called manually to convert scrolling without a mouse, such as in tests.
+    #[inline]
+    pub fn set_scroll_unit(&mut self, unit: Option<MouseScrollUnit>) {
+        self.scroll_unit = unit;
+    }
+
+    /// Provides mutable access to the persistent state stored for the `input` on the given `gamepad`,
+    /// initializing it with [`Default`] if it does not exist yet.
+    ///
+    /// This allows inputs whose values depend on previous frames, such as [`DebouncedButton`](crate::user_input::DebouncedButton),
+    /// to remember what happened, even though their values are computed from a shared reference to the store.
+    /// Compare against [`CentralInputStore::frame`] to ensure that repeated reads during the same frame
+    /// do not advance the state more than once.
+    ///
+    /// State that hasn't been accessed for [`CentralInputStore::INPUT_MEMORY_LIFETIME`] frames is discarded,
+    /// so that memory doesn't keep growing as inputs and gamepads come and go.
+    /// The next access then starts over from [`Default`].
+    ///
+    /// The state must be [`Clone`], so that it can be captured by [`CentralInputStore::snapshot`].
+    ///
+    /// # Panics
+    ///
+    /// The state is locked while `f` runs, so `f` must not call this method again.
+    /// Compute the values of any wrapped inputs before calling this method.
+    pub fn with_input_memory<K, S, R>(
+        &self,
+        input: &K,
+        gamepad: Entity,
+        f: impl FnOnce(&mut S) -> R,
+    ) -> R
+    where
+        K: Clone + Eq + Hash + Send + 'static,
+        S: Clone + Default + Send + 'static,
+    {
+        let mut input_memory = self.input_memory.lock().unwrap();
+        let memory = input_memory
+            .entry(TypeId::of::<(K, S)>())
+            .or_insert_with(|| Box::new(InputMemory::<K, S>(HashMap::new())))
+            .as_any_mut()
+            .downcast_mut::<InputMemory<K, S>>()
+            .expect("input memory is always keyed by the type of its contents");
+
+        let (last_frame, state) = memory.0.entry((input.clone(), gamepad)).or_default();
+        *last_frame = self.frame;
+        f(state)
+    }
+
     /// Updates the value of a [`Buttonlike`] input.
     pub fn update_buttonlike<B: Buttonlike>(&mut self, buttonlike: B, value: ButtonValue) {
         let updated_values = self
@@ -145,6 +423,38 @@ impl CentralInputStore {
             .unwrap_or(0.0)
     }
 
+    /// Returns an iterator over every [`Buttonlike`] input that is currently pressed,
+    /// across all registered input kinds.
+    pub fn pressed_buttonlikes(&self) -> impl Iterator<Item = &(dyn Buttonlike + 'static)> {
+        self.updated_values
+            .values()
+            .filter_map(|updated_values| match updated_values {
+                UpdatedValues::Buttonlike(buttonlikes) => Some(buttonlikes),
+                _ => None,
+            })
+            .flatten()
+            .filter(|(_, button_value)| button_value.pressed)
+            .map(|(buttonlike, _)| buttonlike.as_ref())
+    }
+
+    /// Checks if any [`Buttonlike`] input is currently pressed, across all registered input kinds.
+    ///
+    /// This is useful for "press any key" screens, without binding every possible input to an action.
+    /// Inputs that are held down count as pressed, not just those pressed this frame.
+    #[must_use]
+    pub fn any_button_pressed(&self) -> bool {
+        self.pressed_buttonlikes().next().is_some()
+    }
+
+    /// Returns a [`Buttonlike`] input that is currently pressed, across all registered input kinds, if any.
+    ///
+    /// If several inputs are pressed, which one is returned is unspecified.
+    /// Use [`CentralInputStore::pressed_buttonlikes`] to handle each of them instead.
+    #[must_use]
+    pub fn first_pressed_button(&self) -> Option<Box<dyn Buttonlike>> {
+        self.pressed_buttonlikes().next().map(dyn_clone::clone_box)
+    }
+
     /// Fetches the value of an [`Axislike`] input.
     ///
     /// This should be between -1.0 and 1.0, where -1.0 is fully left or down and 1.0 is fully right or up.
@@ -202,6 +512,83 @@ impl CentralInputStore {
     }
 }
 
+/// The values of every input stored in a [`CentralInputStore`] during a single frame,
+/// captured using [`CentralInputStore::snapshot`] and restored using [`CentralInputStore::restore`].
+///
+/// The inputs are stored as trait objects, so snapshots can be serialized and deserialized
+/// as long as every kind of input they contain has been registered,
+/// for example using [`RegisterUserInput`](crate::user_input::RegisterUserInput).
+/// The memory of stateful inputs is skipped when serializing, and ignored when comparing snapshots.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct InputSnapshot {
+    /// The [`frame`](CentralInputStore::frame) count when the snapshot was captured.
+    frame: u64,
+    /// The [`elapsed`](CentralInputStore::elapsed) time when the snapshot was captured.
+    elapsed: Duration,
+    /// The [`primary_window_size`](CentralInputStore::primary_window_size) when the snapshot was captured.
+    #[serde(default)]
+    primary_window_size: Option<Vec2>,
+    /// The [`scroll_unit`](CentralInputStore::scroll_unit) when the snapshot was captured.
+    #[serde(default)]
+    scroll_unit: Option<MouseScrollUnit>,
+    /// The values of each [`Buttonlike`] input.
+    buttonlikes: Vec<(Box<dyn Buttonlike>, ButtonValue)>,
+    /// The values of each [`Axislike`] input.
+    axislikes: Vec<(Box<dyn Axislike>, f32)>,
+    /// The values of each [`DualAxislike`] input.
+    dual_axislikes: Vec<(Box<dyn DualAxislike>, Vec2)>,
+    /// The values of each [`TripleAxislike`] input.
+    triple_axislikes: Vec<(Box<dyn TripleAxislike>, Vec3)>,
+    /// The memory of stateful inputs, or [`None`] if the snapshot was deserialized.
+    #[serde(skip)]
+    input_memory: Option<HashMap<TypeId, Box<dyn ErasedInputMemory>>>,
+}
+
+impl Clone for InputSnapshot {
+    fn clone(&self) -> Self {
+        Self {
+            frame: self.frame,
+            elapsed: self.elapsed,
+            primary_window_size: self.primary_window_size,
+            scroll_unit: self.scroll_unit,
+            buttonlikes: self.buttonlikes.clone(),
+            axislikes: self.axislikes.clone(),
+            dual_axislikes: self.dual_axislikes.clone(),
+            triple_axislikes: self.triple_axislikes.clone(),
+            input_memory: self.input_memory.as_ref().map(|input_memory| {
+                input_memory
+                    .iter()
+                    .map(|(type_id, memory)| (*type_id, memory.clone_box()))
+                    .collect()
+            }),
+        }
+    }
+}
+
+impl PartialEq for InputSnapshot {
+    fn eq(&self, other: &Self) -> bool {
+        self.frame == other.frame
+            && self.elapsed == other.elapsed
+            && self.primary_window_size == other.primary_window_size
+            && self.scroll_unit == other.scroll_unit
+            && self.buttonlikes == other.buttonlikes
+            && self.axislikes == other.axislikes
+            && self.dual_axislikes == other.dual_axislikes
+            && self.triple_axislikes == other.triple_axislikes
+    }
+}
+
+impl InputSnapshot {
+    /// Returns `true` if no input values were captured.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.buttonlikes.is_empty()
+            && self.axislikes.is_empty()
+            && self.dual_axislikes.is_empty()
+            && self.triple_axislikes.is_empty()
+    }
+}
+
 /// Trait for registering updatable inputs with the central input store
 pub trait InputRegistration {
     /// Registers a new source of raw input data of a matching `kind`.
@@ -248,6 +635,8 @@ pub(crate) fn register_standard_input_kinds(app: &mut App) {
     // Buttonlike
     #[cfg(feature = "keyboard")]
     app.register_input_kind::<bevy::input::keyboard::KeyCode>(InputControlKind::Button);
+    #[cfg(feature = "keyboard")]
+    app.register_input_kind::<crate::prelude::LogicalKey>(InputControlKind::Button);
     #[cfg(feature = "mouse")]
     app.register_input_kind::<bevy::input::mouse::MouseButton>(InputControlKind::Button);
     #[cfg(feature = "gamepad")]
@@ -287,6 +676,58 @@ impl UpdatedValues {
     }
 }
 
+/// The state of every stateful input of type `K` whose memory has type `S`,
+/// along with the frame in which each of them was last accessed.
+///
+/// See [`CentralInputStore::with_input_memory`] for details.
+struct InputMemory<K, S>(HashMap<(K, Entity), (u64, S)>);
+
+/// A type-erased [`InputMemory`], allowing the memory of different kinds of inputs to be stored together.
+trait ErasedInputMemory: Send {
+    /// Discards the state of inputs that were last accessed before the `oldest_frame`.
+    fn evict(&mut self, oldest_frame: u64);
+
+    /// Returns `true` if no state is stored.
+    fn is_empty(&self) -> bool;
+
+    /// Upcasts to [`Any`], so that the memory can be downcast to its concrete type.
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+
+    /// Clones the memory into a new box, so that it can be captured by an [`InputSnapshot`].
+    fn clone_box(&self) -> Box<dyn ErasedInputMemory>;
+}
+
+impl<K, S> ErasedInputMemory for InputMemory<K, S>
+where
+    K: Clone + Eq + Hash + Send + 'static,
+    S: Clone + Send + 'static,
+{
+    fn evict(&mut self, oldest_frame: u64) {
+        self.0
+            .retain(|_, (last_frame, _)| *last_frame >= oldest_frame);
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn ErasedInputMemory> {
+        Box::new(InputMemory(self.0.clone()))
+    }
+}
+
+impl std::fmt::Debug for dyn ErasedInputMemory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InputMemory")
+            .field("is_empty", &self.is_empty())
+            .finish()
+    }
+}
+
 /// A trait that enables user input to be updated based on the state of the world.
 ///
 /// This trait is intended to be used for the values stored inside of [`CentralInputStore`].
@@ -347,6 +788,122 @@ mod tests {
         assert!(app.world().contains_resource::<CentralInputStore>());
     }
 
+    #[test]
+    fn unread_input_memory_is_evicted() {
+        let mut input_store = CentralInputStore::default();
+        let read = |input_store: &CentralInputStore| {
+            input_store.with_input_memory(
+                &TestAction::Run,
+                Entity::PLACEHOLDER,
+                |memory: &mut u32| {
+                    *memory += 1;
+                    *memory
+                },
+            )
+        };
+        assert_eq!(read(&input_store), 1);
+
+        // Memory is kept while it is read
+        for _ in 0..CentralInputStore::INPUT_MEMORY_LIFETIME {
+            input_store.clear();
+        }
+        assert_eq!(read(&input_store), 2);
+
+        // But is discarded once it hasn't been read for long enough
+        for _ in 0..=CentralInputStore::INPUT_MEMORY_LIFETIME {
+            input_store.clear();
+        }
+        assert_eq!(read(&input_store), 1);
+    }
+
+    #[cfg(feature = "keyboard")]
+    #[test]
+    fn any_button_pressed() {
+        use bevy::prelude::KeyCode;
+
+        let mut input_store = CentralInputStore::default();
+        assert!(!input_store.any_button_pressed());
+        assert_eq!(input_store.first_pressed_button(), None);
+
+        // Released inputs are ignored
+        input_store.update_buttonlike(KeyCode::KeyB, ButtonValue::from_pressed(false));
+        assert!(!input_store.any_button_pressed());
+        assert_eq!(input_store.first_pressed_button(), None);
+
+        input_store.update_buttonlike(KeyCode::KeyA, ButtonValue::from_pressed(true));
+        assert!(input_store.any_button_pressed());
+        assert_eq!(
+            input_store.first_pressed_button(),
+            Some(Box::new(KeyCode::KeyA) as Box<dyn Buttonlike>)
+        );
+
+        input_store.clear();
+        assert!(!input_store.any_button_pressed());
+    }
+
+    #[cfg(feature = "keyboard")]
+    #[test]
+    fn snapshots_restore_frame_time_and_memory() {
+        use bevy::prelude::KeyCode;
+
+        let mut input_store = CentralInputStore::default();
+        let read = |input_store: &CentralInputStore| {
+            input_store.with_input_memory(
+                &TestAction::Run,
+                Entity::PLACEHOLDER,
+                |memory: &mut u32| {
+                    *memory += 1;
+                    *memory
+                },
+            )
+        };
+
+        input_store.clear();
+        input_store.set_elapsed(Duration::from_secs(1));
+        input_store.set_primary_window_size(Some(Vec2::new(1920.0, 1080.0)));
+        input_store.set_scroll_unit(Some(MouseScrollUnit::Line));
+        for key in [KeyCode::KeyC, KeyCode::KeyA, KeyCode::KeyB] {
+            input_store.update_buttonlike(key, ButtonValue::from_pressed(true));
+        }
+        assert_eq!(read(&input_store), 1);
+        let snapshot = input_store.snapshot();
+
+        // Inputs are sorted, so that snapshots are deterministic
+        let inputs: Vec<String> = snapshot
+            .buttonlikes
+            .iter()
+            .map(|(input, _)| format!("{input:?}"))
+            .collect();
+        assert!(inputs.is_sorted());
+
+        input_store.clear();
+        input_store.set_elapsed(Duration::from_secs(2));
+        input_store.set_primary_window_size(None);
+        input_store.set_scroll_unit(Some(MouseScrollUnit::Pixel));
+        assert_eq!(read(&input_store), 2);
+
+        // The frame, time, window size, scroll unit and memory are restored alongside the input values
+        input_store.restore(&snapshot);
+        assert_eq!(input_store.frame(), 1);
+        assert_eq!(input_store.elapsed(), Duration::from_secs(1));
+        assert_eq!(
+            input_store.primary_window_size(),
+            Some(Vec2::new(1920.0, 1080.0))
+        );
+        assert_eq!(input_store.scroll_unit(), Some(MouseScrollUnit::Line));
+        assert!(input_store.pressed(&KeyCode::KeyA));
+        assert_eq!(read(&input_store), 2);
+
+        // Deserialized snapshots have no memory, so the current memory is kept
+        let deserialized = InputSnapshot {
+            input_memory: None,
+            ..snapshot.clone()
+        };
+        assert_eq!(deserialized, snapshot);
+        input_store.restore(&deserialized);
+        assert_eq!(read(&input_store), 3);
+    }
+
     #[test]
     fn number_of_maps_matches_number_of_registered_input_kinds() {
         let mut app = App::new();
@@ -380,4 +937,69 @@ mod tests {
         dbg!(central_input_store);
         assert!(central_input_store.pressed(&MouseButton::Left));
     }
+
+    #[cfg(all(feature = "keyboard", feature = "gamepad"))]
+    #[test]
+    fn snapshots_restore_input_values() {
+        use crate::clashing_inputs::ClashStrategy;
+        use crate::input_map::InputMap;
+        use crate::user_input::gamepad::SpecificGamepadAxis;
+        use bevy::prelude::{GamepadAxis, KeyCode};
+
+        #[derive(Actionlike, Debug, PartialEq, Eq, Hash, Clone, Reflect)]
+        enum Action {
+            Jump,
+            #[actionlike(Axis)]
+            Steer,
+        }
+
+        // Registers the input kinds, so that snapshots can be deserialized
+        let mut app = App::new();
+        app.add_plugins(InputManagerPlugin::<Action>::default());
+
+        let gamepad = Entity::from_raw(42);
+        let input_map = InputMap::new([(Action::Jump, KeyCode::Space)])
+            .with_axis(Action::Steer, GamepadAxis::LeftStickX)
+            .with_gamepad(gamepad);
+
+        let mut input_store = CentralInputStore::default();
+        input_store.update_buttonlike(KeyCode::Space, ButtonValue::from_pressed(true));
+        input_store.update_axislike(
+            SpecificGamepadAxis::new(gamepad, GamepadAxis::LeftStickX),
+            0.5,
+        );
+        let expected = input_map.process_actions(None, &input_store, ClashStrategy::PressAll);
+        assert!(expected.pressed(&Action::Jump));
+
+        let snapshot = input_store.snapshot();
+        assert!(!snapshot.is_empty());
+        let serialized = ron::to_string(&snapshot).unwrap();
+        let deserialized: InputSnapshot = ron::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, snapshot);
+
+        // Mutate the store
+        input_store.clear();
+        input_store.update_buttonlike(KeyCode::Enter, ButtonValue::from_pressed(true));
+        input_store.update_axislike(
+            SpecificGamepadAxis::new(gamepad, GamepadAxis::LeftStickX),
+            -1.0,
+        );
+        assert_ne!(
+            input_map.process_actions(None, &input_store, ClashStrategy::PressAll),
+            expected
+        );
+
+        // Restoring the snapshot reproduces the same actions
+        input_store.restore(&snapshot);
+        assert_eq!(
+            input_map.process_actions(None, &input_store, ClashStrategy::PressAll),
+            expected
+        );
+        input_store.restore(&deserialized);
+        assert_eq!(
+            input_map.process_actions(None, &input_store, ClashStrategy::PressAll),
+            expected
+        );
+        assert!(!input_store.pressed(&KeyCode::Enter));
+    }
 }
@@ -3,8 +3,9 @@
 use crate as leafwing_input_manager;
 use crate::clashing_inputs::BasicInputs;
 use crate::input_processing::{
-    AxisProcessor, DualAxisProcessor, WithAxisProcessingPipelineExt,
-    WithDualAxisProcessingPipelineExt,
+    AxisDeadZone, AxisProcessor, DualAxisProcessor, TripleAxisProcessor,
+    WithAxisProcessingPipelineExt, WithDualAxisProcessingPipelineExt,
+    WithTripleAxisProcessingPipelineExt,
 };
 use crate::prelude::updating::CentralInputStore;
 use crate::prelude::{Axislike, DualAxislike, TripleAxislike, UserInput};
@@ -197,6 +198,15 @@ impl UserInput for VirtualAxis {
     fn decompose(&self) -> BasicInputs {
         BasicInputs::Composite(vec![self.negative.clone(), self.positive.clone()])
     }
+
+    /// Returns the names of the negative and positive buttons joined by `" / "`, such as `"A / D"`.
+    fn display_name(&self) -> String {
+        format!(
+            "{} / {}",
+            self.negative.display_name(),
+            self.positive.display_name()
+        )
+    }
 }
 
 #[serde_typetag]
@@ -226,6 +236,12 @@ impl Axislike for VirtualAxis {
             self.positive.set_value_as_gamepad(world, value, gamepad);
         }
     }
+
+    /// Returns the processors applied to the value of this input, in the order they are applied.
+    #[inline]
+    fn processors(&self) -> &[AxisProcessor] {
+        &self.processors
+    }
 }
 
 impl WithAxisProcessingPipelineExt for VirtualAxis {
@@ -269,6 +285,9 @@ impl WithAxisProcessingPipelineExt for VirtualAxis {
 /// - `1.0` if only the positive button is currently pressed (Up/Right).
 /// - `0.0` if neither button is pressed, or both are pressed simultaneously.
 ///
+/// As a result, diagonals have a length of `√2` by default.
+/// Use [`VirtualDPad::normalized`] to clamp them to unit length instead.
+///
 /// ```rust
 /// use bevy::prelude::*;
 /// use bevy::input::InputPlugin;
@@ -306,6 +325,11 @@ pub struct VirtualDPad {
     /// The button for the rightward direction.
     pub right: Box<dyn Buttonlike>,
 
+    /// Whether the raw axis pair is clamped to unit length,
+    /// so that diagonals aren't faster than cardinal directions.
+    #[serde(default)]
+    pub normalized: bool,
+
     /// A processing pipeline that handles input values.
     pub processors: Vec<DualAxisProcessor>,
 }
@@ -325,10 +349,21 @@ impl VirtualDPad {
             down: Box::new(down),
             left: Box::new(left),
             right: Box::new(right),
+            normalized: false,
             processors: Vec::new(),
         }
     }
 
+    /// Clamps the raw axis pair to unit length before it is processed,
+    /// so that pressing two directions at once yields a unit-length diagonal instead of one with a length of `√2`.
+    ///
+    /// Values within the unit circle, such as those of partially pressed analog buttons, are left unchanged.
+    #[inline]
+    pub fn normalized(mut self) -> Self {
+        self.normalized = true;
+        self
+    }
+
     /// The [`VirtualDPad`] using the common arrow key mappings.
     ///
     /// - [`KeyCode::ArrowUp`] for upward direction.
@@ -427,6 +462,15 @@ impl UserInput for VirtualDPad {
             self.right.clone(),
         ])
     }
+
+    /// Returns the names of the up, left, down and right buttons joined by `" / "`, such as `"W / A / S / D"`.
+    fn display_name(&self) -> String {
+        [&self.up, &self.left, &self.down, &self.right]
+            .iter()
+            .map(|button| button.display_name())
+            .collect::<Vec<_>>()
+            .join(" / ")
+    }
 }
 
 #[serde_typetag]
@@ -439,7 +483,10 @@ impl DualAxislike for VirtualDPad {
         let down = self.down.value(input_store, gamepad);
         let left = self.left.value(input_store, gamepad);
         let right = self.right.value(input_store, gamepad);
-        let value = Vec2::new(right - left, up - down);
+        let mut value = Vec2::new(right - left, up - down);
+        if self.normalized {
+            value = value.clamp_length_max(1.0);
+        }
         self.processors
             .iter()
             .fold(value, |value, processor| processor.process(value))
@@ -465,6 +512,12 @@ impl DualAxislike for VirtualDPad {
             self.up.set_value_as_gamepad(world, y, gamepad);
         }
     }
+
+    /// Returns the processors applied to the value of this input, in the order they are applied.
+    #[inline]
+    fn processors(&self) -> &[DualAxisProcessor] {
+        &self.processors
+    }
 }
 
 impl WithDualAxisProcessingPipelineExt for VirtualDPad {
@@ -499,6 +552,40 @@ impl WithDualAxisProcessingPipelineExt for VirtualDPad {
 /// - `-1.0` if only the negative button is currently pressed (Down/Left/Forward).
 /// - `1.0` if only the positive button is currently pressed (Up/Right/Backward).
 /// - `0.0` if neither button is pressed, or both are pressed simultaneously.
+///
+/// # Value Processing
+///
+/// Each axis has its own pipeline of [`AxisProcessor`]s,
+/// configured using [`VirtualDPad3D::with_processor_x`] and its siblings,
+/// or using shortcuts like [`VirtualDPad3D::with_sensitivity`] for all three axes at once.
+/// The combined value is then passed through a pipeline of [`TripleAxisProcessor`]s,
+/// configured using [`WithTripleAxisProcessingPipelineExt`].
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy::input::InputPlugin;
+/// use leafwing_input_manager::prelude::*;
+/// use leafwing_input_manager::plugin::CentralInputStorePlugin;
+/// use leafwing_input_manager::prelude::updating::CentralInputStore;
+///
+/// let mut app = App::new();
+/// app.add_plugins((InputPlugin, CentralInputStorePlugin));
+///
+/// // Fly using WASD, with Q and E for descending and ascending
+/// let input = VirtualDPad3D::wasd_qe();
+///
+/// KeyCode::KeyW.press(app.world_mut());
+/// KeyCode::KeyE.press(app.world_mut());
+/// app.update();
+/// let input_store = app.world().resource::<CentralInputStore>();
+/// let value = input.axis_triple(input_store, Entity::PLACEHOLDER);
+/// assert_eq!(value, Vec3::new(0.0, 1.0, -1.0));
+///
+/// // Each axis can be scaled independently
+/// let scaled = input.with_sensitivity(Vec3::new(1.0, 0.5, 2.0));
+/// let value = scaled.axis_triple(input_store, Entity::PLACEHOLDER);
+/// assert_eq!(value, Vec3::new(0.0, 0.5, -2.0));
+/// ```
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
 #[must_use]
 pub struct VirtualDPad3D {
@@ -519,6 +606,23 @@ pub struct VirtualDPad3D {
 
     /// The button for the backward direction.
     pub backward: Box<dyn Buttonlike>,
+
+    /// A processing pipeline that handles the values of the X-axis.
+    #[serde(default)]
+    pub processors_x: Vec<AxisProcessor>,
+
+    /// A processing pipeline that handles the values of the Y-axis.
+    #[serde(default)]
+    pub processors_y: Vec<AxisProcessor>,
+
+    /// A processing pipeline that handles the values of the Z-axis.
+    #[serde(default)]
+    pub processors_z: Vec<AxisProcessor>,
+
+    /// A processing pipeline that handles the combined triple-axis value,
+    /// applied after the per-axis pipelines.
+    #[serde(default)]
+    pub processors: Vec<TripleAxisProcessor>,
 }
 
 impl VirtualDPad3D {
@@ -540,8 +644,81 @@ impl VirtualDPad3D {
             right: Box::new(right),
             forward: Box::new(forward),
             backward: Box::new(backward),
+            processors_x: Vec::new(),
+            processors_y: Vec::new(),
+            processors_z: Vec::new(),
+            processors: Vec::new(),
         }
     }
+
+    /// The [`VirtualDPad3D`] using the common WASD key mappings for horizontal movement,
+    /// along with Q and E for vertical movement.
+    ///
+    /// - [`KeyCode::KeyE`] for upward direction.
+    /// - [`KeyCode::KeyQ`] for downward direction.
+    /// - [`KeyCode::KeyA`] for leftward direction.
+    /// - [`KeyCode::KeyD`] for rightward direction.
+    /// - [`KeyCode::KeyW`] for forward direction.
+    /// - [`KeyCode::KeyS`] for backward direction.
+    #[cfg(feature = "keyboard")]
+    #[inline]
+    pub fn wasd_qe() -> Self {
+        Self::new(
+            KeyCode::KeyE,
+            KeyCode::KeyQ,
+            KeyCode::KeyA,
+            KeyCode::KeyD,
+            KeyCode::KeyW,
+            KeyCode::KeyS,
+        )
+    }
+
+    /// Appends the given [`AxisProcessor`] as the next processing step for the X-axis.
+    #[inline]
+    pub fn with_processor_x(mut self, processor: impl Into<AxisProcessor>) -> Self {
+        self.processors_x.push(processor.into());
+        self
+    }
+
+    /// Appends the given [`AxisProcessor`] as the next processing step for the Y-axis.
+    #[inline]
+    pub fn with_processor_y(mut self, processor: impl Into<AxisProcessor>) -> Self {
+        self.processors_y.push(processor.into());
+        self
+    }
+
+    /// Appends the given [`AxisProcessor`] as the next processing step for the Z-axis.
+    #[inline]
+    pub fn with_processor_z(mut self, processor: impl Into<AxisProcessor>) -> Self {
+        self.processors_z.push(processor.into());
+        self
+    }
+
+    /// Appends an [`AxisProcessor::Sensitivity`] processor to each axis,
+    /// multiplying the value of each axis by the corresponding component of `sensitivity`.
+    #[inline]
+    pub fn with_sensitivity(self, sensitivity: Vec3) -> Self {
+        self.with_processor_x(AxisProcessor::Sensitivity(sensitivity.x))
+            .with_processor_y(AxisProcessor::Sensitivity(sensitivity.y))
+            .with_processor_z(AxisProcessor::Sensitivity(sensitivity.z))
+    }
+
+    /// Appends a symmetric [`AxisDeadZone`] to each axis,
+    /// using the corresponding component of `threshold` as the deadzone size of each axis.
+    ///
+    /// # Requirements
+    ///
+    /// - Each component of `threshold` >= `0.0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the requirements aren't met.
+    #[inline]
+    pub fn with_deadzone(self, threshold: Vec3) -> Self {
+        self.with_processor_x(AxisDeadZone::symmetric(threshold.x))
+            .with_processor_y(AxisDeadZone::symmetric(threshold.y))
+            .with_processor_z(AxisDeadZone::symmetric(threshold.z))
+    }
 }
 
 impl UserInput for VirtualDPad3D {
@@ -563,21 +740,51 @@ impl UserInput for VirtualDPad3D {
             self.backward.clone(),
         ])
     }
+
+    /// Returns the names of the up, left, down, right, forward and backward buttons joined by `" / "`.
+    fn display_name(&self) -> String {
+        [
+            &self.up,
+            &self.left,
+            &self.down,
+            &self.right,
+            &self.forward,
+            &self.backward,
+        ]
+        .iter()
+        .map(|button| button.display_name())
+        .collect::<Vec<_>>()
+        .join(" / ")
+    }
 }
 
 #[serde_typetag]
 impl TripleAxislike for VirtualDPad3D {
-    /// Retrieves the current X, Y, and Z values of this D-pad.
+    /// Retrieves the current X, Y, and Z values of this D-pad after processing each axis by its associated processors,
+    /// and then processing the combined value by the triple-axis processors.
     #[must_use]
     #[inline]
     fn axis_triple(&self, input_store: &CentralInputStore, gamepad: Entity) -> Vec3 {
+        fn process(processors: &[AxisProcessor], value: f32) -> f32 {
+            processors
+                .iter()
+                .fold(value, |value, processor| processor.process(value))
+        }
+
         let up = self.up.value(input_store, gamepad);
         let down = self.down.value(input_store, gamepad);
         let left = self.left.value(input_store, gamepad);
         let right = self.right.value(input_store, gamepad);
         let forward = self.forward.value(input_store, gamepad);
         let backward = self.backward.value(input_store, gamepad);
-        Vec3::new(right - left, up - down, backward - forward)
+        let value = Vec3::new(
+            process(&self.processors_x, right - left),
+            process(&self.processors_y, up - down),
+            process(&self.processors_z, backward - forward),
+        );
+        self.processors
+            .iter()
+            .fold(value, |value, processor| processor.process(value))
     }
 
     /// Sets the value of corresponding button on each axis based on the given `value`.
@@ -606,6 +813,35 @@ impl TripleAxislike for VirtualDPad3D {
             self.backward.set_value_as_gamepad(world, z, gamepad);
         }
     }
+
+    /// Returns the processors applied to the value of this input, in the order they are applied.
+    #[inline]
+    fn processors(&self) -> &[TripleAxisProcessor] {
+        &self.processors
+    }
+}
+
+impl WithTripleAxisProcessingPipelineExt for VirtualDPad3D {
+    #[inline]
+    fn reset_processing_pipeline(mut self) -> Self {
+        self.processors.clear();
+        self
+    }
+
+    #[inline]
+    fn replace_processing_pipeline(
+        mut self,
+        processors: impl IntoIterator<Item = TripleAxisProcessor>,
+    ) -> Self {
+        self.processors = processors.into_iter().collect();
+        self
+    }
+
+    #[inline]
+    fn with_processor(mut self, processor: impl Into<TripleAxisProcessor>) -> Self {
+        self.processors.push(processor.into());
+        self
+    }
 }
 
 #[cfg(feature = "keyboard")]
@@ -689,4 +925,111 @@ mod tests {
         assert_eq!(xy.axis_pair(inputs, gamepad), Vec2::new(0.0, 0.0));
         assert_eq!(xyz.axis_triple(inputs, gamepad), Vec3::new(0.0, 0.0, 1.0));
     }
+
+    #[test]
+    fn test_virtual_dpad_normalization() {
+        let raw = VirtualDPad::arrow_keys();
+        let normalized = VirtualDPad::arrow_keys().normalized();
+        let gamepad = Entity::PLACEHOLDER;
+
+        // Cardinal directions are unaffected
+        let mut app = test_app();
+        KeyCode::ArrowUp.press(app.world_mut());
+        app.update();
+        let inputs = app.world().resource::<CentralInputStore>();
+
+        assert_eq!(raw.axis_pair(inputs, gamepad), Vec2::Y);
+        assert_eq!(normalized.axis_pair(inputs, gamepad), Vec2::Y);
+
+        // Diagonals are clamped to unit length
+        let mut app = test_app();
+        KeyCode::ArrowUp.press(app.world_mut());
+        KeyCode::ArrowRight.press(app.world_mut());
+        app.update();
+        let inputs = app.world().resource::<CentralInputStore>();
+
+        let raw_value = raw.axis_pair(inputs, gamepad);
+        assert_eq!(raw_value, Vec2::ONE);
+        assert!((raw_value.length() - 2.0_f32.sqrt()).abs() < f32::EPSILON);
+
+        let normalized_value = normalized.axis_pair(inputs, gamepad);
+        assert!((normalized_value.length() - 1.0).abs() < 1e-6);
+        assert_eq!(normalized_value.x, normalized_value.y);
+    }
+
+    #[test]
+    fn test_virtual_dpad_3d_processing() {
+        let input = VirtualDPad3D::wasd_qe();
+        let gamepad = Entity::PLACEHOLDER;
+
+        let mut app = test_app();
+        KeyCode::KeyD.press(app.world_mut());
+        KeyCode::KeyE.press(app.world_mut());
+        KeyCode::KeyS.press(app.world_mut());
+        app.update();
+        let inputs = app.world().resource::<CentralInputStore>();
+
+        assert_eq!(input.axis_triple(inputs, gamepad), Vec3::ONE);
+
+        // Each axis scales independently
+        let scaled = input.clone().with_sensitivity(Vec3::new(2.0, 0.5, -1.0));
+        assert_eq!(
+            scaled.axis_triple(inputs, gamepad),
+            Vec3::new(2.0, 0.5, -1.0)
+        );
+
+        let scaled_x = input
+            .clone()
+            .with_processor_x(AxisProcessor::Sensitivity(3.0));
+        assert_eq!(
+            scaled_x.axis_triple(inputs, gamepad),
+            Vec3::new(3.0, 1.0, 1.0)
+        );
+
+        let scaled_z = input
+            .clone()
+            .with_processor_z(AxisProcessor::Sensitivity(0.0));
+        assert_eq!(
+            scaled_z.axis_triple(inputs, gamepad),
+            Vec3::new(1.0, 1.0, 0.0)
+        );
+
+        // Triple-axis processors run on the combined value
+        let bounded = input.clone().with_sphere_bounds(1.0).inverted_y();
+        let expected = Vec3::new(1.0, -1.0, 1.0).normalize();
+        assert!((bounded.axis_triple(inputs, gamepad) - expected).length() <= 0.00001);
+        assert_eq!(
+            bounded
+                .reset_processing_pipeline()
+                .axis_triple(inputs, gamepad),
+            Vec3::ONE
+        );
+
+        // Deadzones only affect their own axis
+        #[cfg(feature = "gamepad")]
+        {
+            use crate::buttonlike::ButtonValue;
+            use crate::user_input::gamepad::SpecificGamepadButton;
+
+            let button = |button| SpecificGamepadButton::new(gamepad, button);
+            let input = VirtualDPad3D::new(
+                button(GamepadButton::DPadUp),
+                button(GamepadButton::DPadDown),
+                button(GamepadButton::DPadLeft),
+                button(GamepadButton::DPadRight),
+                button(GamepadButton::LeftTrigger),
+                button(GamepadButton::RightTrigger),
+            )
+            .with_deadzone(Vec3::new(0.5, 0.0, 0.0));
+
+            let mut inputs = CentralInputStore::default();
+            for direction in [GamepadButton::DPadRight, GamepadButton::DPadUp] {
+                inputs.update_buttonlike(button(direction), ButtonValue::new(true, 0.2));
+            }
+            assert_eq!(
+                input.axis_triple(&inputs, gamepad),
+                Vec3::new(0.0, 0.2, 0.0)
+            );
+        }
+    }
 }
@@ -1,5 +1,5 @@
 use bevy::{input::InputPlugin, prelude::*};
-use leafwing_input_manager::action_diff::{ActionDiff, ActionDiffEvent};
+use leafwing_input_manager::action_diff::{ActionDiff, ActionDiffEpsilon, ActionDiffEvent};
 use leafwing_input_manager::{
     prelude::*,
     systems::{generate_action_diffs, generate_action_diffs_filtered},
@@ -100,6 +100,10 @@ fn assert_action_diff_received(app: &mut App, action_diff_event: ActionDiffEvent
         ActionDiff::Released { action } => {
             assert!(action_state.released(&action));
         }
+        ActionDiff::Consumed { action } => {
+            assert!(action_state.released(&action));
+            assert!(action_state.consumed(&action));
+        }
         ActionDiff::AxisChanged { action, value } => {
             assert_eq!(action_state.value(&action), value);
         }
@@ -144,6 +148,9 @@ fn generate_button_action_diffs() {
             ActionDiff::Released { .. } => {
                 panic!("Expected a `Pressed` variant got a `Released` variant")
             }
+            ActionDiff::Consumed { .. } => {
+                panic!("Expected a `Pressed` variant got a `Consumed` variant")
+            }
             ActionDiff::AxisChanged { .. } => {
                 panic!("Expected a `Pressed` variant got an `AxisChanged` variant")
             }
@@ -180,6 +187,9 @@ fn generate_button_action_diffs() {
             ActionDiff::Released { .. } => {
                 panic!("Expected a `Pressed` variant got a `Released` variant")
             }
+            ActionDiff::Consumed { .. } => {
+                panic!("Expected a `Pressed` variant got a `Consumed` variant")
+            }
             ActionDiff::AxisChanged { .. } => {
                 panic!("Expected a `Pressed` variant got an `AxisChanged` variant")
             }
@@ -211,6 +221,9 @@ fn generate_button_action_diffs() {
             ActionDiff::Pressed { .. } => {
                 panic!("Expected a `Released` variant got a `Pressed` variant")
             }
+            ActionDiff::Consumed { .. } => {
+                panic!("Expected a `Released` variant got a `Consumed` variant")
+            }
             ActionDiff::AxisChanged { .. } => {
                 panic!("Expected a `Released` variant got an `AxisChanged` variant")
             }
@@ -255,6 +268,9 @@ fn generate_axis_action_diffs() {
             ActionDiff::Released { .. } => {
                 panic!("Expected a `DualAxisChanged` variant got a `Released` variant")
             }
+            ActionDiff::Consumed { .. } => {
+                panic!("Expected a `DualAxisChanged` variant got a `Consumed` variant")
+            }
             ActionDiff::Pressed { .. } => {
                 panic!("Expected a `DualAxisChanged` variant got a `Pressed` variant")
             }
@@ -291,6 +307,9 @@ fn generate_axis_action_diffs() {
             ActionDiff::Released { .. } => {
                 panic!("Expected a `DualAxisChanged` variant got a `Released` variant")
             }
+            ActionDiff::Consumed { .. } => {
+                panic!("Expected a `DualAxisChanged` variant got a `Consumed` variant")
+            }
             ActionDiff::Pressed { .. } => {
                 panic!("Expected a `DualAxisChanged` variant got a `Pressed` variant")
             }
@@ -341,6 +360,9 @@ fn generate_filtered_binary_action_diffs() {
             ActionDiff::Released { .. } => {
                 panic!("Expected a `Pressed` variant got a `Released` variant")
             }
+            ActionDiff::Consumed { .. } => {
+                panic!("Expected a `Pressed` variant got a `Consumed` variant")
+            }
             ActionDiff::AxisChanged { .. } => {
                 panic!("Expected a `Pressed` variant got a `ValueChanged` variant")
             }
@@ -458,3 +480,298 @@ fn process_axis_action_diff() {
 
     assert_action_diff_received(&mut app, action_diff_event);
 }
+
+#[test]
+fn small_axis_changes_are_coalesced() {
+    let mut app = create_app();
+    let entity = app
+        .world_mut()
+        .query_filtered::<Entity, With<ActionState<Action>>>()
+        .single(app.world());
+    app.add_systems(PostUpdate, generate_action_diffs::<Action>);
+
+    // Drains the action diffs sent this frame, counting (axis, dual-axis, button) diffs
+    let drain_diffs = |app: &mut App| {
+        let mut counts = (0, 0, 0);
+        for diff in get_events_mut::<ActionDiffEvent<Action>>(app)
+            .drain()
+            .flat_map(|event| event.action_diffs)
+        {
+            match diff {
+                ActionDiff::AxisChanged { .. } => counts.0 += 1,
+                ActionDiff::DualAxisChanged { .. } => counts.1 += 1,
+                ActionDiff::Pressed { .. }
+                | ActionDiff::Released { .. }
+                | ActionDiff::Consumed { .. } => counts.2 += 1,
+                ActionDiff::TripleAxisChanged { .. } => unreachable!(),
+            }
+        }
+        counts
+    };
+
+    // Slowly drift both axes, while toggling the button every frame
+    let mut totals = (0, 0, 0);
+    for frame in 1..=100 {
+        let mut action_state = app
+            .world_mut()
+            .query::<&mut ActionState<Action>>()
+            .get_mut(app.world_mut(), entity)
+            .unwrap();
+        action_state.set_value(&Action::Axis, frame as f32 * 3e-5);
+        action_state.set_axis_pair(&Action::DualAxis, Vec2::new(0.0, frame as f32 * 3e-5));
+        if frame % 2 == 0 {
+            action_state.press(&Action::Button);
+        } else {
+            action_state.release(&Action::Button);
+        }
+        app.update();
+
+        let counts = drain_diffs(&mut app);
+        totals = (
+            totals.0 + counts.0,
+            totals.1 + counts.1,
+            totals.2 + counts.2,
+        );
+    }
+
+    // With the default epsilon of 1e-4, the axes are sent as soon as they leave zero,
+    // and then only once they have drifted far enough, which happens every 4 frames
+    assert_eq!(totals.0, 25);
+    assert_eq!(totals.1, 25);
+    // But every press and release is sent
+    assert_eq!(totals.2, 99);
+
+    // Without an epsilon, every change is sent,
+    // and the dual axis catches up with the drift that hadn't been sent yet
+    app.insert_resource(ActionDiffEpsilon(0.0));
+    let mut totals = (0, 0);
+    for frame in 101..=110 {
+        let mut action_state = app
+            .world_mut()
+            .query::<&mut ActionState<Action>>()
+            .get_mut(app.world_mut(), entity)
+            .unwrap();
+        action_state.set_value(&Action::Axis, frame as f32 * 3e-5);
+        app.update();
+
+        let counts = drain_diffs(&mut app);
+        totals = (totals.0 + counts.0, totals.1 + counts.1);
+    }
+    assert_eq!(totals, (10, 1));
+}
+
+#[test]
+fn consumed_actions_are_sent_exactly_once() {
+    let mut app = create_app();
+    let entity = app
+        .world_mut()
+        .query_filtered::<Entity, With<ActionState<Action>>>()
+        .single(app.world());
+    app.world_mut()
+        .entity_mut(entity)
+        .insert(InputMap::new([(Action::Button, KeyCode::Space)]));
+    app.add_systems(PostUpdate, generate_action_diffs::<Action>);
+
+    // Mirrors the action state on a remote client, counting the (pressed, released, consumed) diffs it receives
+    let mut client_action_state = ActionState::<Action>::default();
+    let sync_client = |app: &mut App, client_action_state: &mut ActionState<Action>| {
+        let mut counts = (0, 0, 0);
+        for diff in get_events_mut::<ActionDiffEvent<Action>>(app)
+            .drain()
+            .flat_map(|event| event.action_diffs)
+        {
+            match diff {
+                ActionDiff::Pressed { .. } => counts.0 += 1,
+                ActionDiff::Released { .. } => counts.1 += 1,
+                ActionDiff::Consumed { .. } => counts.2 += 1,
+                _ => (),
+            }
+            client_action_state.apply_diff(&diff);
+        }
+        counts
+    };
+    let consume = |app: &mut App| {
+        app.world_mut()
+            .query::<&mut ActionState<Action>>()
+            .get_mut(app.world_mut(), entity)
+            .unwrap()
+            .consume(&Action::Button);
+    };
+
+    // Frame 1: the ability is pressed
+    KeyCode::Space.press(app.world_mut());
+    app.update();
+    assert_eq!(sync_client(&mut app, &mut client_action_state), (1, 0, 0));
+    assert!(client_action_state.pressed(&Action::Button));
+
+    // Frame 2: the ability is consumed while its key is still held,
+    // releasing and consuming it on the client immediately
+    consume(&mut app);
+    app.update();
+    assert_eq!(sync_client(&mut app, &mut client_action_state), (0, 0, 1));
+    assert!(client_action_state.released(&Action::Button));
+    assert!(client_action_state.consumed(&Action::Button));
+
+    // Frame 3: holding the key does not press the ability again
+    app.update();
+    assert_eq!(sync_client(&mut app, &mut client_action_state), (0, 0, 0));
+
+    // Frame 4: physically releasing the key does not send a release
+    KeyCode::Space.release(app.world_mut());
+    app.update();
+    assert_eq!(sync_client(&mut app, &mut client_action_state), (0, 0, 0));
+    assert!(client_action_state.released(&Action::Button));
+
+    // Frame 5: the next genuine press is sent as usual, clearing the consumed state on the client
+    KeyCode::Space.press(app.world_mut());
+    app.update();
+    assert_eq!(sync_client(&mut app, &mut client_action_state), (1, 0, 0));
+    assert!(client_action_state.pressed(&Action::Button));
+    assert!(!client_action_state.consumed(&Action::Button));
+}
+
+#[test]
+fn consumed_diff_round_trip() {
+    let previous = ActionState::<Action>::default();
+    let mut current = previous.clone();
+    current.press(&Action::Button);
+
+    // The press and the consumption are sent as separate diffs
+    let mut replica = previous.clone();
+    replica.apply_diffs(&current.generate_diffs(&previous));
+    assert!(replica.pressed(&Action::Button));
+
+    let previous = current.clone();
+    current.consume(&Action::Button);
+    let diffs = current.generate_diffs(&previous);
+    assert_eq!(
+        diffs,
+        vec![ActionDiff::Consumed {
+            action: Action::Button
+        }]
+    );
+
+    replica.apply_diffs(&diffs);
+    assert_eq!(
+        replica.released(&Action::Button),
+        current.released(&Action::Button)
+    );
+    assert_eq!(
+        replica.consumed(&Action::Button),
+        current.consumed(&Action::Button)
+    );
+    assert!(replica.consumed(&Action::Button));
+}
+
+#[test]
+fn axis_changes_to_and_from_zero_are_sent() {
+    let mut app = create_app();
+    let entity = app
+        .world_mut()
+        .query_filtered::<Entity, With<ActionState<Action>>>()
+        .single(app.world());
+    app.add_systems(PostUpdate, generate_action_diffs::<Action>);
+
+    let send_axis = |app: &mut App, value: f32| {
+        let mut action_state = app
+            .world_mut()
+            .query::<&mut ActionState<Action>>()
+            .get_mut(app.world_mut(), entity)
+            .unwrap();
+        action_state.set_value(&Action::Axis, value);
+        app.update();
+
+        get_events_mut::<ActionDiffEvent<Action>>(app)
+            .drain()
+            .flat_map(|event| event.action_diffs)
+            .filter_map(|diff| match diff {
+                ActionDiff::AxisChanged { value, .. } => Some(value),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+    };
+
+    // Moving away from zero is sent, even when the change is smaller than the epsilon
+    assert_eq!(send_axis(&mut app, 5e-5), vec![5e-5]);
+    assert_eq!(send_axis(&mut app, 6e-5), Vec::<f32>::new());
+
+    // As is coming back to rest
+    assert_eq!(send_axis(&mut app, 0.0), vec![0.0]);
+    assert_eq!(send_axis(&mut app, 0.0), Vec::<f32>::new());
+}
+
+#[test]
+fn server_plugin_applies_action_diffs_without_input_resources() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputManagerPlugin::<Action>::server()))
+        .add_systems(Startup, spawn_test_entity)
+        .init_resource::<ActionState<Action>>();
+    app.update();
+
+    // No input collection happens on the server
+    assert!(!app.world().contains_resource::<ButtonInput<KeyCode>>());
+    assert!(!app
+        .world()
+        .contains_resource::<updating::CentralInputStore>());
+
+    let entity = app
+        .world_mut()
+        .query_filtered::<Entity, With<ActionState<Action>>>()
+        .single(app.world());
+
+    send_action_diff(
+        &mut app,
+        ActionDiffEvent {
+            owner: Some(entity),
+            action_diffs: vec![
+                ActionDiff::Pressed {
+                    action: Action::Button,
+                    value: 1.0,
+                },
+                ActionDiff::DualAxisChanged {
+                    action: Action::DualAxis,
+                    axis_pair: Vec2::new(0.5, -0.5),
+                },
+            ],
+        },
+    );
+    send_action_diff(
+        &mut app,
+        ActionDiffEvent {
+            owner: None,
+            action_diffs: vec![ActionDiff::AxisChanged {
+                action: Action::Axis,
+                value: 0.25,
+            }],
+        },
+    );
+    app.update();
+
+    let action_state = app.world().get::<ActionState<Action>>(entity).unwrap();
+    assert!(action_state.just_pressed(&Action::Button));
+    assert_eq!(
+        action_state.axis_pair(&Action::DualAxis),
+        Vec2::new(0.5, -0.5)
+    );
+    let global_action_state = app.world().resource::<ActionState<Action>>();
+    assert_eq!(global_action_state.value(&Action::Axis), 0.25);
+
+    // The diffs are reconstructed state, so the action stays pressed until released
+    app.update();
+    let action_state = app.world().get::<ActionState<Action>>(entity).unwrap();
+    assert!(action_state.pressed(&Action::Button));
+    assert!(!action_state.just_pressed(&Action::Button));
+
+    send_action_diff(
+        &mut app,
+        ActionDiffEvent {
+            owner: Some(entity),
+            action_diffs: vec![ActionDiff::Released {
+                action: Action::Button,
+            }],
+        },
+    );
+    app.update();
+    let action_state = app.world().get::<ActionState<Action>>(entity).unwrap();
+    assert!(action_state.just_released(&Action::Button));
+}
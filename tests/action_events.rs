@@ -0,0 +1,162 @@
+#![cfg(feature = "keyboard")]
+
+use bevy::input::InputPlugin;
+use bevy::prelude::*;
+use leafwing_input_manager::action_events::{ActionPressed, ActionReleased};
+use leafwing_input_manager::prelude::*;
+
+#[derive(Actionlike, Clone, Copy, Debug, Reflect, PartialEq, Eq, Hash)]
+enum Action {
+    Jump,
+}
+
+// Records every event sent, in the order they were read
+#[derive(Resource, Default, Debug)]
+struct ReceivedEvents {
+    pressed: Vec<ActionPressed<Action>>,
+    released: Vec<ActionReleased<Action>>,
+}
+
+fn record_events(
+    mut pressed: EventReader<ActionPressed<Action>>,
+    mut released: EventReader<ActionReleased<Action>>,
+    mut received: ResMut<ReceivedEvents>,
+) {
+    received.pressed.extend(pressed.read().cloned());
+    received.released.extend(released.read().cloned());
+}
+
+// Counts the events observed on the player entity
+#[derive(Resource, Default, Debug, PartialEq)]
+struct ObservedCount {
+    pressed: usize,
+    released: usize,
+}
+
+fn test_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(InputPlugin)
+        .add_plugins(InputManagerPlugin::<Action>::default().send_action_events())
+        .init_resource::<ReceivedEvents>()
+        .init_resource::<ObservedCount>()
+        .add_systems(Update, record_events);
+    app
+}
+
+#[test]
+fn action_events_are_sent_for_component() {
+    let mut app = test_app();
+    let player = app
+        .world_mut()
+        .spawn(InputManagerBundle::with_map(InputMap::new([(
+            Action::Jump,
+            KeyCode::Space,
+        )])))
+        .observe(
+            |_trigger: Trigger<ActionPressed<Action>>, mut count: ResMut<ObservedCount>| {
+                count.pressed += 1;
+            },
+        )
+        .observe(
+            |_trigger: Trigger<ActionReleased<Action>>, mut count: ResMut<ObservedCount>| {
+                count.released += 1;
+            },
+        )
+        .id();
+
+    // Nothing is sent while the action stays released
+    app.update();
+    let received = app.world().resource::<ReceivedEvents>();
+    assert!(received.pressed.is_empty());
+    assert!(received.released.is_empty());
+
+    // Pressing sends a single event, targeting the player
+    KeyCode::Space.press(app.world_mut());
+    app.update();
+    let expected_pressed = ActionPressed {
+        entity: Some(player),
+        action: Action::Jump,
+    };
+    let received = app.world().resource::<ReceivedEvents>();
+    assert_eq!(received.pressed, vec![expected_pressed.clone()]);
+    assert!(received.released.is_empty());
+
+    // Holding the action does not send it again
+    app.update();
+    let received = app.world().resource::<ReceivedEvents>();
+    assert_eq!(received.pressed, vec![expected_pressed.clone()]);
+
+    // Releasing sends a single released event
+    KeyCode::Space.release(app.world_mut());
+    app.update();
+    app.update();
+    let received = app.world().resource::<ReceivedEvents>();
+    assert_eq!(received.pressed, vec![expected_pressed]);
+    assert_eq!(
+        received.released,
+        vec![ActionReleased {
+            entity: Some(player),
+            action: Action::Jump,
+        }]
+    );
+
+    // Observers on the entity saw the same events
+    assert_eq!(
+        *app.world().resource::<ObservedCount>(),
+        ObservedCount {
+            pressed: 1,
+            released: 1,
+        }
+    );
+}
+
+#[test]
+fn action_events_are_sent_for_resource() {
+    let mut app = test_app();
+    app.init_resource::<ActionState<Action>>()
+        .insert_resource(InputMap::<Action>::new([(Action::Jump, KeyCode::Space)]));
+    app.add_observer(
+        |trigger: Trigger<ActionPressed<Action>>, mut count: ResMut<ObservedCount>| {
+            assert_eq!(trigger.entity(), Entity::PLACEHOLDER);
+            count.pressed += 1;
+        },
+    );
+
+    KeyCode::Space.press(app.world_mut());
+    app.update();
+    KeyCode::Space.release(app.world_mut());
+    app.update();
+
+    let received = app.world().resource::<ReceivedEvents>();
+    assert_eq!(
+        received.pressed,
+        vec![ActionPressed {
+            entity: None,
+            action: Action::Jump,
+        }]
+    );
+    assert_eq!(
+        received.released,
+        vec![ActionReleased {
+            entity: None,
+            action: Action::Jump,
+        }]
+    );
+    assert_eq!(app.world().resource::<ObservedCount>().pressed, 1);
+}
+
+#[test]
+fn action_events_are_not_sent_by_default() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(InputPlugin)
+        .add_plugins(InputManagerPlugin::<Action>::default());
+
+    assert!(!app
+        .world()
+        .contains_resource::<Events<ActionPressed<Action>>>());
+    assert!(!app
+        .world()
+        .contains_resource::<Events<ActionReleased<Action>>>());
+}
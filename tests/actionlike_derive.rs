@@ -19,18 +19,6 @@ enum SimpleAction {
     Two,
 }
 
-#[derive(Actionlike, Debug, Hash, PartialEq, Eq, Clone, Copy, Reflect)]
-enum UnnamedFieldVariantsAction {
-    Run,
-    Jump(usize),
-}
-
-#[derive(Actionlike, Debug, Hash, PartialEq, Eq, Clone, Copy, Reflect)]
-enum NamedFieldVariantsAction {
-    Run { x: usize, y: usize },
-    Jump,
-}
-
 #[derive(Actionlike, Debug, Hash, PartialEq, Eq, Clone, Copy, Reflect)]
 struct StructAction {
     x: usize,
@@ -39,3 +27,63 @@ struct StructAction {
 
 #[derive(Actionlike, Debug, Hash, PartialEq, Eq, Clone, Copy, Reflect)]
 struct TupleAction(usize, usize);
+
+#[derive(Actionlike, Debug, Hash, PartialEq, Eq, Clone, Copy, Reflect)]
+#[actionlike(Axis, group = "camera")]
+enum GroupedAction {
+    Zoom,
+    #[actionlike(DualAxis)]
+    Pan,
+    #[actionlike(Button, group = "ui")]
+    Screenshot,
+    #[actionlike(group = "ui")]
+    Menu,
+}
+
+#[test]
+fn grouped_actions() {
+    use leafwing_input_manager::InputControlKind;
+
+    assert_eq!(GroupedAction::Zoom.group(), Some("camera"));
+    assert_eq!(GroupedAction::Pan.group(), Some("camera"));
+    assert_eq!(
+        GroupedAction::Pan.input_control_kind(),
+        InputControlKind::DualAxis
+    );
+    assert_eq!(GroupedAction::Screenshot.group(), Some("ui"));
+    assert_eq!(
+        GroupedAction::Screenshot.input_control_kind(),
+        InputControlKind::Button
+    );
+    assert_eq!(GroupedAction::Menu.group(), Some("ui"));
+    assert_eq!(SimpleAction::Zero.group(), None);
+}
+
+#[test]
+fn variants() {
+    assert_eq!(UnitAction::variants().count(), 0);
+    assert_eq!(OneAction::variants().collect::<Vec<_>>(), [OneAction::Jump]);
+    assert_eq!(
+        SimpleAction::variants().collect::<Vec<_>>(),
+        [SimpleAction::Zero, SimpleAction::One, SimpleAction::Two]
+    );
+    assert_eq!(
+        GroupedAction::variants().collect::<Vec<_>>(),
+        [
+            GroupedAction::Zoom,
+            GroupedAction::Pan,
+            GroupedAction::Screenshot,
+            GroupedAction::Menu,
+        ]
+    );
+
+    // Structs can't be enumerated
+    assert_eq!(StructAction::variants().count(), 0);
+    assert_eq!(TupleAction::variants().count(), 0);
+}
+
+#[test]
+fn enums_with_fields_are_rejected() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/actionlike_*.rs");
+}
@@ -0,0 +1,38 @@
+//! Helpers shared between integration tests.
+
+use bevy::input::gamepad::{GamepadConnection, GamepadConnectionEvent};
+use bevy::input::InputPlugin;
+use bevy::prelude::*;
+use bevy::reflect::GetTypeRegistration;
+use leafwing_input_manager::prelude::*;
+
+/// Creates an app handling the actions `A`, with two connected gamepads.
+pub fn create_test_app<A: Actionlike + GetTypeRegistration>() -> (App, Entity, Entity) {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins).add_plugins(InputPlugin);
+    app.add_plugins(InputManagerPlugin::<A>::default());
+
+    let gamepad_1 = app.world_mut().spawn(()).id();
+    let gamepad_2 = app.world_mut().spawn(()).id();
+
+    let mut gamepad_connection_events = app
+        .world_mut()
+        .resource_mut::<Events<GamepadConnectionEvent>>();
+    for (gamepad, name) in [(gamepad_1, "First"), (gamepad_2, "Second")] {
+        gamepad_connection_events.send(GamepadConnectionEvent {
+            gamepad,
+            connection: GamepadConnection::Connected {
+                name: name.to_owned(),
+                vendor_id: None,
+                product_id: None,
+            },
+        });
+    }
+
+    // Ensure the gamepads are picked up
+    app.update();
+    // Flush the gamepad connection events
+    app.update();
+
+    (app, gamepad_1, gamepad_2)
+}
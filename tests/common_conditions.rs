@@ -0,0 +1,173 @@
+#![cfg(feature = "keyboard")]
+
+use bevy::input::InputPlugin;
+use bevy::prelude::*;
+use leafwing_input_manager::common_conditions::{
+    action_just_pressed, action_just_released, action_pressed, entity_action_just_pressed,
+    entity_action_pressed,
+};
+use leafwing_input_manager::prelude::*;
+
+#[derive(Actionlike, Clone, Copy, Debug, Reflect, PartialEq, Eq, Hash)]
+enum Action {
+    Jump,
+}
+
+// Counts how many times each gated system has run
+#[derive(Resource, Default, PartialEq, Debug)]
+struct Runs {
+    pressed: usize,
+    just_pressed: usize,
+    just_released: usize,
+}
+
+fn create_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(InputPlugin)
+        .add_plugins(InputManagerPlugin::<Action>::default())
+        .init_resource::<Runs>()
+        .add_systems(
+            Update,
+            (
+                (|mut runs: ResMut<Runs>| runs.pressed += 1).run_if(action_pressed(Action::Jump)),
+                (|mut runs: ResMut<Runs>| runs.just_pressed += 1)
+                    .run_if(action_just_pressed(Action::Jump)),
+                (|mut runs: ResMut<Runs>| runs.just_released += 1)
+                    .run_if(action_just_released(Action::Jump)),
+            ),
+        );
+    app
+}
+
+#[test]
+fn conditions_follow_action_state() {
+    let mut app = create_app();
+    app.init_resource::<ActionState<Action>>()
+        .insert_resource(InputMap::new([(Action::Jump, KeyCode::Space)]));
+    app.update();
+    assert_eq!(*app.world().resource::<Runs>(), Runs::default());
+
+    // The just pressed condition is only active on the frame of the press
+    KeyCode::Space.press(app.world_mut());
+    app.update();
+    assert_eq!(
+        *app.world().resource::<Runs>(),
+        Runs {
+            pressed: 1,
+            just_pressed: 1,
+            just_released: 0,
+        }
+    );
+
+    app.update();
+    assert_eq!(
+        *app.world().resource::<Runs>(),
+        Runs {
+            pressed: 2,
+            just_pressed: 1,
+            just_released: 0,
+        }
+    );
+
+    KeyCode::Space.release(app.world_mut());
+    app.update();
+    app.update();
+    assert_eq!(
+        *app.world().resource::<Runs>(),
+        Runs {
+            pressed: 2,
+            just_pressed: 1,
+            just_released: 1,
+        }
+    );
+}
+
+#[test]
+fn conditions_without_action_state() {
+    let mut app = create_app();
+
+    // Without an `ActionState` resource, the conditions are never active
+    KeyCode::Space.press(app.world_mut());
+    app.update();
+    app.update();
+    assert_eq!(*app.world().resource::<Runs>(), Runs::default());
+}
+
+// Counts how many times the system gated on each player has run
+#[derive(Resource, Default, PartialEq, Debug)]
+struct PlayerRuns {
+    one: usize,
+    two: usize,
+}
+
+#[test]
+fn entity_conditions() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(InputPlugin)
+        .add_plugins(InputManagerPlugin::<Action>::default())
+        .init_resource::<PlayerRuns>();
+
+    let player_one = app
+        .world_mut()
+        .spawn(InputManagerBundle::with_map(InputMap::new([(
+            Action::Jump,
+            KeyCode::Space,
+        )])))
+        .id();
+    let player_two = app
+        .world_mut()
+        .spawn(InputManagerBundle::with_map(InputMap::new([(
+            Action::Jump,
+            KeyCode::Enter,
+        )])))
+        .id();
+    app.add_systems(
+        Update,
+        (
+            (|mut runs: ResMut<PlayerRuns>| runs.one += 1)
+                .run_if(entity_action_pressed(player_one, Action::Jump)),
+            (|mut runs: ResMut<PlayerRuns>| runs.two += 1)
+                .run_if(entity_action_pressed(player_two, Action::Jump)),
+        ),
+    );
+
+    // Only the system gated on the player pressing the action runs
+    KeyCode::Space.press(app.world_mut());
+    app.update();
+    assert_eq!(
+        *app.world().resource::<PlayerRuns>(),
+        PlayerRuns { one: 1, two: 0 }
+    );
+
+    // Despawned entities never activate their conditions
+    app.world_mut().despawn(player_one);
+    app.update();
+    assert_eq!(
+        *app.world().resource::<PlayerRuns>(),
+        PlayerRuns { one: 1, two: 0 }
+    );
+}
+
+#[test]
+fn entity_conditions_without_action_state() {
+    let mut app = create_app();
+    app.insert_resource(InputMap::new([(Action::Jump, KeyCode::Space)]))
+        .init_resource::<ActionState<Action>>();
+
+    // Entities without an `ActionState` component never activate their conditions,
+    // even if the `ActionState` resource is pressed
+    let entity = app.world_mut().spawn_empty().id();
+    app.add_systems(
+        Update,
+        (|mut runs: ResMut<PlayerRuns>| runs.one += 1)
+            .run_if(entity_action_just_pressed(entity, Action::Jump)),
+    )
+    .init_resource::<PlayerRuns>();
+
+    KeyCode::Space.press(app.world_mut());
+    app.update();
+    assert_eq!(app.world().resource::<Runs>().just_pressed, 1);
+    assert_eq!(*app.world().resource::<PlayerRuns>(), PlayerRuns::default());
+}
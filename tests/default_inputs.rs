@@ -0,0 +1,43 @@
+#![cfg(all(feature = "gamepad", feature = "keyboard", feature = "mouse"))]
+
+use bevy::prelude::*;
+use leafwing_input_manager::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Actionlike, Clone, Copy, Debug, Reflect, PartialEq, Eq, Hash, Serialize, Deserialize)]
+enum Action {
+    Jump,
+    Shoot,
+    #[actionlike(Axis)]
+    Zoom,
+    #[actionlike(DualAxis)]
+    Move,
+}
+
+#[test]
+fn default_inputs_round_trip_without_plugin() {
+    // The input registries are global, so this test lives in its own binary,
+    // where nothing else registers inputs.
+    App::new().register_default_inputs();
+
+    let input_map = InputMap::new([
+        (Action::Jump, KeyCode::Space),
+        (Action::Shoot, KeyCode::KeyF),
+    ])
+    .with(Action::Jump, GamepadButton::South)
+    .with(Action::Shoot, MouseButton::Left)
+    .with(
+        Action::Shoot,
+        ButtonlikeChord::modified(ModifierKey::Control, KeyCode::KeyS),
+    )
+    .with_axis(Action::Zoom, MouseScrollAxis::Y)
+    .with_axis(Action::Zoom, GamepadControlAxis::RIGHT_Y)
+    .with_dual_axis(Action::Move, VirtualDPad::wasd())
+    .with_dual_axis(Action::Move, GamepadStick::LEFT)
+    .with_dual_axis(Action::Move, MouseMove::default());
+
+    let serialized = ron::to_string(&input_map).unwrap();
+    let deserialized: InputMap<Action> = ron::from_str(&serialized).unwrap();
+
+    assert_eq!(deserialized, input_map);
+}
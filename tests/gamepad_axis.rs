@@ -183,6 +183,33 @@ fn gamepad_single_axis_inverted() {
     assert_eq!(action_state.value(&AxislikeTestAction::Y), 1.0);
 }
 
+#[test]
+fn gamepad_dual_axis_inverted_y() {
+    let mut app = test_app();
+    app.insert_resource(
+        InputMap::default()
+            .with_dual_axis(AxislikeTestAction::XY, GamepadStick::right().inverted_y()),
+    );
+
+    // The Y component is negated, while the X component is preserved
+    let input = GamepadStick::RIGHT;
+    input.set_axis_pair(app.world_mut(), Vec2::new(0.5, 0.8));
+    app.update();
+    let action_state = app.world().resource::<ActionState<AxislikeTestAction>>();
+    assert_eq!(
+        action_state.axis_pair(&AxislikeTestAction::XY),
+        Vec2::new(0.5, -0.8)
+    );
+
+    input.set_axis_pair(app.world_mut(), Vec2::new(-0.3, -1.0));
+    app.update();
+    let action_state = app.world().resource::<ActionState<AxislikeTestAction>>();
+    assert_eq!(
+        action_state.axis_pair(&AxislikeTestAction::XY),
+        Vec2::new(-0.3, 1.0)
+    );
+}
+
 #[test]
 fn gamepad_dual_axis_deadzone() {
     let mut app = test_app();
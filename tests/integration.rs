@@ -208,3 +208,78 @@ fn duration() {
         .resource::<ActionState<Action>>()
         .pressed(&Action::PayRespects));
 }
+
+#[test]
+fn triggering_input() {
+    use bevy::input::InputPlugin;
+
+    let mut app = App::new();
+
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(InputPlugin)
+        .add_plugins(InputManagerPlugin::<Action>::default().record_triggering_inputs())
+        .init_resource::<ActionState<Action>>()
+        .insert_resource(InputMap::<Action>::new([
+            (Action::PayRespects, KeyCode::KeyF),
+            (Action::PayRespects, KeyCode::KeyR),
+        ]));
+
+    // Nothing has been pressed yet
+    app.update();
+    let action_state = app.world().resource::<ActionState<Action>>();
+    assert!(action_state
+        .triggering_input(&Action::PayRespects)
+        .is_none());
+
+    // Press F
+    KeyCode::KeyF.press(app.world_mut());
+    app.update();
+    let action_state = app.world().resource::<ActionState<Action>>();
+    assert_eq!(
+        action_state.triggering_input(&Action::PayRespects),
+        Some(&KeyCode::KeyF as &dyn Buttonlike)
+    );
+
+    // The input is kept after release
+    KeyCode::KeyF.release(app.world_mut());
+    app.update();
+    let action_state = app.world().resource::<ActionState<Action>>();
+    assert!(action_state.released(&Action::PayRespects));
+    assert_eq!(
+        action_state.triggering_input(&Action::PayRespects),
+        Some(&KeyCode::KeyF as &dyn Buttonlike)
+    );
+
+    // Press R
+    KeyCode::KeyR.press(app.world_mut());
+    app.update();
+    let action_state = app.world().resource::<ActionState<Action>>();
+    assert_eq!(
+        action_state.triggering_input(&Action::PayRespects),
+        Some(&KeyCode::KeyR as &dyn Buttonlike)
+    );
+}
+
+#[test]
+fn triggering_input_not_recorded_by_default() {
+    use bevy::input::InputPlugin;
+
+    let mut app = App::new();
+
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(InputPlugin)
+        .add_plugins(InputManagerPlugin::<Action>::default())
+        .add_systems(Startup, spawn_player);
+
+    KeyCode::KeyF.press(app.world_mut());
+    app.update();
+
+    let action_state = app
+        .world_mut()
+        .query_filtered::<&ActionState<Action>, With<Player>>()
+        .single(app.world());
+    assert!(action_state.pressed(&Action::PayRespects));
+    assert!(action_state
+        .triggering_input(&Action::PayRespects)
+        .is_none());
+}
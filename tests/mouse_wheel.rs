@@ -137,3 +137,61 @@ fn mouse_scroll_dual_axis() {
         Vec2::new(5.0, 0.0)
     );
 }
+
+#[test]
+fn mouse_scroll_reset_on_focus_change() {
+    use bevy::input::mouse::AccumulatedMouseScroll;
+    use bevy::window::WindowFocused;
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(InputPlugin)
+        .add_plugins(
+            InputManagerPlugin::<AxislikeTestAction>::default().reset_mouse_on_focus_change(),
+        )
+        .init_resource::<ActionState<AxislikeTestAction>>()
+        .insert_resource(InputMap::default().with_axis(AxislikeTestAction::Y, MouseScrollAxis::Y));
+    let window = app.world_mut().spawn_empty().id();
+
+    // Scrolling works as usual while focused
+    MouseScrollAxis::Y.set_value(app.world_mut(), 2.0);
+    app.update();
+    let action_state = app.world().resource::<ActionState<AxislikeTestAction>>();
+    assert_eq!(action_state.value(&AxislikeTestAction::Y), 2.0);
+
+    // Scrolling on the frame focus is lost is discarded
+    app.world_mut().send_event(WindowFocused {
+        window,
+        focused: false,
+    });
+    MouseScrollAxis::Y.set_value(app.world_mut(), 5.0);
+    app.update();
+    assert_eq!(
+        app.world().resource::<AccumulatedMouseScroll>().delta,
+        Vec2::ZERO
+    );
+    let action_state = app.world().resource::<ActionState<AxislikeTestAction>>();
+    assert_eq!(action_state.value(&AxislikeTestAction::Y), 0.0);
+
+    // As is scrolling while unfocused
+    MouseScrollAxis::Y.set_value(app.world_mut(), 5.0);
+    app.update();
+    let action_state = app.world().resource::<ActionState<AxislikeTestAction>>();
+    assert_eq!(action_state.value(&AxislikeTestAction::Y), 0.0);
+
+    // And the stale scrolling flushed when focus is regained
+    app.world_mut().send_event(WindowFocused {
+        window,
+        focused: true,
+    });
+    MouseScrollAxis::Y.set_value(app.world_mut(), 50.0);
+    app.update();
+    let action_state = app.world().resource::<ActionState<AxislikeTestAction>>();
+    assert_eq!(action_state.value(&AxislikeTestAction::Y), 0.0);
+
+    // Scrolling works again afterwards
+    MouseScrollAxis::Y.set_value(app.world_mut(), 3.0);
+    app.update();
+    let action_state = app.world().resource::<ActionState<AxislikeTestAction>>();
+    assert_eq!(action_state.value(&AxislikeTestAction::Y), 3.0);
+}
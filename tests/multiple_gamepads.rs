@@ -1,53 +1,18 @@
 #![cfg(feature = "gamepad")]
 
-use bevy::input::gamepad::{
-    GamepadConnection, GamepadConnectionEvent, GamepadEvent, RawGamepadEvent,
-};
+use bevy::input::gamepad::{GamepadConnection, GamepadConnectionEvent, RawGamepadEvent};
 use bevy::input::InputPlugin;
 use bevy::prelude::*;
 use leafwing_input_manager::prelude::*;
 
+mod common;
+use common::create_test_app;
+
 #[derive(Actionlike, Clone, Copy, Debug, Reflect, PartialEq, Eq, Hash)]
 enum MyAction {
     Jump,
 }
 
-fn create_test_app() -> App {
-    let mut app = App::new();
-    app.add_plugins(MinimalPlugins).add_plugins(InputPlugin);
-    app.add_plugins(InputManagerPlugin::<MyAction>::default());
-
-    let gamepad_1 = app.world_mut().spawn(()).id();
-    let gamepad_2 = app.world_mut().spawn(()).id();
-
-    let mut gamepad_events = app.world_mut().resource_mut::<Events<GamepadEvent>>();
-    gamepad_events.send(GamepadEvent::Connection(GamepadConnectionEvent {
-        // Must be consistent with mocked events
-        gamepad: gamepad_1,
-        connection: GamepadConnection::Connected {
-            name: "FirstController".into(),
-            vendor_id: None,
-            product_id: None,
-        },
-    }));
-    gamepad_events.send(GamepadEvent::Connection(GamepadConnectionEvent {
-        // Must be consistent with mocked events
-        gamepad: gamepad_2,
-        connection: GamepadConnection::Connected {
-            name: "SecondController".into(),
-            vendor_id: None,
-            product_id: None,
-        },
-    }));
-
-    // Ensure the gamepads are picked up
-    app.update();
-    // Flush the gamepad connection events
-    app.update();
-
-    app
-}
-
 fn jump_button_press_event(gamepad: Entity) -> RawGamepadEvent {
     use bevy::input::gamepad::RawGamepadButtonChangedEvent;
 
@@ -60,7 +25,7 @@ fn jump_button_press_event(gamepad: Entity) -> RawGamepadEvent {
 
 #[test]
 fn accepts_preferred_gamepad() {
-    let mut app = create_test_app();
+    let (mut app, _, _) = create_test_app::<MyAction>();
 
     let preferred_gamepad = app.world_mut().spawn(()).id();
     let mut gamepad_connection_events = app
@@ -97,7 +62,7 @@ fn accepts_preferred_gamepad() {
 
 #[test]
 fn filters_out_other_gamepads() {
-    let mut app = create_test_app();
+    let (mut app, _, _) = create_test_app::<MyAction>();
 
     let preferred_gamepad = app.world_mut().spawn(()).id();
     let other_gamepad = app.world_mut().spawn(()).id();
@@ -116,3 +81,237 @@ fn filters_out_other_gamepads() {
     let action_state = app.world_mut().resource_mut::<ActionState<MyAction>>();
     assert!(action_state.released(&MyAction::Jump));
 }
+
+#[test]
+fn player_indexes_follow_connection_order() {
+    let (mut app, first_gamepad, second_gamepad) = create_test_app::<MyAction>();
+
+    let registry = app.world().resource::<GamepadRegistry>();
+    assert_eq!(registry.gamepad(PlayerIndex(0)), Some(first_gamepad));
+    assert_eq!(registry.gamepad(PlayerIndex(1)), Some(second_gamepad));
+
+    let player_1 = app
+        .world_mut()
+        .spawn(InputManagerBundle::with_map(
+            InputMap::new([(MyAction::Jump, GamepadButton::South)]).with_player(PlayerIndex(0)),
+        ))
+        .id();
+    let player_2 = app
+        .world_mut()
+        .spawn(InputManagerBundle::with_map(
+            InputMap::new([(MyAction::Jump, GamepadButton::South)]).with_player(PlayerIndex(1)),
+        ))
+        .id();
+
+    // Only the second player jumps when the second gamepad is pressed
+    let mut events = app.world_mut().resource_mut::<Events<RawGamepadEvent>>();
+    events.send(jump_button_press_event(second_gamepad));
+    app.update();
+
+    let input_map = app.world().get::<InputMap<MyAction>>(player_2).unwrap();
+    assert_eq!(input_map.gamepad(), Some(second_gamepad));
+    let action_state = app.world().get::<ActionState<MyAction>>(player_1).unwrap();
+    assert!(action_state.released(&MyAction::Jump));
+    let action_state = app.world().get::<ActionState<MyAction>>(player_2).unwrap();
+    assert!(action_state.pressed(&MyAction::Jump));
+}
+
+#[test]
+fn gamepads_connected_at_startup_are_registered() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins).add_plugins(InputPlugin);
+    app.add_plugins(InputManagerPlugin::<MyAction>::default());
+
+    // These gamepads were connected before the app started, so no connection events are sent
+    let first_gamepad = app.world_mut().spawn(Gamepad::default()).id();
+    let second_gamepad = app.world_mut().spawn(Gamepad::default()).id();
+    app.update();
+
+    let registry = app.world().resource::<GamepadRegistry>();
+    assert_eq!(registry.gamepad(PlayerIndex(0)), Some(first_gamepad));
+    assert_eq!(registry.gamepad(PlayerIndex(1)), Some(second_gamepad));
+}
+
+#[test]
+fn players_without_gamepads_have_no_gamepad() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins).add_plugins(InputPlugin);
+    app.add_plugins(InputManagerPlugin::<MyAction>::default());
+
+    let gamepad = app.world_mut().spawn(Gamepad::default()).id();
+    let player = app
+        .world_mut()
+        .spawn(InputManagerBundle::with_map(
+            InputMap::new([(MyAction::Jump, GamepadButton::South)]).with_player(PlayerIndex(5)),
+        ))
+        .id();
+
+    app.update();
+    let registry = app.world().resource::<GamepadRegistry>();
+    assert_eq!(registry.gamepad(PlayerIndex(0)), Some(gamepad));
+
+    // Pressing another player's gamepad does not affect a player without a gamepad
+    let mut events = app.world_mut().resource_mut::<Events<RawGamepadEvent>>();
+    events.send(jump_button_press_event(gamepad));
+    app.update();
+
+    let input_map = app.world().get::<InputMap<MyAction>>(player).unwrap();
+    assert_eq!(input_map.gamepad(), None);
+    let action_state = app.world().get::<ActionState<MyAction>>(player).unwrap();
+    assert!(action_state.released(&MyAction::Jump));
+}
+
+#[test]
+fn associated_gamepads_are_isolated() {
+    use bevy::input::gamepad::RawGamepadAxisChangedEvent;
+
+    let (mut app, gamepad_1, gamepad_2) = create_test_app::<MoveAction>();
+
+    let input_map = InputMap::default().with_axis(MoveAction::Steer, GamepadControlAxis::LEFT_X);
+    let player_1 = app
+        .world_mut()
+        .spawn(InputManagerBundle::with_map(
+            input_map.clone().with_gamepad(gamepad_1),
+        ))
+        .id();
+    let player_2 = app
+        .world_mut()
+        .spawn(InputManagerBundle::with_map(
+            input_map.with_gamepad(gamepad_2),
+        ))
+        .id();
+
+    let steer = |app: &App, player: Entity| {
+        app.world()
+            .get::<ActionState<MoveAction>>(player)
+            .unwrap()
+            .value(&MoveAction::Steer)
+    };
+
+    // Each stick only drives the entity of its own gamepad
+    let mut events = app.world_mut().resource_mut::<Events<RawGamepadEvent>>();
+    events.send(RawGamepadEvent::Axis(RawGamepadAxisChangedEvent::new(
+        gamepad_1,
+        GamepadAxis::LeftStickX,
+        1.0,
+    )));
+    events.send(RawGamepadEvent::Axis(RawGamepadAxisChangedEvent::new(
+        gamepad_2,
+        GamepadAxis::LeftStickX,
+        -1.0,
+    )));
+    app.update();
+
+    assert_eq!(steer(&app, player_1), 1.0);
+    assert_eq!(steer(&app, player_2), -1.0);
+
+    // A despawned gamepad stops driving its entity, without falling back to the other gamepad
+    app.world_mut().despawn(gamepad_1);
+    app.update();
+
+    assert_eq!(steer(&app, player_1), 0.0);
+    assert_eq!(steer(&app, player_2), -1.0);
+}
+
+#[test]
+fn capture_ignores_other_gamepads() {
+    let (mut app, gamepad_1, gamepad_2) = create_test_app::<MyAction>();
+
+    let mut input_map = InputMap::<MyAction>::default().with_gamepad(gamepad_1);
+    input_map.begin_capture(MyAction::Jump);
+    let player = app.world_mut().spawn(input_map).id();
+    app.update();
+
+    // Buttons of another gamepad are not captured
+    let mut events = app.world_mut().resource_mut::<Events<RawGamepadEvent>>();
+    events.send(jump_button_press_event(gamepad_2));
+    app.update();
+
+    let input_map = app.world().get::<InputMap<MyAction>>(player).unwrap();
+    assert_eq!(input_map.pending_capture(), Some(&MyAction::Jump));
+    assert_eq!(input_map.get_buttonlike(&MyAction::Jump), None);
+
+    // While buttons of the associated gamepad are
+    let mut events = app.world_mut().resource_mut::<Events<RawGamepadEvent>>();
+    events.send(jump_button_press_event(gamepad_1));
+    app.update();
+
+    let input_map = app.world().get::<InputMap<MyAction>>(player).unwrap();
+    let expected: Vec<Box<dyn Buttonlike>> = vec![Box::new(SpecificGamepadButton::new(
+        gamepad_1,
+        GamepadButton::South,
+    ))];
+    assert_eq!(input_map.pending_capture(), None);
+    assert_eq!(input_map.get_buttonlike(&MyAction::Jump), Some(&expected));
+}
+
+#[derive(Actionlike, Clone, Copy, Debug, Reflect, PartialEq, Eq, Hash)]
+enum MoveAction {
+    #[actionlike(Axis)]
+    Steer,
+}
+
+#[cfg(feature = "keyboard")]
+#[test]
+fn mixed_device_chords_read_the_associated_gamepad() {
+    use bevy::input::gamepad::RawGamepadButtonChangedEvent;
+
+    #[derive(Actionlike, Clone, Copy, Debug, Reflect, PartialEq, Eq, Hash)]
+    enum ChordAction {
+        Jump,
+        SuperJump,
+    }
+
+    let (mut app, gamepad_1, gamepad_2) = create_test_app::<ChordAction>();
+
+    // The chord shares its gamepad button with a shorter binding
+    let input_map = InputMap::new([(ChordAction::Jump, GamepadButton::South)])
+        .with(
+            ChordAction::SuperJump,
+            ButtonlikeChord::new([KeyCode::ShiftLeft]).with(GamepadButton::South),
+        )
+        .with_gamepad(gamepad_2);
+    app.insert_resource(input_map);
+    app.init_resource::<ActionState<ChordAction>>();
+
+    let set_south = |app: &mut App, gamepad: Entity, value: f32| {
+        let mut events = app.world_mut().resource_mut::<Events<RawGamepadEvent>>();
+        events.send(RawGamepadEvent::Button(RawGamepadButtonChangedEvent::new(
+            gamepad,
+            GamepadButton::South,
+            value,
+        )));
+    };
+
+    // The gamepad member of the chord is not pressed by other gamepads
+    KeyCode::ShiftLeft.press(app.world_mut());
+    set_south(&mut app, gamepad_1, 1.0);
+    app.update();
+    let action_state = app.world().resource::<ActionState<ChordAction>>();
+    assert!(action_state.released(&ChordAction::Jump));
+    assert!(action_state.released(&ChordAction::SuperJump));
+
+    // The keyboard member alone does not press the chord
+    set_south(&mut app, gamepad_1, 0.0);
+    KeyCode::ShiftLeft.release(app.world_mut());
+    set_south(&mut app, gamepad_2, 1.0);
+    app.update();
+    let action_state = app.world().resource::<ActionState<ChordAction>>();
+    assert!(action_state.pressed(&ChordAction::Jump));
+    assert!(action_state.released(&ChordAction::SuperJump));
+
+    // Pressing the key together with the button of the associated gamepad presses the chord,
+    // which takes priority over the shorter binding
+    KeyCode::ShiftLeft.press(app.world_mut());
+    app.update();
+    let action_state = app.world().resource::<ActionState<ChordAction>>();
+    assert!(action_state.pressed(&ChordAction::SuperJump));
+    assert!(action_state.released(&ChordAction::Jump));
+
+    // Releasing the gamepad button releases the chord
+    set_south(&mut app, gamepad_2, 0.0);
+    app.update();
+    let action_state = app.world().resource::<ActionState<ChordAction>>();
+    assert!(action_state.released(&ChordAction::SuperJump));
+    assert!(action_state.released(&ChordAction::Jump));
+}
@@ -0,0 +1,99 @@
+use bevy::prelude::*;
+use bevy::time::Real;
+use leafwing_input_manager::prelude::*;
+use leafwing_input_manager::systems::tick_action_state;
+
+#[derive(Actionlike, Clone, Copy, Debug, Reflect, PartialEq, Eq, Hash)]
+enum Action {
+    Jump,
+    Run,
+}
+
+#[test]
+fn parallel_tick_matches_serial_tick() {
+    let mut app = App::new();
+    app.add_plugins(TaskPoolPlugin::default())
+        .init_resource::<Time<Real>>()
+        .add_systems(Update, tick_action_state::<Action>);
+
+    // Spawn enough entities for the query to be split into several batches
+    let mut expected: Vec<(Entity, ActionState<Action>)> = (0..1000)
+        .map(|i| {
+            let mut action_state = ActionState::default();
+            match i % 4 {
+                0 => action_state.press(&Action::Jump),
+                1 => {
+                    action_state.press(&Action::Run);
+                    action_state.release(&Action::Run);
+                }
+                2 => {
+                    action_state.press(&Action::Jump);
+                    action_state.press(&Action::Run);
+                }
+                _ => {}
+            }
+
+            let entity = app.world_mut().spawn(action_state.clone()).id();
+            (entity, action_state)
+        })
+        .collect();
+
+    let startup = app.world().resource::<Time<Real>>().startup();
+    let mut previous_instant = startup;
+    for _ in 0..3 {
+        app.world_mut().resource_mut::<Time<Real>>().update();
+        app.update();
+
+        // Tick the same action states one by one
+        let current_instant = app.world().resource::<Time<Real>>().last_update().unwrap();
+        for (_, action_state) in expected.iter_mut() {
+            action_state.tick(current_instant, previous_instant);
+        }
+        previous_instant = current_instant;
+
+        for (entity, action_state) in expected.iter() {
+            assert_eq!(
+                app.world().get::<ActionState<Action>>(*entity),
+                Some(action_state)
+            );
+        }
+    }
+}
+
+#[derive(Component)]
+struct Player;
+
+#[test]
+fn tick_all_entities_only_ticks_matching_entities() {
+    fn tick_players(
+        mut query: Query<&mut ActionState<Action>, With<Player>>,
+        time: Res<Time<Real>>,
+    ) {
+        let current_instant = time.last_update().unwrap_or_else(|| time.startup());
+        ActionState::tick_all_entities(&mut query, current_instant, time.startup());
+    }
+
+    let mut app = App::new();
+    app.add_plugins(TaskPoolPlugin::default())
+        .init_resource::<Time<Real>>()
+        .add_systems(Update, tick_players);
+
+    let mut action_state = ActionState::default();
+    action_state.press(&Action::Jump);
+    let players: Vec<Entity> = (0..100)
+        .map(|_| app.world_mut().spawn((action_state.clone(), Player)).id())
+        .collect();
+    let other = app.world_mut().spawn(action_state.clone()).id();
+
+    app.world_mut().resource_mut::<Time<Real>>().update();
+    app.update();
+
+    // Matching entities are ticked, while the others are left untouched
+    for player in players {
+        let action_state = app.world().get::<ActionState<Action>>(player).unwrap();
+        assert!(action_state.pressed(&Action::Jump));
+        assert!(!action_state.just_pressed(&Action::Jump));
+    }
+    let action_state = app.world().get::<ActionState<Action>>(other).unwrap();
+    assert!(action_state.just_pressed(&Action::Jump));
+}
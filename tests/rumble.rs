@@ -0,0 +1,109 @@
+#![cfg(feature = "rumble")]
+
+use bevy::input::gamepad::{
+    GamepadRumbleIntensity, GamepadRumbleRequest, RawGamepadButtonChangedEvent, RawGamepadEvent,
+};
+use bevy::prelude::*;
+use bevy::utils::Duration;
+use leafwing_input_manager::prelude::*;
+use leafwing_input_manager::rumble::{ActionRumble, RumbleEffect};
+
+mod common;
+use common::create_test_app;
+
+#[derive(Actionlike, Clone, Copy, Debug, Reflect, PartialEq, Eq, Hash)]
+enum Action {
+    Shoot,
+    Jump,
+}
+
+const SHOOT_RUMBLE: RumbleEffect =
+    RumbleEffect::new(GamepadRumbleIntensity::MAX, Duration::from_millis(150));
+
+fn set_button(app: &mut App, gamepad: Entity, button: GamepadButton, value: f32) {
+    let mut events = app.world_mut().resource_mut::<Events<RawGamepadEvent>>();
+    events.send(RawGamepadEvent::Button(RawGamepadButtonChangedEvent::new(
+        gamepad, button, value,
+    )));
+}
+
+fn drain_rumble_requests(app: &mut App) -> Vec<(Entity, Duration)> {
+    app.world_mut()
+        .resource_mut::<Events<GamepadRumbleRequest>>()
+        .drain()
+        .map(|request| match request {
+            GamepadRumbleRequest::Add {
+                gamepad, duration, ..
+            } => (gamepad, duration),
+            GamepadRumbleRequest::Stop { .. } => panic!("unexpected stop request"),
+        })
+        .collect()
+}
+
+#[test]
+fn rumbles_associated_gamepad_on_just_press() {
+    let (mut app, _, gamepad_2) = create_test_app::<Action>();
+
+    let input_map = InputMap::new([
+        (Action::Shoot, GamepadButton::South),
+        (Action::Jump, GamepadButton::East),
+    ])
+    .with_gamepad(gamepad_2);
+    app.world_mut().spawn((
+        input_map,
+        ActionState::<Action>::default(),
+        ActionRumble::default().with(Action::Shoot, SHOOT_RUMBLE),
+    ));
+    drain_rumble_requests(&mut app);
+
+    // Pressing an action rumbles the associated gamepad
+    set_button(&mut app, gamepad_2, GamepadButton::South, 1.0);
+    app.update();
+    assert_eq!(
+        drain_rumble_requests(&mut app),
+        vec![(gamepad_2, Duration::from_millis(150))]
+    );
+
+    // Holding it doesn't rumble again
+    app.update();
+    assert!(drain_rumble_requests(&mut app).is_empty());
+
+    // Actions without an effect don't rumble
+    set_button(&mut app, gamepad_2, GamepadButton::East, 1.0);
+    app.update();
+    assert!(drain_rumble_requests(&mut app).is_empty());
+
+    // Pressing the action again does
+    set_button(&mut app, gamepad_2, GamepadButton::South, 0.0);
+    app.update();
+    set_button(&mut app, gamepad_2, GamepadButton::South, 1.0);
+    app.update();
+    assert_eq!(drain_rumble_requests(&mut app).len(), 1);
+}
+
+#[test]
+fn rumbles_first_gamepad_without_association() {
+    let (mut app, gamepad_1, gamepad_2) = create_test_app::<Action>();
+
+    app.world_mut().spawn((
+        InputMap::new([(Action::Shoot, GamepadButton::South)]),
+        ActionState::<Action>::default(),
+        ActionRumble::default().with(Action::Shoot, SHOOT_RUMBLE),
+    ));
+    drain_rumble_requests(&mut app);
+
+    // Input is read from the first gamepad, so that is the one that rumbles
+    let first_gamepad = app
+        .world_mut()
+        .query_filtered::<Entity, With<Gamepad>>()
+        .iter(app.world())
+        .next()
+        .unwrap();
+    set_button(&mut app, gamepad_1, GamepadButton::South, 1.0);
+    set_button(&mut app, gamepad_2, GamepadButton::South, 1.0);
+    app.update();
+    assert_eq!(
+        drain_rumble_requests(&mut app),
+        vec![(first_gamepad, Duration::from_millis(150))]
+    );
+}
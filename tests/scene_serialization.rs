@@ -0,0 +1,64 @@
+#![cfg(feature = "keyboard")]
+
+use bevy::ecs::entity::EntityHashMap;
+use bevy::input::InputPlugin;
+use bevy::prelude::*;
+use bevy::scene::serde::SceneDeserializer;
+use bevy::scene::DynamicSceneBuilder;
+use leafwing_input_manager::prelude::*;
+use serde::de::DeserializeSeed;
+
+#[derive(Actionlike, Clone, Copy, Debug, Reflect, PartialEq, Eq, Hash)]
+enum Action {
+    Jump,
+    #[actionlike(DualAxis)]
+    Move,
+}
+
+fn create_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        InputPlugin,
+        InputManagerPlugin::<Action>::default(),
+    ));
+    app
+}
+
+#[test]
+fn input_map_scene_round_trip() {
+    let mut app = create_app();
+
+    let gamepad = app.world_mut().spawn_empty().id();
+    let input_map = InputMap::new([(Action::Jump, KeyCode::Space)])
+        .with(Action::Jump, ModifierKey::Shift)
+        .with_dual_axis(Action::Move, VirtualDPad::wasd())
+        .with_gamepad(gamepad);
+    let player = app.world_mut().spawn(input_map.clone()).id();
+
+    let scene = DynamicSceneBuilder::from_world(app.world())
+        .extract_entities([gamepad, player].into_iter())
+        .build();
+
+    let type_registry = app.world().resource::<AppTypeRegistry>().clone();
+    let serialized = scene.serialize(&type_registry.read()).unwrap();
+
+    let scene_deserializer = SceneDeserializer {
+        type_registry: &type_registry.read(),
+    };
+    let mut deserializer = ron::de::Deserializer::from_str(&serialized).unwrap();
+    let deserialized = scene_deserializer.deserialize(&mut deserializer).unwrap();
+
+    let mut entity_map = EntityHashMap::default();
+    deserialized
+        .write_to_world(app.world_mut(), &mut entity_map)
+        .unwrap();
+
+    let loaded_player = entity_map[&player];
+    let loaded_gamepad = entity_map[&gamepad];
+    let loaded_input_map = app.world().get::<InputMap<Action>>(loaded_player).unwrap();
+
+    // The bindings are preserved, and the gamepad points at the freshly loaded entity
+    assert_eq!(loaded_input_map.gamepad(), Some(loaded_gamepad));
+    assert_eq!(loaded_input_map.clone().with_gamepad(gamepad), input_map);
+}
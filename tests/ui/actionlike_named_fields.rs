@@ -0,0 +1,12 @@
+use bevy::prelude::Reflect;
+use leafwing_input_manager::Actionlike;
+
+#[derive(Actionlike, Debug, Hash, PartialEq, Eq, Clone, Copy, Reflect)]
+enum Action {
+    Jump,
+    Move { x: i8, y: i8 },
+    #[actionlike(Axis)]
+    Zoom(u8),
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use bevy::prelude::Reflect;
+use leafwing_input_manager::Actionlike;
+
+#[derive(Actionlike, Debug, Hash, PartialEq, Eq, Clone, Copy, Reflect)]
+enum Action {
+    Run,
+    UseItem(usize),
+}
+
+fn main() {}